@@ -2,4 +2,14 @@ use solana_program::pubkey;
 use solana_program::pubkey::Pubkey;
 
 pub const FEE_BPS: u16 = 0;
-pub const FEE_ACCOUNT: Pubkey = pubkey!("FEERpjXuYbmyfbKMyNcLvEBTbyJh2nRsNbBWEUdar3e3");
\ No newline at end of file
+pub const FEE_ACCOUNT: Pubkey = pubkey!("FEERpjXuYbmyfbKMyNcLvEBTbyJh2nRsNbBWEUdar3e3");
+
+/// Decimals of listing prices denominated in the reference unit (e.g. micro-USD)
+pub const REFERENCE_PRICE_DECIMALS: u32 = 6;
+pub const MAX_PRICE_AGE_SECONDS: i64 = 60;
+pub const MAX_PRICE_CONFIDENCE_BPS: u64 = 200;
+/// Pyth oracle programs on mainnet-beta and devnet
+pub const PYTH_PROGRAM_IDS: [Pubkey; 2] = [
+	pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH"),
+	pubkey!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s"),
+];
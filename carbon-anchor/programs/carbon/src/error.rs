@@ -38,7 +38,151 @@ pub enum Error {
 	#[msg("Invalid collection config")]
 	InvalidCollectionConfig,
 
-	/// 11
+	/// 15
 	#[msg("Invalid edition")]
 	InvalidEdition,
+	#[msg("Invalid price feed")]
+	InvalidPriceFeed,
+	#[msg("Price is stale")]
+	StalePrice,
+	#[msg("Price confidence interval too wide")]
+	PriceConfidenceExceeded,
+	#[msg("Invalid start time")]
+	InvalidStartTime,
+
+	/// 20
+	#[msg("Listing has not started")]
+	ListingNotStarted,
+	#[msg("Listing not expired")]
+	ListingNotExpired,
+	#[msg("Too many items in swap")]
+	TooManySwapItems,
+	#[msg("Swap must exchange something on both sides")]
+	EmptySwap,
+
+	/// 25
+	#[msg("Swap expired")]
+	SwapExpired,
+	#[msg("Invalid taker")]
+	InvalidTaker,
+	#[msg("Nft is rented")]
+	NftIsRented,
+	#[msg("Invalid rental duration")]
+	InvalidRentalDuration,
+	#[msg("Rental term has not ended")]
+	RentalTermNotEnded,
+
+	/// 30
+	#[msg("Invalid owner")]
+	InvalidOwner,
+	#[msg("Loan is active")]
+	LoanIsActive,
+	#[msg("Loan is not active")]
+	LoanNotActive,
+	#[msg("Loan is overdue")]
+	LoanOverdue,
+	#[msg("Loan is not overdue")]
+	LoanNotOverdue,
+
+	/// 35
+	#[msg("Invalid loan duration")]
+	InvalidLoanDuration,
+	#[msg("Invalid borrower")]
+	InvalidBorrower,
+	#[msg("Custodial programmable NFTs can only be transferred to the marketplace authority")]
+	ProgrammableNftInCustody,
+	#[msg("Invalid amount")]
+	InvalidAmount,
+	#[msg("Listing has less than the amount remaining")]
+	InsufficientListingAmount,
+
+	/// 40
+	#[msg("Order book is full")]
+	OrderBookFull,
+	#[msg("Order not found")]
+	OrderNotFound,
+	#[msg("Invalid open orders account")]
+	InvalidOpenOrders,
+	#[msg("Bonding curve supply exhausted")]
+	SupplyExhausted,
+	#[msg("Bonding curve does not allow burning back")]
+	BurnBackDisabled,
+
+	/// 45
+	#[msg("Insufficient reserve")]
+	InsufficientReserve,
+	#[msg("Unsupported mint standard")]
+	UnsupportedMintStandard,
+	#[msg("Invalid metadata")]
+	InvalidMetadata,
+	#[msg("Invalid recipe")]
+	InvalidRecipe,
+	#[msg("NFTs do not match the recipe inputs")]
+	InvalidRecipeInputs,
+
+	/// 50
+	#[msg("Invalid prizes")]
+	InvalidPrizes,
+	#[msg("Seed does not match its commitment")]
+	InvalidSeed,
+	#[msg("Randomness can't be revealed in the slot it was requested in")]
+	RevealTooEarly,
+	#[msg("Randomness for the requested slot is not available")]
+	RandomnessUnavailable,
+	#[msg("Mystery box has not been opened")]
+	BoxNotOpened,
+
+	/// 55
+	#[msg("Raffle is not open")]
+	RaffleNotOpen,
+	#[msg("Raffle has not ended")]
+	RaffleNotEnded,
+	#[msg("Not enough raffle tickets left")]
+	RaffleSoldOut,
+	#[msg("Raffle has no tickets sold")]
+	RaffleHasNoTickets,
+	#[msg("Raffle is not closed")]
+	RaffleNotClosed,
+
+	/// 60
+	#[msg("Raffle has not been drawn")]
+	RaffleNotDrawn,
+	#[msg("Ticket is not the winning ticket")]
+	NotWinningTicket,
+	#[msg("Raffle has tickets sold")]
+	RaffleHasTickets,
+	#[msg("Winning ticket must be claimed")]
+	WinningTicketUnclaimed,
+	#[msg("Invalid rent payer")]
+	InvalidRentPayer,
+
+	/// 65
+	#[msg("Invalid session")]
+	InvalidSession,
+	#[msg("Session expired")]
+	SessionExpired,
+	#[msg("Action is outside the scope of the session")]
+	SessionScopeExceeded,
+	#[msg("Action requires the owner's signature")]
+	OwnerSignatureRequired,
+	#[msg("Invalid authorization")]
+	InvalidAuthorization,
+
+	/// 70
+	#[msg("Authorization expired")]
+	AuthorizationExpired,
+	#[msg("Authorization has not expired")]
+	AuthorizationNotExpired,
+	#[msg("Invalid treasury")]
+	InvalidTreasury,
+	#[msg("Draw can still be revealed")]
+	DrawNotExpired,
+	#[msg("Raffle has not expired")]
+	RaffleNotExpired,
+
+	/// 75
+	#[msg("Invalid mint record")]
+	InvalidMintRecord,
+	#[msg("Raffle can still be closed")]
+	RaffleCanBeClosed,
 }
\ No newline at end of file
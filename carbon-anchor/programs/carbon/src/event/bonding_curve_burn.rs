@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct BondingCurveBurn {
+	pub item_type: [u8;32],
+	pub item_id: [u8;32],
+	pub mint: Pubkey,
+	pub owner: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	pub currency_mint: Pubkey,
+	pub refund: u64,
+	/// Units in circulation after the burn
+	pub supply: u64,
+}
\ No newline at end of file
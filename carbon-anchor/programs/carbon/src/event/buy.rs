@@ -14,4 +14,15 @@ pub struct Buy {
 	pub marketplace_authority: Pubkey,
 	pub collection_mint: Pubkey,
 	pub fee_config: FeeConfig,
+	pub price_feed: Pubkey,
+	/// Amount received by the seller, net of royalties, marketplace fees and transfer fees
+	pub seller_amount: u64,
+	/// Amount received by the creators, net of transfer fees
+	pub royalty_amount: u64,
+	/// Amount received by the marketplace fee account, net of transfer fees
+	pub fee_amount: u64,
+	/// Amount bought, in base units of the mint. 1 for NFTs and virtual items
+	pub amount: u64,
+	/// Amount left for sale on the listing after this buy
+	pub remaining_amount: u64,
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct Craft {
+	pub recipe_id: [u8;32],
+	/// Item ID of the crafted item
+	pub item_id: [u8;32],
+	pub mint: Pubkey,
+	pub player: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	/// Mints of the NFTs burned as inputs
+	pub burned_mints: Vec<Pubkey>,
+}
\ No newline at end of file
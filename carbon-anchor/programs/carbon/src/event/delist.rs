@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
 use solana_program::pubkey::Pubkey;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DelistReason {
+	Seller,
+	Expired,
+}
+
 #[event]
 pub struct Delist {
 	pub item_id: [u8;32],
 	pub seller: Pubkey,
 	pub marketplace_authority: Pubkey,
 	pub collection_mint: Pubkey,
+	pub reason: DelistReason,
 }
\ No newline at end of file
@@ -7,10 +7,14 @@ pub struct List {
 	pub item_id: [u8;32],
 	pub price: u64,
 	pub expiry: i64,
+	pub start_time: i64,
 	pub seller: Pubkey,
 	pub is_virtual: bool,
 	pub currency_mint: Pubkey,
 	pub collection_mint: Pubkey,
 	pub marketplace_authority: Pubkey,
 	pub fee_config: FeeConfig,
+	pub price_feed: Pubkey,
+	/// Amount listed, in base units of the mint. 1 for NFTs and virtual items
+	pub amount: u64,
 }
\ No newline at end of file
@@ -7,10 +7,12 @@ pub struct ListingUpdate {
 	pub item_id: [u8;32],
 	pub price: u64,
 	pub expiry: i64,
+	pub start_time: i64,
 	pub seller: Pubkey,
 	pub is_virtual: bool,
 	pub currency_mint: Pubkey,
 	pub collection_mint: Pubkey,
 	pub marketplace_authority: Pubkey,
 	pub fee_config: FeeConfig,
+	pub price_feed: Pubkey,
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct LoanCancel {
+	pub loan_id: [u8;32],
+	pub lender: Pubkey,
+	pub marketplace_authority: Pubkey,
+}
\ No newline at end of file
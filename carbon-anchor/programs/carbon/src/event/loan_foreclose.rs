@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct LoanForeclose {
+	pub loan_id: [u8;32],
+	pub lender: Pubkey,
+	pub borrower: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub mint: Pubkey,
+}
\ No newline at end of file
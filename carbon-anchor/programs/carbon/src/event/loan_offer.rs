@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct LoanOffer {
+	pub loan_id: [u8;32],
+	pub lender: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	pub currency_mint: Pubkey,
+	pub principal: u64,
+	pub interest: u64,
+	pub duration: i64,
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct LoanRepay {
+	pub loan_id: [u8;32],
+	pub lender: Pubkey,
+	pub borrower: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub mint: Pubkey,
+	pub amount: u64,
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct LoanTake {
+	pub loan_id: [u8;32],
+	pub lender: Pubkey,
+	pub borrower: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub mint: Pubkey,
+	pub due_time: i64,
+}
\ No newline at end of file
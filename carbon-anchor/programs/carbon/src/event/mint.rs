@@ -8,4 +8,11 @@ pub struct Mint {
 	pub buyer: Pubkey,
 	pub marketplace_authority: Pubkey,
 	pub collection_mint: Pubkey,
+	/// Price paid for the primary sale, 0 if minted for free
+	pub price: u64,
+	pub currency_mint: Pubkey,
+	/// Amount received by the treasury, net of marketplace fees and transfer fees
+	pub treasury_amount: u64,
+	/// Amount received by the marketplace fee account, net of transfer fees
+	pub fee_amount: u64,
 }
\ No newline at end of file
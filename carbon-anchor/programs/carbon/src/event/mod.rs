@@ -5,6 +5,34 @@ mod buy;
 mod mint;
 mod custody;
 mod uncustody;
+mod swap_create;
+mod swap_take;
+mod swap_cancel;
+mod rental_list;
+mod rent;
+mod rental_end;
+mod loan_offer;
+mod loan_take;
+mod loan_repay;
+mod loan_foreclose;
+mod loan_cancel;
+mod order_place;
+mod order_cancel;
+mod order_fill;
+mod bonding_curve_buy;
+mod bonding_curve_burn;
+mod craft;
+mod mystery_box_open;
+mod mystery_box_reveal;
+mod raffle_create;
+mod raffle_ticket_buy;
+mod raffle_draw;
+mod raffle_claim;
+mod raffle_cancel;
+mod raffle_expire;
+mod raffle_ticket_refund;
+mod session_create;
+mod session_revoke;
 
 pub use list::*;
 pub use listing_update::*;
@@ -12,4 +40,32 @@ pub use delist::*;
 pub use buy::*;
 pub use mint::*;
 pub use custody::*;
-pub use uncustody::*;
\ No newline at end of file
+pub use uncustody::*;
+pub use swap_create::*;
+pub use swap_take::*;
+pub use swap_cancel::*;
+pub use rental_list::*;
+pub use rent::*;
+pub use rental_end::*;
+pub use loan_offer::*;
+pub use loan_take::*;
+pub use loan_repay::*;
+pub use loan_foreclose::*;
+pub use loan_cancel::*;
+pub use order_place::*;
+pub use order_cancel::*;
+pub use order_fill::*;
+pub use bonding_curve_buy::*;
+pub use bonding_curve_burn::*;
+pub use craft::*;
+pub use mystery_box_open::*;
+pub use mystery_box_reveal::*;
+pub use raffle_create::*;
+pub use raffle_ticket_buy::*;
+pub use raffle_draw::*;
+pub use raffle_claim::*;
+pub use raffle_cancel::*;
+pub use raffle_expire::*;
+pub use raffle_ticket_refund::*;
+pub use session_create::*;
+pub use session_revoke::*;
\ No newline at end of file
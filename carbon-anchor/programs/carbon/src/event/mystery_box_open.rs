@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct MysteryBoxOpen {
+	pub box_id: [u8;32],
+	/// Mint of the burned box NFT
+	pub box_mint: Pubkey,
+	pub holder: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	pub open_slot: u64,
+}
\ No newline at end of file
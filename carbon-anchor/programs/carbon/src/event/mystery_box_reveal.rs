@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct MysteryBoxReveal {
+	pub box_id: [u8;32],
+	/// Mint of the burned box NFT
+	pub box_mint: Pubkey,
+	pub holder: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	/// Index of the prize drawn in the prize table
+	pub prize_index: u8,
+	/// Item ID of the prize
+	pub item_id: [u8;32],
+	/// Mint of the prize NFT
+	pub mint: Pubkey,
+	pub randomness: [u8;32],
+}
\ No newline at end of file
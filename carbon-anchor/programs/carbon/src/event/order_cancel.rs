@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+use crate::state::Side;
+
+#[event]
+pub struct OrderCancel {
+	pub market: Pubkey,
+	pub order_id: u64,
+	pub owner: Pubkey,
+	pub side: Side,
+	/// Lots left unfilled when the order was cancelled
+	pub quantity: u64,
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct OrderFill {
+	pub market: Pubkey,
+	pub bid_order_id: u64,
+	pub ask_order_id: u64,
+	/// Price per lot of the older of the two orders
+	pub price: u64,
+	/// Lots exchanged
+	pub quantity: u64,
+	/// Quote units taken from the seller's proceeds as marketplace fees
+	pub fee_amount: u64,
+}
\ No newline at end of file
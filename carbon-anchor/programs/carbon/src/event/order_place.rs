@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+use crate::state::Side;
+
+#[event]
+pub struct OrderPlace {
+	pub market: Pubkey,
+	pub order_id: u64,
+	pub owner: Pubkey,
+	pub side: Side,
+	pub price: u64,
+	pub quantity: u64,
+}
\ No newline at end of file
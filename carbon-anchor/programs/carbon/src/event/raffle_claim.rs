@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RaffleClaim {
+	pub raffle_id: [u8;32],
+	pub winner: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub is_virtual: bool,
+	pub item_id: [u8;32],
+	/// Mint of the prize NFT
+	pub mint: Pubkey,
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+use crate::FeeConfig;
+
+#[event]
+pub struct RaffleCreate {
+	pub raffle_id: [u8;32],
+	pub creator: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub is_virtual: bool,
+	/// Item ID of the prize, the mint if an escrowed NFT
+	pub item_id: [u8;32],
+	pub currency_mint: Pubkey,
+	pub ticket_price: u64,
+	pub max_tickets: u32,
+	pub end_time: i64,
+	pub fee_config: FeeConfig,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RaffleDraw {
+	pub raffle_id: [u8;32],
+	pub creator: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub winning_ticket: u32,
+	pub tickets_sold: u32,
+	pub randomness: [u8;32],
+	pub currency_mint: Pubkey,
+	/// Proceeds received by the creator
+	pub creator_amount: u64,
+	pub fee_amount: u64,
+}
\ No newline at end of file
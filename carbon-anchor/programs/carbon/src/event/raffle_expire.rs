@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RaffleExpire {
+	pub raffle_id: [u8;32],
+	pub creator: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub tickets_sold: u32,
+}
\ No newline at end of file
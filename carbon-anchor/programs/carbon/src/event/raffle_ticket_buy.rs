@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RaffleTicketBuy {
+	pub raffle_id: [u8;32],
+	pub buyer: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub first_ticket: u32,
+	pub count: u32,
+	pub price: u64,
+	pub amount: u64,
+}
\ No newline at end of file
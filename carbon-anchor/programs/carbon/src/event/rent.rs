@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct Rent {
+	pub mint: Pubkey,
+	pub owner: Pubkey,
+	pub renter: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub price: u64,
+	pub rented_until: i64,
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RentalEnd {
+	pub mint: Pubkey,
+	pub owner: Pubkey,
+	pub renter: Pubkey,
+	pub marketplace_authority: Pubkey,
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+use crate::FeeConfig;
+
+#[event]
+pub struct RentalList {
+	pub mint: Pubkey,
+	pub owner: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub price_per_day: u64,
+	pub max_days: u16,
+	pub apply_royalties: bool,
+	pub fee_config: FeeConfig,
+}
\ No newline at end of file
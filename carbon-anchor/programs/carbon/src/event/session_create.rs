@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+use crate::SessionScope;
+
+#[event]
+pub struct SessionCreate {
+	pub owner: Pubkey,
+	pub session_key: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub expiry: i64,
+	pub scope: SessionScope,
+	pub collection_mints: Vec<Pubkey>,
+	pub max_price: u64,
+	pub min_price: u64,
+}
\ No newline at end of file
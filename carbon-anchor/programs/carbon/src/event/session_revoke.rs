@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct SessionRevoke {
+	pub owner: Pubkey,
+	pub session_key: Pubkey,
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct SwapCreate {
+	pub swap_id: [u8;32],
+	pub maker: Pubkey,
+	pub taker: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	pub currency_mint: Pubkey,
+	pub maker_mints: Vec<Pubkey>,
+	pub maker_amount: u64,
+	pub taker_mints: Vec<Pubkey>,
+	pub taker_amount: u64,
+	pub royalty_per_nft: u64,
+	pub expiry: i64,
+}
\ No newline at end of file
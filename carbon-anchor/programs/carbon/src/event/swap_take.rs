@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct SwapTake {
+	pub swap_id: [u8;32],
+	pub maker: Pubkey,
+	pub taker: Pubkey,
+	pub marketplace_authority: Pubkey,
+	pub collection_mint: Pubkey,
+	pub royalties_paid: u64,
+}
\ No newline at end of file
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata
+};
+use crate::{
+	state::{BondingCurve, CollectionConfig, MintRecord},
+	event::BondingCurveBurn,
+	util::{assert_keys_equal, is_native_mint, transfer_lamports, transfer_spl, BurnableNft},
+	error::Error
+};
+
+#[derive(Accounts)]
+#[instruction(item_id: [u8;32], serial: u64)]
+pub struct BurnBondingCurve<'info> {
+	/// Holder of the NFT.
+	#[account(mut)]
+	pub holder: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the holder pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Wallet that paid the rent of the mint record.
+	/// CHECK: Safe because of mint_record constraint
+	#[account(mut)]
+	pub rent_payer: UncheckedAccount<'info>,
+
+	/// CHECK: Safe due to mint_record constraint
+	#[account(mut)]
+	pub mint: UncheckedAccount<'info>,
+
+	/// Holder NFT token account.
+	/// CHECK: Verified in burn CPI
+	#[account(mut)]
+	pub holder_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the NFT.
+	/// CHECK: Verified in handler and burn CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the NFT.
+	/// CHECK: Verified in handler and burn CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in burn CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_config.collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		mut,
+		seeds = [
+			BondingCurve::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			bonding_curve.item_type.as_ref()
+		],
+		bump = bonding_curve.bump[0],
+		has_one = collection_config,
+		constraint = bonding_curve.item_id(serial) == item_id,
+		constraint = serial < bonding_curve.minted,
+	)]
+	pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+	#[account(
+		mut,
+		close = rent_payer,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			item_id.as_ref(),
+		],
+		bump,
+		has_one = mint @ Error::InvalidMint,
+		constraint = mint_record.payer == rent_payer.key() @ Error::InvalidRentPayer,
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Burns an NFT minted by the curve and refunds the holder from the curve's reserve. The refund is
+/// the curve's refund share of the price of the last unit in circulation.
+///
+/// When the curve is priced in an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account
+/// 2. bonding curve currency ata
+/// 3. holder currency ata
+pub fn burn_bonding_curve_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, BurnBondingCurve<'info>>,
+	item_id: [u8;32],
+	_serial: u64
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.holder).to_account_info();
+	BurnableNft {
+		metadata: ctx.accounts.metadata_account.to_account_info(),
+		owner: ctx.accounts.holder.to_account_info(),
+		mint: ctx.accounts.mint.to_account_info(),
+		token_account: ctx.accounts.holder_token_account.to_account_info(),
+		edition: ctx.accounts.edition.to_account_info(),
+		collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+	}.burn(
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info()
+	)?;
+
+	let bonding_curve_account = &ctx.accounts.bonding_curve.to_account_info();
+	let bonding_curve = &mut ctx.accounts.bonding_curve;
+	let refund = bonding_curve.burn()?;
+
+	if is_native_mint(bonding_curve.currency_mint) {
+		transfer_lamports(
+			bonding_curve_account,
+			&ctx.accounts.holder.to_account_info(),
+			refund
+		)?;
+	} else {
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let currency_mint = next_account_info(remaining_accounts)?;
+		let reserve_currency_account = next_account_info(remaining_accounts)?;
+		let holder_currency_account = next_account_info(remaining_accounts)?;
+		assert_keys_equal(bonding_curve.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+
+		transfer_spl(
+			bonding_curve_account,
+			&ctx.accounts.holder.to_account_info(),
+			reserve_currency_account,
+			holder_currency_account,
+			currency_mint,
+			&payer,
+			&ctx.accounts.associated_token_program.to_account_info(),
+			&ctx.accounts.token_program.to_account_info(),
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None,
+			Some(&bonding_curve.auth_seeds()),
+			None,
+			refund
+		)?;
+	}
+
+	emit!(BondingCurveBurn {
+		item_type: bonding_curve.item_type,
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		owner: ctx.accounts.holder.key(),
+		marketplace_authority: bonding_curve.marketplace_authority,
+		collection_mint: ctx.accounts.collection_config.collection_mint,
+		currency_mint: bonding_curve.currency_mint,
+		refund,
+		supply: bonding_curve.supply,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		update_primary_sale_happened_via_token,
+		VerifySizedCollectionItem,
+		UpdatePrimarySaleHappenedViaToken
+	},
+	metadata
+};
+use crate::{
+	state::{BondingCurve, CollectionConfig, MintRecord},
+	event::BondingCurveBuy,
+	util::{assert_keys_equal, is_native_mint, mint_nft, transfer_sol, transfer_spl},
+};
+
+#[derive(Accounts)]
+#[instruction(item_id: [u8;32])]
+pub struct BuyBondingCurve<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Marketplace authority wallet.
+	pub marketplace_authority: Signer<'info>,
+
+	/// The new mint to be used for the NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Buyer NFT token account.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub buyer_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the NFT to mint.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		mut,
+		seeds = [
+			BondingCurve::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			bonding_curve.item_type.as_ref()
+		],
+		bump = bonding_curve.bump[0],
+		has_one = collection_config,
+		constraint = bonding_curve.item_id(bonding_curve.minted) == item_id,
+	)]
+	pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mints the next unit of the curve to the buyer at the curve's current price, paid into the
+/// curve's reserve. The item ID is derived from the curve and the serial of the unit.
+///
+/// When buying with an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account
+/// 2. buyer currency ata
+/// 3. bonding curve currency ata
+pub fn buy_bonding_curve_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, BuyBondingCurve<'info>>,
+	item_id: [u8;32],
+	max_price: u64
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let bonding_curve_account = &ctx.accounts.bonding_curve.to_account_info();
+	let bonding_curve = &mut ctx.accounts.bonding_curve;
+	let price = bonding_curve.buy(max_price)?;
+
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
+
+	let data = &ctx.accounts.collection_config.get_mpl_metadata(bonding_curve.metadata.clone())?;
+	// Mint the NFT to the buyer.
+	mint_nft(
+		&payer,
+		&ctx.accounts.buyer.to_account_info(),
+		&ctx.accounts.buyer_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data.clone(),
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		None
+	)?;
+
+	// Mark the item as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: payer.clone(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: ctx.accounts.marketplace_authority.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+		),
+		None
+	)?;
+
+	// Mark that the primary sale happened.
+	update_primary_sale_happened_via_token(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			UpdatePrimarySaleHappenedViaToken {
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				owner: ctx.accounts.buyer.to_account_info(),
+				token: ctx.accounts.buyer_token_account.to_account_info(),
+			}
+		)
+	)?;
+
+	if is_native_mint(bonding_curve.currency_mint) {
+		transfer_sol(
+			&ctx.accounts.buyer.to_account_info(),
+			bonding_curve_account,
+			&ctx.accounts.system_program.to_account_info(),
+			None,
+			price
+		)?;
+	} else {
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let currency_mint = next_account_info(remaining_accounts)?;
+		let buyer_currency_account = next_account_info(remaining_accounts)?;
+		let reserve_currency_account = next_account_info(remaining_accounts)?;
+		assert_keys_equal(bonding_curve.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+
+		transfer_spl(
+			&ctx.accounts.buyer.to_account_info(),
+			bonding_curve_account,
+			buyer_currency_account,
+			reserve_currency_account,
+			currency_mint,
+			&payer,
+			&ctx.accounts.associated_token_program.to_account_info(),
+			&ctx.accounts.token_program.to_account_info(),
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None,
+			None,
+			None,
+			price
+		)?;
+	}
+
+	emit!(BondingCurveBuy {
+		item_type: bonding_curve.item_type,
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		buyer: ctx.accounts.buyer.key(),
+		marketplace_authority: bonding_curve.marketplace_authority,
+		collection_mint: ctx.accounts.collection_mint.key(),
+		currency_mint: bonding_curve.currency_mint,
+		price,
+		supply: bonding_curve.supply,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	associated_token::AssociatedToken,
+	token::{self, Mint, Token, Transfer}
+};
+use crate::{
+	state::FungibleListing,
+	event::Buy,
+	util::{assert_is_ata, assert_keys_equal, is_native_mint, make_ata, next_currency_accounts, release_escrow, transfer_sol, transfer_spl},
+	error::Error
+};
+
+#[derive(Accounts)]
+pub struct BuyFungible<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Seller wallet.
+	/// CHECK: Safe because of listing constraint
+	#[account(mut)]
+	pub seller: UncheckedAccount<'info>,
+
+	/// Wallet that paid the rent of the listing.
+	/// CHECK: Safe because of listing constraint
+	#[account(mut)]
+	pub rent_payer: UncheckedAccount<'info>,
+
+	/// Mint of the token for sale.
+	pub mint: Box<Account<'info, Mint>>,
+
+	#[account(
+		mut,
+		seeds = [
+			FungibleListing::PREFIX.as_bytes(),
+			listing.namespace.as_ref(),
+			seller.key().as_ref(),
+			mint.key().as_ref()
+		],
+		bump = listing.bump[0],
+		has_one = seller @ Error::InvalidSeller,
+		has_one = mint @ Error::InvalidMint,
+		constraint = listing.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+		constraint = listing.payer == rent_payer.key() @ Error::InvalidRentPayer,
+	)]
+	pub listing: Box<Account<'info, FungibleListing>>,
+
+	/// Listing's token account holding the tokens for sale.
+	/// CHECK: Validated in handler
+	#[account(mut)]
+	pub escrow_token_account: UncheckedAccount<'info>,
+
+	/// Buyer's token account of the mint, created if it does not exist.
+	/// CHECK: Validated in handler
+	#[account(mut)]
+	pub buyer_token_account: UncheckedAccount<'info>,
+
+	/// Account to send fees to.
+	/// CHECK: Safe because of listing constraint
+	#[account(mut)]
+	pub fee_account: UncheckedAccount<'info>,
+
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Buys part or all of the amount left on the listing. The price is the amount times the price of
+/// one whole token, rounded up, and the listing is closed once nothing is left.
+///
+/// When buying with an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
+/// 2. buyer currency ata
+/// 3. seller currency ata
+/// 4. marketplace fee currency ata
+pub fn buy_fungible_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, BuyFungible<'info>>,
+	amount: u64,
+	max_price: u64
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let listing_account = &ctx.accounts.listing.to_account_info();
+	let listing = &mut ctx.accounts.listing;
+	let price = listing.fill(amount, max_price)?;
+	let marketplace_fees = listing.get_fee_amount(price)?;
+	let seller_amount = price
+		.checked_sub(marketplace_fees)
+		.ok_or(Error::OverflowError)?;
+
+	assert_is_ata(&ctx.accounts.escrow_token_account, &listing.key(), &listing.mint)?;
+	if ctx.accounts.buyer_token_account.data_is_empty() {
+		make_ata(
+			ctx.accounts.buyer_token_account.to_account_info(),
+			ctx.accounts.buyer.to_account_info(),
+			ctx.accounts.mint.to_account_info(),
+			payer.clone(),
+			ctx.accounts.associated_token_program.to_account_info(),
+			ctx.accounts.token_program.to_account_info(),
+			ctx.accounts.system_program.to_account_info(),
+			ctx.accounts.rent.to_account_info(),
+			None
+		)?;
+	} else {
+		assert_is_ata(&ctx.accounts.buyer_token_account, ctx.accounts.buyer.key, &listing.mint)?;
+	}
+
+	let auth_seeds = listing.auth_seeds();
+	if listing.remaining_amount == 0 {
+		release_escrow(
+			listing_account,
+			&ctx.accounts.escrow_token_account.to_account_info(),
+			&ctx.accounts.buyer_token_account.to_account_info(),
+			&ctx.accounts.mint.to_account_info(),
+			&ctx.accounts.rent_payer.to_account_info(),
+			&ctx.accounts.token_program.to_account_info(),
+			&auth_seeds
+		)?;
+	} else {
+		token::transfer(
+			CpiContext::new(
+				ctx.accounts.token_program.to_account_info(),
+				Transfer {
+					from: ctx.accounts.escrow_token_account.to_account_info(),
+					to: ctx.accounts.buyer_token_account.to_account_info(),
+					authority: listing_account.clone(),
+				},
+			).with_signer(&[&auth_seeds]),
+			amount
+		)?;
+	}
+
+	let (seller_received, fee_received) = if is_native_mint(listing.currency_mint) {
+		transfer_sol(
+			&ctx.accounts.buyer.to_account_info(),
+			&ctx.accounts.fee_account.to_account_info(),
+			&ctx.accounts.system_program.to_account_info(),
+			None,
+			marketplace_fees
+		)?;
+
+		transfer_sol(
+			&ctx.accounts.buyer.to_account_info(),
+			&ctx.accounts.seller.to_account_info(),
+			&ctx.accounts.system_program.to_account_info(),
+			None,
+			seller_amount
+		)?;
+
+		(seller_amount, marketplace_fees)
+	} else {
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let (currency_mint, currency_token_program) = &next_currency_accounts(
+			remaining_accounts,
+			&ctx.accounts.token_program.to_account_info()
+		)?;
+		assert_keys_equal(listing.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+		let buyer_currency_account = next_account_info(remaining_accounts)?;
+		let seller_currency_account = next_account_info(remaining_accounts)?;
+		let fee_currency_account = next_account_info(remaining_accounts)?;
+
+		let fee_received = transfer_spl(
+			&ctx.accounts.buyer.to_account_info(),
+			&ctx.accounts.fee_account.to_account_info(),
+			buyer_currency_account,
+			fee_currency_account,
+			currency_mint,
+			&payer,
+			&ctx.accounts.associated_token_program.to_account_info(),
+			currency_token_program,
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None,
+			None,
+			None,
+			marketplace_fees
+		)?;
+
+		let seller_received = transfer_spl(
+			&ctx.accounts.buyer.to_account_info(),
+			&ctx.accounts.seller.to_account_info(),
+			buyer_currency_account,
+			seller_currency_account,
+			currency_mint,
+			&payer,
+			&ctx.accounts.associated_token_program.to_account_info(),
+			currency_token_program,
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None,
+			None,
+			None,
+			seller_amount
+		)?;
+
+		(seller_received, fee_received)
+	};
+
+	emit!(Buy {
+		item_id: listing.mint.to_bytes(),
+		mint: listing.mint,
+		price,
+		seller: listing.seller,
+		buyer: ctx.accounts.buyer.key(),
+		is_virtual: false,
+		currency_mint: listing.currency_mint,
+		marketplace_authority: listing.marketplace_authority,
+		collection_mint: Pubkey::default(),
+		fee_config: listing.fee_config,
+		price_feed: Pubkey::default(),
+		seller_amount: seller_received,
+		royalty_amount: 0,
+		fee_amount: fee_received,
+		amount,
+		remaining_amount: listing.remaining_amount,
+	});
+
+	if listing.remaining_amount == 0 {
+		listing.close(ctx.accounts.rent_payer.to_account_info())?;
+	}
+
+	Ok(())
+}
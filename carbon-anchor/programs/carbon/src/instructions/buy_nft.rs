@@ -7,7 +7,10 @@ use anchor_spl::{
 use crate::{
 	state::{Listing, CustodyAccount},
 	event::Buy,
-	util::{assert_keys_equal, thaw, transfer_payment, transfer_spl},
+	util::{
+		assert_is_ata, assert_keys_equal, is_programmable, make_ata, release_escrow, thaw, transfer_payment,
+		transfer_spl, PaymentAmounts, ProgrammableNft
+	},
 	error::Error
 };
 
@@ -17,6 +20,10 @@ pub struct BuyNft<'info> {
 	#[account(mut)]
 	pub buyer: Signer<'info>,
 
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
 	/// Seller wallet.
 	/// CHECK: Safe because of listing constraint
 	#[account(mut)]
@@ -46,11 +53,17 @@ pub struct BuyNft<'info> {
 	#[account(mut)]
 	pub edition: UncheckedAccount<'info>,
 
+	/// Wallet that paid the rent of the listing.
+	/// CHECK: Safe because of listing constraint
+	#[account(mut)]
+	pub rent_payer: UncheckedAccount<'info>,
+
 	#[account(
 		mut,
-		close = seller,
+		close = rent_payer,
 		seeds = [
 			Listing::PREFIX.as_bytes(),
+			listing.namespace_seed(),
 			mint.key().as_ref()
 		],
 		bump = listing.bump[0],
@@ -58,6 +71,7 @@ pub struct BuyNft<'info> {
 		constraint = !listing.is_virtual @ Error::IsVirtual,
 		constraint = listing.item_id == mint.key().to_bytes() @ Error::InvalidMint,
 		constraint = listing.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+		constraint = listing.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
 	)]
 	pub listing: Box<Account<'info, Listing>>,
 
@@ -65,6 +79,15 @@ pub struct BuyNft<'info> {
 	/// CHECK: Validated in handler
 	pub custody_account: UncheckedAccount<'info>,
 
+	/// Wallet that paid the rent of the custody account, or any account if the NFT is not custodied.
+	/// CHECK: Validated in handler
+	#[account(mut)]
+	pub custody_rent_payer: UncheckedAccount<'info>,
+
+	/// Price feed of the listing, or the default pubkey if priced in the currency.
+	/// CHECK: Validated against the listing in handler
+	pub price_feed: UncheckedAccount<'info>,
+
 	/// Account to send fees to.
 	/// CHECK: Safe because of listing constraint
 	#[account(mut)]
@@ -81,31 +104,95 @@ pub struct BuyNft<'info> {
 /// 1. marketplace auth wallet
 ///
 /// When buying with an SPL token, the remaining accounts should be in the following order:
-/// 1. currency mint account
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
 /// 2. buyer currency ata
 /// 3. marketplace auth wallet
 /// 4. marketplace auth currency ata
 /// 5. marketplace fee currency ata
 /// 6. seller currency ata
+///
+/// When the NFT is programmable, the payment accounts above are preceded by:
+/// 1. seller token record account
+/// 2. authorization rules program, or token metadata program if the NFT has no rule set
+/// 3. authorization rules account, or token metadata program if the NFT has no rule set
+/// 4. instructions sysvar
+/// 5. buyer token record account
+///
+/// When the NFT is escrowed, the payment accounts above are preceded by:
+/// 1. listing token account
 pub fn buy_nft_handler<'info>(
 	ctx: Context<'_, '_, '_, 'info, BuyNft<'info>>,
 	max_price: u64,
 ) -> Result<()> {
-	ctx.accounts.listing.assert_can_buy(max_price)?;
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let price = ctx.accounts.listing.assert_can_buy(
+		max_price,
+		&ctx.accounts.price_feed.to_account_info()
+	)?;
 
 	CustodyAccount::assert_is_key_for_mint(
 		ctx.accounts.custody_account.key(),
 		ctx.accounts.mint.key(),
 	)?;
 
-	if ctx.accounts.custody_account.data_is_empty() {
+	let payment = if ctx.accounts.listing.is_escrowed {
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let listing = &ctx.accounts.listing;
+		let buyer_token_account = &ctx.accounts.buyer_token_account.to_account_info();
+
+		if buyer_token_account.data_is_empty() {
+			make_ata(
+				buyer_token_account.clone(),
+				ctx.accounts.buyer.to_account_info(),
+				ctx.accounts.mint.to_account_info(),
+				payer.clone(),
+				ctx.accounts.associated_token_program.to_account_info(),
+				ctx.accounts.token_program.to_account_info(),
+				ctx.accounts.system_program.to_account_info(),
+				ctx.accounts.rent.to_account_info(),
+				None
+			)?;
+		} else {
+			assert_is_ata(buyer_token_account, ctx.accounts.buyer.key, ctx.accounts.mint.key)?;
+		}
+
+		release_escrow(
+			&listing.to_account_info(),
+			next_account_info(remaining_accounts)?,
+			buyer_token_account,
+			&ctx.accounts.mint.to_account_info(),
+			&ctx.accounts.rent_payer.to_account_info(),
+			&ctx.accounts.token_program.to_account_info(),
+			&listing.auth_seeds()
+		)?;
+
+		ctx.accounts.pay(remaining_accounts.as_slice(), price)?
+	} else if is_programmable(&ctx.accounts.metadata_account)? {
+		require!(ctx.accounts.custody_account.data_is_empty(), Error::ProgrammableNftInCustody);
+
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let nft = ctx.accounts.programmable_nft(remaining_accounts)?;
+		let listing = &ctx.accounts.listing;
+		let auth_seeds = listing.auth_seeds();
+		nft.transfer(
+			&ctx.accounts.buyer_token_account.to_account_info(),
+			&ctx.accounts.buyer.to_account_info(),
+			next_account_info(remaining_accounts)?,
+			&listing.to_account_info(),
+			&ctx.accounts.associated_token_program.to_account_info(),
+			Some(&auth_seeds)
+		)?;
+
+		ctx.accounts.pay(remaining_accounts.as_slice(), price)?
+	} else if ctx.accounts.custody_account.data_is_empty() {
 		let listing = &ctx.accounts.listing;
 		let auth_seeds = listing.auth_seeds();
 		ctx.accounts.transfer_with_seeds(
 			&ctx.accounts.listing.to_account_info(),
 			&auth_seeds,
-			ctx.remaining_accounts
-		)?;
+			ctx.remaining_accounts,
+			price
+		)?
 	} else {
 		let account_loader = AccountLoader::<'info, CustodyAccount>::try_from(
 			&ctx.accounts.custody_account.to_account_info()
@@ -117,25 +204,33 @@ pub fn buy_nft_handler<'info>(
 			"Invalid marketplace authority"
 		)?;
 
+		assert_keys_equal(
+			account_loader.load()?.rent_payer(),
+			ctx.accounts.custody_rent_payer.key(),
+			"Invalid custody rent payer"
+		)?;
+
 		let bump = account_loader.load()?.bump;
 		let auth_seeds = CustodyAccount::auth_seeds_from_args(
 			ctx.accounts.mint.key,
 			&bump
 		);
 
-		ctx.accounts.transfer_with_seeds(
+		let payment = ctx.accounts.transfer_with_seeds(
 			&ctx.accounts.custody_account.to_account_info(),
 			&auth_seeds,
-			ctx.remaining_accounts
+			ctx.remaining_accounts,
+			price
 		)?;
 
-		account_loader.close(ctx.accounts.seller.to_account_info())?;
-	}
+		account_loader.close(ctx.accounts.custody_rent_payer.to_account_info())?;
+		payment
+	};
 
 	emit!(Buy {
 		item_id: ctx.accounts.listing.item_id,
         mint: ctx.accounts.mint.key(),
-        price: ctx.accounts.listing.price,
+        price,
         seller: ctx.accounts.listing.seller,
 		buyer: ctx.accounts.buyer.key(),
         is_virtual: false,
@@ -143,6 +238,12 @@ pub fn buy_nft_handler<'info>(
         marketplace_authority: ctx.accounts.listing.marketplace_authority,
 		collection_mint: ctx.accounts.listing.collection_mint,
         fee_config: ctx.accounts.listing.fee_config,
+		price_feed: ctx.accounts.listing.price_feed,
+		seller_amount: payment.seller_amount,
+		royalty_amount: payment.royalty_amount,
+		fee_amount: payment.fee_amount,
+		amount: 1,
+		remaining_amount: 0,
     });
 
 	Ok(())
@@ -154,8 +255,9 @@ impl<'info> BuyNft<'info> {
 		&self,
 		delegate: &AccountInfo<'info>,
 		auth_seeds: &[&[u8]],
-		remaining_accounts: &'b [AccountInfo<'info>]
-	) -> Result<()> {
+		remaining_accounts: &'b [AccountInfo<'info>],
+		price: u64
+	) -> Result<PaymentAmounts> {
 		thaw(
 			&self.seller_token_account.to_account_info(),
 			&self.mint.to_account_info(),
@@ -172,7 +274,7 @@ impl<'info> BuyNft<'info> {
 			&self.seller_token_account.to_account_info(),
 			&self.buyer_token_account.to_account_info(),
 			&self.mint.to_account_info(),
-			&self.buyer.to_account_info(),
+			&self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
 			&self.associated_token_program.to_account_info(),
 			&self.token_program.to_account_info(),
 			&self.system_program.to_account_info(),
@@ -183,8 +285,13 @@ impl<'info> BuyNft<'info> {
 			1
 		)?;
 
+		self.pay(remaining_accounts, price)
+	}
+
+	fn pay<'b>(&self, remaining_accounts: &'b [AccountInfo<'info>], price: u64) -> Result<PaymentAmounts> {
 		transfer_payment(
 			&self.buyer.to_account_info(),
+			&self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
 			&self.seller.to_account_info(),
 			&self.fee_account.to_account_info(),
 			&self.mint.to_account_info(),
@@ -194,12 +301,34 @@ impl<'info> BuyNft<'info> {
 			&self.token_program.to_account_info(),
 			&self.system_program.to_account_info(),
 			&self.rent.to_account_info(),
-			&remaining_accounts,
-			self.listing.price,
-			self.listing.get_fee_amount()?
-		)?;
+			remaining_accounts,
+			price,
+			self.listing.get_fee_amount(price)?
+		)
+	}
+
+	fn programmable_nft<'b>(
+		&self,
+		remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+	) -> Result<ProgrammableNft<'info>> {
+		let nft = ProgrammableNft {
+			token_account: self.seller_token_account.to_account_info(),
+			token_owner: self.seller.to_account_info(),
+			mint: self.mint.to_account_info(),
+			metadata: self.metadata_account.to_account_info(),
+			edition: self.edition.to_account_info(),
+			token_record: next_account_info(remaining_accounts)?.clone(),
+			authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+			authorization_rules: next_account_info(remaining_accounts)?.clone(),
+			sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+			payer: self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
+			token_metadata_program: self.token_metadata_program.to_account_info(),
+			token_program: self.token_program.to_account_info(),
+			system_program: self.system_program.to_account_info(),
+		};
+		nft.assert_is_valid()?;
 
-		Ok(())
+		Ok(nft)
 	}
 
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken
+};
+use crate::{
+	state::{Raffle, RaffleTicket},
+	event::RaffleTicketBuy,
+	util::{assert_keys_equal, is_native_mint, next_currency_accounts, transfer_sol, transfer_spl},
+	error::Error
+};
+
+#[derive(Accounts)]
+pub struct BuyRaffleTickets<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	#[account(
+		mut,
+		seeds = [
+			Raffle::PREFIX.as_bytes(),
+			raffle.namespace.as_ref(),
+			raffle.raffle_id.as_ref()
+		],
+		bump = raffle.bump[0],
+	)]
+	pub raffle: Box<Account<'info, Raffle>>,
+
+	#[account(
+		init,
+		seeds = [
+			RaffleTicket::PREFIX.as_bytes(),
+			raffle.key().as_ref(),
+			raffle.tickets_sold.to_le_bytes().as_ref()
+		],
+		bump,
+		space = RaffleTicket::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub ticket: Box<Account<'info, RaffleTicket>>,
+
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Buys the next tickets of the raffle, paid into the raffle until it is drawn. The ticket
+/// account is derived from the number of the first ticket bought and records the amount the raffle
+/// received, which is what gets refunded.
+///
+/// When buying with an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
+/// 2. buyer currency ata
+/// 3. raffle currency ata
+pub fn buy_raffle_tickets_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, BuyRaffleTickets<'info>>,
+	count: u32
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let raffle_account = &ctx.accounts.raffle.to_account_info();
+	let raffle = &mut ctx.accounts.raffle;
+	let (first_ticket, price) = raffle.buy_tickets(count)?;
+
+	let ticket = &mut ctx.accounts.ticket;
+	ticket.init(
+		[*ctx.bumps.get("ticket").ok_or(Error::BumpSeedNotInHashMap)?],
+		raffle.key(),
+		ctx.accounts.buyer.key(),
+		first_ticket,
+		count,
+	)?;
+	ticket.payer = payer.key();
+
+	let amount = if is_native_mint(raffle.currency_mint) {
+		transfer_sol(
+			&ctx.accounts.buyer.to_account_info(),
+			raffle_account,
+			&ctx.accounts.system_program.to_account_info(),
+			None,
+			price
+		)?;
+
+		price
+	} else {
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let (currency_mint, currency_token_program) = &next_currency_accounts(
+			remaining_accounts,
+			&ctx.accounts.token_program.to_account_info()
+		)?;
+		assert_keys_equal(raffle.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+		let buyer_currency_account = next_account_info(remaining_accounts)?;
+		let raffle_currency_account = next_account_info(remaining_accounts)?;
+
+		transfer_spl(
+			&ctx.accounts.buyer.to_account_info(),
+			raffle_account,
+			buyer_currency_account,
+			raffle_currency_account,
+			currency_mint,
+			&payer,
+			&ctx.accounts.associated_token_program.to_account_info(),
+			currency_token_program,
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None,
+			None,
+			None,
+			price
+		)?
+	};
+	ticket.amount = amount;
+	raffle.add_proceeds(amount)?;
+
+	emit!(RaffleTicketBuy {
+		raffle_id: raffle.raffle_id,
+		buyer: ctx.accounts.buyer.key(),
+		marketplace_authority: raffle.marketplace_authority,
+		first_ticket,
+		count,
+		price,
+		amount,
+	});
+
+	Ok(())
+}
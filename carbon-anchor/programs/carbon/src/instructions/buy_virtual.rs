@@ -13,7 +13,7 @@ use anchor_spl::{
 use crate::{
 	state::{Listing, CollectionConfig, Metadata, MintRecord},
 	event::Buy,
-	util::{mint_nft, transfer_payment, is_native_mint},
+	util::{mint_nft, next_currency_accounts, transfer_payment, is_native_mint},
 	error::Error
 };
 
@@ -24,6 +24,10 @@ pub struct BuyVirtual<'info> {
 	#[account(mut)]
 	pub buyer: Signer<'info>,
 
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
 	/// Seller wallet.
 	/// CHECK: Safe because of listing constraint
 	#[account(mut)]
@@ -64,11 +68,17 @@ pub struct BuyVirtual<'info> {
 	#[account(mut)]
 	pub collection_edition: UncheckedAccount<'info>,
 
+	/// Wallet that paid the rent of the listing.
+	/// CHECK: Safe because of listing constraint
+	#[account(mut)]
+	pub rent_payer: UncheckedAccount<'info>,
+
 	#[account(
 		mut,
-		close = seller,
+		close = rent_payer,
 		seeds = [
 			Listing::PREFIX.as_bytes(),
+			listing.namespace_seed(),
 			item_id.as_ref()
 		],
 		bump = listing.bump[0],
@@ -77,6 +87,7 @@ pub struct BuyVirtual<'info> {
 		constraint = listing.item_id == item_id,
 		constraint = listing.is_virtual @ Error::NotVirtual,
 		constraint = listing.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+		constraint = listing.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
 	)]
 	pub listing: Box<Account<'info, Listing>>,
 
@@ -99,7 +110,7 @@ pub struct BuyVirtual<'info> {
 		],
 		bump,
 		space = MintRecord::SPACE,
-        payer = buyer,
+        payer = payer.as_ref().unwrap_or(&buyer),
 	)]
 	pub mint_record: Box<Account<'info, MintRecord>>,
 
@@ -108,6 +119,10 @@ pub struct BuyVirtual<'info> {
 	#[account(mut)]
 	pub fee_account: UncheckedAccount<'info>,
 
+	/// Price feed of the listing, or the default pubkey if priced in the currency.
+	/// CHECK: Validated against the listing in handler
+	pub price_feed: UncheckedAccount<'info>,
+
 	pub token_metadata_program: Program<'info, metadata::Metadata>,
 	pub token_program: Program<'info, Token>,
 	pub associated_token_program: Program<'info, AssociatedToken>,
@@ -119,7 +134,7 @@ pub struct BuyVirtual<'info> {
 /// 1. marketplace auth wallet
 ///
 /// When buying with an SPL token, the remaining accounts should be in the following order:
-/// 1. currency mint account
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
 /// 2. buyer currency ata
 /// 3. marketplace auth wallet
 /// 4. marketplace auth currency ata
@@ -131,19 +146,29 @@ pub fn buy_virtual_handler<'info>(
 	max_price: u64,
 	metadata: Metadata
 ) -> Result<()> {
-	ctx.accounts.listing.assert_can_buy(max_price)?;
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let price = ctx.accounts.listing.assert_can_buy(
+		max_price,
+		&ctx.accounts.price_feed.to_account_info()
+	)?;
 
 	// Ensure valid collection config
-	let marketplace_authority = if is_native_mint(ctx.accounts.listing.currency_mint) {
-		&ctx.remaining_accounts[0]
-	} else {
-		&ctx.remaining_accounts[2]
-	};
+	let remaining_accounts = &mut ctx.remaining_accounts.iter();
+	if !is_native_mint(ctx.accounts.listing.currency_mint) {
+		next_currency_accounts(remaining_accounts, &ctx.accounts.token_program.to_account_info())?;
+		next_account_info(remaining_accounts)?;
+	}
+	let marketplace_authority = next_account_info(remaining_accounts)?;
 	require!(ctx.accounts.collection_config.marketplace_authority == marketplace_authority.key(),
 		Error::InvalidCollectionConfig);
 
 	let mint_record = &mut ctx.accounts.mint_record;
-	mint_record.init(ctx.accounts.collection_config.key(), item_id, ctx.accounts.mint.key())?;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
 
 	let data = &ctx.accounts.collection_config.get_mpl_metadata(metadata)?;
 	// Mint the NFT to the buyer.
@@ -160,7 +185,8 @@ pub fn buy_virtual_handler<'info>(
 		&ctx.accounts.associated_token_program.to_account_info(),
 		&ctx.accounts.token_program.to_account_info(),
 		&ctx.accounts.system_program.to_account_info(),
-		&ctx.accounts.rent.to_account_info()
+		&ctx.accounts.rent.to_account_info(),
+		None
 	)?;
 
 	// Mark the item as a verified item in the collection.
@@ -168,7 +194,7 @@ pub fn buy_virtual_handler<'info>(
 		CpiContext::new(
 			ctx.accounts.token_metadata_program.to_account_info(),
 			VerifySizedCollectionItem {
-				payer: ctx.accounts.buyer.to_account_info(),
+				payer: payer.clone(),
 				metadata: ctx.accounts.metadata_account.to_account_info(),
 				collection_authority: marketplace_authority.clone(),
 				collection_mint: ctx.accounts.collection_mint.to_account_info(),
@@ -191,8 +217,9 @@ pub fn buy_virtual_handler<'info>(
 		)
 	)?;
 
-	transfer_payment(
+	let payment = transfer_payment(
 		&ctx.accounts.buyer.to_account_info(),
+		&payer,
 		&ctx.accounts.seller.to_account_info(),
 		&ctx.accounts.fee_account.to_account_info(),
 		&ctx.accounts.mint.to_account_info(),
@@ -203,14 +230,14 @@ pub fn buy_virtual_handler<'info>(
 		&ctx.accounts.system_program.to_account_info(),
 		&ctx.accounts.rent.to_account_info(),
 		&ctx.remaining_accounts,
-		ctx.accounts.listing.price,
-		ctx.accounts.listing.get_fee_amount()?
+		price,
+		ctx.accounts.listing.get_fee_amount(price)?
 	)?;
 
 	emit!(Buy {
 		item_id,
         mint: ctx.accounts.mint.key(),
-        price: ctx.accounts.listing.price,
+        price,
         seller: ctx.accounts.listing.seller,
 		buyer: ctx.accounts.buyer.key(),
         is_virtual: true,
@@ -218,6 +245,12 @@ pub fn buy_virtual_handler<'info>(
         marketplace_authority: ctx.accounts.listing.marketplace_authority,
 		collection_mint: ctx.accounts.listing.collection_mint,
         fee_config: ctx.accounts.listing.fee_config,
+		price_feed: ctx.accounts.listing.price_feed,
+		seller_amount: payment.seller_amount,
+		royalty_amount: payment.royalty_amount,
+		fee_amount: payment.fee_amount,
+		amount: 1,
+		remaining_amount: 0,
     });
 
 	Ok(())
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token}
+};
+use crate::{
+    state::Loan,
+    event::LoanCancel,
+    util::{close_token_account, is_native_mint, transfer_lamports, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CancelLoanOffer<'info> {
+    /// Wallet that offered the loan.
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    /// Wallet that paid the rent of the loan.
+    /// CHECK: Safe because of loan constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Loan::PREFIX.as_bytes(),
+            loan.lender.as_ref(),
+            loan.loan_id.as_ref()
+        ],
+        bump = loan.bump[0],
+        has_one = lender,
+        has_one = currency_mint,
+        constraint = loan.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    /// The currency of the loan or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// When the loan is in an SPL token, the remaining accounts should contain:
+/// 1. lender currency ata
+/// 2. loan currency ata
+pub fn cancel_loan_offer_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelLoanOffer<'info>>,
+) -> Result<()> {
+    let loan = &ctx.accounts.loan;
+    require!(!loan.is_active(), Error::LoanIsActive);
+
+    if is_native_mint(loan.currency_mint) {
+        // The escrowed SOL sits on the loan account itself, while its rent goes to the rent payer
+        transfer_lamports(&loan.to_account_info(), &ctx.accounts.lender.to_account_info(), loan.principal)?;
+    } else {
+        let loan_account = &loan.to_account_info();
+        let auth_seeds = loan.auth_seeds();
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let lender_currency_account = next_account_info(remaining_accounts)?;
+        let loan_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            loan_account,
+            &ctx.accounts.lender.to_account_info(),
+            loan_currency_account,
+            lender_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &ctx.accounts.lender.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&auth_seeds),
+            None,
+            loan.principal
+        )?;
+
+        close_token_account(
+            loan_currency_account,
+            &ctx.accounts.rent_payer.to_account_info(),
+            loan_account,
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&auth_seeds)
+        )?;
+    }
+
+    emit!(LoanCancel {
+        loan_id: loan.loan_id,
+        lender: loan.lender,
+        marketplace_authority: loan.marketplace_authority,
+    });
+
+    Ok(())
+}
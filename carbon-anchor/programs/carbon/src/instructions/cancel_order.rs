@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Market, OpenOrders, OrderBookSide, Side},
+    event::OrderCancel,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    /// Wallet that placed the order.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = bids,
+        has_one = asks,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub bids: AccountLoader<'info, OrderBookSide>,
+
+    #[account(mut)]
+    pub asks: AccountLoader<'info, OrderBookSide>,
+
+    #[account(
+        mut,
+        seeds = [
+            OpenOrders::PREFIX.as_bytes(),
+            market.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump = open_orders.load()?.bump[0],
+        has_one = market,
+        has_one = owner,
+    )]
+    pub open_orders: AccountLoader<'info, OpenOrders>,
+}
+
+/// Takes the order off the book and frees what it still had locked, to be settled out
+pub fn cancel_order_handler(
+    ctx: Context<CancelOrder>,
+    side: Side,
+    order_id: u64,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let book = match side {
+        Side::Bid => &ctx.accounts.bids,
+        Side::Ask => &ctx.accounts.asks,
+    };
+
+    let order = {
+        let book = &mut book.load_mut()?;
+        let index = book.find(order_id).ok_or(Error::OrderNotFound)?;
+        require!(
+            book.orders[index].open_orders == ctx.accounts.open_orders.key(),
+            Error::InvalidOpenOrders
+        );
+        book.remove(index)
+    };
+
+    let locked = market.locked_amount(side, order.quantity, order.price)?;
+    let open_orders = &mut ctx.accounts.open_orders.load_mut()?;
+    match side {
+        Side::Bid => open_orders.credit(0, locked)?,
+        Side::Ask => open_orders.credit(locked, 0)?,
+    }
+
+    emit!(OrderCancel {
+        market: market.key(),
+        order_id,
+        owner: ctx.accounts.owner.key(),
+        side,
+        quantity: order.quantity,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+    metadata::Metadata
+};
+use crate::{
+    state::{Swap, CustodyAccount},
+    event::SwapCancel,
+    util::{
+        assert_keys_equal, close_token_account, is_native_mint, thaw_and_revoke, transfer_lamports,
+        transfer_spl
+    },
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CancelSwap<'info> {
+    /// Wallet that proposed the swap.
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// Wallet that paid the rent of the swap.
+    /// CHECK: Safe because of swap constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Swap::PREFIX.as_bytes(),
+            swap.maker.as_ref(),
+            swap.swap_id.as_ref()
+        ],
+        bump = swap.bump[0],
+        has_one = maker,
+        has_one = currency_mint,
+        constraint = swap.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub swap: Box<Account<'info, Swap>>,
+
+    /// The currency of the swap amounts or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// The remaining accounts should contain the following for each maker mint, in the order of the swap:
+/// 1. mint account
+/// 2. maker token account
+/// 3. edition account
+/// 4. custody account
+///
+/// When the maker escrowed an SPL token, followed by:
+/// 1. maker currency ata
+/// 2. swap currency ata
+pub fn cancel_swap_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>,
+) -> Result<()> {
+    let swap = &ctx.accounts.swap;
+    let swap_account = &swap.to_account_info();
+    let auth_seeds = swap.auth_seeds();
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+
+    for expected_mint in swap.maker_mints.iter() {
+        let mint = next_account_info(remaining_accounts)?;
+        let token_account = next_account_info(remaining_accounts)?;
+        let edition = next_account_info(remaining_accounts)?;
+        let custody_account = next_account_info(remaining_accounts)?;
+
+        assert_keys_equal(mint.key(), *expected_mint, "Invalid swap mint")?;
+        CustodyAccount::assert_is_key_for_mint(custody_account.key(), mint.key())?;
+
+        if custody_account.data_is_empty() {
+            thaw_and_revoke(
+                token_account,
+                mint,
+                edition,
+                &ctx.accounts.maker.to_account_info(),
+                swap_account,
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.token_metadata_program.to_account_info(),
+                Some(&auth_seeds)
+            )?;
+        } else {
+            let account_loader = AccountLoader::<'info, CustodyAccount>::try_from(custody_account)?;
+
+            assert_keys_equal(
+                account_loader.load()?.marketplace_authority,
+                swap.marketplace_authority,
+                "Invalid marketplace authority"
+            )?;
+
+            let custody_account = &mut account_loader.load_mut()?;
+            custody_account.is_listed = false;
+        }
+    }
+
+    if is_native_mint(swap.currency_mint) {
+        // The escrowed SOL sits on the swap account itself, while its rent goes to the rent payer
+        transfer_lamports(swap_account, &ctx.accounts.maker.to_account_info(), swap.maker_amount)?;
+    } else if swap.maker_amount > 0 {
+        let maker_currency_account = next_account_info(remaining_accounts)?;
+        let swap_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            swap_account,
+            &ctx.accounts.maker.to_account_info(),
+            swap_currency_account,
+            maker_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&auth_seeds),
+            None,
+            swap.maker_amount
+        )?;
+
+        close_token_account(
+            swap_currency_account,
+            &ctx.accounts.rent_payer.to_account_info(),
+            swap_account,
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&auth_seeds)
+        )?;
+    }
+
+    emit!(SwapCancel {
+        swap_id: swap.swap_id,
+        maker: swap.maker,
+        marketplace_authority: swap.marketplace_authority,
+        collection_mint: swap.collection_mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use crate::{
+    state::{Raffle, RaffleState, RaffleTicket},
+    event::RaffleClaim,
+    util::{assert_is_ata, make_ata, release_escrow},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct ClaimRaffleNft<'info> {
+    /// Wallet holding the winning ticket.
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the winner pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Wallet that created the raffle.
+    /// CHECK: Safe due to raffle constraint
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the raffle.
+    /// CHECK: Safe because of raffle constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the ticket.
+    /// CHECK: Safe because of ticket constraint
+    #[account(mut)]
+    pub ticket_rent_payer: UncheckedAccount<'info>,
+
+    /// Mint of the raffled NFT.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Raffle::PREFIX.as_bytes(),
+            raffle.namespace.as_ref(),
+            raffle.raffle_id.as_ref()
+        ],
+        bump = raffle.bump[0],
+        has_one = creator,
+        has_one = mint @ Error::InvalidMint,
+        constraint = raffle.state == RaffleState::Drawn @ Error::RaffleNotDrawn,
+        constraint = !raffle.is_virtual @ Error::InvalidMint,
+        constraint = raffle.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    #[account(
+        mut,
+        close = ticket_rent_payer,
+        seeds = [
+            RaffleTicket::PREFIX.as_bytes(),
+            raffle.key().as_ref(),
+            ticket.first_ticket.to_le_bytes().as_ref()
+        ],
+        bump = ticket.bump[0],
+        has_one = raffle,
+        constraint = ticket.buyer == winner.key() @ Error::NotWinningTicket,
+        constraint = ticket.contains(raffle.winning_ticket) @ Error::NotWinningTicket,
+        constraint = ticket.payer == ticket_rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub ticket: Box<Account<'info, RaffleTicket>>,
+
+    /// Raffle's token account escrowing the NFT.
+    /// CHECK: Validated in release_escrow
+    #[account(mut)]
+    pub escrow_token_account: UncheckedAccount<'info>,
+
+    /// Winner's token account of the NFT, created if it does not exist.
+    /// CHECK: Validated in handler
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Releases the escrowed NFT to the holder of the winning ticket, returning the raffle's rent to
+/// whoever paid it
+pub fn claim_raffle_nft_handler(ctx: Context<ClaimRaffleNft>) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.winner).to_account_info();
+    let raffle = &ctx.accounts.raffle;
+
+    if ctx.accounts.winner_token_account.data_is_empty() {
+        make_ata(
+            ctx.accounts.winner_token_account.to_account_info(),
+            ctx.accounts.winner.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            payer.clone(),
+            ctx.accounts.associated_token_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            None,
+        )?;
+    } else {
+        assert_is_ata(&ctx.accounts.winner_token_account, ctx.accounts.winner.key, &raffle.mint)?;
+    }
+
+    let auth_seeds = raffle.auth_seeds();
+    release_escrow(
+        &raffle.to_account_info(),
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.winner_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &auth_seeds,
+    )?;
+
+    emit!(RaffleClaim {
+        raffle_id: raffle.raffle_id,
+        winner: ctx.accounts.winner.key(),
+        marketplace_authority: raffle.marketplace_authority,
+        is_virtual: false,
+        item_id: raffle.item_id,
+        mint: raffle.mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		update_primary_sale_happened_via_token,
+		VerifySizedCollectionItem,
+		UpdatePrimarySaleHappenedViaToken
+	},
+	metadata
+};
+use crate::{
+	state::{CollectionConfig, MintRecord, Raffle, RaffleState, RaffleTicket},
+	event::RaffleClaim,
+	util::mint_nft,
+	error::Error
+};
+
+#[derive(Accounts)]
+pub struct ClaimRaffleVirtual<'info> {
+	/// Wallet holding the winning ticket.
+	#[account(mut)]
+	pub winner: Signer<'info>,
+
+	/// Wallet that paid the rent of the ticket.
+	/// CHECK: Safe because of ticket constraint
+	#[account(mut)]
+	pub ticket_rent_payer: UncheckedAccount<'info>,
+
+	/// Marketplace authority wallet.
+	#[account(mut)]
+	pub marketplace_authority: Signer<'info>,
+
+	/// The new mint to be used for the prize NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Winner NFT token account.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub winner_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the prize NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the prize NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		mut,
+		close = marketplace_authority,
+		seeds = [
+			Raffle::PREFIX.as_bytes(),
+			raffle.namespace.as_ref(),
+			raffle.raffle_id.as_ref()
+		],
+		bump = raffle.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_config,
+		constraint = raffle.state == RaffleState::Drawn @ Error::RaffleNotDrawn,
+		constraint = raffle.is_virtual @ Error::InvalidMint,
+	)]
+	pub raffle: Box<Account<'info, Raffle>>,
+
+	#[account(
+		mut,
+		close = ticket_rent_payer,
+		seeds = [
+			RaffleTicket::PREFIX.as_bytes(),
+			raffle.key().as_ref(),
+			ticket.first_ticket.to_le_bytes().as_ref()
+		],
+		bump = ticket.bump[0],
+		has_one = raffle,
+		constraint = ticket.buyer == winner.key() @ Error::NotWinningTicket,
+		constraint = ticket.payer == ticket_rent_payer.key() @ Error::InvalidRentPayer,
+		constraint = ticket.contains(raffle.winning_ticket) @ Error::NotWinningTicket,
+	)]
+	pub ticket: Box<Account<'info, RaffleTicket>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			raffle.item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = marketplace_authority,
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mints the raffled virtual item to the holder of the winning ticket
+pub fn claim_raffle_virtual_handler(ctx: Context<ClaimRaffleVirtual>) -> Result<()> {
+	let raffle = &ctx.accounts.raffle;
+
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		raffle.item_id,
+		ctx.accounts.mint.key(),
+		ctx.accounts.marketplace_authority.key(),
+	)?;
+
+	let data = &ctx.accounts.collection_config.get_mpl_metadata(raffle.metadata.clone())?;
+	// Mint the prize NFT to the winner.
+	mint_nft(
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.winner.to_account_info(),
+		&ctx.accounts.winner_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data.clone(),
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		None
+	)?;
+
+	// Mark the prize as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: ctx.accounts.marketplace_authority.to_account_info(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: ctx.accounts.marketplace_authority.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+		),
+		None
+	)?;
+
+	// Mark that the primary sale happened.
+	update_primary_sale_happened_via_token(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			UpdatePrimarySaleHappenedViaToken {
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				owner: ctx.accounts.winner.to_account_info(),
+				token: ctx.accounts.winner_token_account.to_account_info(),
+			}
+		)
+	)?;
+
+	emit!(RaffleClaim {
+		raffle_id: raffle.raffle_id,
+		winner: ctx.accounts.winner.key(),
+		marketplace_authority: raffle.marketplace_authority,
+		is_virtual: true,
+		item_id: raffle.item_id,
+		mint: ctx.accounts.mint.key(),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::AuthorizationNonce,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CloseAuthorizationNonce<'info> {
+    /// Wallet that paid the rent of the nonce account.
+    /// CHECK: Safe because of authorization_nonce constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            AuthorizationNonce::PREFIX.as_bytes(),
+            authorization_nonce.marketplace_authority.as_ref(),
+            authorization_nonce.nonce.to_le_bytes().as_ref()
+        ],
+        bump = authorization_nonce.bump[0],
+        constraint = authorization_nonce.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub authorization_nonce: Box<Account<'info, AuthorizationNonce>>,
+}
+
+/// Returns the rent of a used nonce to whoever paid it once its authorization or mint voucher
+/// expired, as the expiry alone then keeps it from being used again. Anyone can close it.
+pub fn close_authorization_nonce_handler(ctx: Context<CloseAuthorizationNonce>) -> Result<()> {
+    require!(
+        ctx.accounts.authorization_nonce.expiry <= Clock::get()?.unix_timestamp,
+        Error::AuthorizationNotExpired
+    );
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    metadata::Metadata
+};
+use mpl_token_metadata::instruction::RevokeArgs;
+use crate::{
+    state::{Listing, MarketplaceConfig, CustodyAccount},
+    event::{Delist, DelistReason},
+    util::{
+        assert_is_ata, assert_is_metadata_account, assert_keys_equal, is_programmable, release_escrow, thaw, thaw_and_revoke,
+        ProgrammableNft
+    },
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CloseExpiredListing<'info> {
+    /// Wallet closing the listing. Receives the keeper reward.
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Seller wallet.
+    /// CHECK: Safe because of listing constraint
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the listing.
+    /// CHECK: Safe because of listing constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Listing::PREFIX.as_bytes(),
+            listing.namespace_seed(),
+            listing.item_id.as_ref()
+        ],
+        bump = listing.bump[0],
+        has_one = seller @ Error::InvalidSeller,
+        constraint = listing.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            listing.marketplace_authority.as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Virtual listings do not require any remaining accounts.
+///
+/// NFT listings require the remaining accounts in the following order:
+/// 1. mint account
+/// 2. token account holding the NFT
+/// 3. edition account
+/// 4. custody account
+/// 5. listing token account, if the NFT is escrowed
+///
+/// Programmable NFTs are never escrowed and continue with these instead:
+/// 5. metadata account
+/// 6. token record account
+/// 7. authorization rules program, or token metadata program if the NFT has no rule set
+/// 8. authorization rules account, or token metadata program if the NFT has no rule set
+/// 9. instructions sysvar
+///
+/// Escrowed NFTs are returned to the token account, which must be the ATA of the seller.
+///
+/// Unless the seller signs, the closed listing stays the delegate of a non-custodial NFT, as only the
+/// token owner can revoke it. The delegation is replaced the next time the NFT is listed.
+///
+/// The keeper reward comes out of the listing rent, so it is only paid when the seller paid that
+/// rent, leaving the rent of a sponsoring payer whole.
+pub fn close_expired_listing_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    require!(listing.is_expired()?, Error::ListingNotExpired);
+
+    if !listing.is_virtual {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let mint = next_account_info(remaining_accounts)?;
+        let token_account = next_account_info(remaining_accounts)?;
+        let edition = next_account_info(remaining_accounts)?;
+        let custody_account = next_account_info(remaining_accounts)?;
+
+        require!(listing.item_id == mint.key().to_bytes(), Error::InvalidMint);
+        CustodyAccount::assert_is_key_for_mint(custody_account.key(), mint.key())?;
+
+        if listing.is_escrowed {
+            assert_is_ata(token_account, ctx.accounts.seller.key, mint.key)?;
+            release_escrow(
+                &listing.to_account_info(),
+                next_account_info(remaining_accounts)?,
+                token_account,
+                mint,
+                &ctx.accounts.rent_payer.to_account_info(),
+                &ctx.accounts.token_program.to_account_info(),
+                &listing.auth_seeds()
+            )?;
+        } else if custody_account.data_is_empty() {
+            let auth_seeds = listing.auth_seeds();
+
+            let programmable_metadata = match remaining_accounts.next() {
+                Some(metadata) => {
+                    assert_is_metadata_account(metadata.key(), mint.key())?;
+                    is_programmable(metadata)?.then_some(metadata)
+                }
+                None => None,
+            };
+
+            // Revoking needs the token owner's signature. Without it the approval is left
+            // to the closed listing and replaced the next time the NFT is listed.
+            if let Some(metadata) = programmable_metadata {
+                // Programmable NFTs are always frozen, only the sale delegation is revoked
+                if ctx.accounts.seller.is_signer {
+                    let nft = ProgrammableNft {
+                        token_account: token_account.clone(),
+                        token_owner: ctx.accounts.seller.to_account_info(),
+                        mint: mint.clone(),
+                        metadata: metadata.clone(),
+                        edition: edition.clone(),
+                        token_record: next_account_info(remaining_accounts)?.clone(),
+                        authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+                        authorization_rules: next_account_info(remaining_accounts)?.clone(),
+                        sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+                        payer: ctx.accounts.seller.to_account_info(),
+                        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    };
+                    nft.assert_is_valid()?;
+                    nft.revoke(&listing.to_account_info(), RevokeArgs::SaleV1)?;
+                }
+            } else if ctx.accounts.seller.is_signer {
+                thaw_and_revoke(
+                    token_account,
+                    mint,
+                    edition,
+                    &ctx.accounts.seller.to_account_info(),
+                    &listing.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.token_metadata_program.to_account_info(),
+                    Some(&auth_seeds)
+                )?;
+            } else {
+                thaw(
+                    token_account,
+                    mint,
+                    edition,
+                    &listing.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.token_metadata_program.to_account_info(),
+                    Some(&auth_seeds)
+                )?;
+            }
+        } else {
+            let account_loader = AccountLoader::<'info, CustodyAccount>::try_from(custody_account)?;
+
+            assert_keys_equal(
+                account_loader.load()?.marketplace_authority,
+                listing.marketplace_authority,
+                "Invalid marketplace authority"
+            )?;
+
+            let custody_account = &mut account_loader.load_mut()?;
+            custody_account.is_listed = false;
+        }
+    }
+
+    // The keeper reward comes out of the seller's listing rent, the rest is returned to the rent
+    // payer on close
+    let listing_info = listing.to_account_info();
+    let keeper_reward = if listing.rent_payer() == listing.seller {
+        ctx.accounts.marketplace_config.keeper_reward.min(listing_info.lamports())
+    } else {
+        0
+    };
+    if keeper_reward > 0 {
+        let keeper_info = ctx.accounts.keeper.to_account_info();
+        **listing_info.try_borrow_mut_lamports()? = listing_info.lamports()
+            .checked_sub(keeper_reward)
+            .ok_or(Error::OverflowError)?;
+        **keeper_info.try_borrow_mut_lamports()? = keeper_info.lamports()
+            .checked_add(keeper_reward)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    emit!(Delist {
+        item_id: listing.item_id,
+        seller: listing.seller,
+        marketplace_authority: listing.marketplace_authority,
+        collection_mint: listing.collection_mint,
+        reason: DelistReason::Expired,
+    });
+
+    Ok(())
+}
@@ -11,6 +11,11 @@ pub struct CloseMintRecord<'info> {
     #[account(mut)]
     pub marketplace_authority: Signer<'info>,
 
+    /// Wallet that paid the rent of the mint record.
+    /// CHECK: Safe because of mint_record constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
     /// CHECK: Safe due to mint_record constraint
     pub mint: UncheckedAccount<'info>,
 
@@ -31,7 +36,7 @@ pub struct CloseMintRecord<'info> {
 
     #[account(
         mut,
-        close = marketplace_authority,
+        close = rent_payer,
         seeds = [
 			MintRecord::PREFIX.as_bytes(),
 			collection_config.key().as_ref(),
@@ -40,6 +45,7 @@ pub struct CloseMintRecord<'info> {
         bump,
         has_one = collection_config @ Error::InvalidCollectionConfig,
         has_one = mint @ Error::InvalidMint,
+        constraint = mint_record.payer == rent_payer.key() @ Error::InvalidRentPayer,
     )]
     pub mint_record: Box<Account<'info, MintRecord>>,
 
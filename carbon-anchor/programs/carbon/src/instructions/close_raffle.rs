@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::Raffle;
+
+#[derive(Accounts)]
+pub struct CloseRaffle<'info> {
+    /// Marketplace authority wallet.
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Raffle::PREFIX.as_bytes(),
+            raffle.namespace.as_ref(),
+            raffle.raffle_id.as_ref()
+        ],
+        bump = raffle.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+}
+
+/// Ends ticket sales once the raffle is sold out or past its end time. The marketplace authority
+/// commits to the hash of a secret seed here, which is revealed when drawing together with the
+/// entropy of the slot the raffle was closed in, so neither side alone can pick the winner.
+pub fn close_raffle_handler(ctx: Context<CloseRaffle>, seed_hash: [u8;32]) -> Result<()> {
+    ctx.accounts.raffle.close_sales(seed_hash)
+}
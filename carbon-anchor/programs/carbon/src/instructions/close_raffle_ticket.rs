@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Raffle, RaffleState, RaffleTicket},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CloseRaffleTicket<'info> {
+    /// Buyer wallet.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Wallet that paid the rent of the ticket.
+    /// CHECK: Safe because of ticket constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Raffle the tickets were bought for, closed once its prize was claimed.
+    /// CHECK: Validated in handler
+    pub raffle: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            RaffleTicket::PREFIX.as_bytes(),
+            raffle.key().as_ref(),
+            ticket.first_ticket.to_le_bytes().as_ref()
+        ],
+        bump = ticket.bump[0],
+        has_one = raffle,
+        has_one = buyer,
+        constraint = ticket.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub ticket: Box<Account<'info, RaffleTicket>>,
+}
+
+/// Returns the rent of tickets that did not win to whoever paid it once the raffle was drawn
+pub fn close_raffle_ticket_handler(ctx: Context<CloseRaffleTicket>) -> Result<()> {
+    if ctx.accounts.raffle.data_is_empty() {
+        return Ok(());
+    }
+
+    let raffle = Account::<Raffle>::try_from(&ctx.accounts.raffle.to_account_info())?;
+    require!(raffle.state == RaffleState::Drawn, Error::RaffleNotDrawn);
+    require!(!ctx.accounts.ticket.contains(raffle.winning_ticket), Error::WinningTicketUnclaimed);
+
+    Ok(())
+}
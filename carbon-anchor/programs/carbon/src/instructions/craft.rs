@@ -0,0 +1,241 @@
+use anchor_lang::{prelude::*, AccountsClose};
+use anchor_spl::{
+	token::{Mint, Token},
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		update_primary_sale_happened_via_token,
+		MetadataAccount,
+		VerifySizedCollectionItem,
+		UpdatePrimarySaleHappenedViaToken
+	},
+	metadata
+};
+use crate::{
+	state::{CollectionConfig, MintRecord, Recipe},
+	event::Craft,
+	util::{assert_is_nft_in_collection, assert_keys_equal, mint_nft, BurnableNft},
+	error::Error
+};
+
+#[derive(Accounts)]
+#[instruction(item_id: [u8;32])]
+pub struct CraftItem<'info> {
+	/// Player wallet holding the inputs.
+	#[account(mut)]
+	pub player: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the player pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Marketplace authority wallet.
+	pub marketplace_authority: Signer<'info>,
+
+	/// The new mint to be used for the crafted NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Player NFT token account for the crafted NFT.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub player_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the crafted NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the crafted NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection and burn CPIs
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		seeds = [
+			Recipe::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			recipe.recipe_id.as_ref()
+		],
+		bump = recipe.bump[0],
+		has_one = collection_config,
+	)]
+	pub recipe: Box<Account<'info, Recipe>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = payer.as_ref().unwrap_or(&player),
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Burns the player's NFTs matching the recipe's inputs and mints the recipe's output to the
+/// player as a new item of the collection. The mint records of the burned NFTs are closed to
+/// whoever paid their rent.
+///
+/// The remaining accounts should contain for each NFT to burn, in the following order:
+/// 1. mint account
+/// 2. player token account
+/// 3. metadata account
+/// 4. edition account
+/// 5. mint record account
+/// 6. wallet that paid the rent of the mint record
+pub fn craft_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, CraftItem<'info>>,
+	item_id: [u8;32]
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.player).to_account_info();
+	let recipe = &ctx.accounts.recipe;
+	let collection_config_key = ctx.accounts.collection_config.key();
+	let player = &ctx.accounts.player.to_account_info();
+
+	require!(
+		ctx.remaining_accounts.len() == recipe.input_quantity() * 6,
+		Error::InvalidRecipeInputs
+	);
+
+	let mut names = Vec::with_capacity(recipe.input_quantity());
+	let mut burned_mints = Vec::with_capacity(recipe.input_quantity());
+	let remaining_accounts = &mut ctx.remaining_accounts.iter();
+	while remaining_accounts.len() > 0 {
+		let mint = next_account_info(remaining_accounts)?;
+		let token_account = next_account_info(remaining_accounts)?;
+		let metadata_account = next_account_info(remaining_accounts)?;
+		let edition = next_account_info(remaining_accounts)?;
+		let mint_record_account = next_account_info(remaining_accounts)?;
+		let mint_record_rent_payer = next_account_info(remaining_accounts)?;
+
+		let nft_mint = Account::<Mint>::try_from(mint)?;
+		let nft_metadata = Account::<MetadataAccount>::try_from(metadata_account)?;
+		assert_is_nft_in_collection(&nft_mint, &nft_metadata, ctx.accounts.collection_mint.key())?;
+		names.push(nft_metadata.data.name.trim_end_matches(char::from(0)).to_string());
+
+		let mint_record = Account::<MintRecord>::try_from(mint_record_account)?;
+		assert_keys_equal(mint_record.collection_config, collection_config_key, "Invalid mint record")?;
+		assert_keys_equal(mint_record.mint, mint.key(), "Invalid mint record")?;
+		require!(mint_record.payer == mint_record_rent_payer.key(), Error::InvalidRentPayer);
+
+		BurnableNft {
+			metadata: metadata_account.clone(),
+			owner: player.clone(),
+			mint: mint.clone(),
+			token_account: token_account.clone(),
+			edition: edition.clone(),
+			collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+		}.burn(
+			&ctx.accounts.token_metadata_program.to_account_info(),
+			&ctx.accounts.token_program.to_account_info()
+		)?;
+		mint_record.close(mint_record_rent_payer.clone())?;
+
+		burned_mints.push(mint.key());
+	}
+	recipe.assert_inputs(&names)?;
+
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		collection_config_key,
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
+
+	let data = &ctx.accounts.collection_config.get_mpl_metadata(recipe.output.clone())?;
+	// Mint the crafted NFT to the player.
+	mint_nft(
+		&payer,
+		player,
+		&ctx.accounts.player_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data.clone(),
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		None
+	)?;
+
+	// Mark the item as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: payer.clone(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: ctx.accounts.marketplace_authority.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+		),
+		None
+	)?;
+
+	// Mark that the primary sale happened.
+	update_primary_sale_happened_via_token(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			UpdatePrimarySaleHappenedViaToken {
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				owner: player.clone(),
+				token: ctx.accounts.player_token_account.to_account_info(),
+			}
+		)
+	)?;
+
+	emit!(Craft {
+		recipe_id: recipe.recipe_id,
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		player: player.key(),
+		marketplace_authority: ctx.accounts.collection_config.marketplace_authority,
+		collection_mint: ctx.accounts.collection_mint.key(),
+		burned_mints,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::{MarketplaceConfig, Raffle, RaffleArgs},
+    event::RaffleCreate,
+    util::assert_is_mint,
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: RaffleArgs)]
+pub struct CreateRaffle<'info> {
+    /// Creator wallet.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the creator pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Creator's token account of the NFT to raffle.
+    #[account(
+        mut,
+        constraint = token_account.owner == creator.key(),
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the NFT to raffle.
+    #[account(constraint = mint.supply == 1 && mint.decimals == 0 @ Error::InvalidMint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The currency tickets are paid in or native mint if using SOL
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            Raffle::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
+            args.raffle_id.as_ref()
+        ],
+        bump,
+        space = Raffle::SPACE,
+        payer = payer.as_ref().unwrap_or(&creator),
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    /// Raffle's token account escrowing the NFT.
+    #[account(
+        init,
+        associated_token::mint = mint,
+        associated_token::authority = raffle,
+        payer = payer.as_ref().unwrap_or(&creator),
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            marketplace_config.marketplace_authority.key().as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Raffles an NFT escrowed in the raffle's token account. Programmable NFTs are not supported as
+/// their token accounts stay frozen outside of Token Metadata transfers.
+pub fn create_raffle_handler(
+    ctx: Context<CreateRaffle>,
+    args: RaffleArgs,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.creator).to_account_info();
+    assert_is_mint(&ctx.accounts.currency_mint)?;
+
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.init(
+        [*ctx.bumps.get(Raffle::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        &ctx.accounts.marketplace_config,
+        ctx.accounts.creator.key(),
+        ctx.accounts.currency_mint.key(),
+        args,
+    )?;
+    raffle.payer = payer.key();
+    raffle.set_nft_prize(ctx.accounts.mint.key());
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    emit!(RaffleCreate {
+        raffle_id: raffle.raffle_id,
+        creator: raffle.creator,
+        marketplace_authority: raffle.marketplace_authority,
+        is_virtual: false,
+        item_id: raffle.item_id,
+        currency_mint: raffle.currency_mint,
+        ticket_price: raffle.ticket_price,
+        max_tickets: raffle.max_tickets,
+        end_time: raffle.end_time,
+        fee_config: raffle.fee_config,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Session, SessionArgs},
+    event::SessionCreate,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct CreateSession<'info> {
+    /// Wallet the session key signs for.
+    pub owner: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the owner pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Ephemeral key allowed to sign for the owner.
+    /// CHECK: Can be any key the owner chooses
+    pub session_key: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            Session::PREFIX.as_bytes(),
+            owner.key().as_ref(),
+            session_key.key().as_ref()
+        ],
+        bump,
+        space = Session::SPACE,
+        payer = payer.as_ref().unwrap_or(&owner),
+    )]
+    pub session: Box<Account<'info, Session>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorizes the session key to sign listing and custody actions for the owner until the expiry,
+/// within the scope of the session.
+///
+/// Actions that move or delegate the owner's tokens still need the owner's signature, except
+/// custody of an NFT the owner already approved the custody account as delegate of.
+pub fn create_session_handler(ctx: Context<CreateSession>, args: SessionArgs) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.owner).to_account_info();
+    let session = &mut ctx.accounts.session;
+    session.init(
+        [*ctx.bumps.get(Session::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.owner.key(),
+        ctx.accounts.session_key.key(),
+        args,
+    )?;
+    session.payer = payer.key();
+
+    emit!(SessionCreate {
+        owner: session.owner,
+        session_key: session.session_key,
+        marketplace_authority: session.marketplace_authority,
+        expiry: session.expiry,
+        scope: session.scope,
+        collection_mints: session.collection_mints.clone(),
+        max_price: session.max_price,
+        min_price: session.min_price,
+    });
+
+    Ok(())
+}
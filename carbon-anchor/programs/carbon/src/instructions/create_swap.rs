@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+    metadata::{Metadata, MetadataAccount}
+};
+use crate::{
+    state::{Swap, SwapArgs, CollectionConfig, CustodyAccount},
+    event::SwapCreate,
+    util::{approve_and_freeze, assert_is_nft_in_collection, assert_keys_equal, is_native_mint, transfer_sol, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: SwapArgs)]
+pub struct CreateSwap<'info> {
+    /// Wallet proposing the swap.
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the maker pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    #[account(
+        init,
+        seeds = [
+            Swap::PREFIX.as_bytes(),
+            maker.key().as_ref(),
+            args.swap_id.as_ref()
+        ],
+        bump,
+        space = Swap::SPACE,
+        payer = payer.as_ref().unwrap_or(&maker),
+    )]
+    pub swap: Box<Account<'info, Swap>>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    /// The currency of the swap amounts or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// The remaining accounts should contain the following for each maker mint, in the order of the args:
+/// 1. mint account
+/// 2. maker token account
+/// 3. edition account
+/// 4. custody account
+/// 5. metadata account
+///
+/// When the maker escrows an SPL token, followed by:
+/// 1. maker currency ata
+/// 2. swap currency ata
+pub fn create_swap_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSwap<'info>>,
+    args: SwapArgs,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.maker).to_account_info();
+    let swap_account = &ctx.accounts.swap.to_account_info().clone();
+    let collection_config = &ctx.accounts.collection_config;
+
+    let swap = &mut ctx.accounts.swap;
+    swap.init(
+        [*ctx.bumps.get(Swap::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.maker.key(),
+        args,
+        collection_config,
+        ctx.accounts.currency_mint.key(),
+    )?;
+    swap.payer = payer.key();
+
+    let auth_seeds = swap.auth_seeds();
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+
+    for expected_mint in swap.maker_mints.iter() {
+        let mint = next_account_info(remaining_accounts)?;
+        let token_account = next_account_info(remaining_accounts)?;
+        let edition = next_account_info(remaining_accounts)?;
+        let custody_account = next_account_info(remaining_accounts)?;
+        let metadata_account = next_account_info(remaining_accounts)?;
+
+        assert_keys_equal(mint.key(), *expected_mint, "Invalid swap mint")?;
+        assert_is_nft_in_collection(
+            &Account::<'info, Mint>::try_from(mint)?,
+            &Account::<'info, MetadataAccount>::try_from(metadata_account)?,
+            swap.collection_mint
+        )?;
+        CustodyAccount::assert_is_key_for_mint(custody_account.key(), mint.key())?;
+
+        if custody_account.data_is_empty() {
+            approve_and_freeze(
+                token_account,
+                mint,
+                edition,
+                &ctx.accounts.maker.to_account_info(),
+                swap_account,
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.token_metadata_program.to_account_info(),
+                Some(&auth_seeds),
+                1
+            )?;
+        } else {
+            let account_loader = AccountLoader::<'info, CustodyAccount>::try_from(custody_account)?;
+
+            {
+                let custody_account = account_loader.load()?;
+                assert_keys_equal(
+                    custody_account.marketplace_authority,
+                    swap.marketplace_authority,
+                    "Invalid marketplace authority"
+                )?;
+                assert_keys_equal(custody_account.owner, swap.maker, "Invalid custody owner")?;
+                require!(!custody_account.is_listed, Error::NftIsListed);
+            }
+
+            let custody_account = &mut account_loader.load_mut()?;
+            custody_account.is_listed = true;
+        }
+    }
+
+    if is_native_mint(swap.currency_mint) {
+        transfer_sol(
+            &ctx.accounts.maker.to_account_info(),
+            swap_account,
+            &ctx.accounts.system_program.to_account_info(),
+            None,
+            swap.maker_amount
+        )?;
+    } else if swap.maker_amount > 0 {
+        let maker_currency_account = next_account_info(remaining_accounts)?;
+        let swap_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            &ctx.accounts.maker.to_account_info(),
+            swap_account,
+            maker_currency_account,
+            swap_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            None,
+            None,
+            swap.maker_amount
+        )?;
+    }
+
+    emit!(SwapCreate {
+        swap_id: swap.swap_id,
+        maker: swap.maker,
+        taker: swap.taker,
+        marketplace_authority: swap.marketplace_authority,
+        collection_mint: swap.collection_mint,
+        currency_mint: swap.currency_mint,
+        maker_mints: swap.maker_mints.clone(),
+        maker_amount: swap.maker_amount,
+        taker_mints: swap.taker_mints.clone(),
+        taker_amount: swap.taker_amount,
+        royalty_per_nft: swap.royalty_per_nft,
+        expiry: swap.expiry,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{CollectionConfig, MarketplaceConfig, Metadata, MintStandard, Raffle, RaffleArgs},
+    event::RaffleCreate,
+    util::assert_is_mint,
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: RaffleArgs)]
+pub struct CreateVirtualRaffle<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    /// The currency tickets are paid in or native mint if using SOL
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            Raffle::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
+            args.raffle_id.as_ref()
+        ],
+        bump,
+        space = Raffle::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            marketplace_authority.key().as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Raffles a virtual item of the collection, minted to the winner when claimed
+pub fn create_virtual_raffle_handler(
+    ctx: Context<CreateVirtualRaffle>,
+    args: RaffleArgs,
+    item_id: [u8;32],
+    metadata: Metadata,
+) -> Result<()> {
+    require!(
+        ctx.accounts.collection_config.mint_standard == MintStandard::NonFungible,
+        Error::UnsupportedMintStandard
+    );
+    assert_is_mint(&ctx.accounts.currency_mint)?;
+
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.init(
+        [*ctx.bumps.get(Raffle::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        &ctx.accounts.marketplace_config,
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.currency_mint.key(),
+        args,
+    )?;
+    raffle.payer = ctx.accounts.marketplace_authority.key();
+    raffle.set_virtual_prize(ctx.accounts.collection_config.key(), item_id, metadata)?;
+
+    emit!(RaffleCreate {
+        raffle_id: raffle.raffle_id,
+        creator: raffle.creator,
+        marketplace_authority: raffle.marketplace_authority,
+        is_virtual: true,
+        item_id,
+        currency_mint: raffle.currency_mint,
+        ticket_price: raffle.ticket_price,
+        max_tickets: raffle.max_tickets,
+        end_time: raffle.end_time,
+        fee_config: raffle.fee_config,
+    });
+
+    Ok(())
+}
@@ -1,17 +1,32 @@
 use anchor_lang::prelude::*;
 use anchor_spl::metadata::Metadata;
 use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::instruction::DelegateArgs;
+use solana_program::program_option::COption;
 use crate::{
-    state::{CustodyAccount, Listing},
-    util::approve_and_freeze,
+    state::{CustodyAccount, Listing, Session, SessionAction},
+    util::{approve_and_freeze, freeze, is_programmable, ProgrammableNft},
     error::Error
 };
 
 #[derive(Accounts)]
 pub struct Custody<'info> {
-    /// User wallet.
+    /// User wallet, which only has to sign when it has not approved the custody account as
+    /// delegate of the token account.
+    /// CHECK: Validated in handler
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: UncheckedAccount<'info>,
+
+    /// The owner, or the key of a session of the owner.
+    pub authority: Signer<'info>,
+
+    /// Session authorizing the authority, or any account if the owner signs.
+    /// CHECK: Validated in handler
+    pub session: UncheckedAccount<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the authority pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
 
     /// Marketplace authority wallet.
     /// CHECK: Can be any marketplace authority
@@ -40,7 +55,7 @@ pub struct Custody<'info> {
         ],
         bump,
         space = CustodyAccount::SPACE,
-        payer = owner,
+        payer = payer.as_ref().unwrap_or(&authority),
     )]
     pub custody_account: AccountLoader<'info, CustodyAccount>,
 
@@ -53,17 +68,38 @@ pub struct Custody<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// When the NFT is programmable, the remaining accounts should contain:
+/// 1. metadata account
+/// 2. token record account
+/// 3. authorization rules program, or token metadata program if the NFT has no rule set
+/// 4. authorization rules account, or token metadata program if the NFT has no rule set
+/// 5. instructions sysvar
+///
+/// A session key can only custody a non-programmable NFT whose owner already approved the custody
+/// account as delegate of the token account, so the session's collections don't apply.
 pub fn custody_handler<'info>(
-    ctx: Context<Custody>,
+    ctx: Context<'_, '_, '_, 'info, Custody<'info>>,
     item_id: [u8; 32],
 ) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.authority).to_account_info();
     let listing = Listing::from_account_info_with_checks(
         &ctx.accounts.listing.to_account_info(),
+        ctx.accounts.marketplace_authority.key(),
         ctx.accounts.mint.key().to_bytes()
     )?;
 
     require!(listing.is_none(), Error::NftIsListed);
 
+    Session::assert_can_sign_for(
+        ctx.accounts.owner.key(),
+        ctx.accounts.authority.key(),
+        &ctx.accounts.session.to_account_info(),
+        ctx.accounts.marketplace_authority.key(),
+        SessionAction::Custody,
+        None,
+        None,
+    )?;
+
     {
         let custody_account = &mut ctx.accounts.custody_account.load_init()?;
         custody_account.init(
@@ -73,6 +109,7 @@ pub fn custody_handler<'info>(
             ctx.accounts.mint.key(),
             item_id
         )?;
+        custody_account.payer = payer.key();
     }
 
     let bump = [*ctx.bumps.get(CustodyAccount::PREFIX).unwrap()];
@@ -81,17 +118,50 @@ pub fn custody_handler<'info>(
         &bump
     );
 
-    approve_and_freeze(
-        &ctx.accounts.token_account.to_account_info(),
-        &ctx.accounts.mint.to_account_info(),
-        &ctx.accounts.edition.to_account_info(),
-        &ctx.accounts.owner.to_account_info(),
-        &ctx.accounts.custody_account.to_account_info(),
-        &ctx.accounts.token_program.to_account_info(),
-        &ctx.accounts.token_metadata_program.to_account_info(),
-        Some(&auth_seeds),
-        1
-    )?;
+    let programmable_nft = ctx.accounts.programmable_nft(&mut ctx.remaining_accounts.iter())?;
+
+    if let Some(nft) = programmable_nft {
+        require!(ctx.accounts.owner.is_signer, Error::OwnerSignatureRequired);
+
+        // Locked transfer delegates can lock the NFT, but only move it to the marketplace authority
+        let custody_account = &ctx.accounts.custody_account.to_account_info();
+        nft.delegate(custody_account, DelegateArgs::LockedTransferV1 {
+            amount: 1,
+            locked_address: ctx.accounts.marketplace_authority.key(),
+            authorization_data: None,
+        })?;
+        nft.lock(custody_account, Some(&auth_seeds))?;
+    } else if !ctx.accounts.owner.is_signer {
+        // The owner approved the custody account up front, so freezing needs no owner signature
+        let token_account = &ctx.accounts.token_account;
+        require!(
+            token_account.delegate == COption::Some(ctx.accounts.custody_account.key()) &&
+            token_account.delegated_amount >= 1,
+            Error::OwnerSignatureRequired
+        );
+
+        freeze(
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.edition.to_account_info(),
+            &ctx.accounts.custody_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            Some(&auth_seeds),
+        )?;
+    } else {
+        approve_and_freeze(
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.edition.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.custody_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            Some(&auth_seeds),
+            1
+        )?;
+    }
 
     emit!(crate::event::Custody {
         marketplace_authority: ctx.accounts.marketplace_authority.key(),
@@ -101,4 +171,38 @@ pub fn custody_handler<'info>(
     });
 
     Ok(())
+}
+
+impl<'info> Custody<'info> {
+
+    /// Returns the programmable NFT when the remaining accounts start with the metadata of one
+    fn programmable_nft<'b>(
+        &self,
+        remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+    ) -> Result<Option<ProgrammableNft<'info>>> {
+        let metadata = match remaining_accounts.next() {
+            Some(metadata) if is_programmable(metadata)? => metadata,
+            _ => return Ok(None),
+        };
+
+        let nft = ProgrammableNft {
+            token_account: self.token_account.to_account_info(),
+            token_owner: self.owner.to_account_info(),
+            mint: self.mint.to_account_info(),
+            metadata: metadata.clone(),
+            edition: self.edition.to_account_info(),
+            token_record: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules: next_account_info(remaining_accounts)?.clone(),
+            sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+            payer: self.payer.as_ref().unwrap_or(&self.authority).to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        nft.assert_is_valid()?;
+
+        Ok(Some(nft))
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{
+    state::FungibleListing,
+    event::{Delist, DelistReason},
+    util::release_escrow,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct DelistFungible<'info> {
+    /// Seller wallet.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Wallet that paid the rent of the listing.
+    /// CHECK: Safe because of listing constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Seller's token account to return the unsold tokens to.
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the token for sale.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            FungibleListing::PREFIX.as_bytes(),
+            listing.namespace.as_ref(),
+            seller.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = listing.bump[0],
+        has_one = seller @ Error::InvalidSeller,
+        has_one = mint @ Error::InvalidMint,
+        constraint = listing.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub listing: Box<Account<'info, FungibleListing>>,
+
+    /// Listing's token account holding the tokens for sale.
+    /// CHECK: Validated in release_escrow
+    #[account(mut)]
+    pub escrow_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Returns the unsold tokens to the seller, expired or not
+pub fn delist_fungible_handler(ctx: Context<DelistFungible>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let auth_seeds = listing.auth_seeds();
+
+    release_escrow(
+        &listing.to_account_info(),
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &auth_seeds,
+    )?;
+
+    emit!(Delist {
+        item_id: listing.mint.to_bytes(),
+        seller: listing.seller,
+        marketplace_authority: listing.marketplace_authority,
+        collection_mint: Pubkey::default(),
+        reason: DelistReason::Seller,
+    });
+
+    Ok(())
+}
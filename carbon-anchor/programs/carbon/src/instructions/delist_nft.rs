@@ -3,23 +3,33 @@ use anchor_spl::{
     token::{Mint, Token, TokenAccount},
     metadata::Metadata
 };
+use mpl_token_metadata::instruction::RevokeArgs;
 use crate::{
-    state::{Listing, CustodyAccount},
-    event::Delist,
-    util::{assert_keys_equal, thaw_and_revoke},
+    state::{Listing, CustodyAccount, Session, SessionAction},
+    event::{Delist, DelistReason},
+    util::{assert_keys_equal, is_programmable, release_escrow, thaw_and_revoke, ProgrammableNft},
     error::Error,
 };
 
 #[derive(Accounts)]
 pub struct DelistNft<'info> {
-    /// Seller wallet.
+    /// Seller wallet, which only has to sign when the NFT is frozen or delegated to the listing.
+    /// CHECK: Safe because of listing constraint
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub seller: UncheckedAccount<'info>,
+
+    /// The seller, or the key of a session of the seller.
+    pub authority: Signer<'info>,
+
+    /// Session authorizing the authority, or any account if the seller signs.
+    /// CHECK: Validated in handler
+    pub session: UncheckedAccount<'info>,
 
     /// Seller's token account of the mint to delist.
     #[account(
         mut,
         token::mint = mint,
+        token::authority = seller,
     )]
     pub token_account: Box<Account<'info, TokenAccount>>,
 
@@ -30,17 +40,24 @@ pub struct DelistNft<'info> {
     /// CHECK: Freeze would fail if incorrect
     pub edition: UncheckedAccount<'info>,
 
+    /// Wallet that paid the rent of the listing.
+    /// CHECK: Safe because of listing constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        close = seller,
+        close = rent_payer,
         seeds = [
             Listing::PREFIX.as_bytes(),
+            listing.namespace_seed(),
             mint.key().as_ref()
         ],
         bump = listing.bump[0],
         has_one = seller @ Error::InvalidSeller,
         constraint = !listing.is_virtual @ Error::IsVirtual,
         constraint = listing.item_id == mint.key().to_bytes() @ Error::InvalidMint,
+        constraint = listing.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
     )]
     pub listing: Box<Account<'info, Listing>>,
 
@@ -53,6 +70,18 @@ pub struct DelistNft<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// When the NFT is programmable, the remaining accounts should contain:
+/// 1. metadata account
+/// 2. token record account
+/// 3. authorization rules program, or token metadata program if the NFT has no rule set
+/// 4. authorization rules account, or token metadata program if the NFT has no rule set
+/// 5. instructions sysvar
+///
+/// When the NFT is escrowed, the remaining accounts should contain:
+/// 1. listing token account
+///
+/// A session key can delist escrowed and custodied NFTs, as thawing or revoking the listing's
+/// delegation needs the seller's signature.
 pub fn delist_nft_handler<'info>(
     ctx: Context<'_, '_, '_, 'info, DelistNft<'info>>
 ) -> Result<()> {
@@ -64,7 +93,33 @@ pub fn delist_nft_handler<'info>(
     let listing = &ctx.accounts.listing;
     let auth_seeds = listing.auth_seeds();
 
-    if ctx.accounts.custody_account.data_is_empty() {
+    Session::assert_can_sign_for(
+        listing.seller,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.session.to_account_info(),
+        listing.marketplace_authority,
+        SessionAction::Delist,
+        Some(listing.collection_mint),
+        None,
+    )?;
+
+    if !listing.is_escrowed && ctx.accounts.custody_account.data_is_empty() {
+        require!(ctx.accounts.seller.is_signer, Error::OwnerSignatureRequired);
+    }
+
+    if listing.is_escrowed {
+        release_escrow(
+            &listing.to_account_info(),
+            next_account_info(&mut ctx.remaining_accounts.iter())?,
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.rent_payer.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &auth_seeds
+        )?;
+    } else if let Some(nft) = ctx.accounts.programmable_nft(&mut ctx.remaining_accounts.iter())? {
+        nft.revoke(&ctx.accounts.listing.to_account_info(), RevokeArgs::SaleV1)?;
+    } else if ctx.accounts.custody_account.data_is_empty() {
         thaw_and_revoke(
             &ctx.accounts.token_account.to_account_info(),
             &ctx.accounts.mint.to_account_info(),
@@ -95,7 +150,42 @@ pub fn delist_nft_handler<'info>(
         seller: listing.seller,
         marketplace_authority: listing.marketplace_authority,
         collection_mint: listing.collection_mint,
+        reason: DelistReason::Seller,
     });
 
     Ok(())
+}
+
+impl<'info> DelistNft<'info> {
+
+    /// Returns the programmable NFT when the remaining accounts start with the metadata of one
+    fn programmable_nft<'b>(
+        &self,
+        remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+    ) -> Result<Option<ProgrammableNft<'info>>> {
+        let metadata = match remaining_accounts.next() {
+            Some(metadata) if is_programmable(metadata)? => metadata,
+            _ => return Ok(None),
+        };
+
+        let nft = ProgrammableNft {
+            token_account: self.token_account.to_account_info(),
+            token_owner: self.seller.to_account_info(),
+            mint: self.mint.to_account_info(),
+            metadata: metadata.clone(),
+            edition: self.edition.to_account_info(),
+            token_record: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules: next_account_info(remaining_accounts)?.clone(),
+            sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+            payer: self.seller.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        nft.assert_is_valid()?;
+
+        Ok(Some(nft))
+    }
+
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::{
     state::{Listing},
-    event::Delist,
+    event::{Delist, DelistReason},
+    error::Error,
 };
 
 #[derive(Accounts)]
@@ -11,15 +12,22 @@ pub struct DelistVirtual<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    /// Wallet that paid the rent of the listing.
+    /// CHECK: Safe because of listing constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        close = seller,
+        close = rent_payer,
         seeds = [
             Listing::PREFIX.as_bytes(),
+            listing.namespace_seed(),
             item_id.as_ref()
         ],
         bump = listing.bump[0],
         has_one = seller,
+        constraint = listing.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
     )]
     pub listing: Box<Account<'info, Listing>>,
 
@@ -36,6 +44,7 @@ pub fn delist_virtual_handler<'info>(
         seller: ctx.accounts.listing.seller,
         marketplace_authority: ctx.accounts.listing.marketplace_authority,
         collection_mint: ctx.accounts.listing.collection_mint,
+        reason: DelistReason::Seller,
     });
 
     Ok(())
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+};
+use crate::{
+    state::Raffle,
+    event::RaffleDraw,
+    util::{assert_keys_equal, is_native_mint, next_currency_accounts, transfer_lamports, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct DrawRaffle<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    /// Wallet that created the raffle.
+    /// CHECK: Safe due to raffle constraint
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Raffle::PREFIX.as_bytes(),
+            raffle.namespace.as_ref(),
+            raffle.raffle_id.as_ref()
+        ],
+        bump = raffle.bump[0],
+        has_one = marketplace_authority,
+        has_one = creator,
+        constraint = raffle.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    /// Account to send fees to.
+    /// CHECK: Safe because of raffle constraint
+    #[account(mut)]
+    pub fee_account: UncheckedAccount<'info>,
+
+    /// Account the raffle's randomness source reads entropy from, the SlotHashes sysvar for slot
+    /// hashes.
+    /// CHECK: Verified by the randomness source
+    pub randomness_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Reveals the seed committed when the raffle was closed, draws the winning ticket and pays the
+/// ticket proceeds out to the creator, less marketplace fees. This must happen within 512 slots of
+/// closing, while the slot's hash is still in the SlotHashes sysvar, after which anyone can expire
+/// the raffle and have the tickets refunded.
+///
+/// When tickets were paid with an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
+/// 2. raffle currency ata
+/// 3. creator currency ata
+/// 4. marketplace fee currency ata
+pub fn draw_raffle_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DrawRaffle<'info>>,
+    seed: [u8;32],
+) -> Result<()> {
+    let raffle_account = &ctx.accounts.raffle.to_account_info();
+    let raffle = &mut ctx.accounts.raffle;
+    let randomness = raffle.draw(seed, &ctx.accounts.randomness_account.to_account_info())?;
+
+    let proceeds = raffle.proceeds;
+    let fee_amount = raffle.get_fee_amount(proceeds)?;
+    let creator_amount = proceeds
+        .checked_sub(fee_amount)
+        .ok_or(Error::OverflowError)?;
+
+    let (creator_received, fee_received) = if is_native_mint(raffle.currency_mint) {
+        transfer_lamports(raffle_account, &ctx.accounts.fee_account.to_account_info(), fee_amount)?;
+        transfer_lamports(raffle_account, &ctx.accounts.creator.to_account_info(), creator_amount)?;
+
+        (creator_amount, fee_amount)
+    } else {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let (currency_mint, currency_token_program) = &next_currency_accounts(
+            remaining_accounts,
+            &ctx.accounts.token_program.to_account_info()
+        )?;
+        assert_keys_equal(raffle.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+        let raffle_currency_account = next_account_info(remaining_accounts)?;
+        let creator_currency_account = next_account_info(remaining_accounts)?;
+        let fee_currency_account = next_account_info(remaining_accounts)?;
+
+        let auth_seeds = raffle.auth_seeds();
+        let fee_received = transfer_spl(
+            raffle_account,
+            &ctx.accounts.fee_account.to_account_info(),
+            raffle_currency_account,
+            fee_currency_account,
+            currency_mint,
+            &ctx.accounts.marketplace_authority.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            currency_token_program,
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&auth_seeds),
+            None,
+            fee_amount,
+        )?;
+
+        let creator_received = transfer_spl(
+            raffle_account,
+            &ctx.accounts.creator.to_account_info(),
+            raffle_currency_account,
+            creator_currency_account,
+            currency_mint,
+            &ctx.accounts.marketplace_authority.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            currency_token_program,
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&auth_seeds),
+            None,
+            creator_amount,
+        )?;
+
+        (creator_received, fee_received)
+    };
+
+    emit!(RaffleDraw {
+        raffle_id: raffle.raffle_id,
+        creator: raffle.creator,
+        marketplace_authority: raffle.marketplace_authority,
+        winning_ticket: raffle.winning_ticket,
+        tickets_sold: raffle.tickets_sold,
+        randomness,
+        currency_mint: raffle.currency_mint,
+        creator_amount: creator_received,
+        fee_amount: fee_received,
+    });
+
+    Ok(())
+}
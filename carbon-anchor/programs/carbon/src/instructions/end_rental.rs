@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    metadata::Metadata
+};
+use crate::{
+    state::Rental,
+    event::RentalEnd,
+    util::{thaw, thaw_and_revoke},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct EndRental<'info> {
+    /// Any wallet once the rental term has ended, otherwise the owner.
+    pub authority: Signer<'info>,
+
+    /// Owner wallet.
+    /// CHECK: Safe because of rental constraint
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the rental.
+    /// CHECK: Safe because of rental constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Owner's token account of the rented mint.
+    #[account(
+        mut,
+        constraint = token_account.owner == owner.key(),
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the rented NFT.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Edition of the rented NFT.
+    /// CHECK: Thaw would fail if incorrect
+    pub edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Rental::PREFIX.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump = rental.bump[0],
+        has_one = owner,
+        has_one = mint,
+        constraint = rental.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub rental: Box<Account<'info, Rental>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn end_rental_handler(
+    ctx: Context<EndRental>,
+) -> Result<()> {
+    let rental = &ctx.accounts.rental;
+    let is_owner = ctx.accounts.authority.key() == rental.owner;
+
+    if rental.is_rented() {
+        require!(rental.has_term_ended()?, Error::RentalTermNotEnded);
+    } else {
+        require!(is_owner, Error::InvalidOwner);
+    }
+
+    let auth_seeds = rental.auth_seeds();
+
+    // Revoking needs the owner's signature. Without it the approval is left to the closed
+    // rental and replaced the next time the NFT is delegated.
+    if is_owner {
+        thaw_and_revoke(
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.edition.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &rental.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            Some(&auth_seeds)
+        )?;
+    } else {
+        thaw(
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.edition.to_account_info(),
+            &rental.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            Some(&auth_seeds)
+        )?;
+    }
+
+    emit!(RentalEnd {
+        mint: rental.mint,
+        owner: rental.owner,
+        renter: rental.renter,
+        marketplace_authority: rental.marketplace_authority,
+    });
+
+    Ok(())
+}
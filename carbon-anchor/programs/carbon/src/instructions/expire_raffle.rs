@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use crate::{
+    state::Raffle,
+    event::RaffleExpire,
+    util::{assert_keys_equal, release_escrow},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct ExpireRaffle<'info> {
+    /// Wallet that paid the rent of the raffle.
+    /// CHECK: Safe because of raffle constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Raffle::PREFIX.as_bytes(),
+            raffle.namespace.as_ref(),
+            raffle.raffle_id.as_ref()
+        ],
+        bump = raffle.bump[0],
+        constraint = raffle.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Voids a closed raffle once its draw can no longer be revealed, because the entropy of the
+/// closing slot is gone or the marketplace authority withheld the seed, or an open raffle with sold
+/// tickets the marketplace authority did not close within three days of its end time. Anyone can
+/// expire the raffle, which returns an escrowed NFT to the creator and lets every ticket be
+/// refunded.
+///
+/// When the prize is an escrowed NFT, the remaining accounts should be in the following order:
+/// 1. mint account of the NFT
+/// 2. raffle's escrow token account
+/// 3. creator's token account of the NFT
+pub fn expire_raffle_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExpireRaffle<'info>>,
+) -> Result<()> {
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.expire()?;
+
+    if !raffle.is_virtual {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let mint = next_account_info(remaining_accounts)?;
+        let escrow_token_account = next_account_info(remaining_accounts)?;
+        let creator_token_account = next_account_info(remaining_accounts)?;
+        assert_keys_equal(raffle.mint, mint.key(), "Invalid mint")?;
+
+        let auth_seeds = raffle.auth_seeds();
+        release_escrow(
+            &raffle.to_account_info(),
+            escrow_token_account,
+            creator_token_account,
+            mint,
+            &ctx.accounts.rent_payer.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &auth_seeds,
+        )?;
+    }
+
+    emit!(RaffleExpire {
+        raffle_id: raffle.raffle_id,
+        creator: raffle.creator,
+        marketplace_authority: raffle.marketplace_authority,
+        tickets_sold: raffle.tickets_sold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+    metadata::Metadata
+};
+use crate::{
+    state::Loan,
+    event::LoanForeclose,
+    util::{thaw, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct ForecloseLoan<'info> {
+    /// Wallet that lent the principal.
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    /// Wallet that defaulted on the loan.
+    /// CHECK: Safe because of loan constraint
+    pub borrower: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the loan.
+    /// CHECK: Safe because of loan constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Loan::PREFIX.as_bytes(),
+            loan.lender.as_ref(),
+            loan.loan_id.as_ref()
+        ],
+        bump = loan.bump[0],
+        has_one = lender,
+        has_one = borrower,
+        has_one = mint,
+        constraint = loan.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    /// Borrower's token account of the collateral.
+    #[account(
+        mut,
+        constraint = borrower_token_account.owner == borrower.key(),
+        token::mint = mint,
+    )]
+    pub borrower_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Lender's token account to receive the collateral.
+    /// CHECK: Checked or created in transfer
+    #[account(mut)]
+    pub lender_token_account: UncheckedAccount<'info>,
+
+    /// Mint of the collateral.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Edition of the collateral.
+    /// CHECK: Thaw would fail if incorrect
+    pub edition: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn foreclose_loan_handler(
+    ctx: Context<ForecloseLoan>,
+) -> Result<()> {
+    let loan = &ctx.accounts.loan;
+    require!(loan.is_active(), Error::LoanNotActive);
+    require!(loan.is_overdue()?, Error::LoanNotOverdue);
+
+    let loan_account = &loan.to_account_info();
+    let auth_seeds = loan.auth_seeds();
+
+    thaw(
+        &ctx.accounts.borrower_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.edition.to_account_info(),
+        loan_account,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        Some(&auth_seeds)
+    )?;
+
+    transfer_spl(
+        &ctx.accounts.borrower.to_account_info(),
+        &ctx.accounts.lender.to_account_info(),
+        &ctx.accounts.borrower_token_account.to_account_info(),
+        &ctx.accounts.lender_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.lender.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        Some(loan_account),
+        Some(&auth_seeds),
+        None,
+        1
+    )?;
+
+    emit!(LoanForeclose {
+        loan_id: loan.loan_id,
+        lender: loan.lender,
+        borrower: loan.borrower,
+        marketplace_authority: loan.marketplace_authority,
+        mint: loan.mint,
+    });
+
+    Ok(())
+}
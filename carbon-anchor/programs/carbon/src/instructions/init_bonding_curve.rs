@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    state::{BondingCurve, BondingCurveArgs, CollectionConfig, MintStandard},
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: BondingCurveArgs)]
+pub struct InitBondingCurve<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    /// The currency to use or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [
+            BondingCurve::PREFIX.as_bytes(),
+            collection_config.key().as_ref(),
+            args.item_type.as_ref()
+        ],
+        bump,
+        space = BondingCurve::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a primary sale of an item type, priced by the number of units in circulation
+pub fn init_bonding_curve_handler(
+    ctx: Context<InitBondingCurve>,
+    args: BondingCurveArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.collection_config.mint_standard == MintStandard::NonFungible,
+        Error::UnsupportedMintStandard
+    );
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.init(
+        [*ctx.bumps.get(BondingCurve::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.collection_config.key(),
+        ctx.accounts.currency_mint.key(),
+        args,
+    )?;
+
+    Ok(())
+}
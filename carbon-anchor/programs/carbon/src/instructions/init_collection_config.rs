@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{error::Error, CollectionConfig};
+use crate::{error::Error, CollectionConfig, CollectionVerification, MintStandard, SwapRoyaltyPolicy};
 
 #[derive(Accounts)]
 #[instruction(args: CollectionConfigArgs)]
@@ -28,7 +28,10 @@ pub struct InitCollectionConfig<'info> {
 pub struct CollectionConfigArgs {
     pub collection_mint: Pubkey,
     pub seller_fee_basis_points: u16,
-    pub symbol: String
+    pub symbol: String,
+    pub swap_royalty_policy: SwapRoyaltyPolicy,
+    pub mint_standard: MintStandard,
+    pub verification: CollectionVerification,
 }
 
 pub fn init_collection_config_handler<'info>(
@@ -39,9 +42,7 @@ pub fn init_collection_config_handler<'info>(
     collection_config.init(
         [*ctx.bumps.get(CollectionConfig::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
         ctx.accounts.marketplace_authority.key(),
-        args.collection_mint,
-        args.seller_fee_basis_points,
-        args.symbol,
+        args,
     )?;
 
     Ok(())
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    state::{Market, MarketplaceConfig, OrderBookSide},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct InitMarket<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            marketplace_authority.key().as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+        has_one = marketplace_authority
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    /// Mint of the token being traded.
+    pub base_mint: Box<Account<'info, Mint>>,
+
+    /// Mint of the token prices are quoted in.
+    pub quote_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [
+            Market::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
+            base_mint.key().as_ref(),
+            quote_mint.key().as_ref()
+        ],
+        bump,
+        space = Market::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init,
+        seeds = [
+            OrderBookSide::BIDS_PREFIX.as_bytes(),
+            market.key().as_ref()
+        ],
+        bump,
+        space = OrderBookSide::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub bids: AccountLoader<'info, OrderBookSide>,
+
+    #[account(
+        init,
+        seeds = [
+            OrderBookSide::ASKS_PREFIX.as_bytes(),
+            market.key().as_ref()
+        ],
+        bump,
+        space = OrderBookSide::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub asks: AccountLoader<'info, OrderBookSide>,
+
+    /// Market's token account holding the base tokens of open asks and unsettled buys.
+    #[account(
+        init,
+        associated_token::mint = base_mint,
+        associated_token::authority = market,
+        payer = marketplace_authority,
+    )]
+    pub base_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Market's token account holding the quote tokens of open bids, unsettled sales and fees.
+    #[account(
+        init,
+        associated_token::mint = quote_mint,
+        associated_token::authority = market,
+        payer = marketplace_authority,
+    )]
+    pub quote_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Approves a token pair for trading on the marketplace's order book
+pub fn init_market_handler(ctx: Context<InitMarket>, base_lot_size: u64) -> Result<()> {
+    let market_key = ctx.accounts.market.key();
+
+    let market = &mut ctx.accounts.market;
+    market.init(
+        [*ctx.bumps.get(Market::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        &ctx.accounts.marketplace_config,
+        ctx.accounts.base_mint.key(),
+        ctx.accounts.quote_mint.key(),
+        base_lot_size,
+    )?;
+    market.set_order_book(ctx.accounts.bids.key(), ctx.accounts.asks.key());
+
+    ctx.accounts.bids.load_init()?.init(market_key, true);
+    ctx.accounts.asks.load_init()?.init(market_key, false);
+
+    Ok(())
+}
@@ -25,7 +25,8 @@ pub struct InitMarketplaceConfig<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MarketplaceConfigArgs {
-    pub fee_config: FeeConfig
+    pub fee_config: FeeConfig,
+    pub keeper_reward: u64
 }
 
 pub fn init_marketplace_config_handler<'info>(
@@ -36,7 +37,8 @@ pub fn init_marketplace_config_handler<'info>(
     marketplace_config.init(
         [*ctx.bumps.get(MarketplaceConfig::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
         ctx.accounts.marketplace_authority.key(),
-        args.fee_config
+        args.fee_config,
+        args.keeper_reward
     )?;
 
     Ok(())
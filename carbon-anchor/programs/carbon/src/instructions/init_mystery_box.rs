@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{MysteryBox, MysteryBoxArgs, CollectionConfig, MintStandard},
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: MysteryBoxArgs)]
+pub struct InitMysteryBox<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        init,
+        seeds = [
+            MysteryBox::PREFIX.as_bytes(),
+            collection_config.key().as_ref(),
+            args.box_id.as_ref()
+        ],
+        bump,
+        space = MysteryBox::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub mystery_box: Box<Account<'info, MysteryBox>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a mystery box whose box NFTs are opened into a prize drawn from its prize table
+pub fn init_mystery_box_handler(
+    ctx: Context<InitMysteryBox>,
+    args: MysteryBoxArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.collection_config.mint_standard == MintStandard::NonFungible,
+        Error::UnsupportedMintStandard
+    );
+
+    let mystery_box = &mut ctx.accounts.mystery_box;
+    mystery_box.init(
+        [*ctx.bumps.get(MysteryBox::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.collection_config.key(),
+        args,
+    )?;
+
+    Ok(())
+}
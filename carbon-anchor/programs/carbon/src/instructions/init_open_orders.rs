@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Market, OpenOrders},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct InitOpenOrders<'info> {
+    /// Wallet placing orders on the market.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the owner pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init,
+        seeds = [
+            OpenOrders::PREFIX.as_bytes(),
+            market.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump,
+        space = OpenOrders::SPACE,
+        payer = payer.as_ref().unwrap_or(&owner),
+    )]
+    pub open_orders: AccountLoader<'info, OpenOrders>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_open_orders_handler(ctx: Context<InitOpenOrders>) -> Result<()> {
+    ctx.accounts.open_orders.load_init()?.init(
+        [*ctx.bumps.get(OpenOrders::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.market.key(),
+        ctx.accounts.owner.key(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{error::Error, util::assert_is_mint, PriceFeed};
+
+#[derive(Accounts)]
+pub struct InitPriceFeed<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    /// The currency the price is quoted for or native mint if using SOL
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            PriceFeed::PREFIX.as_bytes(),
+            marketplace_authority.key().as_ref(),
+            currency_mint.key().as_ref()
+        ],
+        bump,
+        space = PriceFeed::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub price_feed: Box<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn init_price_feed_handler(
+    ctx: Context<InitPriceFeed>,
+) -> Result<()> {
+    assert_is_mint(&ctx.accounts.currency_mint)?;
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.init(
+        [*ctx.bumps.get(PriceFeed::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.currency_mint.key(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Recipe, RecipeArgs, CollectionConfig, MintStandard},
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: RecipeArgs)]
+pub struct InitRecipe<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        init,
+        seeds = [
+            Recipe::PREFIX.as_bytes(),
+            collection_config.key().as_ref(),
+            args.recipe_id.as_ref()
+        ],
+        bump,
+        space = Recipe::SPACE,
+        payer = marketplace_authority,
+    )]
+    pub recipe: Box<Account<'info, Recipe>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a recipe for crafting an item of the collection from other items of the collection
+pub fn init_recipe_handler(
+    ctx: Context<InitRecipe>,
+    args: RecipeArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.collection_config.mint_standard == MintStandard::NonFungible,
+        Error::UnsupportedMintStandard
+    );
+
+    let recipe = &mut ctx.accounts.recipe;
+    recipe.init(
+        [*ctx.bumps.get(Recipe::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.collection_config.key(),
+        args,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::{FungibleListing, FungibleListingArgs, MarketplaceConfig},
+    event::List,
+    util::assert_is_mint,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct ListFungible<'info> {
+    /// Seller wallet.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the seller pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Seller's token account of the mint to sell.
+    #[account(
+        mut,
+        constraint = token_account.owner == seller.key(),
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the token to sell.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The currency to use or native mint if using SOL
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            FungibleListing::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
+            seller.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        space = FungibleListing::SPACE,
+        payer = payer.as_ref().unwrap_or(&seller),
+    )]
+    pub listing: Box<Account<'info, FungibleListing>>,
+
+    /// Listing's token account holding the tokens for sale.
+    #[account(
+        init,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+        payer = payer.as_ref().unwrap_or(&seller),
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            marketplace_config.marketplace_authority.key().as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Escrows the amount in the listing's token account, from where buyers take any part of it
+pub fn list_fungible_handler(
+    ctx: Context<ListFungible>,
+    args: FungibleListingArgs,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.seller).to_account_info();
+    assert_is_mint(&ctx.accounts.currency_mint)?;
+
+    let listing = &mut ctx.accounts.listing;
+    listing.init(
+        [*ctx.bumps.get(FungibleListing::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        &ctx.accounts.marketplace_config,
+        ctx.accounts.seller.key(),
+        &ctx.accounts.mint,
+        ctx.accounts.currency_mint.key(),
+        args,
+    )?;
+    listing.payer = payer.key();
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        listing.remaining_amount,
+    )?;
+
+    emit!(List {
+        item_id: listing.mint.to_bytes(),
+        price: listing.price,
+        expiry: listing.expiry,
+        start_time: 0,
+        seller: listing.seller,
+        is_virtual: false,
+        currency_mint: listing.currency_mint,
+        collection_mint: Pubkey::default(),
+        marketplace_authority: listing.marketplace_authority,
+        fee_config: listing.fee_config,
+        price_feed: Pubkey::default(),
+        amount: listing.remaining_amount,
+    });
+
+    Ok(())
+}
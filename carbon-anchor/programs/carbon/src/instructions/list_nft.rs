@@ -4,18 +4,34 @@ use anchor_spl::{
     metadata::Metadata
 };
 use anchor_spl::metadata::MetadataAccount;
+use mpl_token_metadata::instruction::DelegateArgs;
 use crate::{
-    state::{Listing, MarketplaceConfig, CollectionConfig, CustodyAccount},
+    state::{Listing, MarketplaceConfig, CollectionConfig, CustodyAccount, PriceFeed, Session, SessionAction},
     event::List,
-    util::{approve_and_freeze, assert_is_nft_in_collection, assert_keys_equal},
+    util::{
+        approve_and_freeze, assert_is_mint, assert_keys_equal, can_freeze_with_edition, is_programmable, transfer_spl,
+        ProgrammableNft
+    },
     error::Error
 };
 
 #[derive(Accounts)]
 pub struct ListNft<'info> {
-    /// Seller wallet.
+    /// Seller wallet, which only has to sign when the NFT is not custodied.
+    /// CHECK: Validated in handler
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub seller: UncheckedAccount<'info>,
+
+    /// The seller, or the key of a session of the seller.
+    pub authority: Signer<'info>,
+
+    /// Session authorizing the authority, or any account if the seller signs.
+    /// CHECK: Validated in handler
+    pub session: UncheckedAccount<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the authority pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
 
     /// Seller's token account of the mint to sell.
     #[account(
@@ -27,8 +43,10 @@ pub struct ListNft<'info> {
     /// Mint of the NFT to sell.
     pub mint: Box<Account<'info, Mint>>,
 
-    /// The verified collection mint of the NFT to sell.
-    pub collection_mint: Box<Account<'info, Mint>>,
+    /// The collection mint of the collection config, which only has to be a verified collection of
+    /// the NFT when the config verifies by collection.
+    /// CHECK: Safe because of collection_config constraint
+    pub collection_mint: UncheckedAccount<'info>,
 
     /// Metadata of the NFT to sell.
     #[account(
@@ -36,22 +54,29 @@ pub struct ListNft<'info> {
     )]
     pub metadata_account: Box<Account<'info, MetadataAccount>>,
 
-    /// Edition of the NFT to sell.
+    /// Edition of the NFT to sell. The NFT is escrowed instead of frozen if it is not the freeze
+    /// authority of the mint.
     /// CHECK: Freeze would fail if incorrect
     pub edition: UncheckedAccount<'info>,
 
     /// The currency to use or native mint if using SOL
-    pub currency_mint: Box<Account<'info, Mint>>,
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    /// Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency.
+    /// CHECK: Validated in handler
+    pub price_feed: UncheckedAccount<'info>,
 
     #[account(
         init,
         seeds = [
             Listing::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
             mint.key().as_ref()
         ],
         bump,
         space = Listing::SPACE,
-        payer = seller,
+        payer = payer.as_ref().unwrap_or(&authority),
     )]
     pub listing: Box<Account<'info, Listing>>,
 
@@ -85,18 +110,36 @@ pub struct ListNft<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// When the NFT is programmable, the remaining accounts should contain:
+/// 1. token record account
+/// 2. authorization rules program, or token metadata program if the NFT has no rule set
+/// 3. authorization rules account, or token metadata program if the NFT has no rule set
+/// 4. instructions sysvar
+///
+/// When the NFT can't be frozen through its edition, it is escrowed and the remaining accounts
+/// should contain:
+/// 1. listing token account, the ATA of the listing
+/// 2. associated token program
+///
+/// The proof is only checked when the collection config verifies NFTs by Merkle root.
+///
+/// A session key can only list a custodied NFT, as freezing, delegating or escrowing the NFT
+/// needs the seller's signature.
 pub fn list_nft_handler<'info>(
     ctx: Context<'_, '_, '_, 'info, ListNft<'info>>,
     price: u64,
     expiry: i64,
+    start_time: i64,
+    proof: Vec<[u8; 32]>,
 ) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.authority).to_account_info();
     require!(price > 0, Error::InvalidPrice);
     require!(expiry >= 0, Error::InvalidExpiry);
 
-    assert_is_nft_in_collection(
+    ctx.accounts.collection_config.assert_is_nft_verified(
         &ctx.accounts.mint,
         &ctx.accounts.metadata_account,
-        ctx.accounts.collection_mint.key()
+        &proof
     )?;
 
     CustodyAccount::assert_is_key_for_mint(
@@ -104,11 +147,41 @@ pub fn list_nft_handler<'info>(
         ctx.accounts.mint.key(),
     )?;
 
+    Session::assert_can_sign_for(
+        ctx.accounts.seller.key(),
+        ctx.accounts.authority.key(),
+        &ctx.accounts.session.to_account_info(),
+        ctx.accounts.marketplace_config.marketplace_authority,
+        SessionAction::List,
+        Some(ctx.accounts.collection_config.collection_mint),
+        Some(price),
+    )?;
+
+    if ctx.accounts.custody_account.data_is_empty() {
+        require!(ctx.accounts.seller.is_signer, Error::OwnerSignatureRequired);
+    }
+
+    PriceFeed::assert_is_valid_for_listing(
+        &ctx.accounts.price_feed.to_account_info(),
+        ctx.accounts.marketplace_config.marketplace_authority,
+        ctx.accounts.currency_mint.key(),
+    )?;
+
     let listing_account = &ctx.accounts.listing.to_account_info().clone();
+    let is_programmable = is_programmable(&ctx.accounts.metadata_account.to_account_info())?;
+    let programmable_nft = if is_programmable && ctx.accounts.custody_account.data_is_empty() {
+        Some(ctx.accounts.programmable_nft(&mut ctx.remaining_accounts.iter())?)
+    } else {
+        None
+    };
+    let is_escrowed = programmable_nft.is_none() &&
+        ctx.accounts.custody_account.data_is_empty() &&
+        !can_freeze_with_edition(&ctx.accounts.mint, &ctx.accounts.edition);
 
     let listing = &mut ctx.accounts.listing;
     listing.init(
         [*ctx.bumps.get(Listing::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_config.key(),
         ctx.accounts.marketplace_config.marketplace_authority,
         ctx.accounts.seller.key(),
         ctx.accounts.mint.key().to_bytes(),
@@ -116,11 +189,40 @@ pub fn list_nft_handler<'info>(
         ctx.accounts.currency_mint.key(),
         ctx.accounts.collection_mint.key(),
         ctx.accounts.marketplace_config.fee_config.clone(),
+        ctx.accounts.price_feed.key(),
+        assert_is_mint(&ctx.accounts.currency_mint)?.decimals,
         price,
         expiry,
+        start_time,
     )?;
+    listing.is_escrowed = is_escrowed;
+    listing.payer = payer.key();
 
-    if ctx.accounts.custody_account.data_is_empty() {
+    if let Some(nft) = programmable_nft {
+        // A sale delegate can transfer the NFT and stops the owner from moving it while listed
+        nft.delegate(listing_account, DelegateArgs::SaleV1 { amount: 1, authorization_data: None })?;
+    } else if is_escrowed {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let listing_token_account = next_account_info(remaining_accounts)?;
+        let associated_token_program = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            &ctx.accounts.seller.to_account_info(),
+            listing_account,
+            &ctx.accounts.token_account.to_account_info(),
+            listing_token_account,
+            &ctx.accounts.mint.to_account_info(),
+            &payer,
+            associated_token_program,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            None,
+            None,
+            1
+        )?;
+    } else if ctx.accounts.custody_account.data_is_empty() {
         let auth_seeds = listing.auth_seeds();
         approve_and_freeze(
             &ctx.accounts.token_account.to_account_info(),
@@ -134,6 +236,8 @@ pub fn list_nft_handler<'info>(
             1
         )?;
     } else {
+        require!(!is_programmable, Error::ProgrammableNftInCustody);
+
         let account_loader = AccountLoader::<'info, CustodyAccount>::try_from(
             &ctx.accounts.custody_account.to_account_info()
         )?;
@@ -151,6 +255,9 @@ pub fn list_nft_handler<'info>(
             Error::InvalidSeller
         );
 
+        // A custodial NFT escrowed in a swap is flagged as listed
+        require!(!account_loader.load()?.is_listed, Error::NftIsListed);
+
         let custody_account = &mut account_loader.load_mut()?;
         custody_account.is_listed = true;
     }
@@ -159,13 +266,44 @@ pub fn list_nft_handler<'info>(
         item_id: listing.item_id,
         price,
         expiry,
+        start_time,
         seller: listing.seller,
         is_virtual: false,
         currency_mint: listing.currency_mint,
         collection_mint: ctx.accounts.collection_config.collection_mint,
         marketplace_authority: listing.marketplace_authority,
         fee_config: listing.fee_config,
+        price_feed: listing.price_feed,
+        amount: 1,
     });
 
     Ok(())
+}
+
+impl<'info> ListNft<'info> {
+
+    fn programmable_nft<'b>(
+        &self,
+        remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+    ) -> Result<ProgrammableNft<'info>> {
+        let nft = ProgrammableNft {
+            token_account: self.token_account.to_account_info(),
+            token_owner: self.seller.to_account_info(),
+            mint: self.mint.to_account_info(),
+            metadata: self.metadata_account.to_account_info(),
+            edition: self.edition.to_account_info(),
+            token_record: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules: next_account_info(remaining_accounts)?.clone(),
+            sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+            payer: self.payer.as_ref().unwrap_or(&self.authority).to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        nft.assert_is_valid()?;
+
+        Ok(nft)
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    metadata::Metadata
+};
+use crate::{
+    state::{Rental, RentalArgs, MarketplaceConfig},
+    event::RentalList,
+    util::approve_and_freeze,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct ListRental<'info> {
+    /// Owner wallet.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the owner pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Owner's token account of the mint to rent out.
+    #[account(
+        mut,
+        constraint = token_account.owner == owner.key(),
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the NFT to rent out.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Edition of the NFT to rent out.
+    /// CHECK: Freeze would fail if incorrect
+    pub edition: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            Rental::PREFIX.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump,
+        space = Rental::SPACE,
+        payer = payer.as_ref().unwrap_or(&owner),
+    )]
+    pub rental: Box<Account<'info, Rental>>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            marketplace_config.marketplace_authority.key().as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Custodial, listed or swapped NFTs are already frozen, so the approval fails for them
+pub fn list_rental_handler(
+    ctx: Context<ListRental>,
+    args: RentalArgs,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.owner).to_account_info();
+    let rental_account = &ctx.accounts.rental.to_account_info().clone();
+
+    let rental = &mut ctx.accounts.rental;
+    rental.init(
+        [*ctx.bumps.get(Rental::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_config.marketplace_authority,
+        ctx.accounts.owner.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.marketplace_config.fee_config,
+        args,
+    )?;
+    rental.payer = payer.key();
+
+    let auth_seeds = rental.auth_seeds();
+    approve_and_freeze(
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.edition.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        rental_account,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        Some(&auth_seeds),
+        1
+    )?;
+
+    emit!(RentalList {
+        mint: rental.mint,
+        owner: rental.owner,
+        marketplace_authority: rental.marketplace_authority,
+        price_per_day: rental.price_per_day,
+        max_days: rental.max_days,
+        apply_royalties: rental.apply_royalties,
+        fee_config: rental.fee_config,
+    });
+
+    Ok(())
+}
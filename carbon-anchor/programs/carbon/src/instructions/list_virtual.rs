@@ -1,10 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::{Mint},
-};
 use crate::{
-    state::{Listing, MarketplaceConfig, CollectionConfig},
+    state::{Listing, MarketplaceConfig, CollectionConfig, PriceFeed},
     event::List,
+    util::assert_is_mint,
     error::Error,
 };
 
@@ -15,21 +13,31 @@ pub struct ListVirtual<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    /// Wallet sponsoring the rent of new accounts, the seller pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
     /// Marketplace authority wallet.
     pub marketplace_authority: Signer<'info>,
 
     /// The currency to use or native mint if using SOL
-    pub currency_mint: Box<Account<'info, Mint>>,
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    /// Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency.
+    /// CHECK: Validated in handler
+    pub price_feed: UncheckedAccount<'info>,
 
     #[account(
         init,
         seeds = [
             Listing::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
             item_id.as_ref()
         ],
         bump,
         space = Listing::SPACE,
-        payer = seller,
+        payer = payer.as_ref().unwrap_or(&seller),
     )]
     pub listing: Box<Account<'info, Listing>>,
 
@@ -62,13 +70,22 @@ pub fn list_virtual_handler<'info>(
     item_id: [u8;32],
     price: u64,
     expiry: i64,
+    start_time: i64,
 ) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.seller).to_account_info();
     require!(price > 0, Error::InvalidPrice);
     require!(expiry >= 0, Error::InvalidExpiry);
 
+    PriceFeed::assert_is_valid_for_listing(
+        &ctx.accounts.price_feed.to_account_info(),
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.currency_mint.key(),
+    )?;
+
     let listing = &mut ctx.accounts.listing;
     listing.init(
         [*ctx.bumps.get(Listing::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_config.key(),
         ctx.accounts.marketplace_authority.key(),
         ctx.accounts.seller.key(),
         item_id,
@@ -76,20 +93,27 @@ pub fn list_virtual_handler<'info>(
         ctx.accounts.currency_mint.key(),
         ctx.accounts.collection_config.collection_mint,
         ctx.accounts.marketplace_config.fee_config.clone(),
+        ctx.accounts.price_feed.key(),
+        assert_is_mint(&ctx.accounts.currency_mint)?.decimals,
         price,
         expiry,
+        start_time,
     )?;
+    listing.payer = payer.key();
 
     emit!(List {
         item_id,
         price,
         expiry,
+        start_time,
         seller: listing.seller,
         is_virtual: true,
         currency_mint: listing.currency_mint,
         collection_mint: ctx.accounts.collection_config.collection_mint,
         marketplace_authority: listing.marketplace_authority,
         fee_config: listing.fee_config,
+        price_feed: listing.price_feed,
+        amount: 1,
     });
 
     Ok(())
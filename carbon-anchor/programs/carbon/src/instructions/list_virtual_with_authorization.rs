@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{
+        Authorization, AuthorizationNonce, AuthorizedAction, CollectionConfig, Listing, MarketplaceConfig,
+        PriceFeed
+    },
+    event::List,
+    util::assert_is_mint,
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(authorization: Authorization)]
+pub struct ListVirtualWithAuthorization<'info> {
+    /// Seller wallet.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the seller pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Marketplace authority wallet, which signed the authorization.
+    /// CHECK: Safe because of collection_config and marketplace_config constraints
+    pub marketplace_authority: UncheckedAccount<'info>,
+
+    /// The currency to use or native mint if using SOL
+    /// CHECK: Validated as a mint of either token program in handler
+    pub currency_mint: UncheckedAccount<'info>,
+
+    /// Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency.
+    /// CHECK: Validated in handler
+    pub price_feed: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            Listing::PREFIX.as_bytes(),
+            marketplace_config.key().as_ref(),
+            authorization.item_id.as_ref()
+        ],
+        bump,
+        space = Listing::SPACE,
+        payer = payer.as_ref().unwrap_or(&seller),
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        seeds = [
+            MarketplaceConfig::PREFIX.as_bytes(),
+            marketplace_config.marketplace_authority.key().as_ref()
+        ],
+        bump = marketplace_config.bump[0],
+        has_one = marketplace_authority
+    )]
+    pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+    #[account(
+        init,
+        seeds = [
+            AuthorizationNonce::PREFIX.as_bytes(),
+            marketplace_authority.key().as_ref(),
+            authorization.nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = AuthorizationNonce::SPACE,
+        payer = payer.as_ref().unwrap_or(&seller),
+    )]
+    pub authorization_nonce: Box<Account<'info, AuthorizationNonce>>,
+
+    /// CHECK: Safe because of address constraint
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Lists a virtual item like list_virtual, with the marketplace authority's signature of the
+/// authorization checked through an Ed25519 program instruction right before this one instead of
+/// the authority signing the transaction. The item ID, seller and price come from the
+/// authorization, and its nonce can only be used once.
+pub fn list_virtual_with_authorization_handler(
+    ctx: Context<ListVirtualWithAuthorization>,
+    authorization: Authorization,
+    expiry: i64,
+    start_time: i64,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.seller).to_account_info();
+    let price = authorization.price;
+    require!(price > 0, Error::InvalidPrice);
+    require!(expiry >= 0, Error::InvalidExpiry);
+
+    authorization.assert_is_valid(
+        AuthorizedAction::ListVirtual,
+        ctx.accounts.seller.key(),
+        ctx.accounts.collection_config.key(),
+        ctx.accounts.marketplace_authority.key(),
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+    )?;
+
+    ctx.accounts.authorization_nonce.init(
+        [*ctx.bumps.get(AuthorizationNonce::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_authority.key(),
+        authorization.nonce,
+        authorization.expiry,
+        payer.key(),
+    )?;
+
+    PriceFeed::assert_is_valid_for_listing(
+        &ctx.accounts.price_feed.to_account_info(),
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.currency_mint.key(),
+    )?;
+
+    let listing = &mut ctx.accounts.listing;
+    listing.init(
+        [*ctx.bumps.get(Listing::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.marketplace_config.key(),
+        ctx.accounts.marketplace_authority.key(),
+        ctx.accounts.seller.key(),
+        authorization.item_id,
+        true,
+        ctx.accounts.currency_mint.key(),
+        ctx.accounts.collection_config.collection_mint,
+        ctx.accounts.marketplace_config.fee_config,
+        ctx.accounts.price_feed.key(),
+        assert_is_mint(&ctx.accounts.currency_mint)?.decimals,
+        price,
+        expiry,
+        start_time,
+    )?;
+    listing.payer = payer.key();
+
+    emit!(List {
+        item_id: authorization.item_id,
+        price,
+        expiry,
+        start_time,
+        seller: listing.seller,
+        is_virtual: true,
+        currency_mint: listing.currency_mint,
+        collection_mint: ctx.accounts.collection_config.collection_mint,
+        marketplace_authority: listing.marketplace_authority,
+        fee_config: listing.fee_config,
+        price_feed: listing.price_feed,
+        amount: 1,
+    });
+
+    Ok(())
+}
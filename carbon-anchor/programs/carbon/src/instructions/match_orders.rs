@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+};
+use crate::{
+    state::{Market, OpenOrders, OrderBookSide},
+    event::OrderFill,
+    util::transfer_spl,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct MatchOrders<'info> {
+    /// Anyone cranking the market. Pays for the fee token account if it does not exist.
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        has_one = bids,
+        has_one = asks,
+        has_one = quote_mint,
+        constraint = market.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub bids: AccountLoader<'info, OrderBookSide>,
+
+    #[account(mut)]
+    pub asks: AccountLoader<'info, OrderBookSide>,
+
+    /// CHECK: Safe because of market constraint
+    pub quote_mint: UncheckedAccount<'info>,
+
+    /// Market's token account of the quote mint.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub quote_vault: UncheckedAccount<'info>,
+
+    /// Account to send fees to.
+    /// CHECK: Safe because of market constraint
+    pub fee_account: UncheckedAccount<'info>,
+
+    /// Fee account's token account of the quote mint.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub fee_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Matches the best bid against the best ask while they cross, up to the limit of fills. Each fill
+/// is at the price of the older order. The buyer is credited the base tokens and any quote tokens
+/// locked above the fill price, the seller the quote tokens less the marketplace fee, and the fees
+/// of all fills are sent to the fee account.
+///
+/// The remaining accounts should contain the open orders account of every order that fills, in
+/// any order.
+pub fn match_orders_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MatchOrders<'info>>,
+    limit: u16,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let bids = &mut ctx.accounts.bids.load_mut()?;
+    let asks = &mut ctx.accounts.asks.load_mut()?;
+
+    let mut total_fees: u64 = 0;
+    for _ in 0..limit {
+        let (bid, ask) = match (bids.best(), asks.best()) {
+            (Some(bid), Some(ask)) if bid.price >= ask.price => (bid, ask),
+            _ => break,
+        };
+
+        let price = if bid.order_id < ask.order_id { bid.price } else { ask.price };
+        let quantity = bid.quantity.min(ask.quantity);
+        let quote_amount = market.quote_amount(quantity, price)?;
+        let fee_amount = market.get_fee_amount(quote_amount)?;
+        let refund = market.quote_amount(quantity, bid.price - price)?;
+
+        // Loaded one at a time since both orders may belong to the same open orders account
+        OpenOrders::find(ctx.remaining_accounts, bid.open_orders)?
+            .load_mut()?
+            .credit(market.base_amount(quantity)?, refund)?;
+        OpenOrders::find(ctx.remaining_accounts, ask.open_orders)?
+            .load_mut()?
+            .credit(0, quote_amount - fee_amount)?;
+
+        bids.orders[0].quantity -= quantity;
+        if bids.orders[0].quantity == 0 {
+            bids.remove(0);
+        }
+        asks.orders[0].quantity -= quantity;
+        if asks.orders[0].quantity == 0 {
+            asks.remove(0);
+        }
+
+        total_fees = total_fees
+            .checked_add(fee_amount)
+            .ok_or(Error::OverflowError)?;
+
+        emit!(OrderFill {
+            market: market.key(),
+            bid_order_id: bid.order_id,
+            ask_order_id: ask.order_id,
+            price,
+            quantity,
+            fee_amount,
+        });
+    }
+
+    let auth_seeds = market.auth_seeds();
+    transfer_spl(
+        &market.to_account_info(),
+        &ctx.accounts.fee_account.to_account_info(),
+        &ctx.accounts.quote_vault.to_account_info(),
+        &ctx.accounts.fee_token_account.to_account_info(),
+        &ctx.accounts.quote_mint.to_account_info(),
+        &ctx.accounts.cranker.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        None,
+        Some(&auth_seeds),
+        None,
+        total_fees,
+    )?;
+
+    Ok(())
+}
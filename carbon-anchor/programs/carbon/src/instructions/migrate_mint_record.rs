@@ -0,0 +1,72 @@
+use crate::{
+    error::Error,
+    state::{CollectionConfig, MintRecord},
+    util::transfer_sol,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+/// Grows a mint record created before payers were tracked to the current layout. Its rent was
+/// refunded to the marketplace authority before, so the authority is recorded as the payer and
+/// tops up the rent for the extra bytes.
+#[derive(Accounts)]
+pub struct MigrateMintRecord<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    /// CHECK: Legacy layout can't be deserialized, verified in handler
+    #[account(mut, owner = crate::ID)]
+    pub mint_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn migrate_mint_record_handler<'info>(ctx: Context<'_, '_, '_, 'info, MigrateMintRecord<'info>>) -> Result<()> {
+    let mint_record = &ctx.accounts.mint_record;
+    {
+        let data = mint_record.try_borrow_data()?;
+        require!(data.len() == MintRecord::LEGACY_SPACE, Error::InvalidMintRecord);
+        require!(data[..8] == MintRecord::discriminator(), Error::InvalidMintRecord);
+        require!(
+            data[8..40] == ctx.accounts.collection_config.key().to_bytes(),
+            Error::InvalidCollectionConfig
+        );
+
+        let (address, _) = Pubkey::find_program_address(
+            &[
+                MintRecord::PREFIX.as_bytes(),
+                ctx.accounts.collection_config.key().as_ref(),
+                &data[40..72],
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(address, mint_record.key(), Error::InvalidMintRecord);
+    }
+
+    let rent_due = ctx.accounts.rent.minimum_balance(MintRecord::SPACE)
+        .saturating_sub(mint_record.lamports());
+    transfer_sol(
+        &ctx.accounts.marketplace_authority.to_account_info(),
+        &mint_record.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        None,
+        rent_due,
+    )?;
+
+    mint_record.realloc(MintRecord::SPACE, false)?;
+    mint_record.try_borrow_mut_data()?[MintRecord::LEGACY_SPACE..MintRecord::SPACE]
+        .copy_from_slice(ctx.accounts.marketplace_authority.key().as_ref());
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		update_primary_sale_happened_via_token,
+		VerifySizedCollectionItem,
+		UpdatePrimarySaleHappenedViaToken
+	},
+	metadata
+};
+use crate::{
+	state::{CollectionConfig, MintRecord, MysteryBox, SealedBox},
+	event::Mint,
+	util::mint_nft,
+	error::Error
+};
+
+#[derive(Accounts)]
+#[instruction(item_id: [u8;32])]
+pub struct MintMysteryBox<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Marketplace authority wallet.
+	pub marketplace_authority: Signer<'info>,
+
+	/// The new mint to be used for the box NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Buyer NFT token account.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub buyer_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the box NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the box NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		seeds = [
+			MysteryBox::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			mystery_box.box_id.as_ref()
+		],
+		bump = mystery_box.bump[0],
+		has_one = collection_config,
+	)]
+	pub mystery_box: Box<Account<'info, MysteryBox>>,
+
+	#[account(
+		init,
+		seeds = [
+			SealedBox::PREFIX.as_bytes(),
+			mint.key().as_ref()
+		],
+		bump,
+		space = SealedBox::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub sealed_box: Box<Account<'info, SealedBox>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mints a box NFT of the mystery box to the buyer. The marketplace authority commits to the hash
+/// of a secret seed it reveals once the box is opened, which decides the prize together with the
+/// entropy recorded when the box is opened.
+pub fn mint_mystery_box_handler(
+	ctx: Context<MintMysteryBox>,
+	item_id: [u8;32],
+	seed_hash: [u8;32]
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let sealed_box = &mut ctx.accounts.sealed_box;
+	sealed_box.init(
+		[*ctx.bumps.get(SealedBox::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+		ctx.accounts.mystery_box.key(),
+		ctx.accounts.mint.key(),
+		item_id,
+		seed_hash,
+	)?;
+	sealed_box.payer = payer.key();
+
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
+
+	let data = &ctx.accounts.collection_config.get_mpl_metadata(ctx.accounts.mystery_box.metadata.clone())?;
+	// Mint the box NFT to the buyer.
+	mint_nft(
+		&payer,
+		&ctx.accounts.buyer.to_account_info(),
+		&ctx.accounts.buyer_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data.clone(),
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		None
+	)?;
+
+	// Mark the box as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: payer.clone(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: ctx.accounts.marketplace_authority.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+		),
+		None
+	)?;
+
+	// Mark that the primary sale happened.
+	update_primary_sale_happened_via_token(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			UpdatePrimarySaleHappenedViaToken {
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				owner: ctx.accounts.buyer.to_account_info(),
+				token: ctx.accounts.buyer_token_account.to_account_info(),
+			}
+		)
+	)?;
+
+	emit!(Mint {
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		buyer: ctx.accounts.buyer.key(),
+		marketplace_authority: ctx.accounts.collection_config.marketplace_authority,
+		collection_mint: ctx.accounts.collection_mint.key(),
+		price: 0,
+		currency_mint: Pubkey::default(),
+		treasury_amount: 0,
+		fee_amount: 0,
+	});
+
+	Ok(())
+}
@@ -11,9 +11,13 @@ use anchor_spl::{
 	metadata
 };
 use crate::{
-	state::{CollectionConfig, Metadata, MintRecord},
+	state::{CollectionConfig, MarketplaceConfig, Metadata, MintRecord, MintStandard, PrimarySale},
 	event::Mint,
-	util::mint_nft
+	util::{
+		assert_keys_equal, is_native_mint, mint_nft, next_currency_accounts, transfer_sol, transfer_spl,
+		ProgrammableNft
+	},
+	error::Error
 };
 
 #[derive(Accounts)]
@@ -23,6 +27,10 @@ pub struct MintVirtual<'info> {
 	#[account(mut)]
 	pub buyer: Signer<'info>,
 
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
 	/// Marketplace authority wallet.
 	#[account(mut)]
 	pub marketplace_authority: Signer<'info>,
@@ -74,6 +82,16 @@ pub struct MintVirtual<'info> {
 	)]
 	pub collection_config: Box<Account<'info, CollectionConfig>>,
 
+	#[account(
+		seeds = [
+			MarketplaceConfig::PREFIX.as_bytes(),
+			marketplace_authority.key().as_ref()
+		],
+		bump = marketplace_config.bump[0],
+		has_one = marketplace_authority,
+	)]
+	pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
 	#[account(
 		init,
 		seeds = [
@@ -83,7 +101,7 @@ pub struct MintVirtual<'info> {
 		],
 		bump,
 		space = MintRecord::SPACE,
-        payer = buyer,
+        payer = payer.as_ref().unwrap_or(&buyer),
 	)]
 	pub mint_record: Box<Account<'info, MintRecord>>,
 
@@ -94,68 +112,226 @@ pub struct MintVirtual<'info> {
 	pub rent: Sysvar<'info, Rent>,
 }
 
+/// The buyer pays the price of the collection's primary sale, or of the one given by the
+/// marketplace authority for this mint, to the treasury with the marketplace fee taken out.
+///
+/// When the collection mints programmable NFTs, the remaining accounts should start with:
+/// 1. buyer token record account
+/// 2. authorization rules program, or token metadata program if the collection has no rule set
+/// 3. authorization rules account, or token metadata program if the collection has no rule set
+/// 4. instructions sysvar
+///
+/// When the price is not 0, the remaining accounts should then contain:
+/// 1. treasury wallet
+/// 2. marketplace fee account
+///
+/// Followed by these when paying with an SPL token:
+/// 3. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
+/// 4. buyer currency ata
+/// 5. treasury currency ata
+/// 6. marketplace fee currency ata
 pub fn mint_virtual_handler<'info>(
 	ctx: Context<'_, '_, '_, 'info, MintVirtual<'info>>,
 	item_id: [u8;32],
-	metadata: Metadata
+	metadata: Metadata,
+	primary_sale: Option<PrimarySale>
 ) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	let primary_sale = primary_sale.unwrap_or(ctx.accounts.collection_config.primary_sale);
+	primary_sale.assert_is_valid()?;
+
 	let mint_record = &mut ctx.accounts.mint_record;
-	mint_record.init(ctx.accounts.collection_config.key(), item_id, ctx.accounts.mint.key())?;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
 	
 	let marketplace_authority = &ctx.accounts.marketplace_authority.to_account_info();
-	let data = &ctx.accounts.collection_config.get_mpl_metadata(metadata)?;
-	// Mint the NFT to the buyer.
-	mint_nft(
-		&ctx.accounts.buyer.to_account_info(),
-		&ctx.accounts.buyer.to_account_info(),
-		&ctx.accounts.buyer_token_account.to_account_info(),
-		&ctx.accounts.mint.to_account_info(),
-		marketplace_authority,
-		&ctx.accounts.metadata_account.to_account_info(),
-		data.clone(),
-		&ctx.accounts.edition.to_account_info(),
-		&ctx.accounts.token_metadata_program.to_account_info(),
-		&ctx.accounts.associated_token_program.to_account_info(),
-		&ctx.accounts.token_program.to_account_info(),
-		&ctx.accounts.system_program.to_account_info(),
-		&ctx.accounts.rent.to_account_info()
-	)?;
+	let remaining_accounts = &mut ctx.remaining_accounts.iter();
 
-	// Mark the item as a verified item in the collection.
-	verify_sized_collection_item(
-		CpiContext::new(
-			ctx.accounts.token_metadata_program.to_account_info(),
-			VerifySizedCollectionItem {
-				payer: ctx.accounts.buyer.to_account_info(),
-				metadata: ctx.accounts.metadata_account.to_account_info(),
-				collection_authority: marketplace_authority.clone(),
-				collection_mint: ctx.accounts.collection_mint.to_account_info(),
-				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
-				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
-			},
-		),
-		None
-	)?;
+	if let MintStandard::ProgrammableNonFungible { rule_set } = ctx.accounts.collection_config.mint_standard {
+		let asset_data = ctx.accounts.collection_config.get_asset_data(metadata, rule_set)?;
+		let nft = ctx.accounts.programmable_nft(remaining_accounts)?;
 
-	// Mark that the primary sale happened.
-	update_primary_sale_happened_via_token(
-		CpiContext::new(
-			ctx.accounts.token_metadata_program.to_account_info(),
-			UpdatePrimarySaleHappenedViaToken {
-				metadata: ctx.accounts.metadata_account.to_account_info(),
-				owner: ctx.accounts.buyer.to_account_info(),
-				token: ctx.accounts.buyer_token_account.to_account_info(),
-			}
-		)
-	)?;
+		// Mint the NFT to the buyer, with the primary sale already marked as happened.
+		nft.create_and_mint(
+			marketplace_authority,
+			asset_data,
+			&ctx.accounts.associated_token_program.to_account_info()
+		)?;
+	} else {
+		let data = &ctx.accounts.collection_config.get_mpl_metadata(metadata)?;
+		// Mint the NFT to the buyer.
+		mint_nft(
+			&payer,
+			&ctx.accounts.buyer.to_account_info(),
+			&ctx.accounts.buyer_token_account.to_account_info(),
+			&ctx.accounts.mint.to_account_info(),
+			marketplace_authority,
+			&ctx.accounts.metadata_account.to_account_info(),
+			data.clone(),
+			&ctx.accounts.edition.to_account_info(),
+			&ctx.accounts.token_metadata_program.to_account_info(),
+			&ctx.accounts.associated_token_program.to_account_info(),
+			&ctx.accounts.token_program.to_account_info(),
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None
+		)?;
+
+		// Mark that the primary sale happened.
+		update_primary_sale_happened_via_token(
+			CpiContext::new(
+				ctx.accounts.token_metadata_program.to_account_info(),
+				UpdatePrimarySaleHappenedViaToken {
+					metadata: ctx.accounts.metadata_account.to_account_info(),
+					owner: ctx.accounts.buyer.to_account_info(),
+					token: ctx.accounts.buyer_token_account.to_account_info(),
+				}
+			)
+		)?;
+	}
+
+	ctx.accounts.verify_collection()?;
+	let (treasury_amount, fee_amount) = ctx.accounts.pay_primary_sale(&primary_sale, remaining_accounts)?;
 
 	emit!(Mint {
 		item_id,
-        mint: ctx.accounts.mint.key(),
+		mint: ctx.accounts.mint.key(),
 		buyer: ctx.accounts.buyer.key(),
-        marketplace_authority: ctx.accounts.collection_config.marketplace_authority,
+		marketplace_authority: ctx.accounts.collection_config.marketplace_authority,
 		collection_mint: ctx.accounts.collection_mint.key(),
-    });
+		price: primary_sale.price,
+		currency_mint: primary_sale.currency_mint,
+		treasury_amount,
+		fee_amount,
+	});
 
 	Ok(())
-}
\ No newline at end of file
+}
+
+impl<'info> MintVirtual<'info> {
+
+	/// Marks the item as a verified item in the collection.
+	fn verify_collection(&self) -> Result<()> {
+		verify_sized_collection_item(
+			CpiContext::new(
+				self.token_metadata_program.to_account_info(),
+				VerifySizedCollectionItem {
+					payer: self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
+					metadata: self.metadata_account.to_account_info(),
+					collection_authority: self.marketplace_authority.to_account_info(),
+					collection_mint: self.collection_mint.to_account_info(),
+					collection_metadata: self.collection_metadata_account.to_account_info(),
+					collection_master_edition: self.collection_edition.to_account_info()
+				},
+			),
+			None
+		)
+	}
+
+	/// Pays the price of the primary sale to the treasury and the marketplace fee out of it, and
+	/// returns the amounts received by the treasury and the fee account
+	fn pay_primary_sale<'b>(
+		&self,
+		primary_sale: &PrimarySale,
+		remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+	) -> Result<(u64, u64)> {
+		if primary_sale.price == 0 {
+			return Ok((0, 0));
+		}
+
+		let fee_config = self.marketplace_config.fee_config;
+		let fee_amount = fee_config.get_fee_amount(primary_sale.price)?;
+		let treasury_amount = primary_sale.price
+			.checked_sub(fee_amount)
+			.ok_or(Error::OverflowError)?;
+
+		let treasury = next_account_info(remaining_accounts)?;
+		require!(treasury.key() == primary_sale.treasury, Error::InvalidTreasury);
+		let fee_account = next_account_info(remaining_accounts)?;
+		require!(fee_account.key() == fee_config.fee_account, Error::InvalidFeeAccount);
+
+		let buyer = &self.buyer.to_account_info();
+		let system_program = &self.system_program.to_account_info();
+
+		if is_native_mint(primary_sale.currency_mint) {
+			transfer_sol(buyer, fee_account, system_program, None, fee_amount)?;
+			transfer_sol(buyer, treasury, system_program, None, treasury_amount)?;
+
+			return Ok((treasury_amount, fee_amount));
+		}
+
+		let (currency_mint, currency_token_program) = next_currency_accounts(
+			remaining_accounts,
+			&self.token_program.to_account_info()
+		)?;
+		assert_keys_equal(currency_mint.key(), primary_sale.currency_mint, "Invalid currency mint")?;
+		let buyer_currency_account = next_account_info(remaining_accounts)?;
+		let treasury_currency_account = next_account_info(remaining_accounts)?;
+		let fee_currency_account = next_account_info(remaining_accounts)?;
+
+		let fee_received = transfer_spl(
+			buyer,
+			fee_account,
+			buyer_currency_account,
+			fee_currency_account,
+			&currency_mint,
+			&self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
+			&self.associated_token_program.to_account_info(),
+			&currency_token_program,
+			system_program,
+			&self.rent.to_account_info(),
+			None,
+			None,
+			None,
+			fee_amount
+		)?;
+
+		let treasury_received = transfer_spl(
+			buyer,
+			treasury,
+			buyer_currency_account,
+			treasury_currency_account,
+			&currency_mint,
+			&self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
+			&self.associated_token_program.to_account_info(),
+			&currency_token_program,
+			system_program,
+			&self.rent.to_account_info(),
+			None,
+			None,
+			None,
+			treasury_amount
+		)?;
+
+		Ok((treasury_received, fee_received))
+	}
+
+	fn programmable_nft<'b>(
+		&self,
+		remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+	) -> Result<ProgrammableNft<'info>> {
+		let nft = ProgrammableNft {
+			token_account: self.buyer_token_account.to_account_info(),
+			token_owner: self.buyer.to_account_info(),
+			mint: self.mint.to_account_info(),
+			metadata: self.metadata_account.to_account_info(),
+			edition: self.edition.to_account_info(),
+			token_record: next_account_info(remaining_accounts)?.clone(),
+			authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+			authorization_rules: next_account_info(remaining_accounts)?.clone(),
+			sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+			payer: self.payer.as_ref().unwrap_or(&self.buyer).to_account_info(),
+			token_metadata_program: self.token_metadata_program.to_account_info(),
+			token_program: self.token_program.to_account_info(),
+			system_program: self.system_program.to_account_info(),
+		};
+		nft.assert_is_valid()?;
+
+		Ok(nft)
+	}
+
+}
@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		update_primary_sale_happened_via_token,
+		VerifySizedCollectionItem,
+		UpdatePrimarySaleHappenedViaToken
+	},
+	metadata
+};
+use mpl_token_metadata::state::Creator;
+use crate::{
+	state::{
+		Authorization, AuthorizationNonce, AuthorizedAction, CollectionConfig, Metadata, MintRecord, MintStandard
+	},
+	event::Mint,
+	util::mint_nft,
+	error::Error
+};
+
+#[derive(Accounts)]
+#[instruction(authorization: Authorization)]
+pub struct MintVirtualWithAuthorization<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Marketplace authority wallet, which signed the authorization.
+	/// CHECK: Safe because of collection_config constraint
+	pub marketplace_authority: UncheckedAccount<'info>,
+
+	/// The new mint to be used for the NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Buyer NFT token account.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub buyer_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the NFT to mint.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	/// Record of the marketplace authority approving the collection config as collection authority.
+	/// CHECK: Verified in verify collection CPI
+	pub collection_authority_record: UncheckedAccount<'info>,
+
+	/// Collection config for the new NFT, which mints it in place of the marketplace authority.
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			authorization.item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	#[account(
+		init,
+		seeds = [
+			AuthorizationNonce::PREFIX.as_bytes(),
+			marketplace_authority.key().as_ref(),
+			authorization.nonce.to_le_bytes().as_ref()
+		],
+		bump,
+		space = AuthorizationNonce::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub authorization_nonce: Box<Account<'info, AuthorizationNonce>>,
+
+	/// CHECK: Safe because of address constraint
+	#[account(address = solana_program::sysvar::instructions::ID)]
+	pub instructions_sysvar: UncheckedAccount<'info>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mints a virtual item to the buyer like mint_virtual, with the marketplace authority's signature
+/// of the authorization checked through an Ed25519 program instruction right before this one
+/// instead of the authority signing the transaction. The authorization commits to the item ID, the
+/// buyer and the hash of the metadata, and its nonce can only be used once.
+///
+/// Without the marketplace authority signing, the collection config is the mint and update
+/// authority of the NFT and verifies it into the collection through a collection authority record
+/// the marketplace authority approved beforehand. The marketplace authority stays the creator but
+/// is left unverified, for it to sign the metadata later. Only collections minting regular NFTs
+/// are supported.
+pub fn mint_virtual_with_authorization_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, MintVirtualWithAuthorization<'info>>,
+	authorization: Authorization,
+	metadata: Metadata
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	require!(
+		ctx.accounts.collection_config.mint_standard == MintStandard::NonFungible,
+		Error::UnsupportedMintStandard
+	);
+
+	authorization.assert_is_valid(
+		AuthorizedAction::MintVirtual,
+		ctx.accounts.buyer.key(),
+		ctx.accounts.collection_config.key(),
+		ctx.accounts.marketplace_authority.key(),
+		&ctx.accounts.instructions_sysvar.to_account_info(),
+	)?;
+	require!(authorization.metadata_hash == metadata.hash()?, Error::InvalidAuthorization);
+
+	ctx.accounts.authorization_nonce.init(
+		[*ctx.bumps.get(AuthorizationNonce::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+		ctx.accounts.marketplace_authority.key(),
+		authorization.nonce,
+		authorization.expiry,
+		payer.key(),
+	)?;
+
+	let item_id = authorization.item_id;
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
+
+	let collection_config = &ctx.accounts.collection_config;
+	let auth_seeds = collection_config.auth_seeds();
+	let mut data = collection_config.get_mpl_metadata(metadata)?;
+	data.creators = Some(vec![
+		Creator {
+			address: collection_config.marketplace_authority,
+			verified: false,
+			share: 100
+		}
+	]);
+
+	// Mint the NFT to the buyer.
+	mint_nft(
+		&payer,
+		&ctx.accounts.buyer.to_account_info(),
+		&ctx.accounts.buyer_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&collection_config.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data,
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		Some(&auth_seeds)
+	)?;
+
+	// Mark the item as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new_with_signer(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: payer.clone(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: collection_config.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+			&[&auth_seeds]
+		).with_remaining_accounts(vec![ctx.accounts.collection_authority_record.to_account_info()]),
+		Some(ctx.accounts.collection_authority_record.key())
+	)?;
+
+	// Mark that the primary sale happened.
+	update_primary_sale_happened_via_token(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			UpdatePrimarySaleHappenedViaToken {
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				owner: ctx.accounts.buyer.to_account_info(),
+				token: ctx.accounts.buyer_token_account.to_account_info(),
+			}
+		)
+	)?;
+
+	emit!(Mint {
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		buyer: ctx.accounts.buyer.key(),
+		marketplace_authority: collection_config.marketplace_authority,
+		collection_mint: ctx.accounts.collection_mint.key(),
+		price: 0,
+		currency_mint: Pubkey::default(),
+		treasury_amount: 0,
+		fee_amount: 0,
+	});
+
+	Ok(())
+}
@@ -12,6 +12,58 @@ mod custody;
 mod uncustody;
 mod take_ownership;
 mod close_mint_record;
+mod init_price_feed;
+mod update_price_feed;
+mod close_expired_listing;
+mod create_swap;
+mod take_swap;
+mod cancel_swap;
+mod list_rental;
+mod rent_nft;
+mod end_rental;
+mod offer_loan;
+mod take_loan;
+mod repay_loan;
+mod foreclose_loan;
+mod cancel_loan_offer;
+mod list_fungible;
+mod buy_fungible;
+mod delist_fungible;
+mod init_market;
+mod init_open_orders;
+mod place_order;
+mod cancel_order;
+mod match_orders;
+mod settle_funds;
+mod init_bonding_curve;
+mod buy_bonding_curve;
+mod burn_bonding_curve;
+mod withdraw_bonding_curve_reserve;
+mod init_recipe;
+mod craft;
+mod init_mystery_box;
+mod mint_mystery_box;
+mod open_mystery_box;
+mod reveal_mystery_box;
+mod create_raffle;
+mod create_virtual_raffle;
+mod buy_raffle_tickets;
+mod close_raffle;
+mod draw_raffle;
+mod claim_raffle_nft;
+mod claim_raffle_virtual;
+mod close_raffle_ticket;
+mod cancel_raffle;
+mod expire_raffle;
+mod refund_raffle_tickets;
+mod create_session;
+mod revoke_session;
+mod list_virtual_with_authorization;
+mod mint_virtual_with_authorization;
+mod close_authorization_nonce;
+mod redeem_mint_voucher;
+mod set_primary_sale;
+mod migrate_mint_record;
 
 pub use init_marketplace_config::*;
 pub use init_collection_config::*;
@@ -26,4 +78,56 @@ pub use delist_nft::*;
 pub use custody::*;
 pub use uncustody::*;
 pub use take_ownership::*;
-pub use close_mint_record::*;
\ No newline at end of file
+pub use close_mint_record::*;
+pub use init_price_feed::*;
+pub use update_price_feed::*;
+pub use close_expired_listing::*;
+pub use create_swap::*;
+pub use take_swap::*;
+pub use cancel_swap::*;
+pub use list_rental::*;
+pub use rent_nft::*;
+pub use end_rental::*;
+pub use offer_loan::*;
+pub use take_loan::*;
+pub use repay_loan::*;
+pub use foreclose_loan::*;
+pub use cancel_loan_offer::*;
+pub use list_fungible::*;
+pub use buy_fungible::*;
+pub use delist_fungible::*;
+pub use init_market::*;
+pub use init_open_orders::*;
+pub use place_order::*;
+pub use cancel_order::*;
+pub use match_orders::*;
+pub use settle_funds::*;
+pub use init_bonding_curve::*;
+pub use buy_bonding_curve::*;
+pub use burn_bonding_curve::*;
+pub use withdraw_bonding_curve_reserve::*;
+pub use init_recipe::*;
+pub use craft::*;
+pub use init_mystery_box::*;
+pub use mint_mystery_box::*;
+pub use open_mystery_box::*;
+pub use reveal_mystery_box::*;
+pub use create_raffle::*;
+pub use create_virtual_raffle::*;
+pub use buy_raffle_tickets::*;
+pub use close_raffle::*;
+pub use draw_raffle::*;
+pub use claim_raffle_nft::*;
+pub use claim_raffle_virtual::*;
+pub use close_raffle_ticket::*;
+pub use cancel_raffle::*;
+pub use expire_raffle::*;
+pub use refund_raffle_tickets::*;
+pub use create_session::*;
+pub use revoke_session::*;
+pub use list_virtual_with_authorization::*;
+pub use mint_virtual_with_authorization::*;
+pub use close_authorization_nonce::*;
+pub use redeem_mint_voucher::*;
+pub use set_primary_sale::*;
+pub use migrate_mint_record::*;
\ No newline at end of file
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token}
+};
+use crate::{
+    state::{Loan, LoanArgs, CollectionConfig},
+    event::LoanOffer,
+    util::{is_native_mint, transfer_sol, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+#[instruction(args: LoanArgs)]
+pub struct OfferLoan<'info> {
+    /// Wallet lending the principal.
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the lender pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    #[account(
+        init,
+        seeds = [
+            Loan::PREFIX.as_bytes(),
+            lender.key().as_ref(),
+            args.loan_id.as_ref()
+        ],
+        bump,
+        space = Loan::SPACE,
+        payer = payer.as_ref().unwrap_or(&lender),
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    /// The currency of the loan or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// When the loan is in an SPL token, the remaining accounts should contain:
+/// 1. lender currency ata
+/// 2. loan currency ata
+pub fn offer_loan_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OfferLoan<'info>>,
+    args: LoanArgs,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.lender).to_account_info();
+    let loan_account = &ctx.accounts.loan.to_account_info().clone();
+    let collection_config = &ctx.accounts.collection_config;
+
+    let loan = &mut ctx.accounts.loan;
+    loan.init(
+        [*ctx.bumps.get(Loan::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+        ctx.accounts.lender.key(),
+        args,
+        collection_config,
+        ctx.accounts.currency_mint.key(),
+    )?;
+    loan.payer = payer.key();
+
+    if is_native_mint(loan.currency_mint) {
+        transfer_sol(
+            &ctx.accounts.lender.to_account_info(),
+            loan_account,
+            &ctx.accounts.system_program.to_account_info(),
+            None,
+            loan.principal
+        )?;
+    } else {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let lender_currency_account = next_account_info(remaining_accounts)?;
+        let loan_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            &ctx.accounts.lender.to_account_info(),
+            loan_account,
+            lender_currency_account,
+            loan_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            None,
+            None,
+            loan.principal
+        )?;
+    }
+
+    emit!(LoanOffer {
+        loan_id: loan.loan_id,
+        lender: loan.lender,
+        marketplace_authority: loan.marketplace_authority,
+        collection_mint: loan.collection_mint,
+        currency_mint: loan.currency_mint,
+        principal: loan.principal,
+        interest: loan.interest,
+        duration: loan.duration,
+    });
+
+    Ok(())
+}
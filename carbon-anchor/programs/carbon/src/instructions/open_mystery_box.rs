@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token::Token, metadata};
+use crate::{
+    state::{CollectionConfig, MintRecord, MysteryBox, SealedBox},
+    event::MysteryBoxOpen,
+    util::BurnableNft,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct OpenMysteryBox<'info> {
+    /// Holder of the box NFT.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Wallet that paid the rent of the mint record.
+    /// CHECK: Safe because of mint_record constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to sealed_box constraint
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// Holder box NFT token account.
+    /// CHECK: Verified in burn CPI
+    #[account(mut)]
+    pub holder_token_account: UncheckedAccount<'info>,
+
+    /// Metadata account for the box NFT.
+    /// CHECK: Verified in handler and burn CPI
+    #[account(mut)]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// Edition of the box NFT.
+    /// CHECK: Verified in handler and burn CPI
+    #[account(mut)]
+    pub edition: UncheckedAccount<'info>,
+
+    /// Metadata for the collection NFT.
+    /// CHECK: Verified in burn CPI
+    #[account(mut)]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.key().as_ref()
+        ],
+        bump = collection_config.bump[0],
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+    #[account(
+        seeds = [
+            MysteryBox::PREFIX.as_bytes(),
+            collection_config.key().as_ref(),
+            mystery_box.box_id.as_ref()
+        ],
+        bump = mystery_box.bump[0],
+        has_one = collection_config,
+    )]
+    pub mystery_box: Box<Account<'info, MysteryBox>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SealedBox::PREFIX.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump = sealed_box.bump[0],
+        has_one = mystery_box,
+        has_one = mint @ Error::InvalidMint,
+        constraint = !sealed_box.is_open(),
+    )]
+    pub sealed_box: Box<Account<'info, SealedBox>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            MintRecord::PREFIX.as_bytes(),
+            collection_config.key().as_ref(),
+            sealed_box.item_id.as_ref(),
+        ],
+        bump,
+        has_one = mint @ Error::InvalidMint,
+        constraint = mint_record.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub mint_record: Box<Account<'info, MintRecord>>,
+
+    /// Account the mystery box's randomness source reads entropy from, the SlotHashes sysvar for
+    /// slot hashes.
+    /// CHECK: Verified by the randomness source
+    pub randomness_account: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, metadata::Metadata>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burns the box NFT and records the newest entropy of the mystery box's randomness source. The
+/// prize is drawn from it and the seed committed when the box was minted, so it is settled once the
+/// box is opened and the marketplace authority can't redraw it by holding back the reveal.
+pub fn open_mystery_box_handler(ctx: Context<OpenMysteryBox>) -> Result<()> {
+    let entropy = ctx.accounts.mystery_box.randomness_source.latest_entropy(
+        &ctx.accounts.randomness_account.to_account_info()
+    )?;
+
+    BurnableNft {
+        metadata: ctx.accounts.metadata_account.to_account_info(),
+        owner: ctx.accounts.holder.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        token_account: ctx.accounts.holder_token_account.to_account_info(),
+        edition: ctx.accounts.edition.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+    }.burn(
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    let sealed_box = &mut ctx.accounts.sealed_box;
+    sealed_box.open(ctx.accounts.holder.key(), entropy)?;
+
+    emit!(MysteryBoxOpen {
+        box_id: ctx.accounts.mystery_box.box_id,
+        box_mint: ctx.accounts.mint.key(),
+        holder: ctx.accounts.holder.key(),
+        marketplace_authority: ctx.accounts.mystery_box.marketplace_authority,
+        collection_mint: ctx.accounts.collection_config.collection_mint,
+        open_slot: sealed_box.open_slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+};
+use crate::{
+    state::{Market, OpenOrders, Order, OrderBookSide, Side},
+    event::{OrderCancel, OrderPlace},
+    util::{assert_keys_equal, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct PlaceOrder<'info> {
+    /// Wallet placing the order.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the owner pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        has_one = bids,
+        has_one = asks,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub bids: AccountLoader<'info, OrderBookSide>,
+
+    #[account(mut)]
+    pub asks: AccountLoader<'info, OrderBookSide>,
+
+    #[account(
+        seeds = [
+            OpenOrders::PREFIX.as_bytes(),
+            market.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump = open_orders.load()?.bump[0],
+        has_one = market,
+        has_one = owner,
+    )]
+    pub open_orders: AccountLoader<'info, OpenOrders>,
+
+    /// Quote mint for bids, base mint for asks.
+    /// CHECK: Validated in handler
+    pub mint: UncheckedAccount<'info>,
+
+    /// Owner's token account of the mint to lock.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub owner_token_account: UncheckedAccount<'info>,
+
+    /// Market's token account of the mint to lock.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Locks the quote tokens of a bid or base tokens of an ask in the market's vault and rests the
+/// order on the book. Crossing orders are matched by the crank, not here. When the side is full,
+/// the worst-priced order is cancelled to make room if the new order beats its price.
+///
+/// When the side is full, the remaining accounts should contain the open orders account of its
+/// worst-priced order, which is credited what the order had locked.
+pub fn place_order_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceOrder<'info>>,
+    side: Side,
+    price: u64,
+    quantity: u64,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.owner).to_account_info();
+    require!(price > 0, Error::InvalidPrice);
+    require!(quantity > 0, Error::InvalidAmount);
+
+    let market_account = &ctx.accounts.market.to_account_info();
+    let market = &mut ctx.accounts.market;
+    let (expected_mint, book) = match side {
+        Side::Bid => (market.quote_mint, &ctx.accounts.bids),
+        Side::Ask => (market.base_mint, &ctx.accounts.asks),
+    };
+    assert_keys_equal(ctx.accounts.mint.key(), expected_mint, "Invalid order mint")?;
+
+    let order_id = market.take_order_id()?;
+    let evicted = book.load_mut()?.insert(Order {
+        open_orders: ctx.accounts.open_orders.key(),
+        order_id,
+        price,
+        quantity,
+    })?;
+
+    if let Some(evicted) = evicted {
+        let locked = market.locked_amount(side, evicted.quantity, evicted.price)?;
+        let evicted_open_orders = OpenOrders::find(ctx.remaining_accounts, evicted.open_orders)?;
+        let evicted_open_orders = &mut evicted_open_orders.load_mut()?;
+        match side {
+            Side::Bid => evicted_open_orders.credit(0, locked)?,
+            Side::Ask => evicted_open_orders.credit(locked, 0)?,
+        }
+
+        emit!(OrderCancel {
+            market: market.key(),
+            order_id: evicted.order_id,
+            owner: evicted_open_orders.owner,
+            side,
+            quantity: evicted.quantity,
+        });
+    }
+
+    transfer_spl(
+        &ctx.accounts.owner.to_account_info(),
+        market_account,
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &payer,
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        None,
+        None,
+        None,
+        market.locked_amount(side, quantity, price)?,
+    )?;
+
+    emit!(OrderPlace {
+        market: market.key(),
+        order_id,
+        owner: ctx.accounts.owner.key(),
+        side,
+        price,
+        quantity,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		update_primary_sale_happened_via_token,
+		VerifySizedCollectionItem,
+		UpdatePrimarySaleHappenedViaToken
+	},
+	metadata
+};
+use mpl_token_metadata::state::Creator;
+use crate::{
+	state::{
+		AuthorizationNonce, CollectionConfig, MarketplaceConfig, Metadata, MintRecord, MintStandard, MintVoucher
+	},
+	event::Buy,
+	util::{mint_nft, transfer_payment},
+	error::Error
+};
+
+#[derive(Accounts)]
+#[instruction(voucher: MintVoucher)]
+pub struct RedeemMintVoucher<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet sponsoring the rent of new accounts, the buyer pays it when omitted.
+	#[account(mut)]
+	pub payer: Option<Signer<'info>>,
+
+	/// Marketplace authority wallet, which signed the voucher and receives the price.
+	/// CHECK: Safe because of collection_config and marketplace_config constraints
+	#[account(mut)]
+	pub marketplace_authority: UncheckedAccount<'info>,
+
+	/// The new mint to be used for the NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Buyer NFT token account.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub buyer_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the NFT to mint.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	/// Record of the marketplace authority approving the collection config as collection authority.
+	/// CHECK: Verified in verify collection CPI
+	pub collection_authority_record: UncheckedAccount<'info>,
+
+	/// Collection config for the new NFT, which mints it in place of the marketplace authority.
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		seeds = [
+			MarketplaceConfig::PREFIX.as_bytes(),
+			marketplace_authority.key().as_ref()
+		],
+		bump = marketplace_config.bump[0],
+		has_one = marketplace_authority,
+		constraint = marketplace_config.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+	)]
+	pub marketplace_config: Box<Account<'info, MarketplaceConfig>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			voucher.item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	#[account(
+		init,
+		seeds = [
+			AuthorizationNonce::PREFIX.as_bytes(),
+			marketplace_authority.key().as_ref(),
+			voucher.nonce.to_le_bytes().as_ref()
+		],
+		bump,
+		space = AuthorizationNonce::SPACE,
+		payer = payer.as_ref().unwrap_or(&buyer),
+	)]
+	pub authorization_nonce: Box<Account<'info, AuthorizationNonce>>,
+
+	/// Account to send fees to.
+	/// CHECK: Safe because of marketplace_config constraint
+	#[account(mut)]
+	pub fee_account: UncheckedAccount<'info>,
+
+	/// CHECK: Safe because of address constraint
+	#[account(address = solana_program::sysvar::instructions::ID)]
+	pub instructions_sysvar: UncheckedAccount<'info>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mints the item of a voucher signed by the marketplace authority to the buyer, who pays its price
+/// to the marketplace authority with the marketplace fee and royalties taken out like buy_virtual.
+/// The signature is checked through an Ed25519 program instruction right before this one, so the
+/// marketplace authority does not take part in the transaction. The voucher's nonce can only be used
+/// once, so it can't be redeemed again after the mint record is closed.
+///
+/// As with mint_virtual_with_authorization, the collection config mints the NFT and verifies it
+/// into the collection through a collection authority record, and only collections minting regular
+/// NFTs are supported.
+///
+/// When paying with SOL, the remaining accounts should only contain the marketplace auth.
+/// 1. marketplace auth wallet
+///
+/// When paying with an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
+/// 2. buyer currency ata
+/// 3. marketplace auth wallet
+/// 4. marketplace auth currency ata
+/// 5. marketplace fee currency ata
+pub fn redeem_mint_voucher_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, RedeemMintVoucher<'info>>,
+	voucher: MintVoucher,
+	metadata: Metadata
+) -> Result<()> {
+	let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.buyer).to_account_info();
+	require!(
+		ctx.accounts.collection_config.mint_standard == MintStandard::NonFungible,
+		Error::UnsupportedMintStandard
+	);
+
+	voucher.assert_is_valid(
+		ctx.accounts.buyer.key(),
+		&metadata,
+		ctx.accounts.collection_config.key(),
+		ctx.accounts.marketplace_authority.key(),
+		&ctx.accounts.instructions_sysvar.to_account_info(),
+	)?;
+
+	ctx.accounts.authorization_nonce.init(
+		[*ctx.bumps.get(AuthorizationNonce::PREFIX).ok_or(Error::BumpSeedNotInHashMap)?],
+		ctx.accounts.marketplace_authority.key(),
+		voucher.nonce,
+		voucher.expiry,
+		payer.key(),
+	)?;
+
+	let item_id = voucher.item_id;
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		payer.key(),
+	)?;
+
+	let collection_config = &ctx.accounts.collection_config;
+	let auth_seeds = collection_config.auth_seeds();
+	let mut data = collection_config.get_mpl_metadata(metadata)?;
+	data.creators = Some(vec![
+		Creator {
+			address: collection_config.marketplace_authority,
+			verified: false,
+			share: 100
+		}
+	]);
+
+	// Mint the NFT to the buyer.
+	mint_nft(
+		&payer,
+		&ctx.accounts.buyer.to_account_info(),
+		&ctx.accounts.buyer_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&collection_config.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data,
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		Some(&auth_seeds)
+	)?;
+
+	// Mark the item as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new_with_signer(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: payer.clone(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: collection_config.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+			&[&auth_seeds]
+		).with_remaining_accounts(vec![ctx.accounts.collection_authority_record.to_account_info()]),
+		Some(ctx.accounts.collection_authority_record.key())
+	)?;
+
+	// Mark that the primary sale happened.
+	update_primary_sale_happened_via_token(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			UpdatePrimarySaleHappenedViaToken {
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				owner: ctx.accounts.buyer.to_account_info(),
+				token: ctx.accounts.buyer_token_account.to_account_info(),
+			}
+		)
+	)?;
+
+	let fee_config = ctx.accounts.marketplace_config.fee_config;
+	let payment = transfer_payment(
+		&ctx.accounts.buyer.to_account_info(),
+		&payer,
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.fee_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		voucher.currency_mint,
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		ctx.remaining_accounts,
+		voucher.price,
+		fee_config.get_fee_amount(voucher.price)?
+	)?;
+
+	emit!(Buy {
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		price: voucher.price,
+		seller: collection_config.marketplace_authority,
+		buyer: ctx.accounts.buyer.key(),
+		is_virtual: true,
+		currency_mint: voucher.currency_mint,
+		marketplace_authority: collection_config.marketplace_authority,
+		collection_mint: ctx.accounts.collection_mint.key(),
+		fee_config,
+		price_feed: Pubkey::default(),
+		seller_amount: payment.seller_amount,
+		royalty_amount: payment.royalty_amount,
+		fee_amount: payment.fee_amount,
+		amount: 1,
+		remaining_amount: 0,
+	});
+
+	Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken
+};
+use crate::{
+	state::{Raffle, RaffleTicket},
+	event::RaffleTicketRefund,
+	util::{assert_keys_equal, is_native_mint, next_currency_accounts, transfer_lamports, transfer_spl},
+	error::Error
+};
+
+#[derive(Accounts)]
+pub struct RefundRaffleTickets<'info> {
+	/// Buyer wallet.
+	#[account(mut)]
+	pub buyer: Signer<'info>,
+
+	/// Wallet that paid the rent of the ticket.
+	/// CHECK: Safe because of ticket constraint
+	#[account(mut)]
+	pub rent_payer: UncheckedAccount<'info>,
+
+	/// Wallet that paid the rent of the raffle, refunded once the last ticket is.
+	/// CHECK: Safe because of raffle constraint
+	#[account(mut)]
+	pub raffle_rent_payer: UncheckedAccount<'info>,
+
+	#[account(
+		mut,
+		seeds = [
+			Raffle::PREFIX.as_bytes(),
+			raffle.namespace.as_ref(),
+			raffle.raffle_id.as_ref()
+		],
+		bump = raffle.bump[0],
+		constraint = raffle.payer == raffle_rent_payer.key() @ Error::InvalidRentPayer,
+	)]
+	pub raffle: Box<Account<'info, Raffle>>,
+
+	#[account(
+		mut,
+		close = rent_payer,
+		seeds = [
+			RaffleTicket::PREFIX.as_bytes(),
+			raffle.key().as_ref(),
+			ticket.first_ticket.to_le_bytes().as_ref()
+		],
+		bump = ticket.bump[0],
+		has_one = raffle,
+		has_one = buyer,
+		constraint = ticket.payer == rent_payer.key() @ Error::InvalidRentPayer,
+	)]
+	pub ticket: Box<Account<'info, RaffleTicket>>,
+
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Pays the amount the raffle received for the tickets back to their buyer once the raffle expired
+/// without a draw. The raffle is closed when its last ticket is refunded.
+///
+/// When tickets were paid with an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account, followed by the Token-2022 program if it is a Token-2022 mint
+/// 2. raffle currency ata
+/// 3. buyer currency ata
+pub fn refund_raffle_tickets_handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, RefundRaffleTickets<'info>>,
+) -> Result<()> {
+	let raffle_account = &ctx.accounts.raffle.to_account_info();
+	let raffle = &mut ctx.accounts.raffle;
+	let ticket = &ctx.accounts.ticket;
+	let amount = ticket.amount;
+	let is_last_refund = raffle.refund_tickets(ticket.count)?;
+
+	if is_native_mint(raffle.currency_mint) {
+		transfer_lamports(raffle_account, &ctx.accounts.buyer.to_account_info(), amount)?;
+	} else {
+		let remaining_accounts = &mut ctx.remaining_accounts.iter();
+		let (currency_mint, currency_token_program) = &next_currency_accounts(
+			remaining_accounts,
+			&ctx.accounts.token_program.to_account_info()
+		)?;
+		assert_keys_equal(raffle.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+		let raffle_currency_account = next_account_info(remaining_accounts)?;
+		let buyer_currency_account = next_account_info(remaining_accounts)?;
+
+		let auth_seeds = raffle.auth_seeds();
+		transfer_spl(
+			raffle_account,
+			&ctx.accounts.buyer.to_account_info(),
+			raffle_currency_account,
+			buyer_currency_account,
+			currency_mint,
+			&ctx.accounts.buyer.to_account_info(),
+			&ctx.accounts.associated_token_program.to_account_info(),
+			currency_token_program,
+			&ctx.accounts.system_program.to_account_info(),
+			&ctx.accounts.rent.to_account_info(),
+			None,
+			Some(&auth_seeds),
+			None,
+			amount
+		)?;
+	}
+
+	emit!(RaffleTicketRefund {
+		raffle_id: raffle.raffle_id,
+		buyer: ticket.buyer,
+		marketplace_authority: raffle.marketplace_authority,
+		first_ticket: ticket.first_ticket,
+		count: ticket.count,
+		amount,
+	});
+
+	if is_last_refund {
+		raffle.close(ctx.accounts.raffle_rent_payer.to_account_info())?;
+	}
+
+	Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Mint,
+    metadata::MetadataAccount
+};
+use crate::{
+    state::Rental,
+    event::Rent,
+    util::{pay_creator_lamports, transfer_sol},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct RentNft<'info> {
+    /// Renter wallet.
+    #[account(mut)]
+    pub renter: Signer<'info>,
+
+    /// Owner wallet.
+    /// CHECK: Safe because of rental constraint
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Rental::PREFIX.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump = rental.bump[0],
+        has_one = owner,
+        has_one = mint,
+        constraint = rental.fee_config.fee_account == fee_account.key() @ Error::InvalidFeeAccount,
+    )]
+    pub rental: Box<Account<'info, Rental>>,
+
+    /// Mint of the rented NFT.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Metadata of the rented NFT.
+    #[account(
+        constraint = metadata_account.mint == mint.key(),
+    )]
+    pub metadata_account: Box<Account<'info, MetadataAccount>>,
+
+    /// Account to send fees to.
+    /// CHECK: Safe because of rental constraint
+    #[account(mut)]
+    pub fee_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// When the rental applies royalties, the remaining accounts should contain the creator wallets
+/// of the metadata that receive a share of the royalty.
+pub fn rent_nft_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RentNft<'info>>,
+    days: u16,
+    max_price: u64,
+) -> Result<()> {
+    let rental = &mut ctx.accounts.rental;
+    let price = rental.rent(ctx.accounts.renter.key(), days)?;
+    require!(price <= max_price, Error::MaxPriceExceeded);
+
+    let marketplace_fees = rental.get_fee_amount(price)?;
+
+    let creator_fees = if rental.apply_royalties {
+        let royalty = (price as u128)
+            .checked_mul(ctx.accounts.metadata_account.data.seller_fee_basis_points as u128)
+            .ok_or(Error::OverflowError)?
+            .checked_div(10_000)
+            .ok_or(Error::OverflowError)? as u64;
+
+        pay_creator_lamports(
+            &ctx.accounts.renter.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.metadata_account.to_account_info(),
+            &mut ctx.remaining_accounts.iter(),
+            &ctx.accounts.system_program.to_account_info(),
+            royalty
+        )?
+    } else {
+        0
+    };
+
+    let owner_amount = price
+        .checked_sub(marketplace_fees)
+        .ok_or(Error::OverflowError)?
+        .checked_sub(creator_fees)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_sol(
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.fee_account.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        None,
+        marketplace_fees
+    )?;
+
+    transfer_sol(
+        &ctx.accounts.renter.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        None,
+        owner_amount
+    )?;
+
+    emit!(Rent {
+        mint: rental.mint,
+        owner: rental.owner,
+        renter: rental.renter,
+        marketplace_authority: rental.marketplace_authority,
+        price,
+        rented_until: rental.rented_until,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+    metadata::Metadata
+};
+use crate::{
+    state::Loan,
+    event::LoanRepay,
+    util::{is_native_mint, thaw_and_revoke, transfer_sol, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct RepayLoan<'info> {
+    /// Wallet that borrowed against the NFT.
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the borrower pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Wallet that lent the principal.
+    /// CHECK: Safe because of loan constraint
+    #[account(mut)]
+    pub lender: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the loan.
+    /// CHECK: Safe because of loan constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Loan::PREFIX.as_bytes(),
+            loan.lender.as_ref(),
+            loan.loan_id.as_ref()
+        ],
+        bump = loan.bump[0],
+        has_one = lender,
+        has_one = borrower,
+        has_one = mint,
+        has_one = currency_mint,
+        constraint = loan.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    /// Borrower's token account of the collateral.
+    #[account(
+        mut,
+        constraint = token_account.owner == borrower.key(),
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the collateral.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Edition of the collateral.
+    /// CHECK: Thaw would fail if incorrect
+    pub edition: UncheckedAccount<'info>,
+
+    /// The currency of the loan or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// When the loan is in an SPL token, the remaining accounts should contain:
+/// 1. borrower currency ata
+/// 2. lender currency ata
+pub fn repay_loan_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RepayLoan<'info>>,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.borrower).to_account_info();
+    let loan = &ctx.accounts.loan;
+    require!(!loan.is_overdue()?, Error::LoanOverdue);
+
+    let amount = loan.repayment_amount()?;
+
+    if is_native_mint(loan.currency_mint) {
+        transfer_sol(
+            &ctx.accounts.borrower.to_account_info(),
+            &ctx.accounts.lender.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            None,
+            amount
+        )?;
+    } else {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let borrower_currency_account = next_account_info(remaining_accounts)?;
+        let lender_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            &ctx.accounts.borrower.to_account_info(),
+            &ctx.accounts.lender.to_account_info(),
+            borrower_currency_account,
+            lender_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            None,
+            None,
+            amount
+        )?;
+    }
+
+    let auth_seeds = loan.auth_seeds();
+    thaw_and_revoke(
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.edition.to_account_info(),
+        &ctx.accounts.borrower.to_account_info(),
+        &loan.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        Some(&auth_seeds)
+    )?;
+
+    emit!(LoanRepay {
+        loan_id: loan.loan_id,
+        lender: loan.lender,
+        borrower: loan.borrower,
+        marketplace_authority: loan.marketplace_authority,
+        mint: loan.mint,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+	token::Token,
+	associated_token::AssociatedToken,
+	metadata::{
+		verify_sized_collection_item,
+		VerifySizedCollectionItem
+	},
+	metadata
+};
+use crate::{
+	state::{CollectionConfig, MintRecord, MysteryBox, SealedBox},
+	event::MysteryBoxReveal,
+	util::{draw_weighted, mint_nft},
+	error::Error
+};
+
+#[derive(Accounts)]
+#[instruction(item_id: [u8;32])]
+pub struct RevealMysteryBox<'info> {
+	/// Marketplace authority wallet.
+	#[account(mut)]
+	pub marketplace_authority: Signer<'info>,
+
+	/// Wallet that opened the box.
+	/// CHECK: Safe due to sealed_box constraint
+	#[account(mut)]
+	pub holder: UncheckedAccount<'info>,
+
+	/// Wallet that paid the rent of the sealed box.
+	/// CHECK: Safe because of sealed_box constraint
+	#[account(mut)]
+	pub rent_payer: UncheckedAccount<'info>,
+
+	/// The new mint to be used for the prize NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub mint: Signer<'info>,
+
+	/// Holder NFT token account for the prize.
+	/// CHECK: Created for mint CPI
+	#[account(mut)]
+	pub holder_token_account: UncheckedAccount<'info>,
+
+	/// Metadata account for the prize NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the prize NFT.
+	/// CHECK: Verified in mint CPI
+	#[account(mut)]
+	pub edition: UncheckedAccount<'info>,
+
+	/// Mint of the collection NFT.
+	/// CHECK: Safe due to collection_config constraint
+	#[account(mut)]
+	pub collection_mint: UncheckedAccount<'info>,
+
+	/// Metadata for the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_metadata_account: UncheckedAccount<'info>,
+
+	/// Edition of the collection NFT.
+	/// CHECK: Verified in verify collection CPI
+	#[account(mut)]
+	pub collection_edition: UncheckedAccount<'info>,
+
+	#[account(
+		seeds = [
+			CollectionConfig::PREFIX.as_bytes(),
+			collection_mint.key().as_ref()
+		],
+		bump = collection_config.bump[0],
+		has_one = marketplace_authority,
+		has_one = collection_mint,
+	)]
+	pub collection_config: Box<Account<'info, CollectionConfig>>,
+
+	#[account(
+		seeds = [
+			MysteryBox::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			mystery_box.box_id.as_ref()
+		],
+		bump = mystery_box.bump[0],
+		has_one = collection_config,
+	)]
+	pub mystery_box: Box<Account<'info, MysteryBox>>,
+
+	#[account(
+		mut,
+		close = rent_payer,
+		seeds = [
+			SealedBox::PREFIX.as_bytes(),
+			sealed_box.mint.as_ref()
+		],
+		bump = sealed_box.bump[0],
+		has_one = mystery_box,
+		has_one = holder,
+		constraint = sealed_box.is_open() @ Error::BoxNotOpened,
+		constraint = sealed_box.payer == rent_payer.key() @ Error::InvalidRentPayer,
+	)]
+	pub sealed_box: Box<Account<'info, SealedBox>>,
+
+	#[account(
+		init,
+		seeds = [
+			MintRecord::PREFIX.as_bytes(),
+			collection_config.key().as_ref(),
+			item_id.as_ref(),
+		],
+		bump,
+		space = MintRecord::SPACE,
+		payer = marketplace_authority,
+	)]
+	pub mint_record: Box<Account<'info, MintRecord>>,
+
+	pub token_metadata_program: Program<'info, metadata::Metadata>,
+	pub token_program: Program<'info, Token>,
+	pub associated_token_program: Program<'info, AssociatedToken>,
+	pub system_program: Program<'info, System>,
+	pub rent: Sysvar<'info, Rent>,
+}
+
+/// Reveals the seed committed when the opened box was minted, draws a prize from the mystery box's
+/// prize table with the entropy recorded at opening and mints it to the holder.
+pub fn reveal_mystery_box_handler(
+	ctx: Context<RevealMysteryBox>,
+	item_id: [u8;32],
+	seed: [u8;32]
+) -> Result<()> {
+	let mystery_box = &ctx.accounts.mystery_box;
+	let sealed_box = &ctx.accounts.sealed_box;
+	let randomness = mystery_box.randomness_source.combine(
+		seed,
+		sealed_box.seed_hash,
+		sealed_box.entropy,
+		sealed_box.open_slot
+	)?;
+	let prize_index = draw_weighted(randomness, &mystery_box.weights())?;
+	let prize = &mystery_box.prizes[prize_index];
+
+	let mint_record = &mut ctx.accounts.mint_record;
+	mint_record.init(
+		ctx.accounts.collection_config.key(),
+		item_id,
+		ctx.accounts.mint.key(),
+		ctx.accounts.marketplace_authority.key(),
+	)?;
+
+	let data = &ctx.accounts.collection_config.get_mpl_metadata(prize.metadata.clone())?;
+	// Mint the prize NFT to the holder.
+	mint_nft(
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.holder.to_account_info(),
+		&ctx.accounts.holder_token_account.to_account_info(),
+		&ctx.accounts.mint.to_account_info(),
+		&ctx.accounts.marketplace_authority.to_account_info(),
+		&ctx.accounts.metadata_account.to_account_info(),
+		data.clone(),
+		&ctx.accounts.edition.to_account_info(),
+		&ctx.accounts.token_metadata_program.to_account_info(),
+		&ctx.accounts.associated_token_program.to_account_info(),
+		&ctx.accounts.token_program.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		&ctx.accounts.rent.to_account_info(),
+		None
+	)?;
+
+	// Mark the prize as a verified item in the collection.
+	verify_sized_collection_item(
+		CpiContext::new(
+			ctx.accounts.token_metadata_program.to_account_info(),
+			VerifySizedCollectionItem {
+				payer: ctx.accounts.marketplace_authority.to_account_info(),
+				metadata: ctx.accounts.metadata_account.to_account_info(),
+				collection_authority: ctx.accounts.marketplace_authority.to_account_info(),
+				collection_mint: ctx.accounts.collection_mint.to_account_info(),
+				collection_metadata: ctx.accounts.collection_metadata_account.to_account_info(),
+				collection_master_edition: ctx.accounts.collection_edition.to_account_info()
+			},
+		),
+		None
+	)?;
+
+	emit!(MysteryBoxReveal {
+		box_id: mystery_box.box_id,
+		box_mint: sealed_box.mint,
+		holder: sealed_box.holder,
+		marketplace_authority: mystery_box.marketplace_authority,
+		collection_mint: ctx.accounts.collection_mint.key(),
+		prize_index: prize_index as u8,
+		item_id,
+		mint: ctx.accounts.mint.key(),
+		randomness,
+	});
+
+	Ok(())
+}
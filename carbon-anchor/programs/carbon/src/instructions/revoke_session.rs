@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Session,
+    event::SessionRevoke,
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    /// The owner or the session key itself.
+    pub authority: Signer<'info>,
+
+    /// Wallet that paid the rent of the session.
+    /// CHECK: Safe because of session constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Session::PREFIX.as_bytes(),
+            session.owner.as_ref(),
+            session.session_key.as_ref()
+        ],
+        bump = session.bump[0],
+        constraint = authority.key() == session.owner || authority.key() == session.session_key @ Error::InvalidSession,
+        constraint = session.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub session: Box<Account<'info, Session>>,
+}
+
+/// Ends the session before its expiry, closing the session account
+pub fn revoke_session_handler(ctx: Context<RevokeSession>) -> Result<()> {
+    let session = &ctx.accounts.session;
+
+    emit!(SessionRevoke {
+        owner: session.owner,
+        session_key: session.session_key,
+    });
+
+    Ok(())
+}
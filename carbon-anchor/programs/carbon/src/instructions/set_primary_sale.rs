@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{CollectionConfig, PrimarySale};
+
+#[derive(Accounts)]
+pub struct SetPrimarySale<'info> {
+    /// Marketplace authority wallet.
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            CollectionConfig::PREFIX.as_bytes(),
+            collection_config.collection_mint.as_ref()
+        ],
+        bump = collection_config.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+}
+
+/// Sets the price items of the collection are sold at through mint_virtual, a price of 0 making
+/// them free again
+pub fn set_primary_sale_handler(
+    ctx: Context<SetPrimarySale>,
+    primary_sale: PrimarySale,
+) -> Result<()> {
+    primary_sale.assert_is_valid()?;
+    ctx.accounts.collection_config.primary_sale = primary_sale;
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+};
+use crate::{
+    state::{Market, OpenOrders},
+    util::transfer_spl,
+};
+
+#[derive(Accounts)]
+pub struct SettleFunds<'info> {
+    /// Wallet owning the open orders account.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the owner pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    #[account(
+        has_one = base_mint,
+        has_one = quote_mint,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [
+            OpenOrders::PREFIX.as_bytes(),
+            market.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump = open_orders.load()?.bump[0],
+        has_one = market,
+        has_one = owner,
+    )]
+    pub open_orders: AccountLoader<'info, OpenOrders>,
+
+    /// CHECK: Safe because of market constraint
+    pub base_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Safe because of market constraint
+    pub quote_mint: UncheckedAccount<'info>,
+
+    /// Market's token account of the base mint.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub base_vault: UncheckedAccount<'info>,
+
+    /// Market's token account of the quote mint.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub quote_vault: UncheckedAccount<'info>,
+
+    /// Owner's token account of the base mint, created if it does not exist.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub owner_base_account: UncheckedAccount<'info>,
+
+    /// Owner's token account of the quote mint, created if it does not exist.
+    /// CHECK: Validated in transfer
+    #[account(mut)]
+    pub owner_quote_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Transfers the free balances of the open orders account out of the market's vaults to the owner
+pub fn settle_funds_handler(ctx: Context<SettleFunds>) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.owner).to_account_info();
+    let market = &ctx.accounts.market;
+    let auth_seeds = market.auth_seeds();
+
+    let (base_free, quote_free) = {
+        let open_orders = &mut ctx.accounts.open_orders.load_mut()?;
+        let free = (open_orders.base_free, open_orders.quote_free);
+        open_orders.base_free = 0;
+        open_orders.quote_free = 0;
+        free
+    };
+
+    for (vault, owner_account, mint, amount) in [
+        (&ctx.accounts.base_vault, &ctx.accounts.owner_base_account, &ctx.accounts.base_mint, base_free),
+        (&ctx.accounts.quote_vault, &ctx.accounts.owner_quote_account, &ctx.accounts.quote_mint, quote_free),
+    ] {
+        transfer_spl(
+            &market.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &vault.to_account_info(),
+            &owner_account.to_account_info(),
+            &mint.to_account_info(),
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&auth_seeds),
+            None,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+    metadata::{Metadata, MetadataAccount}
+};
+use crate::{
+    state::Loan,
+    event::LoanTake,
+    util::{
+        approve_and_freeze, assert_is_nft_in_collection, close_token_account, is_native_mint,
+        transfer_lamports, transfer_spl
+    },
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct TakeLoan<'info> {
+    /// Wallet borrowing against the NFT.
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the borrower pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Wallet lending the principal.
+    /// CHECK: Safe because of loan constraint
+    #[account(mut)]
+    pub lender: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the loan.
+    /// CHECK: Safe because of loan constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Loan::PREFIX.as_bytes(),
+            loan.lender.as_ref(),
+            loan.loan_id.as_ref()
+        ],
+        bump = loan.bump[0],
+        has_one = lender,
+        has_one = currency_mint,
+        constraint = loan.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    /// Borrower's token account of the collateral.
+    #[account(
+        mut,
+        constraint = token_account.owner == borrower.key(),
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the collateral.
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// Metadata of the collateral.
+    #[account(
+        constraint = metadata_account.mint == mint.key(),
+    )]
+    pub metadata_account: Box<Account<'info, MetadataAccount>>,
+
+    /// Edition of the collateral.
+    /// CHECK: Freeze would fail if incorrect
+    pub edition: UncheckedAccount<'info>,
+
+    /// The currency of the loan or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Custodial, listed or otherwise delegated NFTs are already frozen, so the approval fails for them
+///
+/// When the loan is in an SPL token, the remaining accounts should contain:
+/// 1. loan currency ata
+/// 2. borrower currency ata
+pub fn take_loan_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TakeLoan<'info>>,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.borrower).to_account_info();
+    let loan_account = &ctx.accounts.loan.to_account_info().clone();
+
+    assert_is_nft_in_collection(
+        &ctx.accounts.mint,
+        &ctx.accounts.metadata_account,
+        ctx.accounts.loan.collection_mint
+    )?;
+
+    let loan = &mut ctx.accounts.loan;
+    loan.take(ctx.accounts.borrower.key(), ctx.accounts.mint.key())?;
+
+    let auth_seeds = loan.auth_seeds();
+    approve_and_freeze(
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.edition.to_account_info(),
+        &ctx.accounts.borrower.to_account_info(),
+        loan_account,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        Some(&auth_seeds),
+        1
+    )?;
+
+    // The principal sits on the loan account itself when lent in SOL
+    if is_native_mint(loan.currency_mint) {
+        transfer_lamports(loan_account, &ctx.accounts.borrower.to_account_info(), loan.principal)?;
+    } else {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let loan_currency_account = next_account_info(remaining_accounts)?;
+        let borrower_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            loan_account,
+            &ctx.accounts.borrower.to_account_info(),
+            loan_currency_account,
+            borrower_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&auth_seeds),
+            None,
+            loan.principal
+        )?;
+
+        close_token_account(
+            loan_currency_account,
+            &ctx.accounts.rent_payer.to_account_info(),
+            loan_account,
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&auth_seeds)
+        )?;
+    }
+
+    emit!(LoanTake {
+        loan_id: loan.loan_id,
+        lender: loan.lender,
+        borrower: loan.borrower,
+        marketplace_authority: loan.marketplace_authority,
+        mint: loan.mint,
+        due_time: loan.due_time,
+    });
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anchor_spl::metadata::Metadata;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::{
     CustodyAccount, Listing,
-    util::{thaw, transfer_spl},
+    util::{is_programmable, thaw, transfer_spl, ProgrammableNft},
     error::Error
 };
 
@@ -40,9 +40,14 @@ pub struct TakeOwnership<'info> {
     /// CHECK: Verified in thaw
     pub edition: UncheckedAccount<'info>,
 
+    /// Wallet that paid the rent of the custody account.
+    /// CHECK: Safe because of custody account constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        close = owner,
+        close = rent_payer,
         seeds = [
             CustodyAccount::PREFIX.as_bytes(),
             mint.key().as_ref()
@@ -51,6 +56,7 @@ pub struct TakeOwnership<'info> {
         has_one = marketplace_authority,
         has_one = owner,
         has_one = mint,
+        constraint = custody_account.load()?.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
     )]
     pub custody_account: AccountLoader<'info, CustodyAccount>,
 
@@ -64,15 +70,24 @@ pub struct TakeOwnership<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// When the NFT is programmable, the remaining accounts should contain:
+/// 1. metadata account
+/// 2. token record account
+/// 3. authorization rules program, or token metadata program if the NFT has no rule set
+/// 4. authorization rules account, or token metadata program if the NFT has no rule set
+/// 5. instructions sysvar
+/// 6. marketplace authority token record account
 pub fn take_ownership_handler<'info>(
-    ctx: Context<TakeOwnership>,
+    ctx: Context<'_, '_, '_, 'info, TakeOwnership<'info>>,
 ) -> Result<()> {
     let listing = Listing::from_account_info_with_checks(
         &ctx.accounts.listing.to_account_info(),
+        ctx.accounts.marketplace_authority.key(),
         ctx.accounts.mint.key().to_bytes()
     )?;
 
     require!(listing.is_none(), Error::NftIsListed);
+    require!(!ctx.accounts.custody_account.load()?.is_listed, Error::NftIsListed);
 
     let bump = ctx.accounts.custody_account.load()?.bump;
     let auth_seeds = CustodyAccount::auth_seeds_from_args(
@@ -80,6 +95,22 @@ pub fn take_ownership_handler<'info>(
         &bump
     );
 
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    if let Some(nft) = ctx.accounts.programmable_nft(remaining_accounts)? {
+        let custody_account = ctx.accounts.custody_account.as_ref();
+        nft.unlock(custody_account, Some(&auth_seeds))?;
+        nft.transfer(
+            &ctx.accounts.marketplace_authority_token_account.to_account_info(),
+            &ctx.accounts.marketplace_authority.to_account_info(),
+            next_account_info(remaining_accounts)?,
+            custody_account,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            Some(&auth_seeds)
+        )?;
+
+        return Ok(());
+    }
+
     thaw(
         &ctx.accounts.token_account.to_account_info(),
         &ctx.accounts.mint.to_account_info(),
@@ -108,4 +139,38 @@ pub fn take_ownership_handler<'info>(
     )?;
 
     Ok(())
+}
+
+impl<'info> TakeOwnership<'info> {
+
+    /// Returns the programmable NFT when the remaining accounts start with the metadata of one
+    fn programmable_nft<'b>(
+        &self,
+        remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+    ) -> Result<Option<ProgrammableNft<'info>>> {
+        let metadata = match remaining_accounts.next() {
+            Some(metadata) if is_programmable(metadata)? => metadata,
+            _ => return Ok(None),
+        };
+
+        let nft = ProgrammableNft {
+            token_account: self.token_account.to_account_info(),
+            token_owner: self.owner.to_account_info(),
+            mint: self.mint.to_account_info(),
+            metadata: metadata.clone(),
+            edition: self.edition.to_account_info(),
+            token_record: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules: next_account_info(remaining_accounts)?.clone(),
+            sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+            payer: self.marketplace_authority.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        nft.assert_is_valid()?;
+
+        Ok(Some(nft))
+    }
+
 }
\ No newline at end of file
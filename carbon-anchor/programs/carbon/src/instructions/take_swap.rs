@@ -0,0 +1,316 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+    metadata::{Metadata, MetadataAccount}
+};
+use crate::{
+    state::{Swap, CustodyAccount},
+    event::SwapTake,
+    util::{
+        assert_is_nft_in_collection, assert_keys_equal, close_token_account, is_native_mint,
+        pay_creator_lamports, thaw, transfer_lamports, transfer_sol, transfer_spl
+    },
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct TakeSwap<'info> {
+    /// Wallet settling the swap.
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    /// Wallet sponsoring the rent of new accounts, the taker pays it when omitted.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Wallet that proposed the swap.
+    /// CHECK: Safe because of swap constraint
+    #[account(mut)]
+    pub maker: UncheckedAccount<'info>,
+
+    /// Wallet that paid the rent of the swap.
+    /// CHECK: Safe because of swap constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            Swap::PREFIX.as_bytes(),
+            swap.maker.as_ref(),
+            swap.swap_id.as_ref()
+        ],
+        bump = swap.bump[0],
+        has_one = maker,
+        has_one = currency_mint,
+        constraint = swap.payer == rent_payer.key() @ Error::InvalidRentPayer,
+    )]
+    pub swap: Box<Account<'info, Swap>>,
+
+    /// The currency of the swap amounts or native mint if using SOL
+    pub currency_mint: Box<Account<'info, Mint>>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// The remaining accounts should contain the following for each maker mint, in the order of the swap:
+/// 1. mint account
+/// 2. maker token account
+/// 3. taker token account
+/// 4. edition account
+/// 5. custody account
+/// 6. metadata account
+/// 7. wallet that paid the rent of the custody account, or any account if the NFT is not custodied
+/// 8. creator wallets of the metadata when the swap charges royalties
+///
+/// Followed by the following for each taker mint, in the order of the swap:
+/// 1. mint account
+/// 2. taker token account
+/// 3. maker token account
+/// 4. metadata account
+/// 5. creator wallets of the metadata when the swap charges royalties
+///
+/// When the swap is in an SPL token, followed by:
+/// 1. taker currency ata
+/// 2. maker currency ata
+/// 3. swap currency ata
+pub fn take_swap_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TakeSwap<'info>>,
+) -> Result<()> {
+    let payer = ctx.accounts.payer.as_ref().unwrap_or(&ctx.accounts.taker).to_account_info();
+    let swap = &ctx.accounts.swap;
+    swap.assert_can_take(ctx.accounts.taker.key())?;
+
+    let swap_account = &swap.to_account_info();
+    let auth_seeds = swap.auth_seeds();
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let mut royalties_paid: u64 = 0;
+
+    for expected_mint in swap.maker_mints.iter() {
+        let mint = next_account_info(remaining_accounts)?;
+        let maker_token_account = next_account_info(remaining_accounts)?;
+        let taker_token_account = next_account_info(remaining_accounts)?;
+        let edition = next_account_info(remaining_accounts)?;
+        let custody_account = next_account_info(remaining_accounts)?;
+        let metadata_account = next_account_info(remaining_accounts)?;
+        let custody_rent_payer = next_account_info(remaining_accounts)?;
+
+        assert_keys_equal(mint.key(), *expected_mint, "Invalid swap mint")?;
+        CustodyAccount::assert_is_key_for_mint(custody_account.key(), mint.key())?;
+
+        if custody_account.data_is_empty() {
+            ctx.accounts.transfer_nft(
+                maker_token_account,
+                taker_token_account,
+                mint,
+                edition,
+                swap_account,
+                &auth_seeds
+            )?;
+        } else {
+            let account_loader = AccountLoader::<'info, CustodyAccount>::try_from(custody_account)?;
+
+            assert_keys_equal(
+                account_loader.load()?.marketplace_authority,
+                swap.marketplace_authority,
+                "Invalid marketplace authority"
+            )?;
+            assert_keys_equal(account_loader.load()?.owner, swap.maker, "Invalid custody owner")?;
+            assert_keys_equal(
+                account_loader.load()?.rent_payer(),
+                custody_rent_payer.key(),
+                "Invalid custody rent payer"
+            )?;
+
+            let bump = account_loader.load()?.bump;
+            let custody_seeds = CustodyAccount::auth_seeds_from_args(mint.key, &bump);
+
+            ctx.accounts.transfer_nft(
+                maker_token_account,
+                taker_token_account,
+                mint,
+                edition,
+                custody_account,
+                &custody_seeds
+            )?;
+
+            account_loader.close(custody_rent_payer.clone())?;
+        }
+
+        royalties_paid = royalties_paid
+            .checked_add(ctx.accounts.pay_royalties(mint, metadata_account, remaining_accounts)?)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    for expected_mint in swap.taker_mints.iter() {
+        let mint = next_account_info(remaining_accounts)?;
+        let taker_token_account = next_account_info(remaining_accounts)?;
+        let maker_token_account = next_account_info(remaining_accounts)?;
+        let metadata_account = next_account_info(remaining_accounts)?;
+
+        assert_keys_equal(mint.key(), *expected_mint, "Invalid swap mint")?;
+        assert_is_nft_in_collection(
+            &Account::<'info, Mint>::try_from(mint)?,
+            &Account::<'info, MetadataAccount>::try_from(metadata_account)?,
+            swap.collection_mint
+        )?;
+
+        transfer_spl(
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            taker_token_account,
+            maker_token_account,
+            mint,
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            None,
+            None,
+            1
+        )?;
+
+        royalties_paid = royalties_paid
+            .checked_add(ctx.accounts.pay_royalties(mint, metadata_account, remaining_accounts)?)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    if is_native_mint(swap.currency_mint) {
+        transfer_sol(
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            None,
+            swap.taker_amount
+        )?;
+
+        // The escrowed SOL sits on the swap account itself, which is owned by the program
+        transfer_lamports(swap_account, &ctx.accounts.taker.to_account_info(), swap.maker_amount)?;
+    } else if swap.taker_amount > 0 || swap.maker_amount > 0 {
+        let taker_currency_account = next_account_info(remaining_accounts)?;
+        let maker_currency_account = next_account_info(remaining_accounts)?;
+        let swap_currency_account = next_account_info(remaining_accounts)?;
+
+        transfer_spl(
+            &ctx.accounts.taker.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            taker_currency_account,
+            maker_currency_account,
+            &ctx.accounts.currency_mint.to_account_info(),
+            &payer,
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            None,
+            None,
+            swap.taker_amount
+        )?;
+
+        if swap.maker_amount > 0 {
+            transfer_spl(
+                swap_account,
+                &ctx.accounts.taker.to_account_info(),
+                swap_currency_account,
+                taker_currency_account,
+                &ctx.accounts.currency_mint.to_account_info(),
+                &payer,
+                &ctx.accounts.associated_token_program.to_account_info(),
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.rent.to_account_info(),
+                None,
+                Some(&auth_seeds),
+                None,
+                swap.maker_amount
+            )?;
+
+            close_token_account(
+                swap_currency_account,
+                &ctx.accounts.rent_payer.to_account_info(),
+                swap_account,
+                &ctx.accounts.token_program.to_account_info(),
+                Some(&auth_seeds)
+            )?;
+        }
+    }
+
+    emit!(SwapTake {
+        swap_id: swap.swap_id,
+        maker: swap.maker,
+        taker: ctx.accounts.taker.key(),
+        marketplace_authority: swap.marketplace_authority,
+        collection_mint: swap.collection_mint,
+        royalties_paid,
+    });
+
+    Ok(())
+}
+
+impl<'info> TakeSwap<'info> {
+
+    fn transfer_nft(
+        &self,
+        maker_token_account: &AccountInfo<'info>,
+        taker_token_account: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+        edition: &AccountInfo<'info>,
+        delegate: &AccountInfo<'info>,
+        auth_seeds: &[&[u8]],
+    ) -> Result<()> {
+        thaw(
+            maker_token_account,
+            mint,
+            edition,
+            delegate,
+            &self.token_program.to_account_info(),
+            &self.token_metadata_program.to_account_info(),
+            Some(auth_seeds)
+        )?;
+
+        transfer_spl(
+            &self.maker.to_account_info(),
+            &self.taker.to_account_info(),
+            maker_token_account,
+            taker_token_account,
+            mint,
+            &self.payer.as_ref().unwrap_or(&self.taker).to_account_info(),
+            &self.associated_token_program.to_account_info(),
+            &self.token_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.rent.to_account_info(),
+            Some(delegate),
+            Some(auth_seeds),
+            None,
+            1
+        )?;
+
+        Ok(())
+    }
+
+    fn pay_royalties<'b>(
+        &self,
+        mint: &AccountInfo<'info>,
+        metadata_account: &AccountInfo<'info>,
+        remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+    ) -> Result<u64> {
+        pay_creator_lamports(
+            &self.taker.to_account_info(),
+            mint,
+            metadata_account,
+            remaining_accounts,
+            &self.system_program.to_account_info(),
+            self.swap.royalty_per_nft
+        )
+    }
+
+}
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::metadata::Metadata;
 use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::instruction::RevokeArgs;
 use crate::{
     CustodyAccount, Listing,
-    util::{thaw_and_revoke},
+    util::{is_programmable, thaw_and_revoke, ProgrammableNft},
     error::Error
 };
 
@@ -32,9 +33,14 @@ pub struct Uncustody<'info> {
     /// CHECK: Verified in thaw
     pub edition: UncheckedAccount<'info>,
 
+    /// Wallet that paid the rent of the custody account.
+    /// CHECK: Safe because of custody account constraint
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        close = owner,
+        close = rent_payer,
         seeds = [
             CustodyAccount::PREFIX.as_bytes(),
             mint.key().as_ref()
@@ -43,6 +49,7 @@ pub struct Uncustody<'info> {
         has_one = marketplace_authority,
         has_one = owner,
         has_one = mint,
+        constraint = custody_account.load()?.rent_payer() == rent_payer.key() @ Error::InvalidRentPayer,
     )]
     pub custody_account: AccountLoader<'info, CustodyAccount>,
 
@@ -54,15 +61,23 @@ pub struct Uncustody<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// When the NFT is programmable, the remaining accounts should contain:
+/// 1. metadata account
+/// 2. token record account
+/// 3. authorization rules program, or token metadata program if the NFT has no rule set
+/// 4. authorization rules account, or token metadata program if the NFT has no rule set
+/// 5. instructions sysvar
 pub fn uncustody_handler<'info>(
-    ctx: Context<Uncustody>,
+    ctx: Context<'_, '_, '_, 'info, Uncustody<'info>>,
 ) -> Result<()> {
     let listing = Listing::from_account_info_with_checks(
         &ctx.accounts.listing.to_account_info(),
+        ctx.accounts.marketplace_authority.key(),
         ctx.accounts.mint.key().to_bytes()
     )?;
 
     require!(listing.is_none(), Error::NftIsListed);
+    require!(!ctx.accounts.custody_account.load()?.is_listed, Error::NftIsListed);
 
     let bump = ctx.accounts.custody_account.load()?.bump;
     let item_id = ctx.accounts.custody_account.load()?.item_id;
@@ -71,16 +86,24 @@ pub fn uncustody_handler<'info>(
         &bump
     );
 
-    thaw_and_revoke(
-        &ctx.accounts.token_account.to_account_info(),
-        &ctx.accounts.mint.to_account_info(),
-        &ctx.accounts.edition.to_account_info(),
-        &ctx.accounts.owner.to_account_info(),
-        &ctx.accounts.custody_account.to_account_info(),
-        &ctx.accounts.token_program.to_account_info(),
-        &ctx.accounts.token_metadata_program.to_account_info(),
-        Some(&auth_seeds),
-    )?;
+    let programmable_nft = ctx.accounts.programmable_nft(&mut ctx.remaining_accounts.iter())?;
+
+    if let Some(nft) = programmable_nft {
+        let custody_account = &ctx.accounts.custody_account.to_account_info();
+        nft.unlock(custody_account, Some(&auth_seeds))?;
+        nft.revoke(custody_account, RevokeArgs::LockedTransferV1)?;
+    } else {
+        thaw_and_revoke(
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.edition.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.custody_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            Some(&auth_seeds),
+        )?;
+    }
 
     emit!(crate::event::Uncustody {
         marketplace_authority: ctx.accounts.marketplace_authority.key(),
@@ -90,4 +113,38 @@ pub fn uncustody_handler<'info>(
     });
 
     Ok(())
+}
+
+impl<'info> Uncustody<'info> {
+
+    /// Returns the programmable NFT when the remaining accounts start with the metadata of one
+    fn programmable_nft<'b>(
+        &self,
+        remaining_accounts: &mut std::slice::Iter<'b, AccountInfo<'info>>,
+    ) -> Result<Option<ProgrammableNft<'info>>> {
+        let metadata = match remaining_accounts.next() {
+            Some(metadata) if is_programmable(metadata)? => metadata,
+            _ => return Ok(None),
+        };
+
+        let nft = ProgrammableNft {
+            token_account: self.token_account.to_account_info(),
+            token_owner: self.owner.to_account_info(),
+            mint: self.mint.to_account_info(),
+            metadata: metadata.clone(),
+            edition: self.edition.to_account_info(),
+            token_record: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules_program: next_account_info(remaining_accounts)?.clone(),
+            authorization_rules: next_account_info(remaining_accounts)?.clone(),
+            sysvar_instructions: next_account_info(remaining_accounts)?.clone(),
+            payer: self.owner.to_account_info(),
+            token_metadata_program: self.token_metadata_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        nft.assert_is_valid()?;
+
+        Ok(Some(nft))
+    }
+
 }
\ No newline at end of file
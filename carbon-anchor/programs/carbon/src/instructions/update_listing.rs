@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::{Listing},
+    state::{Listing, Session, SessionAction},
     event::ListingUpdate,
     error::Error,
 };
@@ -8,13 +8,21 @@ use crate::{
 #[derive(Accounts)]
 pub struct UpdateListing<'info> {
     /// Seller wallet.
-    #[account(mut)]
-    pub seller: Signer<'info>,
+    /// CHECK: Safe because of listing constraint
+    pub seller: UncheckedAccount<'info>,
+
+    /// The seller, or the key of a session of the seller.
+    pub authority: Signer<'info>,
+
+    /// Session authorizing the authority, or any account if the seller signs.
+    /// CHECK: Validated in handler
+    pub session: UncheckedAccount<'info>,
 
     #[account(
         mut,
         seeds = [
             Listing::PREFIX.as_bytes(),
+            listing.namespace_seed(),
             listing.item_id.as_ref()
         ],
         bump = listing.bump[0],
@@ -27,26 +35,40 @@ pub fn update_listing_handler<'info>(
     ctx: Context<UpdateListing>,
     price: u64,
     expiry: i64,
+    start_time: i64,
 ) -> Result<()> {
     require!(price > 0, Error::InvalidPrice);
     require!(expiry >= 0, Error::InvalidExpiry);
 
+    Session::assert_can_sign_for(
+        ctx.accounts.seller.key(),
+        ctx.accounts.authority.key(),
+        &ctx.accounts.session.to_account_info(),
+        ctx.accounts.listing.marketplace_authority,
+        SessionAction::UpdateListing,
+        Some(ctx.accounts.listing.collection_mint),
+        Some(price),
+    )?;
+
     let listing = &mut ctx.accounts.listing;
     listing.update(
         price,
         expiry,
+        start_time,
     )?;
 
     emit!(ListingUpdate {
         item_id: listing.item_id,
         price,
         expiry,
+        start_time,
         seller: listing.seller,
         is_virtual: true,
         currency_mint: listing.currency_mint,
         collection_mint: listing.collection_mint,
         marketplace_authority: listing.marketplace_authority,
         fee_config: listing.fee_config,
+        price_feed: listing.price_feed,
     });
 
     Ok(())
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::PriceFeed;
+
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    /// Marketplace authority wallet.
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PriceFeed::PREFIX.as_bytes(),
+            marketplace_authority.key().as_ref(),
+            price_feed.currency_mint.as_ref()
+        ],
+        bump = price_feed.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub price_feed: Box<Account<'info, PriceFeed>>,
+}
+
+pub fn update_price_feed_handler(
+    ctx: Context<UpdatePriceFeed>,
+    price: i64,
+    conf: u64,
+    expo: i32,
+) -> Result<()> {
+    ctx.accounts.price_feed.update(price, conf, expo)
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Token, TokenAccount},
+};
+use crate::{
+    state::BondingCurve,
+    util::{assert_is_ata, assert_keys_equal, is_native_mint, transfer_lamports, transfer_spl},
+    error::Error,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawBondingCurveReserve<'info> {
+    /// Marketplace authority wallet.
+    #[account(mut)]
+    pub marketplace_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BondingCurve::PREFIX.as_bytes(),
+            bonding_curve.collection_config.as_ref(),
+            bonding_curve.item_type.as_ref()
+        ],
+        bump = bonding_curve.bump[0],
+        has_one = marketplace_authority,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Withdraws proceeds of the curve's sales to the marketplace authority. Only the part of the
+/// reserve that is not owed as refunds to units in circulation can be withdrawn.
+///
+/// When the curve is priced in an SPL token, the remaining accounts should be in the following order:
+/// 1. currency mint account
+/// 2. bonding curve currency ata
+/// 3. marketplace auth currency ata
+pub fn withdraw_bonding_curve_reserve_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawBondingCurveReserve<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let bonding_curve_account = &ctx.accounts.bonding_curve.to_account_info();
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    if is_native_mint(bonding_curve.currency_mint) {
+        let rent_exempt = ctx.accounts.rent.minimum_balance(bonding_curve_account.data_len());
+        let available = bonding_curve_account.lamports()
+            .saturating_sub(rent_exempt)
+            .saturating_sub(bonding_curve.reserved);
+        require!(amount <= available, Error::InsufficientReserve);
+
+        transfer_lamports(
+            bonding_curve_account,
+            &ctx.accounts.marketplace_authority.to_account_info(),
+            amount,
+        )?;
+    } else {
+        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        let currency_mint = next_account_info(remaining_accounts)?;
+        let reserve_currency_account = next_account_info(remaining_accounts)?;
+        let authority_currency_account = next_account_info(remaining_accounts)?;
+        assert_keys_equal(bonding_curve.currency_mint, currency_mint.key(), "Invalid currency mint")?;
+        assert_is_ata(reserve_currency_account, &bonding_curve.key(), &currency_mint.key())?;
+
+        let balance = Account::<TokenAccount>::try_from(reserve_currency_account)?.amount;
+        require!(amount <= balance.saturating_sub(bonding_curve.reserved), Error::InsufficientReserve);
+
+        transfer_spl(
+            bonding_curve_account,
+            &ctx.accounts.marketplace_authority.to_account_info(),
+            reserve_currency_account,
+            authority_currency_account,
+            currency_mint,
+            &ctx.accounts.marketplace_authority.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            Some(&bonding_curve.auth_seeds()),
+            None,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -26,17 +26,25 @@ pub mod carbon {
     pub fn list_nft<'info>(
         ctx: Context<'_, '_, '_, 'info, ListNft<'info>>,
         price: u64,
-        expiry: i64
+        expiry: i64,
+        start_time: i64,
+        proof: Vec<[u8; 32]>
     ) -> Result<()> {
-        instructions::list_nft_handler(ctx, price, expiry)
+        instructions::list_nft_handler(ctx, price, expiry, start_time, proof)
     }
 
-    pub fn list_virtual(ctx: Context<ListVirtual>, item_id: [u8;32], price: u64, expiry: i64) -> Result<()> {
-        instructions::list_virtual_handler(ctx, item_id, price, expiry)
+    pub fn list_virtual(
+        ctx: Context<ListVirtual>,
+        item_id: [u8;32],
+        price: u64,
+        expiry: i64,
+        start_time: i64
+    ) -> Result<()> {
+        instructions::list_virtual_handler(ctx, item_id, price, expiry, start_time)
     }
 
-    pub fn update_listing(ctx: Context<UpdateListing>, price: u64, expiry: i64) -> Result<()> {
-        instructions::update_listing_handler(ctx, price, expiry)
+    pub fn update_listing(ctx: Context<UpdateListing>, price: u64, expiry: i64, start_time: i64) -> Result<()> {
+        instructions::update_listing_handler(ctx, price, expiry, start_time)
     }
 
     pub fn delist_nft<'info>(ctx: Context<'_, '_, '_, 'info, DelistNft<'info>>) -> Result<()> {
@@ -66,24 +74,291 @@ pub mod carbon {
     pub fn mint_virtual<'info>(
         ctx: Context<'_, '_, '_, 'info, MintVirtual<'info>>,
         item_id: [u8;32],
-        metadata: Metadata
+        metadata: Metadata,
+        primary_sale: Option<PrimarySale>
     ) -> Result<()> {
-        instructions::mint_virtual_handler(ctx, item_id, metadata)
+        instructions::mint_virtual_handler(ctx, item_id, metadata, primary_sale)
     }
 
-    pub fn custody<'info>(ctx: Context<Custody>, item_id: [u8; 32]) -> Result<()> {
+    pub fn custody<'info>(ctx: Context<'_, '_, '_, 'info, Custody<'info>>, item_id: [u8; 32]) -> Result<()> {
         instructions::custody_handler(ctx, item_id)
     }
 
-    pub fn uncustody<'info>(ctx: Context<Uncustody>) -> Result<()> {
+    pub fn uncustody<'info>(ctx: Context<'_, '_, '_, 'info, Uncustody<'info>>) -> Result<()> {
         instructions::uncustody_handler(ctx)
     }
 
-    pub fn take_ownership<'info>(ctx: Context<TakeOwnership>) -> Result<()> {
+    pub fn take_ownership<'info>(ctx: Context<'_, '_, '_, 'info, TakeOwnership<'info>>) -> Result<()> {
         instructions::take_ownership_handler(ctx)
     }
 
     pub fn close_mint_record<'info>(ctx: Context<CloseMintRecord>) -> Result<()> {
         instructions::close_mint_record_handler(ctx)
     }
+
+    pub fn migrate_mint_record<'info>(ctx: Context<'_, '_, '_, 'info, MigrateMintRecord<'info>>) -> Result<()> {
+        instructions::migrate_mint_record_handler(ctx)
+    }
+
+    pub fn init_price_feed(ctx: Context<InitPriceFeed>) -> Result<()> {
+        instructions::init_price_feed_handler(ctx)
+    }
+
+    pub fn update_price_feed(ctx: Context<UpdatePriceFeed>, price: i64, conf: u64, expo: i32) -> Result<()> {
+        instructions::update_price_feed_handler(ctx, price, conf, expo)
+    }
+
+    pub fn close_expired_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>
+    ) -> Result<()> {
+        instructions::close_expired_listing_handler(ctx)
+    }
+
+    pub fn create_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSwap<'info>>,
+        args: SwapArgs
+    ) -> Result<()> {
+        instructions::create_swap_handler(ctx, args)
+    }
+
+    pub fn take_swap<'info>(ctx: Context<'_, '_, '_, 'info, TakeSwap<'info>>) -> Result<()> {
+        instructions::take_swap_handler(ctx)
+    }
+
+    pub fn cancel_swap<'info>(ctx: Context<'_, '_, '_, 'info, CancelSwap<'info>>) -> Result<()> {
+        instructions::cancel_swap_handler(ctx)
+    }
+
+    pub fn list_rental(ctx: Context<ListRental>, args: RentalArgs) -> Result<()> {
+        instructions::list_rental_handler(ctx, args)
+    }
+
+    pub fn rent_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, RentNft<'info>>,
+        days: u16,
+        max_price: u64
+    ) -> Result<()> {
+        instructions::rent_nft_handler(ctx, days, max_price)
+    }
+
+    pub fn end_rental(ctx: Context<EndRental>) -> Result<()> {
+        instructions::end_rental_handler(ctx)
+    }
+
+    pub fn offer_loan<'info>(
+        ctx: Context<'_, '_, '_, 'info, OfferLoan<'info>>,
+        args: LoanArgs
+    ) -> Result<()> {
+        instructions::offer_loan_handler(ctx, args)
+    }
+
+    pub fn take_loan<'info>(ctx: Context<'_, '_, '_, 'info, TakeLoan<'info>>) -> Result<()> {
+        instructions::take_loan_handler(ctx)
+    }
+
+    pub fn repay_loan<'info>(ctx: Context<'_, '_, '_, 'info, RepayLoan<'info>>) -> Result<()> {
+        instructions::repay_loan_handler(ctx)
+    }
+
+    pub fn foreclose_loan(ctx: Context<ForecloseLoan>) -> Result<()> {
+        instructions::foreclose_loan_handler(ctx)
+    }
+
+    pub fn cancel_loan_offer<'info>(ctx: Context<'_, '_, '_, 'info, CancelLoanOffer<'info>>) -> Result<()> {
+        instructions::cancel_loan_offer_handler(ctx)
+    }
+
+    pub fn list_fungible(ctx: Context<ListFungible>, args: FungibleListingArgs) -> Result<()> {
+        instructions::list_fungible_handler(ctx, args)
+    }
+
+    pub fn buy_fungible<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyFungible<'info>>,
+        amount: u64,
+        max_price: u64
+    ) -> Result<()> {
+        instructions::buy_fungible_handler(ctx, amount, max_price)
+    }
+
+    pub fn delist_fungible(ctx: Context<DelistFungible>) -> Result<()> {
+        instructions::delist_fungible_handler(ctx)
+    }
+
+    pub fn init_market(ctx: Context<InitMarket>, base_lot_size: u64) -> Result<()> {
+        instructions::init_market_handler(ctx, base_lot_size)
+    }
+
+    pub fn init_open_orders(ctx: Context<InitOpenOrders>) -> Result<()> {
+        instructions::init_open_orders_handler(ctx)
+    }
+
+    pub fn place_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceOrder<'info>>,
+        side: Side,
+        price: u64,
+        quantity: u64
+    ) -> Result<()> {
+        instructions::place_order_handler(ctx, side, price, quantity)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>, side: Side, order_id: u64) -> Result<()> {
+        instructions::cancel_order_handler(ctx, side, order_id)
+    }
+
+    pub fn match_orders<'info>(
+        ctx: Context<'_, '_, '_, 'info, MatchOrders<'info>>,
+        limit: u16
+    ) -> Result<()> {
+        instructions::match_orders_handler(ctx, limit)
+    }
+
+    pub fn settle_funds(ctx: Context<SettleFunds>) -> Result<()> {
+        instructions::settle_funds_handler(ctx)
+    }
+
+    pub fn init_bonding_curve(ctx: Context<InitBondingCurve>, args: BondingCurveArgs) -> Result<()> {
+        instructions::init_bonding_curve_handler(ctx, args)
+    }
+
+    pub fn buy_bonding_curve<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyBondingCurve<'info>>,
+        item_id: [u8;32],
+        max_price: u64
+    ) -> Result<()> {
+        instructions::buy_bonding_curve_handler(ctx, item_id, max_price)
+    }
+
+    pub fn burn_bonding_curve<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnBondingCurve<'info>>,
+        item_id: [u8;32],
+        serial: u64
+    ) -> Result<()> {
+        instructions::burn_bonding_curve_handler(ctx, item_id, serial)
+    }
+
+    pub fn withdraw_bonding_curve_reserve<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawBondingCurveReserve<'info>>,
+        amount: u64
+    ) -> Result<()> {
+        instructions::withdraw_bonding_curve_reserve_handler(ctx, amount)
+    }
+
+    pub fn init_recipe(ctx: Context<InitRecipe>, args: RecipeArgs) -> Result<()> {
+        instructions::init_recipe_handler(ctx, args)
+    }
+
+    pub fn craft<'info>(ctx: Context<'_, '_, '_, 'info, CraftItem<'info>>, item_id: [u8;32]) -> Result<()> {
+        instructions::craft_handler(ctx, item_id)
+    }
+
+    pub fn init_mystery_box(ctx: Context<InitMysteryBox>, args: MysteryBoxArgs) -> Result<()> {
+        instructions::init_mystery_box_handler(ctx, args)
+    }
+
+    pub fn mint_mystery_box(ctx: Context<MintMysteryBox>, item_id: [u8;32], seed_hash: [u8;32]) -> Result<()> {
+        instructions::mint_mystery_box_handler(ctx, item_id, seed_hash)
+    }
+
+    pub fn open_mystery_box(ctx: Context<OpenMysteryBox>) -> Result<()> {
+        instructions::open_mystery_box_handler(ctx)
+    }
+
+    pub fn reveal_mystery_box(ctx: Context<RevealMysteryBox>, item_id: [u8;32], seed: [u8;32]) -> Result<()> {
+        instructions::reveal_mystery_box_handler(ctx, item_id, seed)
+    }
+
+    pub fn create_raffle(ctx: Context<CreateRaffle>, args: RaffleArgs) -> Result<()> {
+        instructions::create_raffle_handler(ctx, args)
+    }
+
+    pub fn create_virtual_raffle(
+        ctx: Context<CreateVirtualRaffle>,
+        args: RaffleArgs,
+        item_id: [u8;32],
+        metadata: Metadata
+    ) -> Result<()> {
+        instructions::create_virtual_raffle_handler(ctx, args, item_id, metadata)
+    }
+
+    pub fn buy_raffle_tickets<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyRaffleTickets<'info>>,
+        count: u32
+    ) -> Result<()> {
+        instructions::buy_raffle_tickets_handler(ctx, count)
+    }
+
+    pub fn close_raffle(ctx: Context<CloseRaffle>, seed_hash: [u8;32]) -> Result<()> {
+        instructions::close_raffle_handler(ctx, seed_hash)
+    }
+
+    pub fn draw_raffle<'info>(ctx: Context<'_, '_, '_, 'info, DrawRaffle<'info>>, seed: [u8;32]) -> Result<()> {
+        instructions::draw_raffle_handler(ctx, seed)
+    }
+
+    pub fn claim_raffle_nft(ctx: Context<ClaimRaffleNft>) -> Result<()> {
+        instructions::claim_raffle_nft_handler(ctx)
+    }
+
+    pub fn claim_raffle_virtual(ctx: Context<ClaimRaffleVirtual>) -> Result<()> {
+        instructions::claim_raffle_virtual_handler(ctx)
+    }
+
+    pub fn close_raffle_ticket(ctx: Context<CloseRaffleTicket>) -> Result<()> {
+        instructions::close_raffle_ticket_handler(ctx)
+    }
+
+    pub fn cancel_raffle<'info>(ctx: Context<'_, '_, '_, 'info, CancelRaffle<'info>>) -> Result<()> {
+        instructions::cancel_raffle_handler(ctx)
+    }
+
+    pub fn expire_raffle<'info>(ctx: Context<'_, '_, '_, 'info, ExpireRaffle<'info>>) -> Result<()> {
+        instructions::expire_raffle_handler(ctx)
+    }
+
+    pub fn refund_raffle_tickets<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundRaffleTickets<'info>>,
+    ) -> Result<()> {
+        instructions::refund_raffle_tickets_handler(ctx)
+    }
+
+    pub fn create_session(ctx: Context<CreateSession>, args: SessionArgs) -> Result<()> {
+        instructions::create_session_handler(ctx, args)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session_handler(ctx)
+    }
+
+    pub fn list_virtual_with_authorization(
+        ctx: Context<ListVirtualWithAuthorization>,
+        authorization: Authorization,
+        expiry: i64,
+        start_time: i64
+    ) -> Result<()> {
+        instructions::list_virtual_with_authorization_handler(ctx, authorization, expiry, start_time)
+    }
+
+    pub fn mint_virtual_with_authorization<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintVirtualWithAuthorization<'info>>,
+        authorization: Authorization,
+        metadata: Metadata
+    ) -> Result<()> {
+        instructions::mint_virtual_with_authorization_handler(ctx, authorization, metadata)
+    }
+
+    pub fn close_authorization_nonce(ctx: Context<CloseAuthorizationNonce>) -> Result<()> {
+        instructions::close_authorization_nonce_handler(ctx)
+    }
+
+    pub fn redeem_mint_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemMintVoucher<'info>>,
+        voucher: MintVoucher,
+        metadata: Metadata
+    ) -> Result<()> {
+        instructions::redeem_mint_voucher_handler(ctx, voucher, metadata)
+    }
+
+    pub fn set_primary_sale(ctx: Context<SetPrimarySale>, primary_sale: PrimarySale) -> Result<()> {
+        instructions::set_primary_sale_handler(ctx, primary_sale)
+    }
 }
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::Metadata,
+	util::assert_ed25519_signature,
+	error::Error
+};
+
+/// Instruction an authorization can be used for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorizedAction {
+	ListVirtual,
+	MintVirtual,
+}
+
+/// Message the marketplace authority signs off-chain so an action can go through without the
+/// authority signing the transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Authorization {
+	pub action: AuthorizedAction,
+	pub item_id: [u8; 32],
+	/// Collection config the item is listed or minted in
+	pub collection_config: Pubkey,
+	/// Wallet the action is for, the seller of a listing or the buyer of a mint
+	pub owner: Pubkey,
+	/// Price of the listing, or 0 for mints
+	pub price: u64,
+	/// Hash of the metadata of the minted item, or zeroed for listings
+	pub metadata_hash: [u8; 32],
+	/// Unix timestamp after which the authorization can no longer be used
+	pub expiry: i64,
+	/// Number the marketplace authority picks so that each authorization can only be used once
+	pub nonce: u64,
+}
+
+impl Authorization {
+	/// Prefix of signed messages, keeping them from being valid as anything else the key signs
+	pub const MESSAGE_PREFIX: &'static [u8] = b"carbon authorization";
+
+	pub fn message(&self) -> Result<Vec<u8>> {
+		Ok([Authorization::MESSAGE_PREFIX, &self.try_to_vec()?].concat())
+	}
+
+	/// Checks the authorization is for the action, owner and collection, has not expired, and was
+	/// signed by the marketplace authority through the Ed25519 program instruction before this one
+	pub fn assert_is_valid(
+		&self,
+		action: AuthorizedAction,
+		owner: Pubkey,
+		collection_config: Pubkey,
+		marketplace_authority: Pubkey,
+		instructions_sysvar: &AccountInfo,
+	) -> Result<()> {
+		require!(self.action == action, Error::InvalidAuthorization);
+		require!(self.owner == owner, Error::InvalidAuthorization);
+		require!(self.collection_config == collection_config, Error::InvalidAuthorization);
+		require!(self.expiry > Clock::get()?.unix_timestamp, Error::AuthorizationExpired);
+
+		assert_ed25519_signature(instructions_sysvar, marketplace_authority, &self.message()?)
+	}
+}
+
+/// Voucher the marketplace authority signs off-chain so a player can mint an item by paying for it,
+/// with no server in the transaction. Like an authorization, its nonce can only be used once, as mint
+/// records can be closed and would not keep the voucher from being redeemed again.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintVoucher {
+	pub item_id: [u8; 32],
+	/// Collection config the item is minted into
+	pub collection_config: Pubkey,
+	/// Wallet the voucher is for, or the default pubkey if anyone can redeem it
+	pub recipient: Pubkey,
+	pub price: u64,
+	/// The currency the price is paid in or native mint if using SOL
+	pub currency_mint: Pubkey,
+	/// Unix timestamp after which the voucher can no longer be redeemed
+	pub expiry: i64,
+	/// Hash of the metadata of the minted item
+	pub metadata_hash: [u8; 32],
+	/// Number the marketplace authority picks so that each voucher can only be redeemed once
+	pub nonce: u64,
+}
+
+impl MintVoucher {
+	/// Prefix of signed messages, keeping them from being valid as anything else the key signs
+	pub const MESSAGE_PREFIX: &'static [u8] = b"carbon mint voucher";
+
+	pub fn message(&self) -> Result<Vec<u8>> {
+		Ok([MintVoucher::MESSAGE_PREFIX, &self.try_to_vec()?].concat())
+	}
+
+	/// Checks the voucher can be redeemed by the buyer for the metadata in the collection, has not
+	/// expired, and was signed by the marketplace authority through the Ed25519 program instruction
+	/// before this one
+	pub fn assert_is_valid(
+		&self,
+		buyer: Pubkey,
+		metadata: &Metadata,
+		collection_config: Pubkey,
+		marketplace_authority: Pubkey,
+		instructions_sysvar: &AccountInfo,
+	) -> Result<()> {
+		require!(self.collection_config == collection_config, Error::InvalidAuthorization);
+		require!(
+			self.recipient == Pubkey::default() || self.recipient == buyer,
+			Error::InvalidAuthorization
+		);
+		require!(self.metadata_hash == metadata.hash()?, Error::InvalidAuthorization);
+		require!(self.expiry > Clock::get()?.unix_timestamp, Error::AuthorizationExpired);
+
+		assert_ed25519_signature(instructions_sysvar, marketplace_authority, &self.message()?)
+	}
+}
+
+/// Marks the nonce of an authorization or mint voucher as used. The account can be closed once the
+/// authorization or voucher expired, as it can no longer be used anyway.
+#[account]
+pub struct AuthorizationNonce {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	pub nonce: u64,
+	/// Unix timestamp after which the authorization or voucher can no longer be used
+	pub expiry: i64,
+	/// Wallet that paid the rent and gets it back when the account is closed
+	pub payer: Pubkey,
+}
+
+impl AuthorizationNonce {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 8 + 8 + 32;
+
+	pub const PREFIX: &'static str = "authorization_nonce";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_authority: Pubkey,
+		nonce: u64,
+		expiry: i64,
+		payer: Pubkey,
+	) -> Result<()> {
+		self.bump = bump;
+		self.version = AuthorizationNonce::VERSION;
+		self.marketplace_authority = marketplace_authority;
+		self.nonce = nonce;
+		self.expiry = expiry;
+		self.payer = payer;
+
+		Ok(())
+	}
+}
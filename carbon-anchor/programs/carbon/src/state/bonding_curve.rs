@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use solana_program::hash;
+use crate::{
+	state::Metadata,
+	error::Error
+};
+
+#[account]
+pub struct BondingCurve {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Collection config the items are minted into
+	pub collection_config: Pubkey,
+	/// ID of the virtual item type sold by the curve
+	pub item_type: [u8; 32],
+	/// Metadata of every item minted by the curve
+	pub metadata: Metadata,
+	/// Currency to accept for payment
+	pub currency_mint: Pubkey,
+	/// Price of the first unit
+	pub base_price: u64,
+	/// Price increase per unit in circulation
+	pub slope: u64,
+	/// Most units that can be in circulation at once, 0 if unlimited
+	pub max_supply: u64,
+	/// Share of a unit's price refunded from the reserve when it is burned back.
+	/// Burning back is disabled when 0.
+	pub refund_bps: u16,
+	/// Units minted and not burned back
+	pub supply: u64,
+	/// Units ever minted, used as the serial of the next unit
+	pub minted: u64,
+	/// Part of the reserve owed as refunds to the units in circulation
+	pub reserved: u64,
+}
+
+impl BondingCurve {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const MAX_NAME_LENGTH: usize = 32;
+
+	pub const MAX_URI_LENGTH: usize = 200;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 +
+		(4 + BondingCurve::MAX_NAME_LENGTH) + (4 + BondingCurve::MAX_URI_LENGTH) +
+		32 + 8 + 8 + 8 + 2 + 8 + 8 + 8 + 128;
+
+	pub const PREFIX: &'static str = "bonding_curve";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 4] {
+		[
+			BondingCurve::PREFIX.as_bytes(),
+			self.collection_config.as_ref(),
+			self.item_type.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_authority: Pubkey,
+		collection_config: Pubkey,
+		currency_mint: Pubkey,
+		args: BondingCurveArgs,
+	) -> Result<()> {
+		require!(args.metadata.name.len() <= BondingCurve::MAX_NAME_LENGTH, Error::InvalidMetadata);
+		require!(args.metadata.uri.len() <= BondingCurve::MAX_URI_LENGTH, Error::InvalidMetadata);
+		require!(args.refund_bps <= 10_000, Error::InvalidPrice);
+
+		self.bump = bump;
+		self.version = BondingCurve::VERSION;
+		self.marketplace_authority = marketplace_authority;
+		self.collection_config = collection_config;
+		self.item_type = args.item_type;
+		self.metadata = args.metadata;
+		self.currency_mint = currency_mint;
+		self.base_price = args.base_price;
+		self.slope = args.slope;
+		self.max_supply = args.max_supply;
+		self.refund_bps = args.refund_bps;
+		self.supply = 0;
+		self.minted = 0;
+		self.reserved = 0;
+
+		Ok(())
+	}
+
+	/// Item ID of the unit with the serial, unique within the collection
+	pub fn item_id(&self, serial: u64) -> [u8; 32] {
+		hash::hashv(&[
+			BondingCurve::PREFIX.as_bytes(),
+			self.item_type.as_ref(),
+			&serial.to_le_bytes()
+		]).to_bytes()
+	}
+
+	/// Price of the unit minted when the supply is the given amount
+	pub fn price_at(&self, supply: u64) -> Result<u64> {
+		Ok(self.slope
+			.checked_mul(supply)
+			.ok_or(Error::OverflowError)?
+			.checked_add(self.base_price)
+			.ok_or(Error::OverflowError)?)
+	}
+
+	pub fn refund_at(&self, supply: u64) -> Result<u64> {
+		Ok((self.price_at(supply)? as u128)
+			.checked_mul(self.refund_bps as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(10_000)
+			.ok_or(Error::OverflowError)? as u64)
+	}
+
+	/// Mints the next unit and returns its price, reserving its refund
+	pub fn buy(&mut self, max_price: u64) -> Result<u64> {
+		require!(self.max_supply == 0 || self.supply < self.max_supply, Error::SupplyExhausted);
+
+		let price = self.price_at(self.supply)?;
+		require!(price <= max_price, Error::MaxPriceExceeded);
+
+		self.reserved = self.reserved
+			.checked_add(self.refund_at(self.supply)?)
+			.ok_or(Error::OverflowError)?;
+		self.supply += 1;
+		self.minted = self.minted
+			.checked_add(1)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(price)
+	}
+
+	/// Burns back the last unit in circulation and returns its refund
+	pub fn burn(&mut self) -> Result<u64> {
+		require!(self.refund_bps > 0, Error::BurnBackDisabled);
+
+		self.supply -= 1;
+		let refund = self.refund_at(self.supply)?;
+		self.reserved -= refund;
+
+		Ok(refund)
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondingCurveArgs {
+	pub item_type: [u8; 32],
+	pub metadata: Metadata,
+	pub base_price: u64,
+	pub slope: u64,
+	pub max_supply: u64,
+	pub refund_bps: u16,
+}
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
-use mpl_token_metadata::state::{Collection, DataV2};
-use crate::{Metadata};
+use anchor_spl::{metadata::MetadataAccount, token::Mint};
+use mpl_token_metadata::state::{AssetData, Collection, DataV2, TokenStandard};
+use solana_program::keccak;
+use crate::{
+	Metadata,
+	CollectionConfigArgs,
+	util::{assert_is_merkle_leaf, assert_is_nft_by_creator, assert_is_nft_in_collection, assert_keys_equal},
+	error::Error
+};
 
 #[account]
 pub struct CollectionConfig {
@@ -14,6 +21,85 @@ pub struct CollectionConfig {
 	pub seller_fee_basis_points: u16,
 	/// Max 16 chars for symbol. Inserted into newly minted metadata.
 	pub symbol: String,
+	/// Royalties charged on items of the collection traded through swaps.
+	pub swap_royalty_policy: SwapRoyaltyPolicy,
+	/// Token standard newly minted items are created with.
+	pub mint_standard: MintStandard,
+	/// How NFTs listed in the collection are proven to belong to it.
+	pub verification: CollectionVerification,
+	/// Price items minted through mint_virtual are sold at.
+	pub primary_sale: PrimarySale,
+}
+
+/// Swaps have no sale price to take royalty bps from, so the collection picks what is charged per item
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SwapRoyaltyPolicy {
+	/// No royalties are charged on swaps
+	None,
+	/// Fixed lamports per item, split between the creators of the item
+	Fixed { lamports: u64 },
+}
+
+impl SwapRoyaltyPolicy {
+	pub const SPACE: usize = 1 + 8;
+
+	pub fn lamports_per_item(&self) -> u64 {
+		match self {
+			SwapRoyaltyPolicy::None => 0,
+			SwapRoyaltyPolicy::Fixed { lamports } => *lamports,
+		}
+	}
+}
+
+/// Collections opt in to minting programmable NFTs, which enforce royalties through a rule set
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MintStandard {
+	/// Regular NFTs
+	NonFungible,
+	/// Programmable NFTs governed by the rule set, or by no rule set if the default pubkey
+	ProgrammableNonFungible { rule_set: Pubkey },
+}
+
+impl MintStandard {
+	pub const SPACE: usize = 1 + 32;
+}
+
+/// Older collections may have no verified sized collection, so the collection picks what proves an
+/// NFT belongs to it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionVerification {
+	/// The NFT is a verified item of the collection mint
+	VerifiedCollection,
+	/// The first creator of the NFT is this address and has verified it
+	VerifiedCreator { creator: Pubkey },
+	/// The keccak hash of the NFT mint is a leaf of the Merkle tree with this root
+	MerkleRoot { root: [u8; 32] },
+}
+
+impl CollectionVerification {
+	pub const SPACE: usize = 1 + 32;
+}
+
+/// Primary sales are paid to the treasury with the marketplace fee taken out, and free until the
+/// marketplace authority sets a price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrimarySale {
+	/// Price of one item, or 0 if items are minted for free
+	pub price: u64,
+	/// The currency the price is paid in or native mint if using SOL
+	pub currency_mint: Pubkey,
+	/// Wallet receiving the price net of the marketplace fee
+	pub treasury: Pubkey,
+}
+
+impl PrimarySale {
+	pub const SPACE: usize = 8 + 32 + 32;
+
+	pub fn assert_is_valid(&self) -> Result<()> {
+		require!(self.price == 0 || self.treasury != Pubkey::default(), Error::InvalidTreasury);
+
+		Ok(())
+	}
 }
 
 impl CollectionConfig {
@@ -24,14 +110,17 @@ impl CollectionConfig {
 
 	// Additional padding for future proofing
 	pub const SPACE: usize =
-		8 + 1 + 1 + 32 + 32 + 32 + 2 + (4 + CollectionConfig::MAX_SYMBOL_LENGTH) + 256;
+		8 + 1 + 1 + 32 + 32 + 32 + 2 + (4 + CollectionConfig::MAX_SYMBOL_LENGTH) +
+		SwapRoyaltyPolicy::SPACE + MintStandard::SPACE + CollectionVerification::SPACE +
+		PrimarySale::SPACE + 109;
 
 	pub const PREFIX: &'static str = "collection_config";
 
-	pub fn auth_seeds<'a>(&'a self) -> [&'a [u8]; 2] {
+	pub fn auth_seeds<'a>(&'a self) -> [&'a [u8]; 3] {
 		[
 			CollectionConfig::PREFIX.as_bytes(),
-			self.collection_mint.as_ref()
+			self.collection_mint.as_ref(),
+			self.bump.as_ref()
 		]
 	}
 
@@ -39,16 +128,18 @@ impl CollectionConfig {
 		&mut self,
 		bump: [u8; 1],
 		marketplace_authority: Pubkey,
-		collection_mint: Pubkey,
-		seller_fee_basis_points: u16,
-		symbol: String
+		args: CollectionConfigArgs
 	) -> Result<()> {
 		self.bump = bump;
 		self.version = CollectionConfig::VERSION;
 		self.marketplace_authority = marketplace_authority;
-		self.collection_mint = collection_mint;
-		self.seller_fee_basis_points = seller_fee_basis_points;
-		self.symbol = symbol;
+		self.collection_mint = args.collection_mint;
+		self.seller_fee_basis_points = args.seller_fee_basis_points;
+		self.symbol = args.symbol;
+		self.swap_royalty_policy = args.swap_royalty_policy;
+		self.mint_standard = args.mint_standard;
+		self.verification = args.verification;
+		self.primary_sale = PrimarySale::default();
 
 		return Ok(());
 	}
@@ -74,4 +165,44 @@ impl CollectionConfig {
 		});
 	}
 
+	/// Checks the NFT belongs to the collection as proven by its verification mode. The proof is
+	/// only used for Merkle root verification.
+	pub fn assert_is_nft_verified<'info>(
+		&self,
+		mint: &Account<'info, Mint>,
+		metadata: &Account<'info, MetadataAccount>,
+		proof: &[[u8; 32]]
+	) -> Result<()> {
+		match self.verification {
+			CollectionVerification::VerifiedCollection => {
+				assert_is_nft_in_collection(mint, metadata, self.collection_mint)
+			}
+			CollectionVerification::VerifiedCreator { creator } => {
+				assert_is_nft_by_creator(mint, metadata, creator)
+			}
+			CollectionVerification::MerkleRoot { root } => {
+				assert_keys_equal(metadata.mint, mint.key(), "Invalid metadata mint")?;
+				assert_is_merkle_leaf(keccak::hash(mint.key().as_ref()).to_bytes(), proof, root)
+			}
+		}
+	}
+
+	/// Asset data of a newly minted programmable NFT, created with the primary sale done
+	pub fn get_asset_data(&self, metadata: Metadata, rule_set: Pubkey) -> Result<AssetData> {
+		let data = self.get_mpl_metadata(metadata)?;
+		let mut asset_data = AssetData::new(
+			TokenStandard::ProgrammableNonFungible,
+			data.name,
+			data.symbol,
+			data.uri
+		);
+		asset_data.seller_fee_basis_points = data.seller_fee_basis_points;
+		asset_data.creators = data.creators;
+		asset_data.collection = data.collection;
+		asset_data.primary_sale_happened = true;
+		asset_data.rule_set = if rule_set == Pubkey::default() { None } else { Some(rule_set) };
+
+		Ok(asset_data)
+	}
+
 }
@@ -17,6 +17,9 @@ pub struct CustodyAccount {
 	pub item_id: [u8; 32],
 	/// True if the mint is listed for sale
 	pub is_listed: bool,
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	/// Set to the default pubkey for accounts created before payers were tracked.
+	pub payer: Pubkey,
 }
 
 impl CustodyAccount {
@@ -25,7 +28,7 @@ impl CustodyAccount {
 
 	// Additional padding for future proofing
 	pub const SPACE: usize =
-		8 + 1 + 1 + 32 + 32 + 32 + 32 + 1 + 256;
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 1 + 32 + 224;
 
 	pub const PREFIX: &'static str = "custody_account";
 
@@ -84,4 +87,14 @@ impl CustodyAccount {
 
 		return Ok(());
 	}
+
+	/// Wallet to return the rent to when the account is closed, the owner if the payer was not
+	/// tracked
+	pub fn rent_payer(&self) -> Pubkey {
+		if self.payer == Pubkey::default() {
+			self.owner
+		} else {
+			self.payer
+		}
+	}
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::error::Error;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub struct FeeConfig {
@@ -16,4 +17,12 @@ impl FeeConfig {
 			bps: crate::constants::FEE_BPS
 		}
 	}
+
+	pub fn get_fee_amount(&self, price: u64) -> Result<u64> {
+		Ok((price as u128)
+			.checked_mul(self.bps as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(10_000)
+			.ok_or(Error::OverflowError)? as u64)
+	}
 }
\ No newline at end of file
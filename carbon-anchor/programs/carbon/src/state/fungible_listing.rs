@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+	state::{FeeConfig, MarketplaceConfig},
+	error::Error
+};
+
+#[account]
+pub struct FungibleListing {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Marketplace config the listing address is derived from
+	pub namespace: Pubkey,
+	/// Pubkey of the seller's wallet
+	pub seller: Pubkey,
+	/// Mint of the token being sold
+	pub mint: Pubkey,
+	/// Decimals of the token being sold
+	pub decimals: u8,
+	/// Currency to accept for payment
+	pub currency_mint: Pubkey,
+	/// Price of one whole token, in base units of the currency
+	pub price: u64,
+	/// Amount still for sale, in base units of the token
+	pub remaining_amount: u64,
+	/// Unix timestamp of when the listing expires
+	pub expiry: i64,
+	/// Fee config for the listing
+	pub fee_config: FeeConfig,
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	pub payer: Pubkey,
+}
+
+impl FungibleListing {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 1 + 32 + 8 + 8 + 8 + FeeConfig::SPACE + 32 + 96;
+
+	pub const PREFIX: &'static str = "fungible_listing";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 5] {
+		[
+			FungibleListing::PREFIX.as_bytes(),
+			self.namespace.as_ref(),
+			self.seller.as_ref(),
+			self.mint.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_config: &Account<MarketplaceConfig>,
+		seller: Pubkey,
+		mint: &Account<Mint>,
+		currency_mint: Pubkey,
+		args: FungibleListingArgs,
+	) -> Result<()> {
+		require!(args.amount > 0, Error::InvalidAmount);
+		require!(args.price > 0, Error::InvalidPrice);
+		if args.expiry < 0 ||
+			(args.expiry != 0 && args.expiry <= Clock::get()?.unix_timestamp) {
+			return err!(Error::InvalidExpiry);
+		}
+
+		self.bump = bump;
+		self.version = FungibleListing::VERSION;
+		self.marketplace_authority = marketplace_config.marketplace_authority;
+		self.namespace = marketplace_config.key();
+		self.seller = seller;
+		self.mint = mint.key();
+		self.decimals = mint.decimals;
+		self.currency_mint = currency_mint;
+		self.price = args.price;
+		self.remaining_amount = args.amount;
+		self.expiry = args.expiry;
+		self.fee_config = marketplace_config.fee_config;
+
+		Ok(())
+	}
+
+	pub fn is_expired(&self) -> Result<bool> {
+		Ok(self.expiry != 0 && self.expiry <= Clock::get()?.unix_timestamp)
+	}
+
+	/// Takes the amount out of the listing and returns its price in the currency, rounded up so
+	/// that small fills can't be bought for free
+	pub fn fill(&mut self, amount: u64, max_price: u64) -> Result<u64> {
+		if self.is_expired()? {
+			return err!(Error::ListingExpired);
+		}
+
+		require!(amount > 0, Error::InvalidAmount);
+		require!(amount <= self.remaining_amount, Error::InsufficientListingAmount);
+
+		let unit = 10u128.pow(self.decimals as u32);
+		let price = (amount as u128)
+			.checked_mul(self.price as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_add(unit - 1)
+			.ok_or(Error::OverflowError)?
+			.checked_div(unit)
+			.ok_or(Error::OverflowError)?;
+		let price = u64::try_from(price).map_err(|_| Error::OverflowError)?;
+		require!(price <= max_price, Error::MaxPriceExceeded);
+
+		self.remaining_amount -= amount;
+
+		Ok(price)
+	}
+
+	pub fn get_fee_amount(&self, price: u64) -> Result<u64> {
+		Ok((price as u128)
+			.checked_mul(self.fee_config.bps as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(10_000)
+			.ok_or(Error::OverflowError)? as u64)
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FungibleListingArgs {
+	/// Amount to sell, in base units of the token
+	pub amount: u64,
+	/// Price of one whole token, in base units of the currency
+	pub price: u64,
+	/// Unix timestamp of when the listing expires, 0 if never
+	pub expiry: i64,
+}
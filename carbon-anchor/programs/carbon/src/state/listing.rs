@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::{
-	state::fee_config::{FeeConfig},
+	state::{fee_config::{FeeConfig}, MarketplaceConfig, PriceFeed},
 	util::assert_keys_equal,
+	constants::{MAX_PRICE_AGE_SECONDS, MAX_PRICE_CONFIDENCE_BPS},
 	error::Error
 };
 use crate::util::assert_owned_by;
@@ -22,42 +23,71 @@ pub struct Listing {
 	pub currency_mint: Pubkey,
 	/// Collection config for the item
 	pub collection_mint: Pubkey,
-	/// Price of the item
+	/// Price of the item, in reference units if the listing has a price feed
 	pub price: u64,
 	/// Unix timestamp of when the listing expires
 	pub expiry: i64,
 	/// Fee config for the listing
-	pub fee_config: FeeConfig
+	pub fee_config: FeeConfig,
+	/// Price feed used to convert the price into the currency at buy time.
+	/// Set to the default pubkey if the price is in the currency.
+	pub price_feed: Pubkey,
+	/// Decimals of the currency mint
+	pub currency_decimals: u8,
+	/// Unix timestamp of when the listing can be bought, 0 if immediately
+	pub start_time: i64,
+	/// Marketplace config the listing address is derived from.
+	/// Set to the default pubkey for legacy listings derived from the item ID only.
+	pub namespace: Pubkey,
+	/// True if the NFT could not be frozen and is escrowed in the listing's token account instead
+	pub is_escrowed: bool,
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	/// Set to the default pubkey for accounts created before payers were tracked.
+	pub payer: Pubkey,
 }
 
 impl Listing {
 	// Current version of data structure
-	pub const VERSION: u8 = 1;
+	pub const VERSION: u8 = 2;
 
 	// Additional padding for future proofing
 	pub const SPACE: usize =
-		8 + 1 + 1 + 32 + 32 + 1 + 32 + 8 + 8 + FeeConfig::SPACE + 256;
+		8 + 1 + 1 + 32 + 32 + 1 + 32 + 8 + 8 + FeeConfig::SPACE + 32 + 1 + 8 + 32 + 1 + 32 + 150;
 
 	pub const PREFIX: &'static str = "listing";
 
+	/// Loads the listing of an item on a marketplace, accepting both the namespaced address
+	/// and the legacy address derived from the item ID only
 	pub fn from_account_info_with_checks<'a>(
 		account_info: &AccountInfo<'a>,
+		marketplace_authority: Pubkey,
 		item_id: [u8;32]
 	) -> Result<Option<Account<'a, Listing>>> {
+		let namespace = MarketplaceConfig::find_address(marketplace_authority);
 		let (expected_pubkey, _) = Pubkey::find_program_address(
 			&[
 				Listing::PREFIX.as_bytes(),
+				namespace.as_ref(),
 				item_id.as_ref()
 			],
 			&crate::id::ID
 		);
 
-		assert_keys_equal(
-			account_info.key(),
-			expected_pubkey,
-			"Invalid listing account key"
-		)?;
+		if account_info.key() != expected_pubkey {
+			let (legacy_pubkey, _) = Pubkey::find_program_address(
+				&[
+					Listing::PREFIX.as_bytes(),
+					item_id.as_ref()
+				],
+				&crate::id::ID
+			);
 
+			assert_keys_equal(
+				account_info.key(),
+				legacy_pubkey,
+				"Invalid listing account key"
+			)?;
+		}
 
 		if account_info.data_is_empty() {
 			return Ok(None);
@@ -70,9 +100,20 @@ impl Listing {
 		Ok(Some(listing_account))
 	}
 
-	pub fn auth_seeds<'a>(&'a self) -> [&'a [u8]; 3] {
+	/// Seed placed between the prefix and the item ID. Empty for legacy listings so their
+	/// address stays the one derived from the item ID only.
+	pub fn namespace_seed(&self) -> &[u8] {
+		if self.namespace == Pubkey::default() {
+			&[]
+		} else {
+			self.namespace.as_ref()
+		}
+	}
+
+	pub fn auth_seeds<'a>(&'a self) -> [&'a [u8]; 4] {
 		[
 			Listing::PREFIX.as_bytes(),
+			self.namespace_seed(),
 			self.item_id.as_ref(),
 			self.bump.as_ref()
 		]
@@ -81,6 +122,7 @@ impl Listing {
 	pub fn init(
 		&mut self,
 		bump: [u8; 1],
+		namespace: Pubkey,
 		marketplace_authority: Pubkey,
 		seller: Pubkey,
 		item_id: [u8;32],
@@ -88,14 +130,19 @@ impl Listing {
 		currency_mint: Pubkey,
 		collection_mint: Pubkey,
 		fee_config: FeeConfig,
+		price_feed: Pubkey,
+		currency_decimals: u8,
 		price: u64,
-		expiry: i64
+		expiry: i64,
+		start_time: i64
 	) -> Result<()> {
 		if expiry != 0 &&
 			expiry <= Clock::get()?.unix_timestamp {
 			return err!(Error::InvalidExpiry);
 		}
 
+		Listing::assert_valid_start_time(start_time, expiry)?;
+
 		self.bump = bump;
 		self.version = Listing::VERSION;
 		self.marketplace_authority = marketplace_authority;
@@ -107,6 +154,10 @@ impl Listing {
 		self.price = price;
 		self.expiry = expiry;
 		self.fee_config = fee_config;
+		self.price_feed = price_feed;
+		self.currency_decimals = currency_decimals;
+		self.start_time = start_time;
+		self.namespace = namespace;
 
 		return Ok(());
 	}
@@ -114,38 +165,96 @@ impl Listing {
 	pub fn update(
 		&mut self,
 		price: u64,
-		expiry: i64
+		expiry: i64,
+		start_time: i64
 	) -> Result<()> {
-		let timestamp = Clock::get()?.unix_timestamp;
-		if self.expiry != 0 &&
-			self.expiry <= timestamp {
+		if self.is_expired()? {
 			return err!(Error::ListingExpired);
 		}
+
+		Listing::assert_valid_start_time(start_time, expiry)?;
 		
 		self.price = price;
 		self.expiry = expiry;
+		self.start_time = start_time;
 
 		return Ok(());
 	}
 
-	pub fn get_fee_amount(&self) -> Result<u64> {
-		return Ok((self.price as u128)
+	/// A start time must come before the expiry so the listing is buyable at some point
+	pub fn assert_valid_start_time(start_time: i64, expiry: i64) -> Result<()> {
+		require!(start_time >= 0, Error::InvalidStartTime);
+
+		if start_time != 0 && expiry != 0 {
+			require!(start_time < expiry, Error::InvalidStartTime);
+		}
+
+		Ok(())
+	}
+
+	pub fn is_expired(&self) -> Result<bool> {
+		Ok(self.expiry != 0 && self.expiry <= Clock::get()?.unix_timestamp)
+	}
+
+	pub fn has_started(&self) -> Result<bool> {
+		Ok(self.start_time <= Clock::get()?.unix_timestamp)
+	}
+
+	pub fn is_pegged(&self) -> bool {
+		self.price_feed != Pubkey::default()
+	}
+
+	/// Returns the price in the currency, converting through the price feed if pegged
+	pub fn get_buy_price<'a>(&self, price_feed: &AccountInfo<'a>) -> Result<u64> {
+		if !self.is_pegged() {
+			return Ok(self.price);
+		}
+
+		assert_keys_equal(price_feed.key(), self.price_feed, "Invalid price feed")?;
+
+		let price = PriceFeed::load_price(
+			price_feed,
+			self.marketplace_authority,
+			self.currency_mint
+		)?;
+		price.assert_is_valid(MAX_PRICE_AGE_SECONDS, MAX_PRICE_CONFIDENCE_BPS)?;
+
+		price.to_currency_amount(self.price, self.currency_decimals)
+	}
+
+	pub fn get_fee_amount(&self, price: u64) -> Result<u64> {
+		return Ok((price as u128)
 			.checked_mul(self.fee_config.bps as u128)
 			.ok_or(Error::OverflowError)?
 			.checked_div(10_000)
 			.ok_or(Error::OverflowError)? as u64);
 	}
 
-	pub fn assert_can_buy(&self, max_price: u64) -> Result<()> {
-		if self.expiry != 0 &&
-			self.expiry <= Clock::get()?.unix_timestamp {
+	/// Returns the price to pay in the currency
+	pub fn assert_can_buy<'a>(&self, max_price: u64, price_feed: &AccountInfo<'a>) -> Result<u64> {
+		if self.is_expired()? {
 			return err!(Error::ListingExpired);
 		}
 
-		if self.price > max_price {
+		if !self.has_started()? {
+			return err!(Error::ListingNotStarted);
+		}
+
+		let price = self.get_buy_price(price_feed)?;
+		if price > max_price {
 			return err!(Error::MaxPriceExceeded);
 		}
 
-		return Ok(());
+		return Ok(price);
+	}
+
+	/// Wallet to return the rent to when the account is closed, the seller if the payer was not
+	/// tracked
+	pub fn rent_payer(&self) -> Pubkey {
+		if self.payer == Pubkey::default() {
+			self.seller
+		} else {
+			self.payer
+		}
 	}
 }
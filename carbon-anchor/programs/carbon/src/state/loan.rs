@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::CollectionConfig,
+	error::Error
+};
+
+#[account]
+pub struct Loan {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Pubkey of the wallet escrowing the principal
+	pub lender: Pubkey,
+	/// A unique ID for the loan chosen by the lender
+	pub loan_id: [u8; 32],
+	/// Collection the collateral must be verified in
+	pub collection_mint: Pubkey,
+	/// Currency of the principal and interest, native mint if SOL
+	pub currency_mint: Pubkey,
+	/// Amount lent to the borrower
+	pub principal: u64,
+	/// Amount owed to the lender on top of the principal
+	pub interest: u64,
+	/// Seconds the borrower has to repay once the loan is taken
+	pub duration: i64,
+	/// Pubkey of the borrower's wallet, or the default pubkey until the offer is taken
+	pub borrower: Pubkey,
+	/// NFT frozen in the borrower's wallet as collateral, or the default pubkey until the offer is taken
+	pub mint: Pubkey,
+	/// Unix timestamp of when the loan must be repaid by
+	pub due_time: i64,
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	pub payer: Pubkey,
+}
+
+impl Loan {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 32 + 96;
+
+	pub const PREFIX: &'static str = "loan";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 4] {
+		[
+			Loan::PREFIX.as_bytes(),
+			self.lender.as_ref(),
+			self.loan_id.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		lender: Pubkey,
+		args: LoanArgs,
+		collection_config: &CollectionConfig,
+		currency_mint: Pubkey,
+	) -> Result<()> {
+		require!(args.principal > 0, Error::InvalidPrice);
+		require!(args.duration > 0, Error::InvalidLoanDuration);
+
+		self.bump = bump;
+		self.version = Loan::VERSION;
+		self.marketplace_authority = collection_config.marketplace_authority;
+		self.lender = lender;
+		self.loan_id = args.loan_id;
+		self.collection_mint = collection_config.collection_mint;
+		self.currency_mint = currency_mint;
+		self.principal = args.principal;
+		self.interest = args.interest;
+		self.duration = args.duration;
+		self.borrower = Pubkey::default();
+		self.mint = Pubkey::default();
+		self.due_time = 0;
+
+		Ok(())
+	}
+
+	pub fn is_active(&self) -> bool {
+		self.borrower != Pubkey::default()
+	}
+
+	pub fn is_overdue(&self) -> Result<bool> {
+		Ok(self.due_time <= Clock::get()?.unix_timestamp)
+	}
+
+	/// Amount the borrower must pay back to thaw the collateral
+	pub fn repayment_amount(&self) -> Result<u64> {
+		Ok(self.principal
+			.checked_add(self.interest)
+			.ok_or(Error::OverflowError)?)
+	}
+
+	/// Starts the loan term against the collateral
+	pub fn take(&mut self, borrower: Pubkey, mint: Pubkey) -> Result<()> {
+		require!(!self.is_active(), Error::LoanIsActive);
+		require!(self.lender != borrower, Error::InvalidBorrower);
+
+		self.borrower = borrower;
+		self.mint = mint;
+		self.due_time = Clock::get()?.unix_timestamp
+			.checked_add(self.duration)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(())
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LoanArgs {
+	pub loan_id: [u8; 32],
+	pub principal: u64,
+	pub interest: u64,
+	/// Seconds the borrower has to repay once the loan is taken
+	pub duration: i64,
+}
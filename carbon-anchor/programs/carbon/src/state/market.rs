@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::{FeeConfig, MarketplaceConfig},
+	error::Error
+};
+
+#[account]
+pub struct Market {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Marketplace config that approved the token pair
+	pub namespace: Pubkey,
+	/// Mint of the token being traded
+	pub base_mint: Pubkey,
+	/// Mint of the token prices are quoted in
+	pub quote_mint: Pubkey,
+	/// Base units of the base token in one lot. Order quantities are in lots.
+	pub base_lot_size: u64,
+	/// Order book side holding the bids
+	pub bids: Pubkey,
+	/// Order book side holding the asks
+	pub asks: Pubkey,
+	/// Fee config applied on fills
+	pub fee_config: FeeConfig,
+	/// ID given to the next order placed, increasing so older orders have lower IDs
+	pub next_order_id: u64,
+}
+
+impl Market {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 8 + 32 + 32 + FeeConfig::SPACE + 8 + 128;
+
+	pub const PREFIX: &'static str = "market";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 5] {
+		[
+			Market::PREFIX.as_bytes(),
+			self.namespace.as_ref(),
+			self.base_mint.as_ref(),
+			self.quote_mint.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	/// The order book sides are set once they are created, see `set_order_book`
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_config: &Account<MarketplaceConfig>,
+		base_mint: Pubkey,
+		quote_mint: Pubkey,
+		base_lot_size: u64,
+	) -> Result<()> {
+		require!(base_lot_size > 0, Error::InvalidAmount);
+		require!(base_mint != quote_mint, Error::InvalidMint);
+
+		self.bump = bump;
+		self.version = Market::VERSION;
+		self.marketplace_authority = marketplace_config.marketplace_authority;
+		self.namespace = marketplace_config.key();
+		self.base_mint = base_mint;
+		self.quote_mint = quote_mint;
+		self.base_lot_size = base_lot_size;
+		self.bids = Pubkey::default();
+		self.asks = Pubkey::default();
+		self.fee_config = marketplace_config.fee_config;
+		self.next_order_id = 0;
+
+		Ok(())
+	}
+
+	pub fn set_order_book(&mut self, bids: Pubkey, asks: Pubkey) {
+		self.bids = bids;
+		self.asks = asks;
+	}
+
+	/// Base units in a quantity of lots
+	pub fn base_amount(&self, quantity: u64) -> Result<u64> {
+		Ok(quantity
+			.checked_mul(self.base_lot_size)
+			.ok_or(Error::OverflowError)?)
+	}
+
+	/// Quote units paid for a quantity of lots at a price per lot
+	pub fn quote_amount(&self, quantity: u64, price: u64) -> Result<u64> {
+		Ok(quantity
+			.checked_mul(price)
+			.ok_or(Error::OverflowError)?)
+	}
+
+	pub fn get_fee_amount(&self, quote_amount: u64) -> Result<u64> {
+		Ok((quote_amount as u128)
+			.checked_mul(self.fee_config.bps as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(10_000)
+			.ok_or(Error::OverflowError)? as u64)
+	}
+
+	/// Amount locked in the vault while an order rests on the book
+	pub fn locked_amount(&self, side: Side, quantity: u64, price: u64) -> Result<u64> {
+		match side {
+			Side::Bid => self.quote_amount(quantity, price),
+			Side::Ask => self.base_amount(quantity),
+		}
+	}
+
+	pub fn take_order_id(&mut self) -> Result<u64> {
+		let order_id = self.next_order_id;
+		self.next_order_id = order_id
+			.checked_add(1)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(order_id)
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+	/// Buys the base token with the quote token
+	Bid,
+	/// Sells the base token for the quote token
+	Ask,
+}
+
+#[zero_copy]
+pub struct Order {
+	/// Open orders account credited when the order fills or is cancelled
+	pub open_orders: Pubkey,
+	pub order_id: u64,
+	/// Quote units per lot
+	pub price: u64,
+	/// Lots left to fill
+	pub quantity: u64,
+}
+
+/// One side of a market's order book, kept sorted best price first and oldest first within a price
+#[account(zero_copy)]
+pub struct OrderBookSide {
+	pub market: Pubkey,
+	/// True if the side holds bids, false if asks
+	pub is_bid: bool,
+	/// Aligns count as repr(C) would, keeping the layout clients decode the same as on chain
+	pub padding: [u8; 7],
+	/// Number of orders in use at the start of the array
+	pub count: u64,
+	pub orders: [Order; 64],
+}
+
+impl OrderBookSide {
+	pub const MAX_ORDERS: usize = 64;
+
+	pub const SPACE: usize = 8 + std::mem::size_of::<OrderBookSide>();
+
+	pub const BIDS_PREFIX: &'static str = "bids";
+
+	pub const ASKS_PREFIX: &'static str = "asks";
+
+	pub fn init(&mut self, market: Pubkey, is_bid: bool) {
+		self.market = market;
+		self.is_bid = is_bid;
+		self.count = 0;
+	}
+
+	pub fn len(&self) -> usize {
+		self.count as usize
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.count == 0
+	}
+
+	pub fn best(&self) -> Option<Order> {
+		if self.is_empty() {
+			None
+		} else {
+			Some(self.orders[0])
+		}
+	}
+
+	/// Inserts the order behind every order at a better or equal price. When the side is full, the
+	/// worst-priced order is evicted and returned to make room, as long as the new order beats its
+	/// price.
+	pub fn insert(&mut self, order: Order) -> Result<Option<Order>> {
+		let is_bid = self.is_bid;
+		let is_better = |price: u64, than: u64| if is_bid { price > than } else { price < than };
+
+		let evicted = if self.len() == OrderBookSide::MAX_ORDERS {
+			let worst = self.len() - 1;
+			require!(is_better(order.price, self.orders[worst].price), Error::OrderBookFull);
+			Some(self.remove(worst))
+		} else {
+			None
+		};
+
+		let len = self.len();
+		let index = self.orders[..len]
+			.iter()
+			.position(|resting| is_better(order.price, resting.price))
+			.unwrap_or(len);
+
+		self.orders.copy_within(index..len, index + 1);
+		self.orders[index] = order;
+		self.count += 1;
+
+		Ok(evicted)
+	}
+
+	pub fn find(&self, order_id: u64) -> Option<usize> {
+		self.orders[..self.len()]
+			.iter()
+			.position(|order| order.order_id == order_id)
+	}
+
+	pub fn remove(&mut self, index: usize) -> Order {
+		let len = self.len();
+		let order = self.orders[index];
+		self.orders.copy_within(index + 1..len, index);
+		self.count -= 1;
+
+		order
+	}
+}
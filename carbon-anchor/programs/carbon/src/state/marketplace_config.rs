@@ -9,6 +9,8 @@ pub struct MarketplaceConfig {
 	pub marketplace_authority: Pubkey,
 	/// Royalty bps. Inserted into newly minted metadata.
 	pub fee_config: FeeConfig,
+	/// Lamports paid out of the listing rent to whoever closes an expired listing.
+	pub keeper_reward: u64,
 }
 
 impl MarketplaceConfig {
@@ -17,10 +19,20 @@ impl MarketplaceConfig {
 
 	// Additional padding for future proofing
 	pub const SPACE: usize =
-		8 + 1 + 1 + 32 + (FeeConfig::SPACE) + 256;
+		8 + 1 + 1 + 32 + (FeeConfig::SPACE) + 8 + 248;
 
 	pub const PREFIX: &'static str = "marketplace_config";
 
+	pub fn find_address(marketplace_authority: Pubkey) -> Pubkey {
+		Pubkey::find_program_address(
+			&[
+				MarketplaceConfig::PREFIX.as_bytes(),
+				marketplace_authority.as_ref()
+			],
+			&crate::id::ID
+		).0
+	}
+
 	pub fn auth_seeds<'a>(&'a self) -> [&'a [u8]; 2] {
 		[
 			MarketplaceConfig::PREFIX.as_bytes(),
@@ -32,12 +44,14 @@ impl MarketplaceConfig {
 		&mut self,
 		bump: [u8; 1],
 		marketplace_authority: Pubkey,
-		fee_config: FeeConfig
+		fee_config: FeeConfig,
+		keeper_reward: u64
 	) -> Result<()> {
 		self.bump = bump;
 		self.version = MarketplaceConfig::VERSION;
 		self.marketplace_authority = marketplace_authority;
 		self.fee_config = fee_config;
+		self.keeper_reward = keeper_reward;
 
 		return Ok(());
 	}
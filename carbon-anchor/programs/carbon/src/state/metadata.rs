@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
+use solana_program::hash;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct Metadata {
 	pub name: String,
 	pub uri: String,
+}
+
+impl Metadata {
+	/// Hash of the serialized metadata, which signed messages commit to instead of the metadata itself
+	pub fn hash(&self) -> Result<[u8; 32]> {
+		Ok(hash::hash(&self.try_to_vec()?).to_bytes())
+	}
 }
\ No newline at end of file
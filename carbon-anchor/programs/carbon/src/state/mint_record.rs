@@ -8,11 +8,17 @@ pub struct MintRecord {
 	pub item_id: [u8;32],
 	/// Track NFT mint for the item
 	pub mint: Pubkey,
+	/// Wallet that paid the rent and gets it back when the account is closed
+	pub payer: Pubkey,
 }
 
 impl MintRecord {
 
 	pub const SPACE: usize =
+		8 + 32 + 32 + 32 + 32;
+
+	/// Size of records created before payers were tracked, which have to be migrated to be loaded
+	pub const LEGACY_SPACE: usize =
 		8 + 32 + 32 + 32;
 
 	pub const PREFIX: &'static str = "mint_record";
@@ -21,11 +27,13 @@ impl MintRecord {
 		&mut self,
 		collection_config: Pubkey,
 		item_id: [u8;32],
-		mint: Pubkey
+		mint: Pubkey,
+		payer: Pubkey
 	) -> Result<()> {
 		self.collection_config = collection_config;
 		self.item_id = item_id;
 		self.mint = mint;
+		self.payer = payer;
 
 		return Ok(());
 	}
@@ -5,6 +5,20 @@ mod fee_config;
 mod metadata;
 mod custody_account;
 mod mint_record;
+mod price_feed;
+mod swap;
+mod rental;
+mod loan;
+mod fungible_listing;
+mod market;
+mod open_orders;
+mod bonding_curve;
+mod recipe;
+mod mystery_box;
+mod sealed_box;
+mod raffle;
+mod session;
+mod authorization;
 
 pub use marketplace_config::*;
 pub use collection_config::*;
@@ -12,4 +26,18 @@ pub use listing::*;
 pub use fee_config::*;
 pub use metadata::*;
 pub use custody_account::*;
-pub use mint_record::*;
\ No newline at end of file
+pub use mint_record::*;
+pub use price_feed::*;
+pub use swap::*;
+pub use rental::*;
+pub use loan::*;
+pub use fungible_listing::*;
+pub use market::*;
+pub use open_orders::*;
+pub use bonding_curve::*;
+pub use recipe::*;
+pub use mystery_box::*;
+pub use sealed_box::*;
+pub use raffle::*;
+pub use session::*;
+pub use authorization::*;
\ No newline at end of file
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::Metadata,
+	util::RandomnessSource,
+	error::Error
+};
+
+#[account]
+pub struct MysteryBox {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Collection config the boxes and prizes are minted into
+	pub collection_config: Pubkey,
+	/// A unique ID for the mystery box within the collection
+	pub box_id: [u8; 32],
+	/// Metadata of the box NFTs
+	pub metadata: Metadata,
+	/// Source of the entropy prizes are drawn with
+	pub randomness_source: RandomnessSource,
+	/// Items a box can be opened into
+	pub prizes: Vec<Prize>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Prize {
+	/// Metadata of the prize item
+	pub metadata: Metadata,
+	/// Odds of the prize relative to the weights of the other prizes
+	pub weight: u32,
+}
+
+impl Prize {
+	pub const SPACE: usize = (4 + MysteryBox::MAX_NAME_LENGTH) + (4 + MysteryBox::MAX_URI_LENGTH) + 4;
+}
+
+impl MysteryBox {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const MAX_PRIZES: usize = 8;
+
+	pub const MAX_NAME_LENGTH: usize = 32;
+
+	pub const MAX_URI_LENGTH: usize = 200;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 +
+		(4 + MysteryBox::MAX_NAME_LENGTH) + (4 + MysteryBox::MAX_URI_LENGTH) +
+		RandomnessSource::SPACE + (4 + Prize::SPACE * MysteryBox::MAX_PRIZES) + 128;
+
+	pub const PREFIX: &'static str = "mystery_box";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_authority: Pubkey,
+		collection_config: Pubkey,
+		args: MysteryBoxArgs,
+	) -> Result<()> {
+		require!(!args.prizes.is_empty() && args.prizes.len() <= MysteryBox::MAX_PRIZES, Error::InvalidPrizes);
+		require!(args.prizes.iter().any(|prize| prize.weight > 0), Error::InvalidPrizes);
+		require!(
+			MysteryBox::fits(&args.metadata) && args.prizes.iter().all(|prize| MysteryBox::fits(&prize.metadata)),
+			Error::InvalidMetadata
+		);
+
+		self.bump = bump;
+		self.version = MysteryBox::VERSION;
+		self.marketplace_authority = marketplace_authority;
+		self.collection_config = collection_config;
+		self.box_id = args.box_id;
+		self.metadata = args.metadata;
+		self.randomness_source = args.randomness_source;
+		self.prizes = args.prizes;
+
+		Ok(())
+	}
+
+	fn fits(metadata: &Metadata) -> bool {
+		metadata.name.len() <= MysteryBox::MAX_NAME_LENGTH && metadata.uri.len() <= MysteryBox::MAX_URI_LENGTH
+	}
+
+	pub fn weights(&self) -> Vec<u32> {
+		self.prizes.iter().map(|prize| prize.weight).collect()
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MysteryBoxArgs {
+	pub box_id: [u8; 32],
+	pub metadata: Metadata,
+	pub randomness_source: RandomnessSource,
+	pub prizes: Vec<Prize>,
+}
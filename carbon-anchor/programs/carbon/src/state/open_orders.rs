@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::error::Error;
+
+/// Balances of a user on a market that can be settled out of its vaults
+#[account(zero_copy)]
+pub struct OpenOrders {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Market the orders are placed on
+	pub market: Pubkey,
+	/// Pubkey of the user's wallet
+	pub owner: Pubkey,
+	/// Base units bought or released from cancelled asks
+	pub base_free: u64,
+	/// Quote units received from sales, refunded from bids filled at a better price or released
+	/// from cancelled bids
+	pub quote_free: u64,
+}
+
+impl OpenOrders {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 8 + 8 + 128;
+
+	pub const PREFIX: &'static str = "open_orders";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		market: Pubkey,
+		owner: Pubkey,
+	) {
+		self.bump = bump;
+		self.version = OpenOrders::VERSION;
+		self.market = market;
+		self.owner = owner;
+		self.base_free = 0;
+		self.quote_free = 0;
+	}
+
+	/// Finds the open orders account with the key among the remaining accounts
+	pub fn find<'info>(
+		remaining_accounts: &[AccountInfo<'info>],
+		key: Pubkey,
+	) -> Result<AccountLoader<'info, OpenOrders>> {
+		let account_info = remaining_accounts
+			.iter()
+			.find(|account| account.key() == key)
+			.ok_or(Error::InvalidOpenOrders)?;
+
+		AccountLoader::<'info, OpenOrders>::try_from(account_info)
+	}
+
+	pub fn credit(&mut self, base_amount: u64, quote_amount: u64) -> Result<()> {
+		self.base_free = self.base_free
+			.checked_add(base_amount)
+			.ok_or(Error::OverflowError)?;
+		self.quote_free = self.quote_free
+			.checked_add(quote_amount)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::{
+	util::{assert_keys_equal, Price},
+	error::Error
+};
+
+/// Authority-published price for a currency, used where no oracle feed is available
+#[account]
+pub struct PriceFeed {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet allowed to publish prices
+	pub marketplace_authority: Pubkey,
+	/// Currency the price is quoted for
+	pub currency_mint: Pubkey,
+	/// Price of one whole currency unit in reference units, scaled by 10^expo
+	pub price: i64,
+	/// Confidence interval of the price, scaled by 10^expo
+	pub conf: u64,
+	/// Exponent applied to price and conf
+	pub expo: i32,
+	/// Unix timestamp of when the price was last published
+	pub publish_time: i64,
+}
+
+impl PriceFeed {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 8 + 8 + 4 + 8 + 128;
+
+	pub const PREFIX: &'static str = "price_feed";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_authority: Pubkey,
+		currency_mint: Pubkey,
+	) -> Result<()> {
+		self.bump = bump;
+		self.version = PriceFeed::VERSION;
+		self.marketplace_authority = marketplace_authority;
+		self.currency_mint = currency_mint;
+
+		Ok(())
+	}
+
+	pub fn update(
+		&mut self,
+		price: i64,
+		conf: u64,
+		expo: i32,
+	) -> Result<()> {
+		require!(price > 0, Error::InvalidPrice);
+
+		self.price = price;
+		self.conf = conf;
+		self.expo = expo;
+		self.publish_time = Clock::get()?.unix_timestamp;
+
+		Ok(())
+	}
+
+	/// Loads the price from either a Pyth price account or a price feed published by
+	/// the marketplace authority for the currency
+	pub fn load_price<'a>(
+		account_info: &AccountInfo<'a>,
+		marketplace_authority: Pubkey,
+		currency_mint: Pubkey,
+	) -> Result<Price> {
+		if Price::is_pyth_account(account_info) {
+			return Price::from_pyth_account(account_info);
+		}
+
+		require!(account_info.owner == &crate::id::ID, Error::InvalidPriceFeed);
+
+		let price_feed = Account::<'a, PriceFeed>::try_from(account_info)?;
+		assert_keys_equal(
+			price_feed.marketplace_authority,
+			marketplace_authority,
+			"Invalid price feed authority"
+		)?;
+		assert_keys_equal(
+			price_feed.currency_mint,
+			currency_mint,
+			"Invalid price feed currency"
+		)?;
+
+		Ok(Price {
+			price: price_feed.price,
+			conf: price_feed.conf,
+			expo: price_feed.expo,
+			publish_time: price_feed.publish_time,
+		})
+	}
+
+	/// Ensures a listing's price feed can be read. The default pubkey means the listing is
+	/// priced directly in the currency.
+	pub fn assert_is_valid_for_listing<'a>(
+		account_info: &AccountInfo<'a>,
+		marketplace_authority: Pubkey,
+		currency_mint: Pubkey,
+	) -> Result<()> {
+		if account_info.key() == Pubkey::default() {
+			return Ok(());
+		}
+
+		PriceFeed::load_price(account_info, marketplace_authority, currency_mint)?;
+
+		Ok(())
+	}
+}
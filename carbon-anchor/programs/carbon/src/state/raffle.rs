@@ -0,0 +1,318 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::{FeeConfig, MarketplaceConfig, Metadata},
+	util::RandomnessSource,
+	error::Error
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RaffleState {
+	/// Tickets are on sale
+	Open,
+	/// Ticket sales ended and the marketplace authority committed to the seed of the draw
+	Closed,
+	/// The winning ticket was drawn and the proceeds paid out
+	Drawn,
+	/// The raffle was not closed or its draw not revealed in time, so the prize went back to the
+	/// creator and tickets are refunded
+	Expired,
+}
+
+#[account]
+pub struct Raffle {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Marketplace config the raffle address is derived from
+	pub namespace: Pubkey,
+	/// A unique ID for the raffle within the marketplace
+	pub raffle_id: [u8; 32],
+	/// Wallet that created the raffle and receives the proceeds
+	pub creator: Pubkey,
+	/// Whether the prize is a virtual item minted to the winner rather than an escrowed NFT
+	pub is_virtual: bool,
+	/// Mint of the escrowed NFT, or the default pubkey if the prize is virtual
+	pub mint: Pubkey,
+	/// Collection config the virtual prize is minted into, or the default pubkey
+	pub collection_config: Pubkey,
+	/// Item ID of the virtual prize
+	pub item_id: [u8; 32],
+	/// Metadata of the virtual prize
+	pub metadata: Metadata,
+	/// Currency tickets are paid in
+	pub currency_mint: Pubkey,
+	/// Price of one ticket
+	pub ticket_price: u64,
+	/// Most tickets that can be sold
+	pub max_tickets: u32,
+	/// Tickets sold, numbered from 0
+	pub tickets_sold: u32,
+	/// Unix timestamp of when ticket sales end
+	pub end_time: i64,
+	/// Fee config for the proceeds
+	pub fee_config: FeeConfig,
+	/// Source of the entropy the winning ticket is drawn with
+	pub randomness_source: RandomnessSource,
+	pub state: RaffleState,
+	/// Hash of the seed committed by the marketplace authority when the raffle was closed
+	pub seed_hash: [u8; 32],
+	/// Slot the raffle was closed in, whose entropy the winning ticket is drawn with
+	pub close_slot: u64,
+	/// Number of the winning ticket once drawn
+	pub winning_ticket: u32,
+	/// Wallet that paid the rent and gets it back when the account is closed
+	pub payer: Pubkey,
+	/// Tickets refunded since the raffle expired
+	pub tickets_refunded: u32,
+	/// Currency received for the sold tickets, after any transfer fee
+	pub proceeds: u64,
+}
+
+impl Raffle {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const MAX_NAME_LENGTH: usize = 32;
+
+	pub const MAX_URI_LENGTH: usize = 200;
+
+	/// Seconds after the end time the marketplace authority has to close a raffle with sold tickets,
+	/// after which anyone can expire it
+	pub const CLOSE_GRACE_PERIOD: i64 = 3 * 24 * 60 * 60;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 1 + 32 + 32 + 32 +
+		(4 + Raffle::MAX_NAME_LENGTH) + (4 + Raffle::MAX_URI_LENGTH) +
+		32 + 8 + 4 + 4 + 8 + FeeConfig::SPACE + RandomnessSource::SPACE + 1 + 32 + 8 + 4 + 32 + 4 + 8 + 84;
+
+	pub const PREFIX: &'static str = "raffle";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 4] {
+		[
+			Raffle::PREFIX.as_bytes(),
+			self.namespace.as_ref(),
+			self.raffle_id.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_config: &Account<MarketplaceConfig>,
+		creator: Pubkey,
+		currency_mint: Pubkey,
+		args: RaffleArgs,
+	) -> Result<()> {
+		require!(args.ticket_price > 0, Error::InvalidPrice);
+		require!(args.max_tickets > 0, Error::InvalidAmount);
+		require!(args.end_time > Clock::get()?.unix_timestamp, Error::InvalidExpiry);
+
+		self.bump = bump;
+		self.version = Raffle::VERSION;
+		self.marketplace_authority = marketplace_config.marketplace_authority;
+		self.namespace = marketplace_config.key();
+		self.raffle_id = args.raffle_id;
+		self.creator = creator;
+		self.currency_mint = currency_mint;
+		self.ticket_price = args.ticket_price;
+		self.max_tickets = args.max_tickets;
+		self.tickets_sold = 0;
+		self.end_time = args.end_time;
+		self.fee_config = marketplace_config.fee_config;
+		self.randomness_source = args.randomness_source;
+		self.state = RaffleState::Open;
+		self.seed_hash = [0; 32];
+		self.close_slot = 0;
+		self.winning_ticket = 0;
+		self.tickets_refunded = 0;
+		self.proceeds = 0;
+
+		Ok(())
+	}
+
+	pub fn set_nft_prize(&mut self, mint: Pubkey) {
+		self.is_virtual = false;
+		self.mint = mint;
+		self.collection_config = Pubkey::default();
+		self.item_id = mint.to_bytes();
+		self.metadata = Metadata { name: String::new(), uri: String::new() };
+	}
+
+	pub fn set_virtual_prize(&mut self, collection_config: Pubkey, item_id: [u8; 32], metadata: Metadata) -> Result<()> {
+		require!(metadata.name.len() <= Raffle::MAX_NAME_LENGTH, Error::InvalidMetadata);
+		require!(metadata.uri.len() <= Raffle::MAX_URI_LENGTH, Error::InvalidMetadata);
+
+		self.is_virtual = true;
+		self.mint = Pubkey::default();
+		self.collection_config = collection_config;
+		self.item_id = item_id;
+		self.metadata = metadata;
+
+		Ok(())
+	}
+
+	pub fn has_ended(&self) -> Result<bool> {
+		Ok(self.tickets_sold == self.max_tickets || self.end_time <= Clock::get()?.unix_timestamp)
+	}
+
+	/// Sells the next tickets and returns the first ticket number with their price. The currency
+	/// received for them is recorded with `add_proceeds` once paid.
+	pub fn buy_tickets(&mut self, count: u32) -> Result<(u32, u64)> {
+		require!(self.state == RaffleState::Open && !self.has_ended()?, Error::RaffleNotOpen);
+		require!(count > 0, Error::InvalidAmount);
+		let tickets_sold = self.tickets_sold
+			.checked_add(count)
+			.ok_or(Error::OverflowError)?;
+		require!(tickets_sold <= self.max_tickets, Error::RaffleSoldOut);
+
+		let first_ticket = self.tickets_sold;
+		self.tickets_sold = tickets_sold;
+		let price = self.ticket_price
+			.checked_mul(count as u64)
+			.ok_or(Error::OverflowError)?;
+
+		Ok((first_ticket, price))
+	}
+
+	pub fn add_proceeds(&mut self, amount: u64) -> Result<()> {
+		self.proceeds = self.proceeds
+			.checked_add(amount)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(())
+	}
+
+	/// Ends ticket sales, committing to the hash of the seed the winning ticket is drawn with
+	pub fn close_sales(&mut self, seed_hash: [u8; 32]) -> Result<()> {
+		require!(self.state == RaffleState::Open, Error::RaffleNotOpen);
+		require!(self.has_ended()?, Error::RaffleNotEnded);
+		require!(self.tickets_sold > 0, Error::RaffleHasNoTickets);
+
+		self.state = RaffleState::Closed;
+		self.seed_hash = seed_hash;
+		self.close_slot = Clock::get()?.slot;
+
+		Ok(())
+	}
+
+	/// Reveals the committed seed and draws the winning ticket
+	pub fn draw(&mut self, seed: [u8; 32], randomness_account: &AccountInfo) -> Result<[u8; 32]> {
+		require!(self.state == RaffleState::Closed, Error::RaffleNotClosed);
+
+		let randomness = self.randomness_source.reveal(
+			seed,
+			self.seed_hash,
+			self.close_slot,
+			randomness_account
+		)?;
+		let roll = u64::from_le_bytes(randomness[..8].try_into().unwrap());
+		self.winning_ticket = (roll % self.tickets_sold as u64) as u32;
+		self.state = RaffleState::Drawn;
+
+		Ok(randomness)
+	}
+
+	/// Voids a raffle that can no longer be drawn, so the tickets can be refunded. That is a closed
+	/// raffle whose draw can no longer be revealed, or an open raffle with sold tickets that was not
+	/// closed within the grace period after its end time
+	pub fn expire(&mut self) -> Result<()> {
+		match self.state {
+			RaffleState::Open => {
+				require!(self.tickets_sold > 0, Error::RaffleHasNoTickets);
+				let close_deadline = self.end_time
+					.checked_add(Raffle::CLOSE_GRACE_PERIOD)
+					.ok_or(Error::OverflowError)?;
+				require!(close_deadline < Clock::get()?.unix_timestamp, Error::RaffleCanBeClosed);
+			}
+			RaffleState::Closed => {
+				require!(self.randomness_source.has_expired(self.close_slot)?, Error::DrawNotExpired);
+			}
+			_ => return err!(Error::RaffleNotClosed),
+		}
+
+		self.state = RaffleState::Expired;
+
+		Ok(())
+	}
+
+	/// Refunds the tickets, returning whether every sold ticket is now refunded
+	pub fn refund_tickets(&mut self, count: u32) -> Result<bool> {
+		require!(self.state == RaffleState::Expired, Error::RaffleNotExpired);
+
+		self.tickets_refunded = self.tickets_refunded
+			.checked_add(count)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(self.tickets_refunded == self.tickets_sold)
+	}
+
+	pub fn get_fee_amount(&self, price: u64) -> Result<u64> {
+		Ok((price as u128)
+			.checked_mul(self.fee_config.bps as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(10_000)
+			.ok_or(Error::OverflowError)? as u64)
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RaffleArgs {
+	pub raffle_id: [u8; 32],
+	pub ticket_price: u64,
+	pub max_tickets: u32,
+	/// Unix timestamp of when ticket sales end
+	pub end_time: i64,
+	pub randomness_source: RandomnessSource,
+}
+
+/// Tickets bought together by one wallet
+#[account]
+pub struct RaffleTicket {
+	pub bump: [u8; 1],
+	pub version: u8,
+	pub raffle: Pubkey,
+	pub buyer: Pubkey,
+	/// Number of the first ticket
+	pub first_ticket: u32,
+	pub count: u32,
+	/// Wallet that paid the rent and gets it back when the account is closed
+	pub payer: Pubkey,
+	/// Currency the raffle received for the tickets, which is refunded if the raffle expires
+	pub amount: u64,
+}
+
+impl RaffleTicket {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 4 + 4 + 32 + 8 + 32;
+
+	pub const PREFIX: &'static str = "raffle_ticket";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		raffle: Pubkey,
+		buyer: Pubkey,
+		first_ticket: u32,
+		count: u32,
+	) -> Result<()> {
+		self.bump = bump;
+		self.version = RaffleTicket::VERSION;
+		self.raffle = raffle;
+		self.buyer = buyer;
+		self.first_ticket = first_ticket;
+		self.count = count;
+		self.amount = 0;
+
+		Ok(())
+	}
+
+	pub fn contains(&self, ticket: u32) -> bool {
+		ticket >= self.first_ticket && ticket - self.first_ticket < self.count
+	}
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::Metadata,
+	error::Error
+};
+
+#[account]
+pub struct Recipe {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Collection config the inputs are burned from and the output is minted into
+	pub collection_config: Pubkey,
+	/// A unique ID for the recipe within the collection
+	pub recipe_id: [u8; 32],
+	/// Item types burned by crafting, matched by NFT name
+	pub inputs: Vec<RecipeInput>,
+	/// Metadata of the crafted item
+	pub output: Metadata,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecipeInput {
+	/// Name of the NFTs of the item type
+	pub name: String,
+	/// Number of NFTs of the item type to burn
+	pub quantity: u8,
+}
+
+impl RecipeInput {
+	pub const SPACE: usize = (4 + Recipe::MAX_NAME_LENGTH) + 1;
+}
+
+impl Recipe {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const MAX_INPUTS: usize = 4;
+
+	/// Most NFTs that fit in one crafting transaction
+	pub const MAX_INPUT_QUANTITY: usize = 4;
+
+	pub const MAX_NAME_LENGTH: usize = 32;
+
+	pub const MAX_URI_LENGTH: usize = 200;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 +
+		(4 + RecipeInput::SPACE * Recipe::MAX_INPUTS) +
+		(4 + Recipe::MAX_NAME_LENGTH) + (4 + Recipe::MAX_URI_LENGTH) + 128;
+
+	pub const PREFIX: &'static str = "recipe";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_authority: Pubkey,
+		collection_config: Pubkey,
+		args: RecipeArgs,
+	) -> Result<()> {
+		require!(!args.inputs.is_empty() && args.inputs.len() <= Recipe::MAX_INPUTS, Error::InvalidRecipe);
+		require!(
+			args.inputs.iter().all(|input| input.quantity > 0 && input.name.len() <= Recipe::MAX_NAME_LENGTH),
+			Error::InvalidRecipe
+		);
+		require!(Recipe::total_quantity(&args.inputs) <= Recipe::MAX_INPUT_QUANTITY, Error::InvalidRecipe);
+		require!(args.output.name.len() <= Recipe::MAX_NAME_LENGTH, Error::InvalidMetadata);
+		require!(args.output.uri.len() <= Recipe::MAX_URI_LENGTH, Error::InvalidMetadata);
+
+		self.bump = bump;
+		self.version = Recipe::VERSION;
+		self.marketplace_authority = marketplace_authority;
+		self.collection_config = collection_config;
+		self.recipe_id = args.recipe_id;
+		self.inputs = args.inputs;
+		self.output = args.output;
+
+		Ok(())
+	}
+
+	fn total_quantity(inputs: &[RecipeInput]) -> usize {
+		inputs.iter().map(|input| input.quantity as usize).sum()
+	}
+
+	/// Number of NFTs burned by crafting
+	pub fn input_quantity(&self) -> usize {
+		Recipe::total_quantity(&self.inputs)
+	}
+
+	/// Checks the names of the NFTs being burned make up exactly the recipe's inputs
+	pub fn assert_inputs(&self, names: &[String]) -> Result<()> {
+		require!(names.len() == self.input_quantity(), Error::InvalidRecipeInputs);
+
+		for input in self.inputs.iter() {
+			let count = names.iter().filter(|name| **name == input.name).count();
+			require!(count == input.quantity as usize, Error::InvalidRecipeInputs);
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecipeArgs {
+	pub recipe_id: [u8; 32],
+	pub inputs: Vec<RecipeInput>,
+	pub output: Metadata,
+}
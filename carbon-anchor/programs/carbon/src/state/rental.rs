@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::FeeConfig,
+	error::Error
+};
+
+#[account]
+pub struct Rental {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Pubkey of the owner's wallet
+	pub owner: Pubkey,
+	/// Pubkey of the mint being rented out
+	pub mint: Pubkey,
+	/// Lamports charged per day of rental
+	pub price_per_day: u64,
+	/// Longest rental term the owner accepts, in days
+	pub max_days: u16,
+	/// True if the creators of the NFT receive royalties on the rental fee
+	pub apply_royalties: bool,
+	/// Fee config for the rental
+	pub fee_config: FeeConfig,
+	/// Pubkey of the wallet currently using the NFT, or the default pubkey if not rented
+	pub renter: Pubkey,
+	/// Unix timestamp of when the current rental term ends
+	pub rented_until: i64,
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	pub payer: Pubkey,
+}
+
+impl Rental {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const SECONDS_PER_DAY: i64 = 86_400;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 8 + 2 + 1 + FeeConfig::SPACE + 32 + 8 + 32 + 96;
+
+	pub const PREFIX: &'static str = "rental";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 3] {
+		[
+			Rental::PREFIX.as_bytes(),
+			self.mint.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		marketplace_authority: Pubkey,
+		owner: Pubkey,
+		mint: Pubkey,
+		fee_config: FeeConfig,
+		args: RentalArgs,
+	) -> Result<()> {
+		require!(args.max_days > 0, Error::InvalidRentalDuration);
+
+		self.bump = bump;
+		self.version = Rental::VERSION;
+		self.marketplace_authority = marketplace_authority;
+		self.owner = owner;
+		self.mint = mint;
+		self.price_per_day = args.price_per_day;
+		self.max_days = args.max_days;
+		self.apply_royalties = args.apply_royalties;
+		self.fee_config = fee_config;
+		self.renter = Pubkey::default();
+		self.rented_until = 0;
+
+		Ok(())
+	}
+
+	pub fn is_rented(&self) -> bool {
+		self.renter != Pubkey::default()
+	}
+
+	pub fn has_term_ended(&self) -> Result<bool> {
+		Ok(self.rented_until <= Clock::get()?.unix_timestamp)
+	}
+
+	/// Wallet the game should treat as the user of the NFT
+	pub fn current_user(&self) -> Result<Pubkey> {
+		if self.is_rented() && !self.has_term_ended()? {
+			Ok(self.renter)
+		} else {
+			Ok(self.owner)
+		}
+	}
+
+	/// Starts a rental term and returns the rental fee
+	pub fn rent(&mut self, renter: Pubkey, days: u16) -> Result<u64> {
+		require!(!self.is_rented(), Error::NftIsRented);
+		require!(days > 0 && days <= self.max_days, Error::InvalidRentalDuration);
+
+		let duration = (days as i64)
+			.checked_mul(Rental::SECONDS_PER_DAY)
+			.ok_or(Error::OverflowError)?;
+
+		self.renter = renter;
+		self.rented_until = Clock::get()?.unix_timestamp
+			.checked_add(duration)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(self.price_per_day
+			.checked_mul(days as u64)
+			.ok_or(Error::OverflowError)?)
+	}
+
+	pub fn get_fee_amount(&self, price: u64) -> Result<u64> {
+		Ok((price as u128)
+			.checked_mul(self.fee_config.bps as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(10_000)
+			.ok_or(Error::OverflowError)? as u64)
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RentalArgs {
+	pub price_per_day: u64,
+	pub max_days: u16,
+	pub apply_royalties: bool,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+/// Randomness commitment of a minted mystery box, tracking the box from mint to reveal
+#[account]
+pub struct SealedBox {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Mystery box the box was minted from
+	pub mystery_box: Pubkey,
+	/// Mint of the box NFT
+	pub mint: Pubkey,
+	/// Item ID of the box NFT
+	pub item_id: [u8; 32],
+	/// Hash of the seed committed by the marketplace authority when the box was minted
+	pub seed_hash: [u8; 32],
+	/// Wallet that opened the box and receives the prize, or the default pubkey if not opened
+	pub holder: Pubkey,
+	/// Slot the box was opened in
+	pub open_slot: u64,
+	/// Entropy read when the box was opened, which the prize is drawn with together with the seed
+	pub entropy: [u8; 32],
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	pub payer: Pubkey,
+}
+
+impl SealedBox {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 32 + 8 + 32 + 32 + 32;
+
+	pub const PREFIX: &'static str = "sealed_box";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		mystery_box: Pubkey,
+		mint: Pubkey,
+		item_id: [u8; 32],
+		seed_hash: [u8; 32],
+	) -> Result<()> {
+		self.bump = bump;
+		self.version = SealedBox::VERSION;
+		self.mystery_box = mystery_box;
+		self.mint = mint;
+		self.item_id = item_id;
+		self.seed_hash = seed_hash;
+		self.holder = Pubkey::default();
+		self.open_slot = 0;
+		self.entropy = [0; 32];
+
+		Ok(())
+	}
+
+	pub fn is_open(&self) -> bool {
+		self.holder != Pubkey::default()
+	}
+
+	pub fn open(&mut self, holder: Pubkey, entropy: [u8; 32]) -> Result<()> {
+		self.holder = holder;
+		self.open_slot = Clock::get()?.slot;
+		self.entropy = entropy;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::error::Error;
+
+/// Actions a session key can take on behalf of the owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SessionScope {
+	pub list: bool,
+	pub update_listing: bool,
+	pub delist: bool,
+	pub custody: bool,
+}
+
+#[derive(Clone, Copy)]
+pub enum SessionAction {
+	List,
+	UpdateListing,
+	Delist,
+	Custody,
+}
+
+impl SessionScope {
+	pub fn allows(&self, action: SessionAction) -> bool {
+		match action {
+			SessionAction::List => self.list,
+			SessionAction::UpdateListing => self.update_listing,
+			SessionAction::Delist => self.delist,
+			SessionAction::Custody => self.custody,
+		}
+	}
+}
+
+/// An ephemeral key a player authorized to sign listing and custody actions for them
+#[account]
+pub struct Session {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Wallet the session key acts for
+	pub owner: Pubkey,
+	/// Ephemeral key allowed to sign for the owner
+	pub session_key: Pubkey,
+	/// Marketplace whose listings and custody accounts the session key can act on
+	pub marketplace_authority: Pubkey,
+	/// Unix timestamp after which the session key can no longer sign
+	pub expiry: i64,
+	pub scope: SessionScope,
+	/// Collections the session key can act on, or empty for any collection
+	pub collection_mints: Vec<Pubkey>,
+	/// Highest price the session key can list for, or 0 for no limit
+	pub max_price: u64,
+	/// Lowest price the session key can list for, or 0 for no floor
+	pub min_price: u64,
+	/// Wallet that paid the rent and gets it back when the account is closed
+	pub payer: Pubkey,
+}
+
+impl Session {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const MAX_COLLECTIONS: usize = 8;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 8 + 4 + (4 + 32 * Session::MAX_COLLECTIONS) + 8 + 8 + 32 + 64;
+
+	pub const PREFIX: &'static str = "session";
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		owner: Pubkey,
+		session_key: Pubkey,
+		args: SessionArgs,
+	) -> Result<()> {
+		require!(args.expiry > Clock::get()?.unix_timestamp, Error::InvalidExpiry);
+		require!(args.collection_mints.len() <= Session::MAX_COLLECTIONS, Error::InvalidSession);
+		require!(args.max_price == 0 || args.min_price <= args.max_price, Error::InvalidSession);
+
+		self.bump = bump;
+		self.version = Session::VERSION;
+		self.owner = owner;
+		self.session_key = session_key;
+		self.marketplace_authority = args.marketplace_authority;
+		self.expiry = args.expiry;
+		self.scope = args.scope;
+		self.collection_mints = args.collection_mints;
+		self.max_price = args.max_price;
+		self.min_price = args.min_price;
+
+		Ok(())
+	}
+
+	/// Checks the authority can sign for the owner, either being the owner or the key of a live
+	/// session of the owner for the marketplace whose scope covers the action. The collection and
+	/// price are only checked when the action has them.
+	pub fn assert_can_sign_for<'a>(
+		owner: Pubkey,
+		authority: Pubkey,
+		session_account: &AccountInfo<'a>,
+		marketplace_authority: Pubkey,
+		action: SessionAction,
+		collection_mint: Option<Pubkey>,
+		price: Option<u64>,
+	) -> Result<()> {
+		if authority == owner {
+			return Ok(());
+		}
+
+		let session = Account::<Session>::try_from(session_account)?;
+		require!(session.owner == owner && session.session_key == authority, Error::InvalidSession);
+		require!(session.marketplace_authority == marketplace_authority, Error::SessionScopeExceeded);
+		require!(session.expiry > Clock::get()?.unix_timestamp, Error::SessionExpired);
+		require!(session.scope.allows(action), Error::SessionScopeExceeded);
+
+		if let Some(collection_mint) = collection_mint {
+			require!(
+				session.collection_mints.is_empty() || session.collection_mints.contains(&collection_mint),
+				Error::SessionScopeExceeded
+			);
+		}
+
+		if let Some(price) = price {
+			require!(session.max_price == 0 || price <= session.max_price, Error::SessionScopeExceeded);
+			require!(price >= session.min_price, Error::SessionScopeExceeded);
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SessionArgs {
+	/// Marketplace whose listings and custody accounts the session key can act on
+	pub marketplace_authority: Pubkey,
+	/// Unix timestamp after which the session key can no longer sign
+	pub expiry: i64,
+	pub scope: SessionScope,
+	/// Collections the session key can act on, or empty for any collection
+	pub collection_mints: Vec<Pubkey>,
+	/// Highest price the session key can list for, or 0 for no limit
+	pub max_price: u64,
+	/// Lowest price the session key can list for, or 0 for no floor
+	pub min_price: u64,
+}
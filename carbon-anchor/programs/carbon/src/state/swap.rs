@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::{
+	state::CollectionConfig,
+	error::Error
+};
+
+#[account]
+pub struct Swap {
+	pub bump: [u8; 1],
+	pub version: u8,
+	/// Pubkey of the marketplace authority's wallet
+	pub marketplace_authority: Pubkey,
+	/// Pubkey of the wallet proposing the swap
+	pub maker: Pubkey,
+	/// Pubkey of the only wallet allowed to take the swap, or the default pubkey if anyone can
+	pub taker: Pubkey,
+	/// A unique ID for the swap chosen by the maker
+	pub swap_id: [u8; 32],
+	/// Collection all NFTs in the swap are verified in
+	pub collection_mint: Pubkey,
+	/// Currency of the maker and taker amounts, native mint if SOL
+	pub currency_mint: Pubkey,
+	/// NFTs escrowed by the maker, frozen by delegation or flagged in their custody account
+	pub maker_mints: Vec<Pubkey>,
+	/// Currency escrowed by the maker
+	pub maker_amount: u64,
+	/// NFTs requested from the taker
+	pub taker_mints: Vec<Pubkey>,
+	/// Currency requested from the taker
+	pub taker_amount: u64,
+	/// Lamports paid by the taker to the creators of each NFT in the swap
+	pub royalty_per_nft: u64,
+	/// Unix timestamp of when the swap expires, 0 if never
+	pub expiry: i64,
+	/// Wallet that paid the rent and gets it back when the account is closed.
+	pub payer: Pubkey,
+}
+
+impl Swap {
+	// Current version of data structure
+	pub const VERSION: u8 = 1;
+
+	pub const MAX_MINTS: usize = 4;
+
+	// Additional padding for future proofing
+	pub const SPACE: usize =
+		8 + 1 + 1 + 32 + 32 + 32 + 32 + 32 + 32 +
+		(4 + 32 * Swap::MAX_MINTS) + 8 +
+		(4 + 32 * Swap::MAX_MINTS) + 8 +
+		8 + 8 + 32 + 96;
+
+	pub const PREFIX: &'static str = "swap";
+
+	pub fn auth_seeds(&self) -> [&[u8]; 4] {
+		[
+			Swap::PREFIX.as_bytes(),
+			self.maker.as_ref(),
+			self.swap_id.as_ref(),
+			self.bump.as_ref()
+		]
+	}
+
+	pub fn init(
+		&mut self,
+		bump: [u8; 1],
+		maker: Pubkey,
+		args: SwapArgs,
+		collection_config: &CollectionConfig,
+		currency_mint: Pubkey,
+	) -> Result<()> {
+		require!(
+			args.maker_mints.len() <= Swap::MAX_MINTS &&
+			args.taker_mints.len() <= Swap::MAX_MINTS,
+			Error::TooManySwapItems
+		);
+		require!(
+			(!args.maker_mints.is_empty() || args.maker_amount > 0) &&
+			(!args.taker_mints.is_empty() || args.taker_amount > 0),
+			Error::EmptySwap
+		);
+
+		if args.expiry != 0 &&
+			args.expiry <= Clock::get()?.unix_timestamp {
+			return err!(Error::InvalidExpiry);
+		}
+
+		self.bump = bump;
+		self.version = Swap::VERSION;
+		self.marketplace_authority = collection_config.marketplace_authority;
+		self.maker = maker;
+		self.taker = args.taker;
+		self.swap_id = args.swap_id;
+		self.collection_mint = collection_config.collection_mint;
+		self.currency_mint = currency_mint;
+		self.maker_mints = args.maker_mints;
+		self.maker_amount = args.maker_amount;
+		self.taker_mints = args.taker_mints;
+		self.taker_amount = args.taker_amount;
+		self.royalty_per_nft = collection_config.swap_royalty_policy.lamports_per_item();
+		self.expiry = args.expiry;
+
+		Ok(())
+	}
+
+	pub fn is_expired(&self) -> Result<bool> {
+		Ok(self.expiry != 0 && self.expiry <= Clock::get()?.unix_timestamp)
+	}
+
+	pub fn assert_can_take(&self, taker: Pubkey) -> Result<()> {
+		if self.is_expired()? {
+			return err!(Error::SwapExpired);
+		}
+
+		require!(
+			self.taker == Pubkey::default() || self.taker == taker,
+			Error::InvalidTaker
+		);
+		require!(self.maker != taker, Error::InvalidTaker);
+
+		Ok(())
+	}
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SwapArgs {
+	pub swap_id: [u8; 32],
+	/// Wallet allowed to take the swap, or the default pubkey for an open swap
+	pub taker: Pubkey,
+	pub maker_mints: Vec<Pubkey>,
+	pub maker_amount: u64,
+	pub taker_mints: Vec<Pubkey>,
+	pub taker_amount: u64,
+	pub expiry: i64,
+}
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::get_associated_token_address;
 use anchor_spl::token::{Mint};
 use anchor_spl::metadata::{MetadataAccount};
-use solana_program::{pubkey::Pubkey, account_info::AccountInfo};
-use solana_program::program_pack::{IsInitialized, Pack};
-use spl_token::state::Account as SplAccount;
+use solana_program::{pubkey::Pubkey, account_info::AccountInfo, keccak};
+use solana_program::program_pack::IsInitialized;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+	extension::StateWithExtensions,
+	state::{Account as SplAccount, Mint as SplMint}
+};
 use crate::util::error::Error;
 
 pub fn is_native_mint(key: Pubkey) -> bool {
@@ -21,19 +24,41 @@ pub fn assert_keys_equal(key1: Pubkey, key2: Pubkey, error_message: &str) -> Res
 	Ok(())
 }
 
+pub fn assert_is_token_program(key: Pubkey) -> Result<()> {
+	require!(
+		key == spl_token::id() || key == spl_token_2022::id(),
+		Error::InvalidTokenProgram
+	);
+
+	Ok(())
+}
+
+/// Checks the ATA of a token account of either token program, ignoring any Token-2022 extensions
 pub fn assert_is_ata(ata: &AccountInfo, wallet: &Pubkey, mint: &Pubkey) -> Result<SplAccount> {
-	assert_owned_by(ata, &spl_token::id())?;
-	let ata_account: SplAccount = assert_initialized(ata)?;
+	assert_is_token_program(*ata.owner)?;
+	let ata_account = StateWithExtensions::<SplAccount>::unpack(&ata.data.borrow())?.base;
+	if !ata_account.is_initialized() {
+		return err!(Error::UninitializedAccount);
+	}
+
 	assert_keys_equal(ata_account.owner, *wallet, "Invalid ATA owner")?;
 	assert_keys_equal(ata_account.mint, *mint, "Invalid ATA mint")?;
-	assert_keys_equal(get_associated_token_address(wallet, mint), *ata.key, "Invalid ATA address")?;
+	assert_keys_equal(
+		get_associated_token_address_with_program_id(wallet, mint, ata.owner),
+		*ata.key,
+		"Invalid ATA address"
+	)?;
 	Ok(ata_account)
 }
 
-pub fn assert_is_mint<'info>(mint: &AccountInfo<'info>) -> Result<Account<'info, Mint>> {
-	assert_owned_by(mint, &spl_token::id())?;
-	let _spl_mint: spl_token::state::Mint = assert_initialized(mint)?;
-	let mint_account = Account::<'info, Mint>::try_from(mint)?;
+/// Checks the mint of either token program, ignoring any Token-2022 extensions
+pub fn assert_is_mint(mint: &AccountInfo) -> Result<SplMint> {
+	assert_is_token_program(*mint.owner)?;
+	let mint_account = StateWithExtensions::<SplMint>::unpack(&mint.data.borrow())?.base;
+	if !mint_account.is_initialized() {
+		return err!(Error::UninitializedAccount);
+	}
+
 	Ok(mint_account)
 }
 
@@ -47,12 +72,44 @@ pub fn assert_is_nft_in_collection<'info>(
 
 	let collection_data = nft_metadata.collection.as_ref()
 		.ok_or(Error::CollectionNotSet)?;
-	assert!(collection_data.verified);
+	require!(collection_data.verified, Error::CollectionNotVerified);
 	assert_keys_equal(collection_data.key, collection, "Invalid collection key")?;
 
 	Ok(())
 }
 
+/// Checks the first creator of the NFT is the given creator and has verified it
+pub fn assert_is_nft_by_creator<'info>(
+	nft_mint: &Account<'info, Mint>,
+	nft_metadata: &Account<'info, MetadataAccount>,
+	creator: Pubkey
+) -> Result<()> {
+	assert_owned_by(&nft_metadata.to_account_info(), &mpl_token_metadata::id())?;
+	assert_keys_equal(nft_metadata.mint, nft_mint.key(), "Invalid metadata mint")?;
+
+	let first_creator = nft_metadata.data.creators.as_ref()
+		.and_then(|creators| creators.first())
+		.ok_or(Error::CreatorNotVerified)?;
+	require!(first_creator.verified, Error::CreatorNotVerified);
+	assert_keys_equal(first_creator.address, creator, "Invalid creator key")?;
+
+	Ok(())
+}
+
+/// Checks the leaf is in the Merkle tree with the root, hashing each pair of nodes in sorted order
+pub fn assert_is_merkle_leaf(leaf: [u8; 32], proof: &[[u8; 32]], root: [u8; 32]) -> Result<()> {
+	let computed = proof.iter().fold(leaf, |node, sibling| {
+		if node <= *sibling {
+			keccak::hashv(&[&node, sibling]).to_bytes()
+		} else {
+			keccak::hashv(&[sibling, &node]).to_bytes()
+		}
+	});
+	require!(computed == root, Error::InvalidMerkleProof);
+
+	Ok(())
+}
+
 pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
 	if account.owner != owner {
 		err!(Error::IncorrectOwner)
@@ -61,15 +118,6 @@ pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
 	}
 }
 
-pub fn assert_initialized<T: Pack + IsInitialized>(account_info: &AccountInfo) -> Result<T> {
-	let account: T = T::unpack_unchecked(&account_info.data.borrow())?;
-	if !account.is_initialized() {
-		err!(Error::UninitializedAccount)
-	} else {
-		Ok(account)
-	}
-}
-
 pub fn assert_is_metadata_account(
 	metadata_account: Pubkey,
 	mint: Pubkey,
@@ -1,9 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use anchor_spl::token::{Approve, Revoke};
-use solana_program::{account_info::AccountInfo};
+use anchor_spl::token::{Approve, Mint, Revoke};
+use solana_program::{account_info::AccountInfo, program_option::COption};
 use solana_program::program::{invoke_signed};
 
+/// True if Token Metadata can freeze the token through its edition, which holds the freeze
+/// authority of NFTs with a master or print edition
+pub fn can_freeze_with_edition(mint: &Account<Mint>, edition: &AccountInfo) -> bool {
+	mint.freeze_authority == COption::Some(edition.key()) &&
+		*edition.owner == mpl_token_metadata::id() &&
+		!edition.data_is_empty()
+}
+
 pub fn approve_and_freeze<'a>(
 	token_account: &AccountInfo<'a>,
 	mint: &AccountInfo<'a>,
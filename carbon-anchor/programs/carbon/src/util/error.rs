@@ -12,5 +12,17 @@ pub enum Error {
 	#[msg("Overflow error")]
 	OverflowError,
 	#[msg("Collection not set")]
-	CollectionNotSet
+	CollectionNotSet,
+
+	/// 5
+	#[msg("Invalid programmable NFT accounts")]
+	InvalidProgrammableAccounts,
+	#[msg("Invalid token program")]
+	InvalidTokenProgram,
+	#[msg("Collection not verified")]
+	CollectionNotVerified,
+	#[msg("Creator not verified")]
+	CreatorNotVerified,
+	#[msg("Invalid Merkle proof")]
+	InvalidMerkleProof
 }
\ No newline at end of file
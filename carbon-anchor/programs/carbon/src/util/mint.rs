@@ -15,8 +15,11 @@ use anchor_spl::{
 	}
 };
 use mpl_token_metadata::state::{DataV2};
-use crate::util::make_ata;
+use solana_program::program::invoke;
+use crate::util::{assert_is_edition_account, assert_is_metadata_account, make_ata};
 
+/// Mints a full NFT with its own mint, token account, metadata and master edition. The signer seeds
+/// are given when the mint authority is a PDA of the program.
 pub fn mint_nft<'a>(
 	payer: &AccountInfo<'a>,
 	receiver: &AccountInfo<'a>,
@@ -31,8 +34,10 @@ pub fn mint_nft<'a>(
 	token_program: &AccountInfo<'a>,
 	system_program: &AccountInfo<'a>,
 	rent: &AccountInfo<'a>,
+	signer_seeds: Option<&[&[u8]]>,
 ) -> Result<()> {
 	let token_program = token_program.to_account_info();
+	let signer_seeds = signer_seeds.map(|seeds| vec![seeds]).unwrap_or_default();
 
 	create_account(
 		CpiContext::new(system_program.clone(), CreateAccount {
@@ -69,17 +74,17 @@ pub fn mint_nft<'a>(
 
 	// Mint to the receiver
 	mint_to(
-		CpiContext::new(token_program.clone(), MintTo {
+		CpiContext::new_with_signer(token_program.clone(), MintTo {
 			mint: mint.to_account_info(),
 			to: receiver_token_account.to_account_info(),
 			authority: mint_authority.to_account_info(),
-		}),
+		}, &signer_seeds),
 		1
 	)?;
 
 	// Create metadata accounts
 	create_metadata_accounts_v3(
-		CpiContext::new(token_metadata_program.clone(), CreateMetadataAccountsV3 {
+		CpiContext::new_with_signer(token_metadata_program.clone(), CreateMetadataAccountsV3 {
 			metadata: metadata_account.to_account_info(),
 			mint: mint.to_account_info(),
 			mint_authority: mint_authority.to_account_info(),
@@ -87,7 +92,7 @@ pub fn mint_nft<'a>(
 			payer: payer.to_account_info(),
 			system_program: system_program.to_account_info(),
 			rent: rent.to_account_info(),
-		}),
+		}, &signer_seeds),
 		metadata_data,
 		true,
 		true,
@@ -96,7 +101,7 @@ pub fn mint_nft<'a>(
 
 	// Create master edition
 	Ok(create_master_edition_v3(
-		CpiContext::new(token_metadata_program.clone(), CreateMasterEditionV3 {
+		CpiContext::new_with_signer(token_metadata_program.clone(), CreateMasterEditionV3 {
 			payer: payer.to_account_info(),
 			mint: mint.to_account_info(),
 			edition: edition.to_account_info(),
@@ -106,7 +111,54 @@ pub fn mint_nft<'a>(
 			token_program: token_program.to_account_info(),
 			system_program: system_program.to_account_info(),
 			rent: rent.to_account_info(),
-		}),
+		}, &signer_seeds),
 		Some(1),
 	)?)
-}
\ No newline at end of file
+}
+
+/// Accounts Token Metadata needs to burn an NFT that is a verified item of a sized collection
+pub struct BurnableNft<'a> {
+	pub metadata: AccountInfo<'a>,
+	/// Signer of the transaction holding the NFT
+	pub owner: AccountInfo<'a>,
+	pub mint: AccountInfo<'a>,
+	pub token_account: AccountInfo<'a>,
+	pub edition: AccountInfo<'a>,
+	pub collection_metadata: AccountInfo<'a>,
+}
+
+impl<'a> BurnableNft<'a> {
+	/// Burns the token and closes the metadata, edition and token accounts, returning their rent to
+	/// the owner
+	pub fn burn(&self, token_metadata_program: &AccountInfo<'a>, token_program: &AccountInfo<'a>) -> Result<()> {
+		assert_is_metadata_account(self.metadata.key(), self.mint.key())?;
+		assert_is_edition_account(self.edition.key(), self.mint.key())?;
+
+		let ix = mpl_token_metadata::instruction::burn_nft(
+			token_metadata_program.key(),
+			self.metadata.key(),
+			self.owner.key(),
+			self.mint.key(),
+			self.token_account.key(),
+			self.edition.key(),
+			token_program.key(),
+			Some(self.collection_metadata.key()),
+		);
+
+		invoke(
+			&ix,
+			&[
+				self.metadata.clone(),
+				self.owner.clone(),
+				self.mint.clone(),
+				self.token_account.clone(),
+				self.edition.clone(),
+				token_program.clone(),
+				self.collection_metadata.clone(),
+				token_metadata_program.clone(),
+			],
+		)?;
+
+		Ok(())
+	}
+}
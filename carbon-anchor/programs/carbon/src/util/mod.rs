@@ -2,9 +2,17 @@ mod checks;
 mod delegation;
 mod transfer;
 mod mint;
+mod price;
+mod programmable;
+mod randomness;
+mod signature;
 mod error;
 
 pub use checks::*;
 pub use delegation::*;
 pub use transfer::*;
-pub use mint::*;
\ No newline at end of file
+pub use mint::*;
+pub use price::*;
+pub use programmable::*;
+pub use randomness::*;
+pub use signature::*;
\ No newline at end of file
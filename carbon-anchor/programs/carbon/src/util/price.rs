@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use solana_program::account_info::AccountInfo;
+use crate::{
+	constants::{PYTH_PROGRAM_IDS, REFERENCE_PRICE_DECIMALS},
+	error::Error
+};
+
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Price of one whole currency unit in reference units, scaled by 10^expo
+#[derive(Clone)]
+pub struct Price {
+	pub price: i64,
+	pub conf: u64,
+	pub expo: i32,
+	pub publish_time: i64,
+}
+
+impl Price {
+	pub fn is_pyth_account(account_info: &AccountInfo) -> bool {
+		PYTH_PROGRAM_IDS.contains(account_info.owner)
+	}
+
+	/// Reads the aggregate price from a Pyth price account
+	pub fn from_pyth_account(account_info: &AccountInfo) -> Result<Price> {
+		require!(Price::is_pyth_account(account_info), Error::InvalidPriceFeed);
+
+		let data = account_info.try_borrow_data()?;
+		require!(data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN, Error::InvalidPriceFeed);
+		require!(read_u32(&data, 0) == PYTH_MAGIC, Error::InvalidPriceFeed);
+		require!(read_u32(&data, 8) == PYTH_PRICE_ACCOUNT_TYPE, Error::InvalidPriceFeed);
+		require!(read_u32(&data, 224) == PYTH_STATUS_TRADING, Error::StalePrice);
+
+		Ok(Price {
+			expo: read_u32(&data, 20) as i32,
+			publish_time: read_u64(&data, 96) as i64,
+			price: read_u64(&data, 208) as i64,
+			conf: read_u64(&data, 216),
+		})
+	}
+
+	pub fn assert_is_valid(&self, max_age: i64, max_conf_bps: u64) -> Result<()> {
+		require!(self.price > 0, Error::InvalidPriceFeed);
+
+		let age = Clock::get()?.unix_timestamp
+			.checked_sub(self.publish_time)
+			.ok_or(Error::OverflowError)?;
+		require!(age <= max_age, Error::StalePrice);
+
+		let conf_bps = (self.conf as u128)
+			.checked_mul(10_000)
+			.ok_or(Error::OverflowError)?
+			.checked_div(self.price as u128)
+			.ok_or(Error::OverflowError)?;
+		require!(conf_bps <= max_conf_bps as u128, Error::PriceConfidenceExceeded);
+
+		Ok(())
+	}
+
+	/// Converts an amount in reference units to the smallest unit of the currency, rounding up
+	pub fn to_currency_amount(&self, reference_amount: u64, currency_decimals: u8) -> Result<u64> {
+		require!(self.price > 0, Error::InvalidPriceFeed);
+
+		let exponent = currency_decimals as i32 - REFERENCE_PRICE_DECIMALS as i32 - self.expo;
+		let scale = 10u128
+			.checked_pow(exponent.unsigned_abs())
+			.ok_or(Error::OverflowError)?;
+
+		let (numerator, denominator) = if exponent >= 0 {
+			(
+				(reference_amount as u128).checked_mul(scale).ok_or(Error::OverflowError)?,
+				self.price as u128
+			)
+		} else {
+			(
+				reference_amount as u128,
+				(self.price as u128).checked_mul(scale).ok_or(Error::OverflowError)?
+			)
+		};
+
+		let amount = numerator
+			.checked_add(denominator - 1)
+			.ok_or(Error::OverflowError)?
+			.checked_div(denominator)
+			.ok_or(Error::OverflowError)?;
+
+		Ok(u64::try_from(amount).map_err(|_| Error::OverflowError)?)
+	}
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+	u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+	u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use mpl_token_metadata::{
+	instruction::{
+		builders::{
+			CreateBuilder,
+			DelegateBuilder,
+			LockBuilder,
+			MintBuilder,
+			RevokeBuilder,
+			TransferBuilder,
+			UnlockBuilder
+		},
+		CreateArgs,
+		DelegateArgs,
+		InstructionBuilder,
+		LockArgs,
+		MintArgs,
+		RevokeArgs,
+		TransferArgs,
+		UnlockArgs
+	},
+	pda::find_token_record_account,
+	state::{AssetData, TokenStandard}
+};
+use solana_program::{account_info::AccountInfo, instruction::Instruction, program::invoke_signed};
+use crate::util::{assert_is_metadata_account, assert_keys_equal, error::Error};
+
+/// True if the metadata is for a programmable NFT, whose token record and rule set have to be
+/// honored through Token Metadata instead of freezing and transferring the token account directly
+pub fn is_programmable(metadata_account: &AccountInfo) -> Result<bool> {
+	if metadata_account.data_is_empty() {
+		return Ok(false);
+	}
+
+	let metadata = Account::<MetadataAccount>::try_from(metadata_account)?;
+	Ok(metadata.token_standard == Some(TokenStandard::ProgrammableNonFungible))
+}
+
+/// Accounts Token Metadata needs to act on a programmable NFT held in a token account
+pub struct ProgrammableNft<'a> {
+	pub token_account: AccountInfo<'a>,
+	pub token_owner: AccountInfo<'a>,
+	pub mint: AccountInfo<'a>,
+	pub metadata: AccountInfo<'a>,
+	pub edition: AccountInfo<'a>,
+	pub token_record: AccountInfo<'a>,
+	/// Token Metadata program id when the NFT has no rule set
+	pub authorization_rules_program: AccountInfo<'a>,
+	/// Token Metadata program id when the NFT has no rule set
+	pub authorization_rules: AccountInfo<'a>,
+	pub sysvar_instructions: AccountInfo<'a>,
+	/// Signer of the transaction paying for any token record created
+	pub payer: AccountInfo<'a>,
+	pub token_metadata_program: AccountInfo<'a>,
+	pub token_program: AccountInfo<'a>,
+	pub system_program: AccountInfo<'a>,
+}
+
+impl<'a> ProgrammableNft<'a> {
+	pub fn assert_is_valid(&self) -> Result<()> {
+		assert_is_metadata_account(self.metadata.key(), self.mint.key())?;
+
+		let (token_record, _) = find_token_record_account(self.mint.key, self.token_account.key);
+		assert_keys_equal(self.token_record.key(), token_record, "Invalid token record")?;
+		assert_keys_equal(
+			self.sysvar_instructions.key(),
+			solana_program::sysvar::instructions::ID,
+			"Invalid instructions sysvar"
+		)?;
+
+		Ok(())
+	}
+
+	/// Approves a token delegate, signed by the token owner
+	pub fn delegate(&self, delegate: &AccountInfo<'a>, args: DelegateArgs) -> Result<()> {
+		let ix = DelegateBuilder::new()
+			.delegate(delegate.key())
+			.metadata(self.metadata.key())
+			.master_edition(self.edition.key())
+			.token_record(self.token_record.key())
+			.mint(self.mint.key())
+			.token(self.token_account.key())
+			.authority(self.token_owner.key())
+			.payer(self.payer.key())
+			.spl_token_program(self.token_program.key())
+			.authorization_rules_program(self.authorization_rules_program.key())
+			.authorization_rules(self.authorization_rules.key())
+			.build(args)
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(ix, std::slice::from_ref(delegate), None)
+	}
+
+	/// Revokes the token delegate, signed by the token owner
+	pub fn revoke(&self, delegate: &AccountInfo<'a>, args: RevokeArgs) -> Result<()> {
+		let ix = RevokeBuilder::new()
+			.delegate(delegate.key())
+			.metadata(self.metadata.key())
+			.master_edition(self.edition.key())
+			.token_record(self.token_record.key())
+			.mint(self.mint.key())
+			.token(self.token_account.key())
+			.authority(self.token_owner.key())
+			.payer(self.payer.key())
+			.spl_token_program(self.token_program.key())
+			.authorization_rules_program(self.authorization_rules_program.key())
+			.authorization_rules(self.authorization_rules.key())
+			.build(args)
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(ix, std::slice::from_ref(delegate), None)
+	}
+
+	/// Locks the token account, signed by a utility, staking or locked transfer delegate
+	pub fn lock(&self, delegate: &AccountInfo<'a>, signer_seeds: Option<&[&[u8]]>) -> Result<()> {
+		let ix = LockBuilder::new()
+			.authority(delegate.key())
+			.token_owner(self.token_owner.key())
+			.token(self.token_account.key())
+			.mint(self.mint.key())
+			.metadata(self.metadata.key())
+			.edition(self.edition.key())
+			.token_record(self.token_record.key())
+			.payer(self.payer.key())
+			.spl_token_program(self.token_program.key())
+			.authorization_rules_program(self.authorization_rules_program.key())
+			.authorization_rules(self.authorization_rules.key())
+			.build(LockArgs::V1 { authorization_data: None })
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(ix, std::slice::from_ref(delegate), signer_seeds)
+	}
+
+	/// Unlocks the token account, signed by the delegate that locked it
+	pub fn unlock(&self, delegate: &AccountInfo<'a>, signer_seeds: Option<&[&[u8]]>) -> Result<()> {
+		let ix = UnlockBuilder::new()
+			.authority(delegate.key())
+			.token_owner(self.token_owner.key())
+			.token(self.token_account.key())
+			.mint(self.mint.key())
+			.metadata(self.metadata.key())
+			.edition(self.edition.key())
+			.token_record(self.token_record.key())
+			.payer(self.payer.key())
+			.spl_token_program(self.token_program.key())
+			.authorization_rules_program(self.authorization_rules_program.key())
+			.authorization_rules(self.authorization_rules.key())
+			.build(UnlockArgs::V1 { authorization_data: None })
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(ix, std::slice::from_ref(delegate), signer_seeds)
+	}
+
+	/// Transfers the NFT, signed by the token owner or a delegate allowed to transfer it.
+	/// Token Metadata creates the destination token account and token record if needed.
+	pub fn transfer(
+		&self,
+		destination: &AccountInfo<'a>,
+		destination_owner: &AccountInfo<'a>,
+		destination_token_record: &AccountInfo<'a>,
+		authority: &AccountInfo<'a>,
+		ata_program: &AccountInfo<'a>,
+		signer_seeds: Option<&[&[u8]]>,
+	) -> Result<()> {
+		let ix = TransferBuilder::new()
+			.token(self.token_account.key())
+			.token_owner(self.token_owner.key())
+			.destination(destination.key())
+			.destination_owner(destination_owner.key())
+			.mint(self.mint.key())
+			.metadata(self.metadata.key())
+			.edition(self.edition.key())
+			.owner_token_record(self.token_record.key())
+			.destination_token_record(destination_token_record.key())
+			.authority(authority.key())
+			.payer(self.payer.key())
+			.spl_token_program(self.token_program.key())
+			.spl_ata_program(ata_program.key())
+			.authorization_rules_program(self.authorization_rules_program.key())
+			.authorization_rules(self.authorization_rules.key())
+			.build(TransferArgs::V1 { amount: 1, authorization_data: None })
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(
+			ix,
+			&[
+				destination.clone(),
+				destination_owner.clone(),
+				destination_token_record.clone(),
+				authority.clone(),
+				ata_program.clone(),
+			],
+			signer_seeds
+		)
+	}
+
+	/// Creates the mint, metadata and master edition of a new programmable NFT and mints it
+	/// to the token owner
+	pub fn create_and_mint(
+		&self,
+		authority: &AccountInfo<'a>,
+		asset_data: AssetData,
+		ata_program: &AccountInfo<'a>,
+	) -> Result<()> {
+		let create_ix = CreateBuilder::new()
+			.metadata(self.metadata.key())
+			.master_edition(self.edition.key())
+			.mint(self.mint.key())
+			.authority(authority.key())
+			.payer(self.payer.key())
+			.update_authority(authority.key())
+			.spl_token_program(self.token_program.key())
+			.build(CreateArgs::V1 {
+				asset_data,
+				decimals: Some(0),
+				print_supply: Some(mpl_token_metadata::state::PrintSupply::Zero),
+			})
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(create_ix, std::slice::from_ref(authority), None)?;
+
+		let mint_ix = MintBuilder::new()
+			.token(self.token_account.key())
+			.token_owner(self.token_owner.key())
+			.metadata(self.metadata.key())
+			.master_edition(self.edition.key())
+			.token_record(self.token_record.key())
+			.mint(self.mint.key())
+			.authority(authority.key())
+			.payer(self.payer.key())
+			.spl_token_program(self.token_program.key())
+			.spl_ata_program(ata_program.key())
+			.authorization_rules_program(self.authorization_rules_program.key())
+			.authorization_rules(self.authorization_rules.key())
+			.build(MintArgs::V1 { amount: 1, authorization_data: None })
+			.map_err(|_| Error::InvalidProgrammableAccounts)?
+			.instruction();
+
+		self.invoke(mint_ix, &[authority.clone(), ata_program.clone()], None)
+	}
+
+	fn invoke(
+		&self,
+		ix: Instruction,
+		extra_accounts: &[AccountInfo<'a>],
+		signer_seeds: Option<&[&[u8]]>,
+	) -> Result<()> {
+		let mut accounts = vec![
+			self.token_account.clone(),
+			self.token_owner.clone(),
+			self.mint.clone(),
+			self.metadata.clone(),
+			self.edition.clone(),
+			self.token_record.clone(),
+			self.authorization_rules_program.clone(),
+			self.authorization_rules.clone(),
+			self.sysvar_instructions.clone(),
+			self.payer.clone(),
+			self.token_metadata_program.clone(),
+			self.token_program.clone(),
+			self.system_program.clone(),
+		];
+		accounts.extend_from_slice(extra_accounts);
+
+		match signer_seeds {
+			Some(seeds) => invoke_signed(&ix, &accounts, &[seeds])?,
+			None => invoke_signed(&ix, &accounts, &[])?,
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use solana_program::{account_info::AccountInfo, hash, sysvar};
+use crate::error::Error;
+
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+/// Number of recent slots the SlotHashes sysvar keeps hashes for
+pub const SLOT_HASHES_WINDOW: u64 = 512;
+
+/// Source of the entropy combined with a committed seed to draw a random outcome. The seed is
+/// committed before the entropy exists, so neither the committer nor the requester can pick the
+/// outcome alone.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RandomnessSource {
+	/// Hash of a slot read from the SlotHashes sysvar. Draws reading the hash of their request slot
+	/// when revealed have to be revealed within the 512 slots the sysvar keeps.
+	SlotHashes,
+}
+
+impl RandomnessSource {
+	pub const SPACE: usize = 1;
+
+	/// Checks the seed against its commitment and combines it with the entropy of the request slot
+	pub fn reveal(
+		&self,
+		seed: [u8; 32],
+		seed_hash: [u8; 32],
+		request_slot: u64,
+		randomness_account: &AccountInfo,
+	) -> Result<[u8; 32]> {
+		require!(Clock::get()?.slot > request_slot, Error::RevealTooEarly);
+
+		let entropy = match self {
+			RandomnessSource::SlotHashes => read_slot_hash(randomness_account, request_slot)?,
+		};
+
+		self.combine(seed, seed_hash, entropy, request_slot)
+	}
+
+	/// Reads the newest entropy available, for draws that store it when requested instead of
+	/// reading it when revealed
+	pub fn latest_entropy(&self, randomness_account: &AccountInfo) -> Result<[u8; 32]> {
+		match self {
+			RandomnessSource::SlotHashes => read_latest_slot_hash(randomness_account),
+		}
+	}
+
+	/// Checks the seed against its commitment and combines it with the entropy read for the request
+	pub fn combine(
+		&self,
+		seed: [u8; 32],
+		seed_hash: [u8; 32],
+		entropy: [u8; 32],
+		request_slot: u64,
+	) -> Result<[u8; 32]> {
+		require!(hash::hash(&seed).to_bytes() == seed_hash, Error::InvalidSeed);
+
+		Ok(hash::hashv(&[&seed, &entropy, &request_slot.to_le_bytes()]).to_bytes())
+	}
+
+	/// Whether the entropy of the request slot may no longer be readable, so the outcome can't be
+	/// revealed anymore
+	pub fn has_expired(&self, request_slot: u64) -> Result<bool> {
+		let last_reveal_slot = match self {
+			RandomnessSource::SlotHashes => request_slot
+				.checked_add(SLOT_HASHES_WINDOW)
+				.ok_or(Error::OverflowError)?,
+		};
+
+		Ok(Clock::get()?.slot > last_reveal_slot)
+	}
+}
+
+/// Picks an index with probability proportional to its weight
+pub fn draw_weighted(randomness: [u8; 32], weights: &[u32]) -> Result<usize> {
+	let total: u64 = weights.iter().map(|weight| *weight as u64).sum();
+	require!(total > 0, Error::InvalidPrizes);
+
+	let mut roll = u64::from_le_bytes(randomness[..8].try_into().unwrap()) % total;
+	for (index, weight) in weights.iter().enumerate() {
+		if roll < *weight as u64 {
+			return Ok(index);
+		}
+		roll -= *weight as u64;
+	}
+
+	err!(Error::InvalidPrizes)
+}
+
+/// Reads the hash of the slot from the SlotHashes sysvar, which is too large to deserialize
+fn read_slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<[u8; 32]> {
+	require!(sysvar::slot_hashes::check_id(slot_hashes.key), Error::RandomnessUnavailable);
+
+	let data = slot_hashes.try_borrow_data()?;
+	let count = read_u64(&data, 0) as usize;
+	for i in 0..count {
+		let offset = 8 + i * SLOT_HASH_ENTRY_LEN;
+		if offset + SLOT_HASH_ENTRY_LEN > data.len() {
+			break;
+		}
+		// Entries are ordered from the newest slot
+		let entry_slot = read_u64(&data, offset);
+		if entry_slot == slot {
+			return Ok(data[offset + 8..offset + SLOT_HASH_ENTRY_LEN].try_into().unwrap());
+		}
+		if entry_slot < slot {
+			break;
+		}
+	}
+
+	err!(Error::RandomnessUnavailable)
+}
+
+/// Reads the hash of the newest slot in the SlotHashes sysvar, the parent of the current slot
+fn read_latest_slot_hash(slot_hashes: &AccountInfo) -> Result<[u8; 32]> {
+	require!(sysvar::slot_hashes::check_id(slot_hashes.key), Error::RandomnessUnavailable);
+
+	let data = slot_hashes.try_borrow_data()?;
+	require!(
+		read_u64(&data, 0) > 0 && data.len() >= 8 + SLOT_HASH_ENTRY_LEN,
+		Error::RandomnessUnavailable
+	);
+
+	Ok(data[16..8 + SLOT_HASH_ENTRY_LEN].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+	u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use solana_program::{
+	account_info::AccountInfo,
+	ed25519_program,
+	sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use crate::error::Error;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+
+/// Checks the instruction right before the current one is an Ed25519 program instruction verifying
+/// one signature of the message by the signer. The Ed25519 program fails the transaction if the
+/// signature is invalid, so only what it verified has to be checked here.
+pub fn assert_ed25519_signature(
+	instructions_sysvar: &AccountInfo,
+	signer: Pubkey,
+	message: &[u8],
+) -> Result<()> {
+	let current_index = load_current_index_checked(instructions_sysvar)?;
+	require!(current_index > 0, Error::InvalidAuthorization);
+
+	let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+	require!(ix.program_id == ed25519_program::ID, Error::InvalidAuthorization);
+	require!(ix.accounts.is_empty(), Error::InvalidAuthorization);
+
+	let data = &ix.data;
+	require!(data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN, Error::InvalidAuthorization);
+	require!(data[0] == 1, Error::InvalidAuthorization);
+
+	let offsets = &data[SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN];
+	let signature_instruction_index = read_u16(offsets, 2);
+	let pubkey_offset = read_u16(offsets, 4) as usize;
+	let pubkey_instruction_index = read_u16(offsets, 6);
+	let message_offset = read_u16(offsets, 8) as usize;
+	let message_size = read_u16(offsets, 10) as usize;
+	let message_instruction_index = read_u16(offsets, 12);
+
+	// The signature, pubkey and message must all be read from the Ed25519 instruction itself
+	require!(
+		signature_instruction_index == u16::MAX &&
+		pubkey_instruction_index == u16::MAX &&
+		message_instruction_index == u16::MAX,
+		Error::InvalidAuthorization
+	);
+
+	let signed_pubkey = data.get(pubkey_offset..pubkey_offset + PUBKEY_LEN).ok_or(Error::InvalidAuthorization)?;
+	require!(signed_pubkey == signer.as_ref(), Error::InvalidAuthorization);
+
+	let signed_message = data.get(message_offset..message_offset + message_size).ok_or(Error::InvalidAuthorization)?;
+	require!(signed_message == message, Error::InvalidAuthorization);
+
+	Ok(())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+	u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+}
\ No newline at end of file
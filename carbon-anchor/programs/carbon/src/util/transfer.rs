@@ -2,11 +2,23 @@ use std::slice::Iter;
 use anchor_lang::prelude::*;
 use anchor_spl::metadata::{MetadataAccount};
 use anchor_spl::token;
-use anchor_spl::token::{Transfer};
+use anchor_spl::token::{CloseAccount, TokenAccount, Transfer};
 use solana_program::{account_info::AccountInfo, system_instruction};
 use solana_program::program::{invoke, invoke_signed};
 use spl_associated_token_account::instruction::create_associated_token_account;
-use crate::util::{assert_is_ata, assert_is_mint, is_native_mint, error::Error, assert_keys_equal, assert_is_metadata_account};
+use spl_token_2022::{
+	extension::{transfer_fee::{instruction::transfer_checked_with_fee, TransferFeeConfig}, BaseStateWithExtensions, StateWithExtensions},
+	instruction::transfer_checked,
+	state::Mint as SplMint
+};
+use crate::util::{assert_is_ata, assert_is_mint, assert_owned_by, is_native_mint, error::Error, assert_keys_equal, assert_is_metadata_account};
+
+/// Amounts received by each party of a payment, net of any Token-2022 transfer fees
+pub struct PaymentAmounts {
+	pub seller_amount: u64,
+	pub royalty_amount: u64,
+	pub fee_amount: u64,
+}
 
 pub fn transfer_sol<'a>(
 	from: &AccountInfo<'a>,
@@ -47,6 +59,43 @@ pub fn transfer_sol<'a>(
 	Ok(())
 }
 
+/// Returns the fee Token-2022 withholds from a transfer of the amount, 0 if the mint has no transfer fee
+pub fn get_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+	if *mint.owner != spl_token_2022::id() {
+		return Ok(0);
+	}
+
+	let data = mint.data.borrow();
+	let mint_state = StateWithExtensions::<SplMint>::unpack(&data)?;
+	let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+		Ok(config) => config
+			.calculate_epoch_fee(Clock::get()?.epoch, amount)
+			.ok_or(Error::OverflowError)?,
+		Err(_) => 0,
+	};
+
+	Ok(fee)
+}
+
+/// Reads the currency mint from the remaining accounts, followed by the Token-2022 program if it is
+/// a Token-2022 mint, and returns the mint with the token program to transfer it with
+pub fn next_currency_accounts<'a>(
+	remaining_accounts: &mut Iter<AccountInfo<'a>>,
+	token_program: &AccountInfo<'a>,
+) -> Result<(AccountInfo<'a>, AccountInfo<'a>)> {
+	let currency_mint = next_account_info(remaining_accounts)?;
+	if *currency_mint.owner != spl_token_2022::id() {
+		return Ok((currency_mint.clone(), token_program.clone()));
+	}
+
+	let currency_token_program = next_account_info(remaining_accounts)?;
+	assert_keys_equal(currency_token_program.key(), spl_token_2022::id(), "Invalid token program")?;
+
+	Ok((currency_mint.clone(), currency_token_program.clone()))
+}
+
+/// Transfers with `transfer_checked` on the token program owning the mint and returns the amount
+/// received after any Token-2022 transfer fee
 pub fn transfer_spl<'a>(
 	from: &AccountInfo<'a>,
 	to: &AccountInfo<'a>,
@@ -62,12 +111,13 @@ pub fn transfer_spl<'a>(
 	signer_seeds: Option<&[&[u8]]>,
 	fee_payer_seeds: Option<&[&[u8]]>,
 	amount: u64,
-) -> Result<()> {
+) -> Result<u64> {
 	if amount == 0 {
-		return Ok(());
+		return Ok(0);
 	}
 
-	assert_is_mint(mint)?;
+	let mint_account = assert_is_mint(mint)?;
+	assert_owned_by(mint, token_program.key)?;
 
 	if to_token_account.data_is_empty() {
 		make_ata(
@@ -89,25 +139,115 @@ pub fn transfer_spl<'a>(
 		)?;
 	}
 
-	let transfer_cpi = CpiContext::new(
+	let authority = from_authority.unwrap_or(from);
+	let fee = get_transfer_fee(mint, amount)?;
+
+	// Passing the expected fee makes Token-2022 fail the transfer rather than withhold a different one
+	let transfer_ix = if fee > 0 {
+		transfer_checked_with_fee(
+			token_program.key,
+			from_token_account.key,
+			mint.key,
+			to_token_account.key,
+			authority.key,
+			&[],
+			amount,
+			mint_account.decimals,
+			fee,
+		)?
+	} else {
+		transfer_checked(
+			token_program.key,
+			from_token_account.key,
+			mint.key,
+			to_token_account.key,
+			authority.key,
+			&[],
+			amount,
+			mint_account.decimals,
+		)?
+	};
+
+	let transfer_accounts = &[
+		from_token_account.clone(),
+		mint.clone(),
+		to_token_account.clone(),
+		authority.clone(),
+		token_program.clone(),
+	];
+
+	match signer_seeds {
+		Some(seeds) => invoke_signed(&transfer_ix, transfer_accounts, &[seeds])?,
+		None => invoke(&transfer_ix, transfer_accounts)?,
+	}
+
+	Ok(amount - fee)
+}
+
+/// Moves lamports out of an account owned by this program, which the system program can't debit
+pub fn transfer_lamports<'a>(
+	from: &AccountInfo<'a>,
+	to: &AccountInfo<'a>,
+	amount: u64,
+) -> Result<()> {
+	**from.try_borrow_mut_lamports()? = from.lamports()
+		.checked_sub(amount)
+		.ok_or(Error::OverflowError)?;
+	**to.try_borrow_mut_lamports()? = to.lamports()
+		.checked_add(amount)
+		.ok_or(Error::OverflowError)?;
+
+	Ok(())
+}
+
+/// Moves everything escrowed in a listing's token account to an existing token account and closes
+/// the escrow account to the seller
+pub fn release_escrow<'a>(
+	listing: &AccountInfo<'a>,
+	escrow_token_account: &AccountInfo<'a>,
+	to_token_account: &AccountInfo<'a>,
+	mint: &AccountInfo<'a>,
+	seller: &AccountInfo<'a>,
+	token_program: &AccountInfo<'a>,
+	signer_seeds: &[&[u8]],
+) -> Result<()> {
+	assert_is_ata(escrow_token_account, listing.key, mint.key)?;
+	let amount = Account::<TokenAccount>::try_from(escrow_token_account)?.amount;
+
+	token::transfer(
+		CpiContext::new(
+			token_program.to_account_info(),
+			Transfer {
+				from: escrow_token_account.to_account_info(),
+				to: to_token_account.to_account_info(),
+				authority: listing.to_account_info(),
+			},
+		).with_signer(&[signer_seeds]),
+		amount,
+	)?;
+
+	close_token_account(escrow_token_account, seller, listing, token_program, Some(signer_seeds))
+}
+
+pub fn close_token_account<'a>(
+	token_account: &AccountInfo<'a>,
+	destination: &AccountInfo<'a>,
+	authority: &AccountInfo<'a>,
+	token_program: &AccountInfo<'a>,
+	signer_seeds: Option<&[&[u8]]>,
+) -> Result<()> {
+	let close_cpi = CpiContext::new(
 		token_program.to_account_info(),
-		Transfer {
-			from: from_token_account.to_account_info(),
-			to: to_token_account.to_account_info(),
-			authority: if from_authority.is_some() { from_authority.unwrap().to_account_info() } else { from.to_account_info() },
+		CloseAccount {
+			account: token_account.to_account_info(),
+			destination: destination.to_account_info(),
+			authority: authority.to_account_info(),
 		},
 	);
 
-	if signer_seeds.is_none() {
-		token::transfer(
-			transfer_cpi,
-			amount,
-		)?;
-	} else {
-		token::transfer(
-			transfer_cpi.with_signer(&[signer_seeds.unwrap()]),
-			amount,
-		)?;
+	match signer_seeds {
+		Some(seeds) => token::close_account(close_cpi.with_signer(&[seeds]))?,
+		None => token::close_account(close_cpi)?,
 	}
 
 	Ok(())
@@ -152,7 +292,8 @@ pub fn make_ata<'a>(
 	Ok(())
 }
 
-/// Pays creator fees to the creators in the metadata and returns total paid
+/// Pays creator fees to the creators in the metadata and returns total paid and total received
+/// by the creators after any transfer fees
 pub fn pay_creator_fees<'a>(
 	from: &AccountInfo<'a>,
 	currency_mint: Pubkey,
@@ -167,21 +308,21 @@ pub fn pay_creator_fees<'a>(
 	signer_seeds: Option<&[&[u8]]>,
 	fee_payer_seeds: Option<&[&[u8]]>,
 	buy_price: u64,
-) -> Result<u64> {
+) -> Result<(u64, u64)> {
 	let metadata = Account::<'a, MetadataAccount>::try_from(metadata_account)?;
 	if metadata.data.seller_fee_basis_points == 0 {
-		return Ok(0);
+		return Ok((0, 0));
 	}
 
 	if metadata.data.creators.is_none() {
-		return Ok(0);
+		return Ok((0, 0));
 	}
 
 	assert_is_metadata_account(metadata_account.key(), mint.key())?;
 
 	let creators = metadata.data.creators.as_ref().unwrap();
 	if creators.is_empty() {
-		return Ok(0);
+		return Ok((0, 0));
 	}
 
 	let total_royalty = (metadata.data.seller_fee_basis_points as u128)
@@ -191,13 +332,14 @@ pub fn pay_creator_fees<'a>(
 		.ok_or(Error::OverflowError)? as u64;
 
 	let mut total_paid = 0;
+	let mut total_received = 0;
 	let is_native = is_native_mint(currency_mint);
 
-	let (currency_mint_account, from_currency_account) = if is_native {
+	let (currency_accounts, from_currency_account) = if is_native {
 		(None, None)
 	} else {
 		(
-			Some(next_account_info(remaining_accounts)?),
+			Some(next_currency_accounts(remaining_accounts, token_program)?),
 			Some(next_account_info(remaining_accounts)?)
 		)
 	};
@@ -218,7 +360,7 @@ pub fn pay_creator_fees<'a>(
 
 		msg!("Disbursing royalty of {} to {}", creator_fee, creator.address);
 
-		if is_native {
+		let received = if is_native {
 			transfer_sol(
 				from,
 				current_creator_info,
@@ -226,34 +368,93 @@ pub fn pay_creator_fees<'a>(
 				signer_seeds,
 				creator_fee,
 			)?;
+			creator_fee
 		} else {
+			let (currency_mint_account, currency_token_program) = currency_accounts.as_ref().unwrap();
 			let current_creator_token_account_info = next_account_info(remaining_accounts)?;
 			transfer_spl(
 				from,
 				current_creator_info,
 				from_currency_account.unwrap(),
 				current_creator_token_account_info,
-				currency_mint_account.unwrap(),
+				currency_mint_account,
 				fee_payer.unwrap(),
 				ata_program,
-				token_program,
+				currency_token_program,
 				system_program,
 				rent,
 				None,
 				signer_seeds,
 				fee_payer_seeds,
 				creator_fee,
-			)?;
+			)?
 		};
 
+		total_paid += creator_fee;
+		total_received += received;
+	}
+
+	Ok((total_paid, total_received))
+}
+
+/// Splits a fixed amount of lamports between the creators in the metadata by share and returns total paid
+pub fn pay_creator_lamports<'a>(
+	from: &AccountInfo<'a>,
+	mint: &AccountInfo<'a>,
+	metadata_account: &AccountInfo<'a>,
+	remaining_accounts: &mut Iter<AccountInfo<'a>>,
+	system_program: &AccountInfo<'a>,
+	amount: u64,
+) -> Result<u64> {
+	if amount == 0 {
+		return Ok(0);
+	}
+
+	assert_is_metadata_account(metadata_account.key(), mint.key())?;
+
+	let metadata = Account::<'a, MetadataAccount>::try_from(metadata_account)?;
+	let creators = match metadata.data.creators.as_ref() {
+		Some(creators) => creators,
+		None => return Ok(0),
+	};
+
+	let mut total_paid = 0;
+	for creator in creators {
+		let creator_fee = (creator.share as u128)
+			.checked_mul(amount as u128)
+			.ok_or(Error::OverflowError)?
+			.checked_div(100)
+			.ok_or(Error::OverflowError)? as u64;
+
+		if creator_fee == 0 {
+			continue;
+		}
+
+		let current_creator_info = next_account_info(remaining_accounts)?;
+		assert_keys_equal(creator.address, current_creator_info.key(), "Invalid creator key")?;
+
+		msg!("Disbursing royalty of {} to {}", creator_fee, creator.address);
+
+		transfer_sol(
+			from,
+			current_creator_info,
+			system_program,
+			None,
+			creator_fee,
+		)?;
+
 		total_paid += creator_fee;
 	}
 
 	Ok(total_paid)
 }
 
+/// Pays the creators, marketplace fee account and seller their splits of the price. Each party
+/// bears the Token-2022 transfer fee withheld from its own split. Currency accounts that don't
+/// exist yet are created at the fee payer's expense.
 pub fn transfer_payment<'a, 'b>(
 	buyer: &AccountInfo<'a>,
+	fee_payer: &AccountInfo<'a>,
 	seller: &AccountInfo<'a>,
 	fee_account: &AccountInfo<'a>,
 	mint: &AccountInfo<'a>,
@@ -266,13 +467,13 @@ pub fn transfer_payment<'a, 'b>(
 	remaining_accounts: &'b [AccountInfo<'a>],
 	price: u64,
 	marketplace_fees: u64
-) -> Result<()> {
+) -> Result<PaymentAmounts> {
 	let remaining_accounts_clone = &mut remaining_accounts.iter().clone();
 
-	let creator_fees = pay_creator_fees(
+	let (creator_fees, royalty_amount) = pay_creator_fees(
 		&buyer.to_account_info(),
 		currency_mint_key,
-		Some(&buyer.to_account_info()),
+		Some(&fee_payer.to_account_info()),
 		&mint.to_account_info(),
 		&metadata_account.to_account_info(),
 		remaining_accounts_clone,
@@ -291,7 +492,7 @@ pub fn transfer_payment<'a, 'b>(
 		.checked_sub(creator_fees)
 		.ok_or(Error::OverflowError)?;
 
-	if is_native_mint(currency_mint_key) {
+	let (seller_received, fee_received) = if is_native_mint(currency_mint_key) {
 		transfer_sol(
 			&buyer.to_account_info(),
 			&fee_account.to_account_info(),
@@ -307,9 +508,14 @@ pub fn transfer_payment<'a, 'b>(
 			None,
 			seller_amount
 		)?;
+
+		(seller_amount, marketplace_fees)
 	} else {
 		let remaining_accounts_clone = &mut remaining_accounts.iter().clone();
-		let currency_mint = next_account_info(remaining_accounts_clone)?;
+		let (currency_mint, currency_token_program) = &next_currency_accounts(
+			remaining_accounts_clone,
+			token_program
+		)?;
 		assert_keys_equal(currency_mint_key, currency_mint.key(), "Invalid currency mint")?;
 		let buyer_currency_account = next_account_info(remaining_accounts_clone)?;
 		let marketplace_authority = next_account_info(remaining_accounts_clone)?;
@@ -322,15 +528,15 @@ pub fn transfer_payment<'a, 'b>(
 			next_account_info(remaining_accounts_clone)?
 		};
 
-		transfer_spl(
+		let fee_received = transfer_spl(
 			&buyer.to_account_info(),
 			&fee_account.to_account_info(),
 			buyer_currency_account,
 			fee_currency_account,
 			currency_mint,
-			&buyer.to_account_info(),
+			&fee_payer.to_account_info(),
 			&associated_token_program.to_account_info(),
-			&token_program.to_account_info(),
+			currency_token_program,
 			&system_program.to_account_info(),
 			&rent.to_account_info(),
 			None,
@@ -339,15 +545,15 @@ pub fn transfer_payment<'a, 'b>(
 			marketplace_fees
 		)?;
 
-		transfer_spl(
+		let seller_received = transfer_spl(
 			&buyer.to_account_info(),
 			&seller.to_account_info(),
 			buyer_currency_account,
 			seller_currency_account,
 			currency_mint,
-			&buyer.to_account_info(),
+			&fee_payer.to_account_info(),
 			&associated_token_program.to_account_info(),
-			&token_program.to_account_info(),
+			currency_token_program,
 			&system_program.to_account_info(),
 			&rent.to_account_info(),
 			None,
@@ -355,7 +561,13 @@ pub fn transfer_payment<'a, 'b>(
 			None,
 			seller_amount
 		)?;
-	}
-	
-	Ok(())
+
+		(seller_received, fee_received)
+	};
+
+	Ok(PaymentAmounts {
+		seller_amount: seller_received,
+		royalty_amount,
+		fee_amount: fee_received,
+	})
 }
\ No newline at end of file
@@ -12,18 +12,33 @@ import {
 	assertThrows,
 	createCollectionNFT,
 	createNFT,
+	createSFT,
 	createSplToken,
+	createTransferFeeToken,
 	createVirtualItemId,
 	fetchNFT,
 	setBalance,
+	signMessage,
+	waitForSlot,
 } from "./helpers";
 import moment from "moment";
-import { Carbon, FEE_ACCOUNT_KEY } from "@raresloth/carbon-sdk";
 import {
+	Authorization,
+	Carbon,
+	FEE_ACCOUNT_KEY,
+	getAuthorizationMessage,
+	getMintVoucherMessage,
+	hashMetadata,
+	hashSeed,
+	MintVoucher,
+} from "@raresloth/carbon-sdk";
+import {
+	approve,
 	createAssociatedTokenAccount,
 	getAccount,
 	getAssociatedTokenAddressSync,
 	NATIVE_MINT,
+	TOKEN_2022_PROGRAM_ID,
 	transferChecked,
 } from "@solana/spl-token";
 import { assert } from "chai";
@@ -37,9 +52,13 @@ describe("carbon", () => {
 		feeAccount: FEE_ACCOUNT_KEY,
 		bps: 200,
 	};
+	const defaultKeeperReward = 0.001 * LAMPORTS_PER_SOL;
 	const defaultSellerFeeBps = 500;
 	const defaultSymbol = "KR";
-	const mintRecordStorageFee = 1614720;
+	const defaultSwapRoyaltyPolicy = { none: {} };
+	const defaultMintStandard = { nonFungible: {} };
+	const defaultVerification = { verifiedCollection: {} };
+	const mintRecordStorageFee = 1837440;
 
 	let provider: AnchorProvider;
 	let marketplaceAuthority: Keypair;
@@ -98,6 +117,7 @@ describe("carbon", () => {
 			await carbon.methods.initMarketplaceConfig({
 				args: {
 					feeConfig: defaultFeeConfig,
+					keeperReward: defaultKeeperReward,
 				},
 			});
 
@@ -112,6 +132,7 @@ describe("carbon", () => {
 				defaultFeeConfig.feeAccount.toString()
 			);
 			assert.equal(marketplaceConfig.feeConfig.bps, defaultFeeConfig.bps);
+			assert.equal(marketplaceConfig.keeperReward.toNumber(), defaultKeeperReward);
 		});
 	});
 
@@ -122,6 +143,9 @@ describe("carbon", () => {
 					collectionMint,
 					sellerFeeBasisPoints: defaultSellerFeeBps,
 					symbol: defaultSymbol,
+					swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+					mintStandard: defaultMintStandard,
+					verification: defaultVerification,
 				},
 			});
 
@@ -134,6 +158,9 @@ describe("carbon", () => {
 			assert.equal(collectionConfig.collectionMint.toString(), collectionMint.toString());
 			assert.equal(collectionConfig.sellerFeeBasisPoints, defaultSellerFeeBps);
 			assert.equal(collectionConfig.symbol, defaultSymbol);
+			assert.deepEqual(collectionConfig.swapRoyaltyPolicy, defaultSwapRoyaltyPolicy);
+			assert.deepEqual(collectionConfig.mintStandard, defaultMintStandard);
+			assert.deepEqual(collectionConfig.verification, defaultVerification);
 		});
 	});
 
@@ -144,6 +171,7 @@ describe("carbon", () => {
 				carbon.methods.initMarketplaceConfig({
 					args: {
 						feeConfig: defaultFeeConfig,
+						keeperReward: defaultKeeperReward,
 					},
 				}),
 				carbon.methods.initCollectionConfig({
@@ -151,6 +179,9 @@ describe("carbon", () => {
 						collectionMint,
 						sellerFeeBasisPoints: defaultSellerFeeBps,
 						symbol: defaultSymbol,
+						swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+						mintStandard: defaultMintStandard,
+						verification: defaultVerification,
 					},
 				}),
 				createNFT(provider, marketplaceAuthority, collectionMint, {
@@ -177,7 +208,7 @@ describe("carbon", () => {
 				});
 
 				const listing = await program.account.listing.fetch(listingPDA);
-				assert.equal(listing.version, 1);
+				assert.equal(listing.version, 2);
 				assert.equal(listing.seller.toString(), seller.publicKey.toString());
 				assert.deepEqual(listing.itemId, itemId);
 				assert.equal(listing.isVirtual, false);
@@ -189,6 +220,8 @@ describe("carbon", () => {
 					defaultFeeConfig.feeAccount.toString()
 				);
 				assert.equal(listing.feeConfig.bps, defaultFeeConfig.bps);
+				assert.equal(listing.namespace.toString(), marketplaceConfigPDA.toString());
+				assert.equal(listing.isEscrowed, false);
 
 				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
 				assert.equal(sellerTokenAccountObj.delegate.toString(), listingPDA.toString());
@@ -210,7 +243,7 @@ describe("carbon", () => {
 				});
 
 				const listing = await program.account.listing.fetch(listingPDA);
-				assert.equal(listing.version, 1);
+				assert.equal(listing.version, 2);
 				assert.equal(listing.seller.toString(), seller.publicKey.toString());
 				assert.deepEqual(listing.itemId, itemId);
 				assert.equal(listing.isVirtual, false);
@@ -231,6 +264,36 @@ describe("carbon", () => {
 				assert.isTrue(sellerTokenAccountObj.isFrozen);
 			});
 
+			it("should list the nft with a start time", async function () {
+				const startTime = moment().add(1, "hour").unix();
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+					startTime,
+				});
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.startTime.toNumber(), startTime);
+				assert.equal(listing.expiry.toNumber(), expiry);
+			});
+
+			it("should throw when start time is not before expiry", async function () {
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							mint,
+							collectionMint,
+							price,
+							expiry,
+							startTime: expiry,
+						})
+				);
+			});
+
 			it("should throw when custody account key is incorrect", async function () {
 				await assertThrows(
 					async () =>
@@ -246,6 +309,74 @@ describe("carbon", () => {
 						})
 				);
 			});
+
+			async function initCollectionConfigWithVerification(verification: any) {
+				const configCollectionMint = Keypair.generate().publicKey;
+				await carbon.methods.initCollectionConfig({
+					args: {
+						collectionMint: configCollectionMint,
+						sellerFeeBasisPoints: defaultSellerFeeBps,
+						symbol: defaultSymbol,
+						swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+						mintStandard: defaultMintStandard,
+						verification,
+					},
+				});
+				return configCollectionMint;
+			}
+
+			it("should list the nft verified by creator", async function () {
+				const configCollectionMint = await initCollectionConfigWithVerification({
+					verifiedCreator: { creator: marketplaceAuthority.publicKey },
+				});
+
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint: configCollectionMint,
+					price,
+					expiry,
+				});
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.deepEqual(listing.itemId, itemId);
+				assert.equal(listing.collectionMint.toString(), configCollectionMint.toString());
+			});
+
+			it("should throw when the creator is not the first verified creator", async function () {
+				const configCollectionMint = await initCollectionConfigWithVerification({
+					verifiedCreator: { creator: seller.publicKey },
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							mint,
+							collectionMint: configCollectionMint,
+							price,
+							expiry,
+						})
+				);
+			});
+
+			it("should throw when the mint is not in the merkle root", async function () {
+				const configCollectionMint = await initCollectionConfigWithVerification({
+					merkleRoot: { root: Array(32).fill(0) },
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							mint,
+							collectionMint: configCollectionMint,
+							price,
+							expiry,
+							proof: [],
+						})
+				);
+			});
 		});
 
 		describe("update_listing", function () {
@@ -376,6 +507,82 @@ describe("carbon", () => {
 			});
 		});
 
+		describe("close_expired_listing", function () {
+			it("should close the expired listing and thaw the nft", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry: moment().add(2, "seconds").unix(),
+				});
+
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				const keeper = Keypair.generate();
+				await setBalance(provider, keeper, LAMPORTS_PER_SOL);
+				const keeperBalanceBefore = await provider.connection.getBalance(keeper.publicKey);
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				await carbon.methods.closeExpiredListing({
+					keeper: new Wallet(keeper),
+					listing,
+				});
+
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isFalse(sellerTokenAccountObj.isFrozen);
+
+				const keeperBalanceAfter = await provider.connection.getBalance(keeper.publicKey);
+				assert.equal(keeperBalanceAfter, keeperBalanceBefore + defaultKeeperReward - TX_FEE);
+			});
+
+			it("should close the expired custodial listing correctly", async function () {
+				await carbon.methods.custody({
+					owner: new Wallet(seller),
+					mint,
+					itemId,
+				});
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry: moment().add(2, "seconds").unix(),
+				});
+
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				await carbon.methods.closeExpiredListing({ listing });
+
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(sellerTokenAccountObj.delegate.toString(), custodyAccountPDA.toString());
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				assert.isFalse(custodyAccount.isListed);
+			});
+
+			it("should throw when listing is not expired", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+				});
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				await assertThrows(async () => await carbon.methods.closeExpiredListing({ listing }));
+			});
+		});
+
 		describe("buy_nft", function () {
 			it("should buy the nft correctly", async function () {
 				const sellerPreBalance = await provider.connection.getBalance(seller.publicKey);
@@ -495,6 +702,57 @@ describe("carbon", () => {
 				assert.equal(feeAccountPostBalance.value.uiAmount, marketplaceFee);
 			});
 
+			it("should buy the nft with a Token-2022 transfer fee currency correctly", async function () {
+				price = 1000;
+				const transferFeeBps = 100;
+				const { mint: splTokenMint } = await createTransferFeeToken(
+					provider,
+					marketplaceAuthority,
+					buyer.publicKey,
+					price,
+					transferFeeBps
+				);
+
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+					currencyMint: splTokenMint,
+				});
+				const listing = await program.account.listing.fetch(listingPDA);
+
+				await carbon.methods.buyNft({
+					buyer: new Wallet(buyer),
+					listing,
+				});
+
+				const balanceOf = async (owner: PublicKey) =>
+					(
+						await provider.connection.getTokenAccountBalance(
+							getAssociatedTokenAddressSync(splTokenMint, owner, false, TOKEN_2022_PROGRAM_ID)
+						)
+					).value.uiAmount;
+
+				// Each party should receive its split less the fee withheld from its own transfer
+				const transferFee = (amount: number) => Math.ceil((amount * transferFeeBps) / 10000);
+				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
+				const royalty = (price * defaultSellerFeeBps) / 10000;
+				const sellerAmount = price - marketplaceFee - royalty;
+
+				assert.equal(await balanceOf(buyer.publicKey), 0);
+				assert.equal(await balanceOf(seller.publicKey), sellerAmount - transferFee(sellerAmount));
+				assert.equal(
+					await balanceOf(marketplaceAuthority.publicKey),
+					royalty - transferFee(royalty)
+				);
+				assert.equal(
+					await balanceOf(FEE_ACCOUNT_KEY),
+					marketplaceFee - transferFee(marketplaceFee)
+				);
+			});
+
 			it("should buy the custodial nft correctly", async function () {
 				const sellerPreBalance = await provider.connection.getBalance(seller.publicKey);
 				await carbon.methods.custody({
@@ -594,6 +852,127 @@ describe("carbon", () => {
 					0
 				);
 			});
+
+			it("should throw when the listing has not started", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+					startTime: moment().add(1, "hour").unix(),
+				});
+				const listing = await program.account.listing.fetch(listingPDA);
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.buyNft({
+							buyer: new Wallet(buyer),
+							listing,
+						})
+				);
+			});
+
+			describe("with a price feed", function () {
+				let priceFeed: PublicKey;
+				// $10.00 in micro-USD
+				const referencePrice = 10_000_000;
+				// $20.00 per SOL with 8 decimals converts the reference price to 0.5 SOL
+				const feedPrice = 20_00000000;
+				const convertedPrice = LAMPORTS_PER_SOL / 2;
+
+				beforeEach(async function () {
+					priceFeed = carbon.pdas.priceFeed(marketplaceAuthority.publicKey, NATIVE_MINT);
+					await carbon.methods.initPriceFeed({ currencyMint: NATIVE_MINT });
+					await carbon.methods.updatePriceFeed({
+						currencyMint: NATIVE_MINT,
+						price: feedPrice,
+						conf: 1_000000,
+						expo: -8,
+					});
+				});
+
+				it("should buy the nft at the converted price", async function () {
+					const sellerPreBalance = await provider.connection.getBalance(seller.publicKey);
+					await carbon.methods.listNft({
+						seller: new Wallet(seller),
+						mint,
+						collectionMint,
+						price: referencePrice,
+						expiry,
+						priceFeed,
+					});
+					const listing = await program.account.listing.fetch(listingPDA);
+					assert.equal(listing.priceFeed.toString(), priceFeed.toString());
+					assert.equal(listing.currencyDecimals, 9);
+
+					const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+					await carbon.methods.buyNft({
+						buyer: new Wallet(buyer),
+						listing,
+						maxPrice: LAMPORTS_PER_SOL,
+					});
+					const sellerPostBalance = await provider.connection.getBalance(seller.publicKey);
+					const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+					const marketplaceFee = (convertedPrice * defaultFeeConfig.bps) / 10000;
+					const royalty = (convertedPrice * defaultSellerFeeBps) / 10000;
+					assert.equal(
+						sellerPostBalance - sellerPreBalance,
+						convertedPrice - marketplaceFee - royalty - TX_FEE
+					);
+					assert.equal(feeAccountPostBalance - feeAccountPreBalance, marketplaceFee);
+				});
+
+				it("should throw when the converted price exceeds the max price", async function () {
+					await carbon.methods.listNft({
+						seller: new Wallet(seller),
+						mint,
+						collectionMint,
+						price: referencePrice,
+						expiry,
+						priceFeed,
+					});
+					const listing = await program.account.listing.fetch(listingPDA);
+
+					await assertThrows(
+						async () =>
+							await carbon.methods.buyNft({
+								buyer: new Wallet(buyer),
+								listing,
+								maxPrice: convertedPrice - 1,
+							})
+					);
+				});
+
+				it("should throw when the price confidence is too wide", async function () {
+					await carbon.methods.listNft({
+						seller: new Wallet(seller),
+						mint,
+						collectionMint,
+						price: referencePrice,
+						expiry,
+						priceFeed,
+					});
+					const listing = await program.account.listing.fetch(listingPDA);
+
+					await carbon.methods.updatePriceFeed({
+						currencyMint: NATIVE_MINT,
+						price: feedPrice,
+						conf: feedPrice / 10,
+						expo: -8,
+					});
+
+					await assertThrows(
+						async () =>
+							await carbon.methods.buyNft({
+								buyer: new Wallet(buyer),
+								listing,
+								maxPrice: LAMPORTS_PER_SOL,
+							})
+					);
+				});
+			});
 		});
 
 		describe("custody", function () {
@@ -699,44 +1078,45 @@ describe("carbon", () => {
 			});
 		});
 
-		describe("take_ownership", function () {
-			it("should take ownership of the nft correctly", async function () {
-				await carbon.methods.custody({
-					owner: new Wallet(seller),
-					mint,
-					itemId,
+		describe("escrowed nft", function () {
+			let listingTokenAccount: PublicKey;
+
+			beforeEach(setUpData);
+			async function setUpData() {
+				// Without a master edition the NFT can't be frozen, so listing it escrows it
+				const sft = await createSFT(provider, marketplaceAuthority, collectionMint, {
+					tokenOwner: seller.publicKey,
 				});
+				mint = sft.mint;
+				itemId = Array.from(mint.toBuffer());
+				sellerTokenAccount = getAssociatedTokenAddressSync(mint, seller.publicKey);
+				listingPDA = carbon.pdas.listing(itemId);
+				listingTokenAccount = getAssociatedTokenAddressSync(mint, listingPDA, true);
+				custodyAccountPDA = carbon.pdas.custodyAccount(mint);
+			}
 
-				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
-				await carbon.methods.takeOwnership({
-					custodyAccount,
+			it("should move the nft to the listing when listing", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
 				});
 
-				// Custody account should no longer exist
-				await assertThrows(
-					async () => await program.account.custodyAccount.fetch(custodyAccountPDA)
-				);
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.isTrue(listing.isEscrowed);
 
-				// Should now be owned by the marketplace authority
-				const marketplaceTokenAccount = getAssociatedTokenAddressSync(
-					mint,
-					marketplaceAuthority.publicKey
-				);
-				const marketplaceTokenAccountObj = await getAccount(
-					provider.connection,
-					marketplaceTokenAccount
-				);
-				assert.equal(marketplaceTokenAccountObj.amount.toString(), "1");
-				assert.isNull(marketplaceTokenAccountObj.delegate);
-				assert.isFalse(marketplaceTokenAccountObj.isFrozen);
+				const listingTokenAccountObj = await getAccount(provider.connection, listingTokenAccount);
+				assert.equal(Number(listingTokenAccountObj.amount), 1);
+				assert.equal(listingTokenAccountObj.owner.toString(), listingPDA.toString());
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(Number(sellerTokenAccountObj.amount), 0);
+				assert.isFalse(sellerTokenAccountObj.isFrozen);
 			});
 
-			it("should throw when nft is listed", async function () {
-				await carbon.methods.custody({
-					owner: new Wallet(seller),
-					mint,
-					itemId,
-				});
+			it("should release the escrowed nft to the buyer", async function () {
 				await carbon.methods.listNft({
 					seller: new Wallet(seller),
 					mint,
@@ -744,192 +1124,1924 @@ describe("carbon", () => {
 					price,
 					expiry,
 				});
-				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
-				await assertThrows(
-					async () =>
-						await carbon.methods.takeOwnership({
-							custodyAccount,
+				const listing = await program.account.listing.fetch(listingPDA);
+
+				await carbon.methods.buyNft({
+					buyer: new Wallet(buyer),
+					listing,
+				});
+
+				// Listing and its token account should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+				assert.isNull(await provider.connection.getAccountInfo(listingTokenAccount));
+
+				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
+				const buyerTokenAccountObj = await getAccount(provider.connection, buyerTokenAccount);
+				assert.equal(Number(buyerTokenAccountObj.amount), 1);
+				assert.isFalse(buyerTokenAccountObj.isFrozen);
+			});
+
+			it("should return the escrowed nft to the seller when delisting", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await carbon.methods.delistNft({
+					seller: new Wallet(seller),
+					mint,
+					isEscrowed: true,
+				});
+
+				// Listing and its token account should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+				assert.isNull(await provider.connection.getAccountInfo(listingTokenAccount));
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(Number(sellerTokenAccountObj.amount), 1);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.isFalse(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should throw when a session key delists the escrowed nft to its own account", async function () {
+				const sessionKey = Keypair.generate();
+				await setBalance(provider, sessionKey, LAMPORTS_PER_SOL);
+				await createAssociatedTokenAccount(provider.connection, sessionKey, mint, sessionKey.publicKey);
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope: { list: true, updateListing: true, delist: true, custody: true },
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.delistNft({
+							seller: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							tokenOwner: sessionKey.publicKey,
+							mint,
+							isEscrowed: true,
 						})
 				);
 			});
 		});
-	});
 
-	describe("virtual flows", function () {
-		beforeEach(setUpData);
+		describe("programmable nft", function () {
+			const programmable = { ruleSet: PublicKey.default };
 
-		async function setUpData() {
-			await Promise.all([
-				carbon.methods.initMarketplaceConfig({
-					args: {
-						feeConfig: defaultFeeConfig,
-					},
-				}),
-				carbon.methods.initCollectionConfig({
+			beforeEach(setUpData);
+			async function setUpData() {
+				// Programmable NFTs are minted through a collection that opts in to the standard
+				const collectionNft = await createCollectionNFT(
+					provider,
+					marketplaceAuthority,
+					marketplaceAuthority
+				);
+				collectionMint = collectionNft.mint;
+				collectionConfigPDA = carbon.pdas.collectionConfig(collectionMint);
+				await carbon.methods.initCollectionConfig({
 					args: {
 						collectionMint,
 						sellerFeeBasisPoints: defaultSellerFeeBps,
 						symbol: defaultSymbol,
+						swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+						mintStandard: { programmableNonFungible: programmable },
+						verification: defaultVerification,
 					},
-				}),
-			]);
-
-			itemId = createVirtualItemId();
-			listingPDA = carbon.pdas.listing(itemId);
-		}
+				});
 
-		describe("list_virtual", function () {
-			it("should list the virtual item correctly", async function () {
-				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: seller.publicKey,
+					itemId: createVirtualItemId(),
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, seller]);
 
-				const listing = await program.account.listing.fetch(listingPDA);
-				assert.equal(listing.version, 1);
-				assert.equal(listing.seller.toString(), marketplaceAuthority.publicKey.toString());
-				assert.deepEqual(listing.itemId, itemId);
-				assert.equal(listing.isVirtual, true);
-				assert.equal(listing.currencyMint.toString(), currencyMint.toString());
-				assert.equal(listing.price.toNumber(), price);
-				assert.equal(listing.expiry.toNumber(), expiry);
-				assert.equal(listing.feeConfig.feeAccount.toString(), FEE_ACCOUNT_KEY.toString());
-				assert.equal(listing.feeConfig.bps, defaultFeeConfig.bps);
-			});
+				mint = mintKeypair.publicKey;
+				itemId = Array.from(mint.toBuffer());
+				sellerTokenAccount = getAssociatedTokenAddressSync(mint, seller.publicKey);
+				listingPDA = carbon.pdas.listing(itemId);
+				custodyAccountPDA = carbon.pdas.custodyAccount(mint);
+			}
 
-			it("should list the virtual item correctly as a third-party seller", async function () {
-				const listTx = await carbon.transactions.listVirtual({
-					seller: seller.publicKey,
-					itemId,
+			it("should list and buy the programmable nft", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
 					collectionMint,
 					price,
 					expiry,
+					programmable,
 				});
-				await provider.sendAndConfirm(listTx, [seller]);
 
-				const listing = await program.account.listing.fetch(listingPDA);
-				assert.equal(listing.version, 1);
-				assert.equal(listing.seller.toString(), seller.publicKey.toString());
-				assert.deepEqual(listing.itemId, itemId);
-				assert.equal(listing.isVirtual, true);
-				assert.equal(listing.currencyMint.toString(), currencyMint.toString());
-				assert.equal(listing.price.toNumber(), price);
-				assert.equal(listing.expiry.toNumber(), expiry);
-				assert.equal(listing.feeConfig.feeAccount.toString(), FEE_ACCOUNT_KEY.toString());
-				assert.equal(listing.feeConfig.bps, defaultFeeConfig.bps);
+				let listing = await program.account.listing.fetch(listingPDA);
+				assert.isFalse(listing.isEscrowed);
+
+				// The listing is the sale delegate of the NFT, which stays in the seller's wallet
+				let sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(sellerTokenAccountObj.delegate.toString(), listingPDA.toString());
+				assert.equal(Number(sellerTokenAccountObj.amount), 1);
+
+				await carbon.methods.buyNft({
+					buyer: new Wallet(buyer),
+					listing,
+					programmable,
+				});
+
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+
+				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
+				const buyerTokenAccountObj = await getAccount(provider.connection, buyerTokenAccount);
+				assert.equal(Number(buyerTokenAccountObj.amount), 1);
+				assert.isNull(buyerTokenAccountObj.delegate);
+
+				sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(Number(sellerTokenAccountObj.amount), 0);
 			});
 
-			it("should throw when seller is not a signer", async function () {
-				const listTx = await carbon.transactions.listVirtual({
-					seller: seller.publicKey,
-					itemId,
+			it("should list and delist the programmable nft", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
 					collectionMint,
 					price,
 					expiry,
+					programmable,
 				});
-
-				await assertThrows(async () => await provider.sendAndConfirm(listTx, []));
-			});
-		});
-
-		describe("update_listing", function () {
-			it("should update the listing correctly", async function () {
-				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
-
-				const newPrice = price / 2;
-				const newExpiry = 0;
-				await carbon.methods.updateListing({
-					listing: listingPDA,
-					price: newPrice,
-					expiry: newExpiry,
+				await carbon.methods.delistNft({
+					seller: new Wallet(seller),
+					mint,
+					programmable,
 				});
 
-				const listing = await program.account.listing.fetch(listingPDA);
-				assert.equal(listing.price.toNumber(), newPrice);
-				assert.equal(listing.expiry.toNumber(), newExpiry);
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+
+				// Programmable NFTs are always frozen, only the sale delegation is revoked
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+				assert.equal(Number(sellerTokenAccountObj.amount), 1);
 			});
 
-			it("should throw when listing is expired", async function () {
-				await carbon.methods.listVirtual({
-					itemId,
+			it("should revoke the sale delegation when the seller closes the expired listing", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
 					collectionMint,
 					price,
-					expiry: (expiry = moment().add(2, "seconds").unix()),
+					expiry: moment().add(2, "seconds").unix(),
+					programmable,
 				});
 
 				await new Promise((resolve) => setTimeout(resolve, 3000));
 
-				await assertThrows(
-					async () =>
-						await carbon.methods.updateListing({
-							listing: listingPDA,
-							price: price / 2,
-							expiry: 0,
-						})
-				);
-			});
-		});
-
-		describe("delist_virtual", function () {
-			it("should delist the virtual item correctly", async function () {
-				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
-				await carbon.methods.delistVirtual({ itemId });
+				const listing = await program.account.listing.fetch(listingPDA);
+				await carbon.methods.closeExpiredListing({
+					keeper: new Wallet(seller),
+					listing,
+					programmable,
+				});
 
 				// Listing should no longer exist
 				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.equal(Number(sellerTokenAccountObj.amount), 1);
 			});
 
-			it("should delist the virtual item correctly with a third-party seller", async function () {
-				const listTx = await carbon.transactions.listVirtual({
-					seller: seller.publicKey,
-					itemId,
+			it("should close the expired listing without the seller's signature", async function () {
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
 					collectionMint,
 					price,
-					expiry,
+					expiry: moment().add(2, "seconds").unix(),
+					programmable,
 				});
-				await provider.sendAndConfirm(listTx, [seller]);
 
-				await carbon.methods.delistVirtual({
-					seller: new Wallet(seller),
-					itemId,
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				await carbon.methods.closeExpiredListing({
+					listing,
+					programmable,
 				});
 
 				// Listing should no longer exist
 				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
 			});
 
-			it("should throw when delisting as a different seller", async function () {
-				const listTx = await carbon.transactions.listVirtual({
-					seller: seller.publicKey,
+			it("should custody and uncustody the programmable nft", async function () {
+				await carbon.methods.custody({
+					owner: new Wallet(seller),
+					mint,
 					itemId,
-					collectionMint,
-					price,
-					expiry,
+					programmable,
+				});
+
+				let sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(sellerTokenAccountObj.delegate.toString(), custodyAccountPDA.toString());
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				await carbon.methods.uncustody({
+					owner: new Wallet(seller),
+					custodyAccount,
+					programmable,
 				});
-				await provider.sendAndConfirm(listTx, [seller]);
 
+				// Custody account should no longer exist
 				await assertThrows(
-					async () =>
-						await carbon.methods.delistVirtual({
-							seller: new Wallet(marketplaceAuthority),
-							itemId,
-						})
+					async () => await program.account.custodyAccount.fetch(custodyAccountPDA)
 				);
+
+				sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.equal(Number(sellerTokenAccountObj.amount), 1);
 			});
 		});
 
-		describe("buy_virtual", function () {
-			it("should buy the virtual item correctly", async function () {
-				const marketplaceAuthPreBalance = await provider.connection.getBalance(
-					marketplaceAuthority.publicKey
-				);
-				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
-				const listing = await program.account.listing.fetch(listingPDA);
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+		describe("take_ownership", function () {
+			it("should take ownership of the nft correctly", async function () {
+				await carbon.methods.custody({
+					owner: new Wallet(seller),
+					mint,
+					itemId,
+				});
 
-				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
-				const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
-				const { mint: mintKeypair, transaction } = await carbon.transactions.buyVirtual({
-					buyer: buyer.publicKey,
-					collectionConfig,
-					listing,
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				await carbon.methods.takeOwnership({
+					custodyAccount,
+				});
+
+				// Custody account should no longer exist
+				await assertThrows(
+					async () => await program.account.custodyAccount.fetch(custodyAccountPDA)
+				);
+
+				// Should now be owned by the marketplace authority
+				const marketplaceTokenAccount = getAssociatedTokenAddressSync(
+					mint,
+					marketplaceAuthority.publicKey
+				);
+				const marketplaceTokenAccountObj = await getAccount(
+					provider.connection,
+					marketplaceTokenAccount
+				);
+				assert.equal(marketplaceTokenAccountObj.amount.toString(), "1");
+				assert.isNull(marketplaceTokenAccountObj.delegate);
+				assert.isFalse(marketplaceTokenAccountObj.isFrozen);
+			});
+
+			it("should throw when nft is listed", async function () {
+				await carbon.methods.custody({
+					owner: new Wallet(seller),
+					mint,
+					itemId,
+				});
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+				});
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				await assertThrows(
+					async () =>
+						await carbon.methods.takeOwnership({
+							custodyAccount,
+						})
+				);
+			});
+		});
+
+		describe("swap", function () {
+			let takerMint: PublicKey;
+			let swapId: number[];
+
+			beforeEach(async function () {
+				const nft = await createNFT(provider, marketplaceAuthority, collectionMint, {
+					tokenOwner: buyer.publicKey,
+				});
+				takerMint = nft.mint;
+				swapId = createVirtualItemId();
+			});
+
+			it("should swap nfts correctly", async function () {
+				await carbon.methods.createSwap({
+					maker: new Wallet(seller),
+					swapId,
+					collectionMint,
+					makerMints: [mint],
+					takerMints: [takerMint],
+				});
+
+				const swap = await carbon.accounts.swap(seller.publicKey, swapId);
+				assert.equal(swap.maker.toString(), seller.publicKey.toString());
+				assert.equal(swap.taker.toString(), PublicKey.default.toString());
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+
+				await carbon.methods.takeSwap({ taker: new Wallet(buyer), swap });
+
+				// Swap should no longer exist
+				await assertThrows(
+					async () =>
+						await program.account.swap.fetch(carbon.pdas.swap(seller.publicKey, swapId))
+				);
+
+				const buyerTokenAccountObj = await getAccount(
+					provider.connection,
+					getAssociatedTokenAddressSync(mint, buyer.publicKey)
+				);
+				assert.equal(buyerTokenAccountObj.amount.toString(), "1");
+				assert.isFalse(buyerTokenAccountObj.isFrozen);
+
+				const sellerTakerTokenAccountObj = await getAccount(
+					provider.connection,
+					getAssociatedTokenAddressSync(takerMint, seller.publicKey)
+				);
+				assert.equal(sellerTakerTokenAccountObj.amount.toString(), "1");
+			});
+
+			it("should swap a custodial nft for sol correctly", async function () {
+				await carbon.methods.custody({
+					owner: new Wallet(seller),
+					mint,
+					itemId,
+				});
+				await carbon.methods.createSwap({
+					maker: new Wallet(seller),
+					swapId,
+					collectionMint,
+					makerMints: [mint],
+					takerAmount: price,
+				});
+
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				assert.isTrue(custodyAccount.isListed);
+
+				const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+				const swap = await carbon.accounts.swap(seller.publicKey, swapId);
+				await carbon.methods.takeSwap({ taker: new Wallet(buyer), swap });
+
+				const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+				assert.isAbove(sellerBalanceAfter, sellerBalanceBefore + price);
+
+				// Custody account should no longer exist
+				await assertThrows(
+					async () => await program.account.custodyAccount.fetch(custodyAccountPDA)
+				);
+			});
+
+			it("should cancel the swap correctly", async function () {
+				await carbon.methods.createSwap({
+					maker: new Wallet(seller),
+					swapId,
+					collectionMint,
+					makerMints: [mint],
+					takerMints: [takerMint],
+				});
+
+				const swap = await carbon.accounts.swap(seller.publicKey, swapId);
+				await carbon.methods.cancelSwap({ maker: new Wallet(seller), swap });
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.isFalse(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should throw when taker is not the named counterparty", async function () {
+				await carbon.methods.createSwap({
+					maker: new Wallet(seller),
+					swapId,
+					collectionMint,
+					taker: marketplaceAuthority.publicKey,
+					makerMints: [mint],
+					takerMints: [takerMint],
+				});
+
+				const swap = await carbon.accounts.swap(seller.publicKey, swapId);
+				await assertThrows(
+					async () => await carbon.methods.takeSwap({ taker: new Wallet(buyer), swap })
+				);
+			});
+		});
+
+		describe("rental", function () {
+			const pricePerDay = 1_000_000;
+			const maxDays = 7;
+
+			beforeEach(async function () {
+				await carbon.methods.listRental({
+					owner: new Wallet(seller),
+					mint,
+					pricePerDay,
+					maxDays,
+				});
+			});
+
+			it("should list the nft for rent correctly", async function () {
+				const rental = await carbon.accounts.rental(mint);
+				assert.equal(rental.owner.toString(), seller.publicKey.toString());
+				assert.equal(rental.pricePerDay.toNumber(), pricePerDay);
+				assert.equal(rental.maxDays, maxDays);
+				assert.equal(rental.renter.toString(), PublicKey.default.toString());
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(sellerTokenAccountObj.delegate.toString(), carbon.pdas.rental(mint).toString());
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should rent the nft correctly", async function () {
+				const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+				await carbon.methods.rentNft({
+					renter: new Wallet(buyer),
+					rental: await carbon.accounts.rental(mint),
+					days: 2,
+				});
+
+				const rental = await carbon.accounts.rental(mint);
+				assert.equal(rental.renter.toString(), buyer.publicKey.toString());
+				assert.isAbove(rental.rentedUntil.toNumber(), Date.now() / 1000);
+
+				const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+				assert.isAbove(sellerBalanceAfter, sellerBalanceBefore);
+
+				// The NFT stays with the owner while it is rented
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(sellerTokenAccountObj.amount.toString(), "1");
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should end the rental correctly", async function () {
+				await carbon.methods.endRental({
+					authority: new Wallet(seller),
+					rental: await carbon.accounts.rental(mint),
+				});
+
+				// Rental should no longer exist
+				assert.isUndefined(await carbon.accounts.rental(mint));
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.isFalse(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should throw when ending before the term ends", async function () {
+				await carbon.methods.rentNft({
+					renter: new Wallet(buyer),
+					rental: await carbon.accounts.rental(mint),
+					days: 1,
+				});
+
+				const rental = await carbon.accounts.rental(mint);
+				await assertThrows(
+					async () => await carbon.methods.endRental({ authority: new Wallet(seller), rental })
+				);
+				await assertThrows(
+					async () => await carbon.methods.endRental({ authority: new Wallet(buyer), rental })
+				);
+			});
+		});
+
+		describe("loan", function () {
+			const principal = 100_000_000;
+			const interest = 10_000_000;
+			let loanId: number[];
+
+			beforeEach(async function () {
+				loanId = createVirtualItemId();
+			});
+
+			it("should take and repay the loan correctly", async function () {
+				await carbon.methods.offerLoan({
+					lender: new Wallet(buyer),
+					loanId,
+					collectionMint,
+					principal,
+					interest,
+					duration: 60,
+				});
+
+				const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+				await carbon.methods.takeLoan({
+					borrower: new Wallet(seller),
+					loan: await carbon.accounts.loan(buyer.publicKey, loanId),
+					mint,
+				});
+
+				const loan = await carbon.accounts.loan(buyer.publicKey, loanId);
+				assert.equal(loan.borrower.toString(), seller.publicKey.toString());
+				assert.equal(loan.mint.toString(), mint.toString());
+
+				const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+				assert.isAbove(sellerBalanceAfter, sellerBalanceBefore + principal / 2);
+
+				let sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+
+				await carbon.methods.repayLoan({ borrower: new Wallet(seller), loan });
+
+				// Loan should no longer exist
+				assert.isUndefined(await carbon.accounts.loan(buyer.publicKey, loanId));
+
+				sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.isNull(sellerTokenAccountObj.delegate);
+				assert.isFalse(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should foreclose an overdue loan correctly", async function () {
+				await carbon.methods.offerLoan({
+					lender: new Wallet(buyer),
+					loanId,
+					collectionMint,
+					principal,
+					interest,
+					duration: 1,
+				});
+				await carbon.methods.takeLoan({
+					borrower: new Wallet(seller),
+					loan: await carbon.accounts.loan(buyer.publicKey, loanId),
+					mint,
+				});
+
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				const loan = await carbon.accounts.loan(buyer.publicKey, loanId);
+				await assertThrows(
+					async () => await carbon.methods.repayLoan({ borrower: new Wallet(seller), loan })
+				);
+				await carbon.methods.forecloseLoan({ lender: new Wallet(buyer), loan });
+
+				const buyerTokenAccountObj = await getAccount(
+					provider.connection,
+					getAssociatedTokenAddressSync(mint, buyer.publicKey)
+				);
+				assert.equal(buyerTokenAccountObj.amount.toString(), "1");
+				assert.isFalse(buyerTokenAccountObj.isFrozen);
+			});
+
+			it("should throw when foreclosing before the loan is due", async function () {
+				await carbon.methods.offerLoan({
+					lender: new Wallet(buyer),
+					loanId,
+					collectionMint,
+					principal,
+					interest,
+					duration: 60,
+				});
+				await carbon.methods.takeLoan({
+					borrower: new Wallet(seller),
+					loan: await carbon.accounts.loan(buyer.publicKey, loanId),
+					mint,
+				});
+
+				const loan = await carbon.accounts.loan(buyer.publicKey, loanId);
+				await assertThrows(
+					async () => await carbon.methods.forecloseLoan({ lender: new Wallet(buyer), loan })
+				);
+			});
+
+			it("should cancel the loan offer correctly", async function () {
+				await carbon.methods.offerLoan({
+					lender: new Wallet(buyer),
+					loanId,
+					collectionMint,
+					principal,
+					interest,
+					duration: 60,
+				});
+
+				const loan = await carbon.accounts.loan(buyer.publicKey, loanId);
+				await carbon.methods.cancelLoanOffer({ lender: new Wallet(buyer), loan });
+
+				// Loan should no longer exist
+				assert.isUndefined(await carbon.accounts.loan(buyer.publicKey, loanId));
+			});
+		});
+
+		describe("session", function () {
+			const scope = { list: true, updateListing: true, delist: true, custody: true };
+			let sessionKey: Keypair;
+
+			beforeEach(async function () {
+				sessionKey = Keypair.generate();
+				await setBalance(provider, sessionKey, LAMPORTS_PER_SOL);
+			});
+
+			it("should let the session key list, update and delist a custodial nft", async function () {
+				await carbon.methods.custody({ owner: new Wallet(seller), mint, itemId });
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+				});
+
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					authority: new Wallet(sessionKey),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+				});
+				let listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.seller.toString(), seller.publicKey.toString());
+				assert.equal(listing.payer.toString(), sessionKey.publicKey.toString());
+
+				await carbon.methods.updateListing({
+					seller: new Wallet(seller),
+					authority: new Wallet(sessionKey),
+					listing: listingPDA,
+					price: price / 2,
+					expiry: 0,
+				});
+				listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.price.toNumber(), price / 2);
+
+				await carbon.methods.delistNft({
+					seller: new Wallet(seller),
+					authority: new Wallet(sessionKey),
+					mint,
+					rentPayer: sessionKey.publicKey,
+				});
+				assert.isUndefined(await carbon.accounts.listing(itemId));
+
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				assert.isFalse(custodyAccount.isListed);
+			});
+
+			it("should let the session key custody an nft approved for custody", async function () {
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+				});
+				await approve(provider.connection, seller, sellerTokenAccount, custodyAccountPDA, seller, 1);
+
+				await carbon.methods.custody({
+					owner: new Wallet(seller),
+					authority: new Wallet(sessionKey),
+					mint,
+					itemId,
+				});
+
+				const custodyAccount = await program.account.custodyAccount.fetch(custodyAccountPDA);
+				assert.equal(custodyAccount.owner.toString(), seller.publicKey.toString());
+
+				const sellerTokenAccountObj = await getAccount(provider.connection, sellerTokenAccount);
+				assert.equal(sellerTokenAccountObj.delegate.toString(), custodyAccountPDA.toString());
+				assert.isTrue(sellerTokenAccountObj.isFrozen);
+			});
+
+			it("should throw when the session key custodies under another marketplace authority", async function () {
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+				});
+				await approve(provider.connection, seller, sellerTokenAccount, custodyAccountPDA, seller, 1);
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.custody({
+							owner: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							marketplaceAuthority: sessionKey.publicKey,
+							mint,
+							itemId,
+						})
+				);
+			});
+
+			it("should throw when the session key lists a non-custodial nft", async function () {
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							mint,
+							collectionMint,
+							price,
+							expiry,
+						})
+				);
+			});
+
+			it("should throw when the price is above the session's max price", async function () {
+				await carbon.methods.custody({ owner: new Wallet(seller), mint, itemId });
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+					maxPrice: price / 2,
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							mint,
+							collectionMint,
+							price,
+							expiry,
+						})
+				);
+			});
+
+			it("should throw when the session key lowers the price below the session's min price", async function () {
+				await carbon.methods.custody({ owner: new Wallet(seller), mint, itemId });
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+					minPrice: price / 2,
+				});
+				await carbon.methods.listNft({
+					seller: new Wallet(seller),
+					authority: new Wallet(sessionKey),
+					mint,
+					collectionMint,
+					price,
+					expiry,
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.updateListing({
+							seller: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							listing: listingPDA,
+							price: 1,
+							expiry: 0,
+						})
+				);
+			});
+
+			it("should throw when the action is outside the session's scope", async function () {
+				await carbon.methods.custody({ owner: new Wallet(seller), mint, itemId });
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope: { ...scope, list: false },
+				});
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							mint,
+							collectionMint,
+							price,
+							expiry,
+						})
+				);
+			});
+
+			it("should throw once the session is revoked", async function () {
+				await carbon.methods.custody({ owner: new Wallet(seller), mint, itemId });
+				await carbon.methods.createSession({
+					owner: new Wallet(seller),
+					sessionKey: sessionKey.publicKey,
+					expiry: moment().add(1, "hour").unix(),
+					scope,
+				});
+				await carbon.methods.revokeSession({
+					authority: new Wallet(seller),
+					session: await carbon.accounts.session(seller.publicKey, sessionKey.publicKey),
+				});
+				assert.isUndefined(await carbon.accounts.session(seller.publicKey, sessionKey.publicKey));
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.listNft({
+							seller: new Wallet(seller),
+							authority: new Wallet(sessionKey),
+							mint,
+							collectionMint,
+							price,
+							expiry,
+						})
+				);
+			});
+		});
+	});
+
+	describe("fungible flows", function () {
+		const listedAmount = 100;
+		const unitPrice = 10_000_000;
+		let fungibleMint: PublicKey;
+
+		beforeEach(async function () {
+			const results = await Promise.all([
+				carbon.methods.initMarketplaceConfig({
+					args: {
+						feeConfig: defaultFeeConfig,
+						keeperReward: defaultKeeperReward,
+					},
+				}),
+				createSplToken(provider, seller, seller.publicKey, listedAmount),
+			]);
+			fungibleMint = results[1].mint;
+
+			await carbon.methods.listFungible({
+				seller: new Wallet(seller),
+				mint: fungibleMint,
+				amount: listedAmount,
+				price: unitPrice,
+				expiry,
+			});
+		});
+
+		it("should list the tokens correctly", async function () {
+			const listing = await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint);
+			assert.equal(listing.seller.toString(), seller.publicKey.toString());
+			assert.equal(listing.mint.toString(), fungibleMint.toString());
+			assert.equal(listing.price.toNumber(), unitPrice);
+			assert.equal(listing.remainingAmount.toNumber(), listedAmount);
+
+			const escrowTokenAccount = getAssociatedTokenAddressSync(
+				fungibleMint,
+				carbon.pdas.fungibleListing(seller.publicKey, fungibleMint),
+				true
+			);
+			const escrowTokenAccountObj = await getAccount(provider.connection, escrowTokenAccount);
+			assert.equal(escrowTokenAccountObj.amount.toString(), listedAmount.toString());
+		});
+
+		it("should partially fill and then close the listing correctly", async function () {
+			const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+			const feeBalanceBefore = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+			await carbon.methods.buyFungible({
+				buyer: new Wallet(buyer),
+				listing: await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint),
+				amount: 40,
+			});
+
+			const listing = await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint);
+			assert.equal(listing.remainingAmount.toNumber(), listedAmount - 40);
+
+			const buyerTokenAccount = getAssociatedTokenAddressSync(fungibleMint, buyer.publicKey);
+			let buyerTokenAccountObj = await getAccount(provider.connection, buyerTokenAccount);
+			assert.equal(buyerTokenAccountObj.amount.toString(), "40");
+
+			const cost = 40 * unitPrice;
+			const fee = (cost * defaultFeeConfig.bps) / 10_000;
+			const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+			const feeBalanceAfter = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+			assert.equal(sellerBalanceAfter, sellerBalanceBefore + cost - fee);
+			assert.equal(feeBalanceAfter, feeBalanceBefore + fee);
+
+			await carbon.methods.buyFungible({
+				buyer: new Wallet(buyer),
+				listing,
+				amount: listedAmount - 40,
+			});
+
+			// Listing should be closed once nothing is left
+			assert.isUndefined(await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint));
+
+			buyerTokenAccountObj = await getAccount(provider.connection, buyerTokenAccount);
+			assert.equal(buyerTokenAccountObj.amount.toString(), listedAmount.toString());
+		});
+
+		it("should throw when buying more than is left", async function () {
+			const listing = await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint);
+			await assertThrows(
+				async () =>
+					await carbon.methods.buyFungible({
+						buyer: new Wallet(buyer),
+						listing,
+						amount: listedAmount + 1,
+						maxPrice: (listedAmount + 1) * unitPrice,
+					})
+			);
+		});
+
+		it("should delist the unsold tokens correctly", async function () {
+			await carbon.methods.buyFungible({
+				buyer: new Wallet(buyer),
+				listing: await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint),
+				amount: 25,
+			});
+
+			await carbon.methods.delistFungible({ seller: new Wallet(seller), mint: fungibleMint });
+
+			assert.isUndefined(await carbon.accounts.fungibleListing(seller.publicKey, fungibleMint));
+
+			const sellerTokenAccountObj = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(fungibleMint, seller.publicKey)
+			);
+			assert.equal(sellerTokenAccountObj.amount.toString(), (listedAmount - 25).toString());
+		});
+	});
+
+	describe("order book", function () {
+		const baseSupply = 1_000;
+		const quoteSupply = 100_000;
+		let baseMint: PublicKey;
+		let quoteMint: PublicKey;
+		let marketPDA: PublicKey;
+
+		beforeEach(async function () {
+			const results = await Promise.all([
+				carbon.methods.initMarketplaceConfig({
+					args: {
+						feeConfig: defaultFeeConfig,
+						keeperReward: defaultKeeperReward,
+					},
+				}),
+				createSplToken(provider, seller, seller.publicKey, baseSupply),
+				createSplToken(provider, buyer, buyer.publicKey, quoteSupply),
+			]);
+			baseMint = results[1].mint;
+			quoteMint = results[2].mint;
+			marketPDA = carbon.pdas.market(baseMint, quoteMint);
+
+			await carbon.methods.initMarket({ baseMint, quoteMint, baseLotSize: 1 });
+			await Promise.all([
+				carbon.methods.initOpenOrders({ owner: new Wallet(seller), market: marketPDA }),
+				carbon.methods.initOpenOrders({ owner: new Wallet(buyer), market: marketPDA }),
+			]);
+		});
+
+		it("should match crossing orders at the older order's price", async function () {
+			await carbon.methods.placeOrder({
+				owner: new Wallet(seller),
+				market: await carbon.accounts.market(baseMint, quoteMint),
+				side: { ask: {} },
+				price: 100,
+				quantity: 10,
+			});
+			await carbon.methods.placeOrder({
+				owner: new Wallet(buyer),
+				market: await carbon.accounts.market(baseMint, quoteMint),
+				side: { bid: {} },
+				price: 120,
+				quantity: 4,
+			});
+
+			await carbon.methods.matchOrders({
+				market: await carbon.accounts.market(baseMint, quoteMint),
+			});
+
+			const fee = (4 * 100 * defaultFeeConfig.bps) / 10_000;
+			const buyerOpenOrders = await carbon.accounts.openOrders(marketPDA, buyer.publicKey);
+			assert.equal(buyerOpenOrders.baseFree.toNumber(), 4);
+			assert.equal(buyerOpenOrders.quoteFree.toNumber(), 4 * 20);
+			const sellerOpenOrders = await carbon.accounts.openOrders(marketPDA, seller.publicKey);
+			assert.equal(sellerOpenOrders.quoteFree.toNumber(), 4 * 100 - fee);
+
+			const market = await carbon.accounts.market(baseMint, quoteMint);
+			const asks = await program.account.orderBookSide.fetch(market.asks);
+			assert.equal(asks.count.toNumber(), 1);
+			assert.equal(asks.orders[0].quantity.toNumber(), 6);
+
+			await carbon.methods.settleFunds({ owner: new Wallet(buyer), market });
+			await carbon.methods.settleFunds({ owner: new Wallet(seller), market });
+
+			const buyerBase = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(baseMint, buyer.publicKey)
+			);
+			assert.equal(buyerBase.amount.toString(), "4");
+			const buyerQuote = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(quoteMint, buyer.publicKey)
+			);
+			assert.equal(buyerQuote.amount.toString(), (quoteSupply - 4 * 100).toString());
+			const sellerQuote = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(quoteMint, seller.publicKey)
+			);
+			assert.equal(sellerQuote.amount.toString(), (4 * 100 - fee).toString());
+			const feeQuote = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(quoteMint, FEE_ACCOUNT_KEY, true)
+			);
+			assert.equal(feeQuote.amount.toString(), fee.toString());
+		});
+
+		it("should evict the worst-priced order when a side is full", async function () {
+			const market = await carbon.accounts.market(baseMint, quoteMint);
+			for (let i = 0; i < 64; i++) {
+				await carbon.methods.placeOrder({
+					owner: new Wallet(seller),
+					market: await carbon.accounts.market(baseMint, quoteMint),
+					side: { ask: {} },
+					price: 200,
+					quantity: 1,
+				});
+			}
+
+			// An order that doesn't beat the worst price can't make room
+			await assertThrows(async () => {
+				await carbon.methods.placeOrder({
+					owner: new Wallet(seller),
+					market: await carbon.accounts.market(baseMint, quoteMint),
+					side: { ask: {} },
+					price: 200,
+					quantity: 1,
+				});
+			});
+
+			await carbon.methods.placeOrder({
+				owner: new Wallet(seller),
+				market: await carbon.accounts.market(baseMint, quoteMint),
+				side: { ask: {} },
+				price: 150,
+				quantity: 1,
+			});
+
+			const asks = await program.account.orderBookSide.fetch(market.asks);
+			assert.equal(asks.count.toNumber(), 64);
+			assert.equal(asks.orders[0].price.toNumber(), 150);
+			const sellerOpenOrders = await carbon.accounts.openOrders(marketPDA, seller.publicKey);
+			assert.equal(sellerOpenOrders.baseFree.toNumber(), 1);
+		});
+
+		it("should fill a bid and an ask fully and settle both sides", async function () {
+			const market = await carbon.accounts.market(baseMint, quoteMint);
+			const asksInfo = await provider.connection.getAccountInfo(market.asks);
+			// Discriminator, market, is_bid with its padding, count and 64 orders of 56 bytes
+			assert.equal(asksInfo.data.length, 8 + 32 + 8 + 8 + 64 * 56);
+
+			await carbon.methods.placeOrder({
+				owner: new Wallet(buyer),
+				market,
+				side: { bid: {} },
+				price: 100,
+				quantity: 10,
+			});
+			await carbon.methods.placeOrder({
+				owner: new Wallet(seller),
+				market,
+				side: { ask: {} },
+				price: 100,
+				quantity: 10,
+			});
+
+			await carbon.methods.matchOrders({ market });
+
+			const bids = await program.account.orderBookSide.fetch(market.bids);
+			const asks = await program.account.orderBookSide.fetch(market.asks);
+			assert.equal(bids.count.toNumber(), 0);
+			assert.equal(asks.count.toNumber(), 0);
+
+			await carbon.methods.settleFunds({ owner: new Wallet(buyer), market });
+			await carbon.methods.settleFunds({ owner: new Wallet(seller), market });
+
+			const fee = (10 * 100 * defaultFeeConfig.bps) / 10_000;
+			const buyerBase = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(baseMint, buyer.publicKey)
+			);
+			assert.equal(buyerBase.amount.toString(), "10");
+			const sellerQuote = await getAccount(
+				provider.connection,
+				getAssociatedTokenAddressSync(quoteMint, seller.publicKey)
+			);
+			assert.equal(sellerQuote.amount.toString(), (10 * 100 - fee).toString());
+		});
+
+		it("should free the locked tokens when cancelling an order", async function () {
+			await carbon.methods.placeOrder({
+				owner: new Wallet(seller),
+				market: await carbon.accounts.market(baseMint, quoteMint),
+				side: { ask: {} },
+				price: 100,
+				quantity: 10,
+			});
+
+			const market = await carbon.accounts.market(baseMint, quoteMint);
+			const asks = await program.account.orderBookSide.fetch(market.asks);
+			await carbon.methods.cancelOrder({
+				owner: new Wallet(seller),
+				market,
+				side: { ask: {} },
+				orderId: asks.orders[0].orderId,
+			});
+
+			const sellerOpenOrders = await carbon.accounts.openOrders(marketPDA, seller.publicKey);
+			assert.equal(sellerOpenOrders.baseFree.toNumber(), 10);
+			await assertThrows(
+				async () =>
+					await carbon.methods.cancelOrder({
+						owner: new Wallet(seller),
+						market,
+						side: { ask: {} },
+						orderId: asks.orders[0].orderId,
+					})
+			);
+		});
+	});
+
+	describe("virtual flows", function () {
+		beforeEach(setUpData);
+
+		async function setUpData() {
+			await Promise.all([
+				carbon.methods.initMarketplaceConfig({
+					args: {
+						feeConfig: defaultFeeConfig,
+						keeperReward: defaultKeeperReward,
+					},
+				}),
+				carbon.methods.initCollectionConfig({
+					args: {
+						collectionMint,
+						sellerFeeBasisPoints: defaultSellerFeeBps,
+						symbol: defaultSymbol,
+						swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+						mintStandard: defaultMintStandard,
+						verification: defaultVerification,
+					},
+				}),
+			]);
+
+			itemId = createVirtualItemId();
+			listingPDA = carbon.pdas.listing(itemId);
+		}
+
+		describe("list_virtual", function () {
+			it("should list the virtual item correctly", async function () {
+				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.version, 2);
+				assert.equal(listing.seller.toString(), marketplaceAuthority.publicKey.toString());
+				assert.deepEqual(listing.itemId, itemId);
+				assert.equal(listing.isVirtual, true);
+				assert.equal(listing.currencyMint.toString(), currencyMint.toString());
+				assert.equal(listing.price.toNumber(), price);
+				assert.equal(listing.expiry.toNumber(), expiry);
+				assert.equal(listing.feeConfig.feeAccount.toString(), FEE_ACCOUNT_KEY.toString());
+				assert.equal(listing.feeConfig.bps, defaultFeeConfig.bps);
+				assert.equal(listing.namespace.toString(), marketplaceConfigPDA.toString());
+			});
+
+			it("should not derive the listing address from the item id only", async function () {
+				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+
+				assert.isNull(
+					await provider.connection.getAccountInfo(carbon.pdas.legacyListing(itemId))
+				);
+				const listing = await carbon.accounts.listing(itemId);
+				assert.equal(listing.namespace.toString(), marketplaceConfigPDA.toString());
+			});
+
+			it("should list the virtual item correctly as a third-party seller", async function () {
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.version, 2);
+				assert.equal(listing.seller.toString(), seller.publicKey.toString());
+				assert.deepEqual(listing.itemId, itemId);
+				assert.equal(listing.isVirtual, true);
+				assert.equal(listing.currencyMint.toString(), currencyMint.toString());
+				assert.equal(listing.price.toNumber(), price);
+				assert.equal(listing.expiry.toNumber(), expiry);
+				assert.equal(listing.feeConfig.feeAccount.toString(), FEE_ACCOUNT_KEY.toString());
+				assert.equal(listing.feeConfig.bps, defaultFeeConfig.bps);
+			});
+
+			it("should throw when seller is not a signer", async function () {
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+
+				await assertThrows(async () => await provider.sendAndConfirm(listTx, []));
+			});
+		});
+
+		describe("update_listing", function () {
+			it("should update the listing correctly", async function () {
+				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+
+				const newPrice = price / 2;
+				const newExpiry = 0;
+				await carbon.methods.updateListing({
+					listing: listingPDA,
+					price: newPrice,
+					expiry: newExpiry,
+				});
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.price.toNumber(), newPrice);
+				assert.equal(listing.expiry.toNumber(), newExpiry);
+			});
+
+			it("should throw when listing is expired", async function () {
+				await carbon.methods.listVirtual({
+					itemId,
+					collectionMint,
+					price,
+					expiry: (expiry = moment().add(2, "seconds").unix()),
+				});
+
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.updateListing({
+							listing: listingPDA,
+							price: price / 2,
+							expiry: 0,
+						})
+				);
+			});
+		});
+
+		describe("delist_virtual", function () {
+			it("should delist the virtual item correctly", async function () {
+				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+				await carbon.methods.delistVirtual({ itemId });
+
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+			});
+
+			it("should delist the virtual item correctly with a third-party seller", async function () {
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				await carbon.methods.delistVirtual({
+					seller: new Wallet(seller),
+					itemId,
+				});
+
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+			});
+
+			it("should return the rent to the sponsoring payer", async function () {
+				const sellerBalance = await provider.connection.getBalance(seller.publicKey);
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					payer: marketplaceAuthority.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.payer.toString(), marketplaceAuthority.publicKey.toString());
+				assert.equal(await provider.connection.getBalance(seller.publicKey), sellerBalance);
+
+				const rent = await provider.connection.getBalance(listingPDA);
+				const authorityBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+				await carbon.methods.delistVirtual({
+					seller: new Wallet(seller),
+					rentPayer: marketplaceAuthority.publicKey,
+					itemId,
+				});
+
+				assert.equal(
+					await provider.connection.getBalance(marketplaceAuthority.publicKey),
+					authorityBalance + rent
+				);
+			});
+
+			it("should throw when returning the rent to a wallet other than the payer", async function () {
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					payer: marketplaceAuthority.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.delistVirtual({
+							seller: new Wallet(seller),
+							itemId,
+						})
+				);
+			});
+
+			it("should throw when delisting as a different seller", async function () {
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				await assertThrows(
+					async () =>
+						await carbon.methods.delistVirtual({
+							seller: new Wallet(marketplaceAuthority),
+							itemId,
+						})
+				);
+			});
+		});
+
+		describe("close_expired_listing", function () {
+			it("should close the expired virtual listing correctly", async function () {
+				await carbon.methods.listVirtual({
+					itemId,
+					collectionMint,
+					price,
+					expiry: moment().add(2, "seconds").unix(),
+				});
+
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				await carbon.methods.closeExpiredListing({ listing });
+
+				// Listing should no longer exist
+				await assertThrows(async () => await program.account.listing.fetch(listingPDA));
+			});
+
+			it("should return the whole rent to a sponsoring payer", async function () {
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					payer: marketplaceAuthority.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry: moment().add(2, "seconds").unix(),
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				await new Promise((resolve) => setTimeout(resolve, 3000));
+
+				const keeper = Keypair.generate();
+				await setBalance(provider, keeper, LAMPORTS_PER_SOL);
+				const keeperBalanceBefore = await provider.connection.getBalance(keeper.publicKey);
+				const rent = await provider.connection.getBalance(listingPDA);
+				const authorityBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				await carbon.methods.closeExpiredListing({
+					keeper: new Wallet(keeper),
+					listing,
+				});
+
+				// The keeper reward is not taken out of the sponsored rent
+				const keeperBalanceAfter = await provider.connection.getBalance(keeper.publicKey);
+				assert.equal(keeperBalanceAfter, keeperBalanceBefore - TX_FEE);
+				assert.equal(
+					await provider.connection.getBalance(marketplaceAuthority.publicKey),
+					authorityBalance + rent
+				);
+			});
+		});
+
+		describe("buy_virtual", function () {
+			it("should buy the virtual item correctly", async function () {
+				const marketplaceAuthPreBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+				const listing = await program.account.listing.fetch(listingPDA);
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+				const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+				const { mint: mintKeypair, transaction } = await carbon.transactions.buyVirtual({
+					buyer: buyer.publicKey,
+					collectionConfig,
+					listing,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const marketplaceAuthPostBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
+				const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+				// An NFT should now exist with the correct metadata
+				const mint = mintKeypair.publicKey;
+				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
+				assert.equal(
+					nft.updateAuthorityAddress.toString(),
+					marketplaceAuthority.publicKey.toString()
+				);
+				assert.equal(nft.name, "Ghost #1");
+				assert.equal(nft.uri, "https://example.com");
+				assert.equal(nft.symbol, defaultSymbol);
+				assert.equal(nft.sellerFeeBasisPoints, defaultSellerFeeBps);
+				assert.isTrue(nft.collection.verified);
+				assert.equal(nft.collection.address.toString(), collectionMint.toString());
+				assert.isTrue(nft.primarySaleHappened);
+				assert.deepEqual(nft.creators, [
+					{
+						address: marketplaceAuthority.publicKey,
+						verified: true,
+						share: 100,
+					},
+				]);
+
+				// The mint record should exist
+				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
+				const mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+				assert.equal(mintRecord.collectionConfig.toString(), collectionConfigPDA.toString());
+				assert.deepEqual(mintRecord.itemId, itemId);
+				assert.equal(mintRecord.mint.toString(), mint.toString());
+
+				// Make sure buyer is the owner and can transfer the NFT
+				const sellerTokenAccount = await createAssociatedTokenAccount(
+					provider.connection,
+					buyer,
+					mint,
+					seller.publicKey
+				);
+				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
+				await transferChecked(
+					provider.connection,
+					buyer,
+					buyerTokenAccount,
+					mint,
+					sellerTokenAccount,
+					buyer,
+					1,
+					0
+				);
+
+				// Make sure correct amounts were sent to seller and fee account
+				assert.equal(buyerPreBalance - buyerPostBalance, price + mintRecordStorageFee);
+
+				const mintingFee = 0.02 * LAMPORTS_PER_SOL;
+				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
+				assert.isAtLeast(
+					marketplaceAuthPostBalance - marketplaceAuthPreBalance,
+					price - marketplaceFee - mintingFee
+				);
+
+				assert.equal(feeAccountPostBalance - feeAccountPreBalance, marketplaceFee);
+			});
+
+			it("should buy the virtual item with SPL correctly", async function () {
+				price = 1000;
+				const { mint: splTokenMint } = await createSplToken(
+					provider,
+					marketplaceAuthority,
+					buyer.publicKey,
+					price
+				);
+
+				await carbon.methods.listVirtual({
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+					currencyMint: splTokenMint,
+				});
+				const listing = await program.account.listing.fetch(listingPDA);
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				const { mint: mintKeypair, instruction } = await carbon.instructions.buyVirtual({
+					buyer: buyer.publicKey,
+					collectionConfig,
+					listing,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(
+					new Transaction()
+						.add(
+							ComputeBudgetProgram.setComputeUnitLimit({
+								units: 400_000,
+							})
+						)
+						.add(instruction),
+					[marketplaceAuthority, mintKeypair, buyer]
+				);
+
+				const marketplaceAuthTokenAddress = await getAssociatedTokenAddressSync(
+					splTokenMint,
+					marketplaceAuthority.publicKey
+				);
+				const marketplaceAuthPostBalance = await provider.connection.getTokenAccountBalance(
+					marketplaceAuthTokenAddress
+				);
+				const buyerTokenAddress = await getAssociatedTokenAddressSync(
+					splTokenMint,
+					buyer.publicKey
+				);
+				const buyerPostBalance = await provider.connection.getTokenAccountBalance(
+					buyerTokenAddress
+				);
+				const feeAccountTokenAddress = await getAssociatedTokenAddressSync(
+					splTokenMint,
+					FEE_ACCOUNT_KEY
+				);
+				const feeAccountPostBalance = await provider.connection.getTokenAccountBalance(
+					feeAccountTokenAddress
+				);
+
+				// Make sure correct amounts were sent to seller and fee account
+				assert.equal(buyerPostBalance.value.uiAmount, 0);
+
+				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
+				assert.isAtLeast(
+					marketplaceAuthPostBalance.value.uiAmount,
+					price - marketplaceFee - TX_FEE
+				);
+
+				assert.equal(feeAccountPostBalance.value.uiAmount, marketplaceFee);
+			});
+
+			it("should buy the virtual item correctly with a third-party seller", async function () {
+				const sellerPreBalance = await provider.connection.getBalance(seller.publicKey);
+				const marketplaceAuthPreBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+
+				const listTx = await carbon.transactions.listVirtual({
+					seller: seller.publicKey,
+					itemId,
+					collectionMint,
+					price,
+					expiry,
+				});
+				await provider.sendAndConfirm(listTx, [seller]);
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+				const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+				const { mint: mintKeypair, transaction } = await carbon.transactions.buyVirtual({
+					buyer: buyer.publicKey,
+					collectionConfig,
+					listing,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [buyer]);
+
+				const sellerPostBalance = await provider.connection.getBalance(seller.publicKey);
+				const marketplaceAuthPostBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
+				const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+				// An NFT should now exist with the correct metadata
+				const mint = mintKeypair.publicKey;
+				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
+				assert.equal(
+					nft.updateAuthorityAddress.toString(),
+					marketplaceAuthority.publicKey.toString()
+				);
+				assert.equal(nft.name, "Ghost #1");
+				assert.equal(nft.uri, "https://example.com");
+				assert.equal(nft.symbol, defaultSymbol);
+				assert.equal(nft.sellerFeeBasisPoints, defaultSellerFeeBps);
+				assert.isTrue(nft.collection.verified);
+				assert.equal(nft.collection.address.toString(), collectionMint.toString());
+				assert.isTrue(nft.primarySaleHappened);
+				assert.deepEqual(nft.creators, [
+					{
+						address: marketplaceAuthority.publicKey,
+						verified: true,
+						share: 100,
+					},
+				]);
+
+				// Make sure buyer is the owner and can transfer the NFT
+				const sellerTokenAccount = await createAssociatedTokenAccount(
+					provider.connection,
+					buyer,
+					mint,
+					seller.publicKey
+				);
+				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
+				await transferChecked(
+					provider.connection,
+					buyer,
+					buyerTokenAccount,
+					mint,
+					sellerTokenAccount,
+					buyer,
+					1,
+					0
+				);
+
+				assert.equal(buyerPreBalance - buyerPostBalance, price + mintRecordStorageFee);
+
+				// Make sure correct amounts were sent to seller and fee account
+				const royalty = (price * defaultSellerFeeBps) / 10000;
+				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
+				assert.isAtLeast(sellerPostBalance - sellerPreBalance, price - marketplaceFee - royalty);
+
+				const mintingFee = 0.02 * LAMPORTS_PER_SOL;
+				assert.isAtLeast(
+					marketplaceAuthPostBalance,
+					marketplaceAuthPreBalance + royalty - mintingFee
+				);
+
+				assert.equal(feeAccountPostBalance - feeAccountPreBalance, marketplaceFee);
+			});
+
+			it("should throw when giving an incorrect collection config", async function () {
+				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				collectionMint = Keypair.generate().publicKey;
+				await carbon.methods.initCollectionConfig({
+					args: {
+						collectionMint,
+						sellerFeeBasisPoints: defaultSellerFeeBps,
+						symbol: defaultSymbol,
+						swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+						mintStandard: defaultMintStandard,
+						verification: defaultVerification,
+					},
+				});
+
+				collectionConfigPDA = carbon.pdas.collectionConfig(collectionMint);
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				const { mint: mintKeypair, instruction } = await carbon.instructions.buyVirtual({
+					buyer: buyer.publicKey,
+					collectionConfig,
+					listing,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+
+				await assertThrows(async () => {
+					await provider.sendAndConfirm(new Transaction().add(instruction), [
+						marketplaceAuthority,
+						mintKeypair,
+						buyer,
+					]);
+				});
+			});
+		});
+
+		describe("mint_virtual", function () {
+			it("should mint the virtual item correctly", async function () {
+				const marketplaceAuthPreBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+
+				itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const marketplaceAuthPostBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
+
+				// An NFT should now exist with the correct metadata
+				const mint = mintKeypair.publicKey;
+				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
+				assert.equal(
+					nft.updateAuthorityAddress.toString(),
+					marketplaceAuthority.publicKey.toString()
+				);
+				assert.equal(nft.name, "Ghost #1");
+				assert.equal(nft.uri, "https://example.com");
+				assert.equal(nft.symbol, defaultSymbol);
+				assert.equal(nft.sellerFeeBasisPoints, defaultSellerFeeBps);
+				assert.isTrue(nft.collection.verified);
+				assert.equal(nft.collection.address.toString(), collectionMint.toString());
+				assert.isTrue(nft.primarySaleHappened);
+				assert.deepEqual(nft.creators, [
+					{
+						address: marketplaceAuthority.publicKey,
+						verified: true,
+						share: 100,
+					},
+				]);
+
+				// The mint record should exist
+				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
+				const mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+				assert.equal(mintRecord.collectionConfig.toString(), collectionConfigPDA.toString());
+				assert.deepEqual(mintRecord.itemId, itemId);
+				assert.equal(mintRecord.mint.toString(), mint.toString());
+
+				// Make sure buyer is the owner and can transfer the NFT
+				const sellerTokenAccount = await createAssociatedTokenAccount(
+					provider.connection,
+					buyer,
+					mint,
+					seller.publicKey
+				);
+				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
+				await transferChecked(
+					provider.connection,
+					buyer,
+					buyerTokenAccount,
+					mint,
+					sellerTokenAccount,
+					buyer,
+					1,
+					0
+				);
+
+				// Make sure correct amounts were sent to seller and fee account
+				const mintingFee = 0.01 * LAMPORTS_PER_SOL;
+				assert.isAtLeast(buyerPreBalance - buyerPostBalance, mintingFee);
+
+				assert.equal(marketplaceAuthPreBalance - marketplaceAuthPostBalance, 15000);
+			});
+
+			it("should throw when trying to mint the same item id twice", async function () {
+				const marketplaceAuthPreBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+
+				itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				await assertThrows(async () => {
+					const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+						buyer: buyer.publicKey,
+						itemId,
+						collectionConfig,
+						metadata: {
+							name: "Ghost #1",
+							uri: "https://example.com",
+						},
+					});
+					await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+				});
+			});
+
+			it("should charge the collection's primary sale price to the buyer", async function () {
+				await carbon.methods.setPrimarySale({ collectionMint, price, treasury: seller.publicKey });
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				assert.equal(collectionConfig.primarySale.price.toNumber(), price);
+
+				const treasuryPreBalance = await provider.connection.getBalance(seller.publicKey);
+				const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const treasuryPostBalance = await provider.connection.getBalance(seller.publicKey);
+				const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+				const fee = (price * defaultFeeConfig.bps) / 10_000;
+				assert.equal(feeAccountPostBalance - feeAccountPreBalance, fee);
+				assert.equal(treasuryPostBalance - treasuryPreBalance, price - fee);
+			});
+
+			it("should charge the price given for the mint in place of the collection's", async function () {
+				await carbon.methods.setPrimarySale({ collectionMint, price, treasury: seller.publicKey });
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				const mintPrice = price / 4;
+				const treasuryPreBalance = await provider.connection.getBalance(seller.publicKey);
+
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+					primarySale: {
+						price: new anchor.BN(mintPrice),
+						currencyMint: NATIVE_MINT,
+						treasury: seller.publicKey,
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const treasuryPostBalance = await provider.connection.getBalance(seller.publicKey);
+				const fee = (mintPrice * defaultFeeConfig.bps) / 10_000;
+				assert.equal(treasuryPostBalance - treasuryPreBalance, mintPrice - fee);
+			});
+
+			it("should throw when setting a price without a treasury", async function () {
+				await assertThrows(async () => {
+					await carbon.methods.setPrimarySale({
+						collectionMint,
+						price,
+						treasury: PublicKey.default,
+					});
+				});
+			});
+		});
+
+		describe("close_mint_record", function () {
+			it("should close the mint record correctly", async function () {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
 					metadata: {
 						name: "Ghost #1",
 						uri: "https://example.com",
@@ -937,483 +3049,970 @@ describe("carbon", () => {
 				});
 				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
 
-				const marketplaceAuthPostBalance = await provider.connection.getBalance(
-					marketplaceAuthority.publicKey
+				const metaplex = new Metaplex(provider.connection).use(keypairIdentity(buyer));
+				await metaplex.nfts().delete({
+					mintAddress: mintKeypair.publicKey,
+					collection: collectionMint,
+				});
+
+				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
+				let mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+
+				await carbon.methods.closeMintRecord({
+					mintRecord,
+				});
+
+				// The mint record should no longer exist
+				let account = await provider.connection.getAccountInfo(mintRecordPDA);
+				assert.isNull(account);
+			});
+
+			it("should refund the rent of the mint record to its payer", async function () {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const metaplex = new Metaplex(provider.connection).use(keypairIdentity(buyer));
+				await metaplex.nfts().delete({
+					mintAddress: mintKeypair.publicKey,
+					collection: collectionMint,
+				});
+
+				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
+				const mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+				assert.equal(mintRecord.payer.toBase58(), buyer.publicKey.toBase58());
+
+				// Passing another wallet as the rent payer should fail
+				await assertThrows(async () => {
+					await carbon.methods.closeMintRecord({
+						mintRecord: { ...mintRecord, payer: marketplaceAuthority.publicKey },
+					});
+				});
+
+				const rent = await provider.connection.getBalance(mintRecordPDA);
+				const balanceBefore = await provider.connection.getBalance(buyer.publicKey);
+
+				await carbon.methods.closeMintRecord({
+					mintRecord,
+				});
+
+				const balanceAfter = await provider.connection.getBalance(buyer.publicKey);
+				assert.equal(balanceAfter - balanceBefore, rent);
+			});
+
+			it("should throw when closing a mint record with an existing mint", async function () {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
+				let mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+
+				await assertThrows(async () => {
+					await carbon.methods.closeMintRecord({
+						mintRecord,
+					});
+				});
+			});
+
+			it("should throw when closing a mint record as non-authority", async function () {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+
+				itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: {
+						name: "Ghost #1",
+						uri: "https://example.com",
+					},
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+
+				const metaplex = new Metaplex(provider.connection).use(keypairIdentity(buyer));
+				await metaplex.nfts().delete({
+					mintAddress: mintKeypair.publicKey,
+					collection: collectionMint,
+				});
+
+				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
+				let mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+
+				await assertThrows(async () => {
+					const ix = await carbon.instructions.closeMintRecord({
+						marketplaceAuthority: buyer.publicKey,
+						mintRecord,
+					});
+
+					await provider.sendAndConfirm(new Transaction().add(ix), [buyer]);
+				});
+			});
+		});
+
+		describe("bonding curve", function () {
+			const basePrice = 0.1 * LAMPORTS_PER_SOL;
+			const slope = 0.05 * LAMPORTS_PER_SOL;
+			const refundBps = 5_000;
+			let itemType: number[];
+
+			beforeEach(async function () {
+				itemType = createVirtualItemId();
+				await carbon.methods.initBondingCurve({
+					collectionMint,
+					itemType,
+					metadata: {
+						name: "Ghost",
+						uri: "https://example.com",
+					},
+					basePrice,
+					slope,
+					refundBps,
+				});
+			});
+
+			async function buyFromCurve(): Promise<PublicKey> {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const { mint: mintKeypair, instruction } = await carbon.instructions.buyBondingCurve({
+					buyer: buyer.publicKey,
+					collectionConfig,
+					bondingCurve: await carbon.accounts.bondingCurve(collectionMint, itemType),
+				});
+				await provider.sendAndConfirm(
+					new Transaction()
+						.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
+						.add(instruction),
+					[marketplaceAuthority, mintKeypair, buyer]
 				);
-				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
-				const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+				return mintKeypair.publicKey;
+			}
 
-				// An NFT should now exist with the correct metadata
-				const mint = mintKeypair.publicKey;
-				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
+			it("should raise the price with each unit minted", async function () {
+				const bondingCurvePDA = carbon.pdas.bondingCurve(collectionConfigPDA, itemType);
+				const curvePreBalance = await provider.connection.getBalance(bondingCurvePDA);
+
+				const firstMint = await buyFromCurve();
+				const secondMint = await buyFromCurve();
+
+				const curvePostBalance = await provider.connection.getBalance(bondingCurvePDA);
+				assert.equal(curvePostBalance - curvePreBalance, basePrice + (basePrice + slope));
+
+				const bondingCurve = await carbon.accounts.bondingCurve(collectionMint, itemType);
+				assert.equal(bondingCurve.supply.toNumber(), 2);
+				assert.equal(bondingCurve.minted.toNumber(), 2);
 				assert.equal(
-					nft.updateAuthorityAddress.toString(),
-					marketplaceAuthority.publicKey.toString()
+					bondingCurve.reserved.toNumber(),
+					((basePrice + (basePrice + slope)) * refundBps) / 10_000
 				);
-				assert.equal(nft.name, "Ghost #1");
-				assert.equal(nft.uri, "https://example.com");
-				assert.equal(nft.symbol, defaultSymbol);
-				assert.equal(nft.sellerFeeBasisPoints, defaultSellerFeeBps);
+
+				const secondItemId = carbon.pdas.bondingCurveItemId(itemType, 1);
+				const mintRecord = await program.account.mintRecord.fetch(
+					carbon.pdas.mintRecord(collectionConfigPDA, secondItemId)
+				);
+				assert.equal(mintRecord.mint.toString(), secondMint.toString());
+
+				const nft = await fetchNFT(provider, marketplaceAuthority, firstMint);
+				assert.equal(nft.name, "Ghost");
 				assert.isTrue(nft.collection.verified);
-				assert.equal(nft.collection.address.toString(), collectionMint.toString());
-				assert.isTrue(nft.primarySaleHappened);
-				assert.deepEqual(nft.creators, [
-					{
-						address: marketplaceAuthority.publicKey,
-						verified: true,
-						share: 100,
-					},
-				]);
+			});
 
-				// The mint record should exist
-				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
-				const mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
-				assert.equal(mintRecord.collectionConfig.toString(), collectionConfigPDA.toString());
-				assert.deepEqual(mintRecord.itemId, itemId);
-				assert.equal(mintRecord.mint.toString(), mint.toString());
+			it("should refund the holder when burning back a unit", async function () {
+				const mint = await buyFromCurve();
+				await buyFromCurve();
 
-				// Make sure buyer is the owner and can transfer the NFT
-				const sellerTokenAccount = await createAssociatedTokenAccount(
-					provider.connection,
-					buyer,
+				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+				await carbon.methods.burnBondingCurve({
+					holder: new Wallet(buyer),
+					collectionMint,
+					bondingCurve: await carbon.accounts.bondingCurve(collectionMint, itemType),
 					mint,
-					seller.publicKey
+					serial: 0,
+				});
+				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
+
+				// The refund is priced from the last unit in circulation
+				const refund = ((basePrice + slope) * refundBps) / 10_000;
+				assert.isAtLeast(buyerPostBalance - buyerPreBalance, refund - TX_FEE);
+
+				const bondingCurve = await carbon.accounts.bondingCurve(collectionMint, itemType);
+				assert.equal(bondingCurve.supply.toNumber(), 1);
+				assert.equal(bondingCurve.minted.toNumber(), 2);
+				assert.equal(bondingCurve.reserved.toNumber(), (basePrice * refundBps) / 10_000);
+				assert.isNull(
+					await provider.connection.getAccountInfo(
+						carbon.pdas.mintRecord(
+							collectionConfigPDA,
+							carbon.pdas.bondingCurveItemId(itemType, 0)
+						)
+					)
 				);
-				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
-				await transferChecked(
-					provider.connection,
-					buyer,
-					buyerTokenAccount,
-					mint,
-					sellerTokenAccount,
-					buyer,
-					1,
-					0
+			});
+
+			it("should only withdraw the reserve not owed as refunds", async function () {
+				await buyFromCurve();
+				const bondingCurve = await carbon.accounts.bondingCurve(collectionMint, itemType);
+				const withdrawable = basePrice - bondingCurve.reserved.toNumber();
+
+				await assertThrows(async () => {
+					await carbon.methods.withdrawBondingCurveReserve({
+						bondingCurve,
+						amount: withdrawable + 1,
+					});
+				});
+
+				await carbon.methods.withdrawBondingCurveReserve({
+					bondingCurve,
+					amount: withdrawable,
+				});
+			});
+		});
+
+		describe("craft", function () {
+			let recipeId: number[];
+
+			beforeEach(async function () {
+				recipeId = createVirtualItemId();
+				await carbon.methods.initRecipe({
+					collectionMint,
+					recipeId,
+					inputs: [
+						{ name: "Iron", quantity: 2 },
+						{ name: "Wood", quantity: 1 },
+					],
+					output: {
+						name: "Sword",
+						uri: "https://example.com/sword",
+					},
+				});
+			});
+
+			async function mintItem(name: string): Promise<number[]> {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const itemId = createVirtualItemId();
+				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
+					buyer: buyer.publicKey,
+					itemId,
+					collectionConfig,
+					metadata: { name, uri: "https://example.com" },
+				});
+				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+				return itemId;
+			}
+
+			async function craftWith(itemIds: number[][]) {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const inputs = await Promise.all(
+					itemIds.map((itemId) =>
+						program.account.mintRecord.fetch(carbon.pdas.mintRecord(collectionConfigPDA, itemId))
+					)
+				);
+				const itemId = createVirtualItemId();
+				const { mint: mintKeypair, instruction } = await carbon.instructions.craft({
+					player: buyer.publicKey,
+					collectionConfig,
+					recipe: await carbon.accounts.recipe(collectionMint, recipeId),
+					inputs,
+					itemId,
+				});
+				await provider.sendAndConfirm(
+					new Transaction()
+						.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }))
+						.add(instruction),
+					[marketplaceAuthority, mintKeypair, buyer]
+				);
+				return { itemId, inputs, mint: mintKeypair.publicKey };
+			}
+
+			it("should burn the inputs and mint the output", async function () {
+				const itemIds = [await mintItem("Iron"), await mintItem("Wood"), await mintItem("Iron")];
+
+				const { itemId, inputs, mint } = await craftWith(itemIds);
+
+				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
+				assert.equal(nft.name, "Sword");
+				assert.equal(nft.uri, "https://example.com/sword");
+				assert.isTrue(nft.collection.verified);
+				const mintRecord = await program.account.mintRecord.fetch(
+					carbon.pdas.mintRecord(collectionConfigPDA, itemId)
 				);
+				assert.equal(mintRecord.mint.toString(), mint.toString());
+
+				// The inputs and their mint records should be gone
+				for (const input of inputs) {
+					assert.isNull(
+						await provider.connection.getAccountInfo(
+							getAssociatedTokenAddressSync(input.mint, buyer.publicKey)
+						)
+					);
+					assert.isNull(
+						await provider.connection.getAccountInfo(
+							carbon.pdas.mintRecord(collectionConfigPDA, input.itemId)
+						)
+					);
+				}
+			});
 
-				// Make sure correct amounts were sent to seller and fee account
-				assert.equal(buyerPreBalance - buyerPostBalance, price + mintRecordStorageFee);
+			it("should throw when the inputs do not match the recipe", async function () {
+				const itemIds = [await mintItem("Iron"), await mintItem("Wood"), await mintItem("Wood")];
 
-				const mintingFee = 0.02 * LAMPORTS_PER_SOL;
-				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
-				assert.isAtLeast(
-					marketplaceAuthPostBalance - marketplaceAuthPreBalance,
-					price - marketplaceFee - mintingFee
+				await assertThrows(async () => {
+					await craftWith(itemIds);
+				});
+
+				const mintRecord = await program.account.mintRecord.fetch(
+					carbon.pdas.mintRecord(collectionConfigPDA, itemIds[0])
+				);
+				assert.isNotNull(
+					await provider.connection.getAccountInfo(
+						getAssociatedTokenAddressSync(mintRecord.mint, buyer.publicKey)
+					)
 				);
+			});
+		});
 
-				assert.equal(feeAccountPostBalance - feeAccountPreBalance, marketplaceFee);
+		describe("mystery box", function () {
+			let boxId: number[];
+			let seed: number[];
+
+			beforeEach(async function () {
+				boxId = createVirtualItemId();
+				seed = createVirtualItemId();
+				await carbon.methods.initMysteryBox({
+					collectionMint,
+					boxId,
+					metadata: {
+						name: "Loot Box",
+						uri: "https://example.com/box",
+					},
+					prizes: [
+						{ metadata: { name: "Sword", uri: "https://example.com/sword" }, weight: 1 },
+						{ metadata: { name: "Shield", uri: "https://example.com/shield" }, weight: 0 },
+					],
+				});
 			});
 
-			it("should buy the virtual item with SPL correctly", async function () {
-				price = 1000;
-				const { mint: splTokenMint } = await createSplToken(
-					provider,
+			async function mintAndOpenBox(): Promise<PublicKey> {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const mysteryBox = await carbon.accounts.mysteryBox(collectionMint, boxId);
+				const { mint: mintKeypair, instruction } = await carbon.instructions.mintMysteryBox({
+					buyer: buyer.publicKey,
+					collectionConfig,
+					mysteryBox,
+					itemId: createVirtualItemId(),
+					seedHash: hashSeed(seed),
+				});
+				await provider.sendAndConfirm(new Transaction().add(instruction), [
 					marketplaceAuthority,
-					buyer.publicKey,
-					price
-				);
+					mintKeypair,
+					buyer,
+				]);
 
-				await carbon.methods.listVirtual({
-					itemId,
+				await carbon.methods.openMysteryBox({
+					holder: new Wallet(buyer),
 					collectionMint,
-					price,
-					expiry,
-					currencyMint: splTokenMint,
+					mysteryBox,
+					sealedBox: await carbon.accounts.sealedBox(mintKeypair.publicKey),
 				});
-				const listing = await program.account.listing.fetch(listingPDA);
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				return mintKeypair.publicKey;
+			}
 
-				const { mint: mintKeypair, instruction } = await carbon.instructions.buyVirtual({
-					buyer: buyer.publicKey,
+			async function reveal(boxMint: PublicKey, revealedSeed: number[]) {
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const { mint: mintKeypair, instruction } = await carbon.instructions.revealMysteryBox({
 					collectionConfig,
-					listing,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
+					mysteryBox: await carbon.accounts.mysteryBox(collectionMint, boxId),
+					sealedBox: await carbon.accounts.sealedBox(boxMint),
+					itemId: createVirtualItemId(),
+					seed: revealedSeed,
 				});
 				await provider.sendAndConfirm(
 					new Transaction()
-						.add(
-							ComputeBudgetProgram.setComputeUnitLimit({
-								units: 400_000,
-							})
-						)
+						.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
 						.add(instruction),
-					[marketplaceAuthority, mintKeypair, buyer]
+					[marketplaceAuthority, mintKeypair]
 				);
+				return mintKeypair.publicKey;
+			}
 
-				const marketplaceAuthTokenAddress = await getAssociatedTokenAddressSync(
-					splTokenMint,
-					marketplaceAuthority.publicKey
-				);
-				const marketplaceAuthPostBalance = await provider.connection.getTokenAccountBalance(
-					marketplaceAuthTokenAddress
-				);
-				const buyerTokenAddress = await getAssociatedTokenAddressSync(
-					splTokenMint,
-					buyer.publicKey
-				);
-				const buyerPostBalance = await provider.connection.getTokenAccountBalance(
-					buyerTokenAddress
-				);
-				const feeAccountTokenAddress = await getAssociatedTokenAddressSync(
-					splTokenMint,
-					FEE_ACCOUNT_KEY
-				);
-				const feeAccountPostBalance = await provider.connection.getTokenAccountBalance(
-					feeAccountTokenAddress
+			it("should burn the box when opened and mint the drawn prize when revealed", async function () {
+				const boxMint = await mintAndOpenBox();
+
+				assert.isNull(
+					await provider.connection.getAccountInfo(
+						getAssociatedTokenAddressSync(boxMint, buyer.publicKey)
+					)
 				);
+				const sealedBox = await carbon.accounts.sealedBox(boxMint);
+				assert.equal(sealedBox.holder.toString(), buyer.publicKey.toString());
 
-				// Make sure correct amounts were sent to seller and fee account
-				assert.equal(buyerPostBalance.value.uiAmount, 0);
+				const prizeMint = await reveal(boxMint, seed);
 
-				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
-				assert.isAtLeast(
-					marketplaceAuthPostBalance.value.uiAmount,
-					price - marketplaceFee - TX_FEE
+				const nft = await fetchNFT(provider, marketplaceAuthority, prizeMint);
+				assert.equal(nft.name, "Sword");
+				assert.isTrue(nft.collection.verified);
+				const buyerTokenAccount = await getAccount(
+					provider.connection,
+					getAssociatedTokenAddressSync(prizeMint, buyer.publicKey)
 				);
+				assert.equal(Number(buyerTokenAccount.amount), 1);
+				assert.isUndefined(await carbon.accounts.sealedBox(boxMint));
+			});
 
-				assert.equal(feeAccountPostBalance.value.uiAmount, marketplaceFee);
+			it("should throw when revealing a seed other than the committed one", async function () {
+				const boxMint = await mintAndOpenBox();
+
+				await assertThrows(async () => {
+					await reveal(boxMint, createVirtualItemId());
+				});
 			});
 
-			it("should buy the virtual item correctly with a third-party seller", async function () {
-				const sellerPreBalance = await provider.connection.getBalance(seller.publicKey);
-				const marketplaceAuthPreBalance = await provider.connection.getBalance(
-					marketplaceAuthority.publicKey
-				);
+			it("should record the entropy the prize is drawn with when opened", async function () {
+				const boxMint = await mintAndOpenBox();
 
-				const listTx = await carbon.transactions.listVirtual({
-					seller: seller.publicKey,
-					itemId,
+				const sealedBox = await carbon.accounts.sealedBox(boxMint);
+				assert.notDeepEqual(sealedBox.entropy, new Array(32).fill(0));
+			});
+
+			it("should still reveal a box once its opening slot left the slot hashes", async function () {
+				const boxMint = await mintAndOpenBox();
+				const openedBox = await carbon.accounts.sealedBox(boxMint);
+
+				// The SlotHashes sysvar keeps the hashes of the last 512 slots
+				await waitForSlot(provider, openedBox.openSlot.toNumber() + 512);
+
+				const prizeMint = await reveal(boxMint, seed);
+
+				const nft = await fetchNFT(provider, marketplaceAuthority, prizeMint);
+				assert.equal(nft.name, "Sword");
+				assert.isUndefined(await carbon.accounts.sealedBox(boxMint));
+			});
+		});
+
+		describe("raffle", function () {
+			let raffleId: number[];
+			let seed: number[];
+
+			beforeEach(async function () {
+				raffleId = createVirtualItemId();
+				seed = createVirtualItemId();
+				await carbon.methods.createVirtualRaffle({
 					collectionMint,
-					price,
-					expiry,
+					raffleId,
+					itemId,
+					metadata: {
+						name: "Raffle Prize",
+						uri: "https://example.com/prize",
+					},
+					ticketPrice: price,
+					maxTickets: 2,
+					endTime: Math.floor(Date.now() / 1000) + 3600,
 				});
-				await provider.sendAndConfirm(listTx, [seller]);
+			});
 
-				const listing = await program.account.listing.fetch(listingPDA);
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+			it("should draw a winner once sold out and mint the prize to them", async function () {
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(raffleId),
+					count: 2,
+				});
+				await carbon.methods.closeRaffle({
+					raffle: await carbon.accounts.raffle(raffleId),
+					seedHash: hashSeed(seed),
+				});
+				await carbon.methods.drawRaffle({
+					raffle: await carbon.accounts.raffle(raffleId),
+					seed,
+				});
 
-				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
-				const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
-				const { mint: mintKeypair, transaction } = await carbon.transactions.buyVirtual({
-					buyer: buyer.publicKey,
+				const raffle = await carbon.accounts.raffle(raffleId);
+				assert.deepEqual(raffle.state, { drawn: {} });
+				assert.isBelow(raffle.winningTicket, 2);
+
+				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+				const rafflePDA = carbon.pdas.raffle(raffleId);
+				const { mint: mintKeypair, instruction } = await carbon.instructions.claimRaffleVirtual({
+					winner: buyer.publicKey,
 					collectionConfig,
-					listing,
+					raffle,
+					ticket: await carbon.accounts.raffleTicket(rafflePDA, 0),
+				});
+				await provider.sendAndConfirm(
+					new Transaction()
+						.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
+						.add(instruction),
+					[marketplaceAuthority, mintKeypair, buyer]
+				);
+
+				const nft = await fetchNFT(provider, marketplaceAuthority, mintKeypair.publicKey);
+				assert.equal(nft.name, "Raffle Prize");
+				assert.isTrue(nft.collection.verified);
+				assert.isUndefined(await carbon.accounts.raffle(raffleId));
+				assert.isUndefined(await carbon.accounts.raffleTicket(rafflePDA, 0));
+			});
+
+			it("should record the amount received for tickets paid in a Token-2022 transfer fee currency", async function () {
+				const ticketPrice = 1000;
+				const transferFeeBps = 100;
+				const { mint: currencyMint } = await createTransferFeeToken(
+					provider,
+					marketplaceAuthority,
+					buyer.publicKey,
+					ticketPrice,
+					transferFeeBps
+				);
+				const tokenRaffleId = createVirtualItemId();
+				await carbon.methods.createVirtualRaffle({
+					collectionMint,
+					raffleId: tokenRaffleId,
+					itemId: createVirtualItemId(),
 					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
+						name: "Raffle Prize",
+						uri: "https://example.com/prize",
 					},
+					ticketPrice,
+					maxTickets: 2,
+					endTime: Math.floor(Date.now() / 1000) + 3600,
+					currencyMint,
 				});
-				await provider.sendAndConfirm(transaction, [buyer]);
 
-				const sellerPostBalance = await provider.connection.getBalance(seller.publicKey);
-				const marketplaceAuthPostBalance = await provider.connection.getBalance(
-					marketplaceAuthority.publicKey
-				);
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(tokenRaffleId),
+					count: 1,
+				});
+
+				// Refunds pay back what the raffle received, less the fee withheld from the purchase
+				const received = ticketPrice - Math.ceil((ticketPrice * transferFeeBps) / 10000);
+				const rafflePDA = carbon.pdas.raffle(tokenRaffleId);
+				const ticket = await carbon.accounts.raffleTicket(rafflePDA, 0);
+				assert.equal(ticket.amount.toNumber(), received);
+				const raffle = await carbon.accounts.raffle(tokenRaffleId);
+				assert.equal(raffle.proceeds.toNumber(), received);
+			});
+
+			it("should throw when closing before ticket sales end", async function () {
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(raffleId),
+					count: 1,
+				});
+
+				await assertThrows(async () => {
+					await carbon.methods.closeRaffle({
+						raffle: await carbon.accounts.raffle(raffleId),
+						seedHash: hashSeed(seed),
+					});
+				});
+			});
+
+			it("should throw when expiring a raffle that can still be drawn", async function () {
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(raffleId),
+					count: 2,
+				});
+				await carbon.methods.closeRaffle({
+					raffle: await carbon.accounts.raffle(raffleId),
+					seedHash: hashSeed(seed),
+				});
+
+				await assertThrows(async () => {
+					await carbon.methods.expireRaffle({ raffle: await carbon.accounts.raffle(raffleId) });
+				});
+			});
+
+			it("should throw when expiring an open raffle within the close grace period", async function () {
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(raffleId),
+					count: 2,
+				});
+
+				await assertThrows(async () => {
+					await carbon.methods.expireRaffle({ raffle: await carbon.accounts.raffle(raffleId) });
+				});
+			});
+
+			it("should refund the tickets once the draw can no longer be revealed", async function () {
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(raffleId),
+					count: 2,
+				});
+				await carbon.methods.closeRaffle({
+					raffle: await carbon.accounts.raffle(raffleId),
+					seedHash: hashSeed(seed),
+				});
+
+				// The SlotHashes sysvar keeps the hashes of the last 512 slots
+				const closedRaffle = await carbon.accounts.raffle(raffleId);
+				await waitForSlot(provider, closedRaffle.closeSlot.toNumber() + 512);
+
+				await assertThrows(async () => {
+					await carbon.methods.drawRaffle({ raffle: closedRaffle, seed });
+				});
+				await carbon.methods.expireRaffle({ raffle: closedRaffle, wallet: new Wallet(seller) });
+
+				const raffle = await carbon.accounts.raffle(raffleId);
+				assert.deepEqual(raffle.state, { expired: {} });
+
+				const rafflePDA = carbon.pdas.raffle(raffleId);
+				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+				await carbon.methods.refundRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle,
+					ticket: await carbon.accounts.raffleTicket(rafflePDA, 0),
+				});
 				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
-				const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
 
-				// An NFT should now exist with the correct metadata
-				const mint = mintKeypair.publicKey;
-				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
-				assert.equal(
-					nft.updateAuthorityAddress.toString(),
-					marketplaceAuthority.publicKey.toString()
-				);
-				assert.equal(nft.name, "Ghost #1");
-				assert.equal(nft.uri, "https://example.com");
-				assert.equal(nft.symbol, defaultSymbol);
-				assert.equal(nft.sellerFeeBasisPoints, defaultSellerFeeBps);
-				assert.isTrue(nft.collection.verified);
-				assert.equal(nft.collection.address.toString(), collectionMint.toString());
-				assert.isTrue(nft.primarySaleHappened);
-				assert.deepEqual(nft.creators, [
-					{
-						address: marketplaceAuthority.publicKey,
-						verified: true,
-						share: 100,
-					},
-				]);
+				// The ticket rent returned to the buyer covers the transaction fees
+				assert.isAtLeast(buyerPostBalance - buyerPreBalance, price * 2);
+				assert.isUndefined(await carbon.accounts.raffleTicket(rafflePDA, 0));
+				assert.isUndefined(await carbon.accounts.raffle(raffleId));
+			});
 
-				// Make sure buyer is the owner and can transfer the NFT
-				const sellerTokenAccount = await createAssociatedTokenAccount(
-					provider.connection,
-					buyer,
-					mint,
-					seller.publicKey
-				);
-				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
-				await transferChecked(
-					provider.connection,
-					buyer,
-					buyerTokenAccount,
-					mint,
-					sellerTokenAccount,
-					buyer,
-					1,
-					0
+			it("should only be cancellable while no tickets are sold", async function () {
+				await carbon.methods.buyRaffleTickets({
+					buyer: new Wallet(buyer),
+					raffle: await carbon.accounts.raffle(raffleId),
+					count: 1,
+				});
+
+				await assertThrows(async () => {
+					await carbon.methods.cancelRaffle({
+						raffle: await carbon.accounts.raffle(raffleId),
+					});
+				});
+			});
+		});
+
+		describe("authorization", function () {
+			const metadata = {
+				name: "Ghost #1",
+				uri: "https://example.com",
+			};
+
+			function authorize(fields: Partial<Authorization> = {}) {
+				const authorization: Authorization = {
+					action: { listVirtual: {} },
+					itemId,
+					collectionConfig: collectionConfigPDA,
+					owner: seller.publicKey,
+					price: new anchor.BN(price),
+					metadataHash: new Array(32).fill(0),
+					expiry: new anchor.BN(expiry),
+					nonce: new anchor.BN(Math.floor(Math.random() * 1_000_000_000)),
+					...fields,
+				};
+				const signature = signMessage(marketplaceAuthority, getAuthorizationMessage(authorization));
+				return { authorization, signature };
+			}
+
+			async function mintWithAuthorization(authorization: Authorization, signature: Uint8Array) {
+				const { mint: mintKeypair, instructions } =
+					await carbon.instructions.mintVirtualWithAuthorization({
+						authorization,
+						signature,
+						collectionConfig: await program.account.collectionConfig.fetch(collectionConfigPDA),
+						metadata,
+					});
+				// Sent by the buyer alone, without the marketplace authority signing
+				await carbon
+					.getProviderWithWallet(new Wallet(buyer))
+					.sendAndConfirm(
+						new Transaction()
+							.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
+							.add(...instructions),
+						[mintKeypair]
+					);
+				return mintKeypair.publicKey;
+			}
+
+			it("should list the virtual item for the authorized seller", async function () {
+				const { authorization, signature } = authorize();
+				await carbon.methods.listVirtualWithAuthorization({
+					seller: new Wallet(seller),
+					authorization,
+					signature,
+					collectionMint,
+					expiry,
+				});
+
+				const listing = await program.account.listing.fetch(listingPDA);
+				assert.equal(listing.seller.toString(), seller.publicKey.toString());
+				assert.equal(listing.price.toNumber(), price);
+				assert.isTrue(listing.isVirtual);
+
+				const authorizationNonce = await carbon.accounts.authorizationNonce(
+					authorization.nonce.toNumber()
 				);
+				assert.equal(authorizationNonce.payer.toString(), seller.publicKey.toString());
+			});
 
-				assert.equal(buyerPreBalance - buyerPostBalance, price + mintRecordStorageFee);
+			it("should throw when the authorization is used twice", async function () {
+				const { authorization, signature } = authorize();
+				await carbon.methods.listVirtualWithAuthorization({
+					seller: new Wallet(seller),
+					authorization,
+					signature,
+					collectionMint,
+					expiry,
+				});
+				await carbon.methods.delistVirtual({ seller: new Wallet(seller), itemId });
 
-				// Make sure correct amounts were sent to seller and fee account
-				const royalty = (price * defaultSellerFeeBps) / 10000;
-				const marketplaceFee = (price * defaultFeeConfig.bps) / 10000;
-				assert.isAtLeast(sellerPostBalance - sellerPreBalance, price - marketplaceFee - royalty);
+				await assertThrows(async () => {
+					await carbon.methods.listVirtualWithAuthorization({
+						seller: new Wallet(seller),
+						authorization,
+						signature,
+						collectionMint,
+						expiry,
+					});
+				});
+			});
 
-				const mintingFee = 0.02 * LAMPORTS_PER_SOL;
-				assert.isAtLeast(
-					marketplaceAuthPostBalance,
-					marketplaceAuthPreBalance + royalty - mintingFee
-				);
+			it("should throw when the authorization was signed by another wallet", async function () {
+				const { authorization } = authorize();
+				const signature = signMessage(seller, getAuthorizationMessage(authorization));
 
-				assert.equal(feeAccountPostBalance - feeAccountPreBalance, marketplaceFee);
+				await assertThrows(async () => {
+					await carbon.methods.listVirtualWithAuthorization({
+						seller: new Wallet(seller),
+						authorization,
+						signature,
+						collectionMint,
+						expiry,
+					});
+				});
 			});
 
-			it("should throw when giving an incorrect collection config", async function () {
-				await carbon.methods.listVirtual({ itemId, collectionMint, price, expiry });
+			it("should throw when the authorization has expired", async function () {
+				const { authorization, signature } = authorize({
+					expiry: new anchor.BN(moment().subtract(1, "minute").unix()),
+				});
 
-				const listing = await program.account.listing.fetch(listingPDA);
-				collectionMint = Keypair.generate().publicKey;
-				await carbon.methods.initCollectionConfig({
-					args: {
+				await assertThrows(async () => {
+					await carbon.methods.listVirtualWithAuthorization({
+						seller: new Wallet(seller),
+						authorization,
+						signature,
 						collectionMint,
-						sellerFeeBasisPoints: defaultSellerFeeBps,
-						symbol: defaultSymbol,
-					},
+						expiry,
+					});
 				});
+			});
 
-				collectionConfigPDA = carbon.pdas.collectionConfig(collectionMint);
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+			it("should throw when the authorization is for another collection", async function () {
+				const { authorization, signature } = authorize({
+					collectionConfig: Keypair.generate().publicKey,
+				});
 
-				const { mint: mintKeypair, instruction } = await carbon.instructions.buyVirtual({
-					buyer: buyer.publicKey,
-					collectionConfig,
-					listing,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
+				await assertThrows(async () => {
+					await carbon.methods.listVirtualWithAuthorization({
+						seller: new Wallet(seller),
+						authorization,
+						signature,
+						collectionMint,
+						expiry,
+					});
+				});
+			});
+
+			it("should mint the authorized item to the buyer", async function () {
+				await carbon.methods.approveCollectionMinting({ collectionMint });
+
+				const { authorization, signature } = authorize({
+					action: { mintVirtual: {} },
+					owner: buyer.publicKey,
+					metadataHash: hashMetadata(metadata),
+				});
+				const mint = await mintWithAuthorization(authorization, signature);
+
+				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
+				assert.equal(nft.name, metadata.name);
+				assert.isTrue(nft.collection.verified);
+				assert.equal(nft.collection.address.toString(), collectionMint.toString());
+				assert.equal(nft.updateAuthorityAddress.toString(), collectionConfigPDA.toString());
+				const buyerTokenAccount = await getAccount(
+					provider.connection,
+					getAssociatedTokenAddressSync(mint, buyer.publicKey)
+				);
+				assert.equal(Number(buyerTokenAccount.amount), 1);
+
+				const mintRecord = await program.account.mintRecord.fetch(
+					carbon.pdas.mintRecord(collectionConfigPDA, itemId)
+				);
+				assert.equal(mintRecord.mint.toString(), mint.toString());
+			});
+
+			it("should throw when minting metadata other than the authorized one", async function () {
+				await carbon.methods.approveCollectionMinting({ collectionMint });
+
+				const { authorization, signature } = authorize({
+					action: { mintVirtual: {} },
+					owner: buyer.publicKey,
+					metadataHash: hashMetadata({ ...metadata, name: "Ghost #2" }),
 				});
 
 				await assertThrows(async () => {
-					await provider.sendAndConfirm(new Transaction().add(instruction), [
-						marketplaceAuthority,
-						mintKeypair,
-						buyer,
-					]);
+					await mintWithAuthorization(authorization, signature);
 				});
 			});
 		});
 
-		describe("mint_virtual", function () {
-			it("should mint the virtual item correctly", async function () {
-				const marketplaceAuthPreBalance = await provider.connection.getBalance(
-					marketplaceAuthority.publicKey
-				);
+		describe("mint voucher", function () {
+			const metadata = {
+				name: "Ghost #1",
+				uri: "https://example.com",
+			};
 
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
-				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
+			beforeEach(async function () {
+				await carbon.methods.approveCollectionMinting({ collectionMint });
+			});
 
-				itemId = createVirtualItemId();
-				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
-					buyer: buyer.publicKey,
+			function signVoucher(fields: Partial<MintVoucher> = {}) {
+				const voucher: MintVoucher = {
 					itemId,
-					collectionConfig,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
+					collectionConfig: collectionConfigPDA,
+					recipient: PublicKey.default,
+					price: new anchor.BN(price),
+					currencyMint: NATIVE_MINT,
+					expiry: new anchor.BN(expiry),
+					metadataHash: hashMetadata(metadata),
+					nonce: new anchor.BN(Math.floor(Math.random() * 1_000_000_000)),
+					...fields,
+				};
+				const signature = signMessage(marketplaceAuthority, getMintVoucherMessage(voucher));
+				return { voucher, signature };
+			}
+
+			async function redeem(redeemer: Keypair, voucher: MintVoucher, signature: Uint8Array) {
+				const { mint: mintKeypair, instructions } = await carbon.instructions.redeemMintVoucher({
+					buyer: redeemer.publicKey,
+					voucher,
+					signature,
+					collectionConfig: await program.account.collectionConfig.fetch(collectionConfigPDA),
+					metadata,
 				});
-				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+				// Sent by the redeemer alone, without the marketplace authority signing
+				await carbon
+					.getProviderWithWallet(new Wallet(redeemer))
+					.sendAndConfirm(
+						new Transaction()
+							.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
+							.add(...instructions),
+						[mintKeypair]
+					);
+				return mintKeypair.publicKey;
+			}
+
+			it("should mint the item to the buyer and pay the marketplace authority", async function () {
+				const { voucher, signature } = signVoucher();
+				const marketplaceAuthPreBalance = await provider.connection.getBalance(
+					marketplaceAuthority.publicKey
+				);
+				const feeAccountPreBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+				const mint = await redeem(buyer, voucher, signature);
 
 				const marketplaceAuthPostBalance = await provider.connection.getBalance(
 					marketplaceAuthority.publicKey
 				);
-				const buyerPostBalance = await provider.connection.getBalance(buyer.publicKey);
+				const feeAccountPostBalance = await provider.connection.getBalance(FEE_ACCOUNT_KEY);
+
+				// The marketplace authority is the creator too, so it receives the royalties as well
+				const fee = (price * defaultFeeConfig.bps) / 10_000;
+				assert.equal(feeAccountPostBalance - feeAccountPreBalance, fee);
+				assert.equal(marketplaceAuthPostBalance - marketplaceAuthPreBalance, price - fee);
 
-				// An NFT should now exist with the correct metadata
-				const mint = mintKeypair.publicKey;
 				const nft = await fetchNFT(provider, marketplaceAuthority, mint);
-				assert.equal(
-					nft.updateAuthorityAddress.toString(),
-					marketplaceAuthority.publicKey.toString()
-				);
-				assert.equal(nft.name, "Ghost #1");
-				assert.equal(nft.uri, "https://example.com");
-				assert.equal(nft.symbol, defaultSymbol);
-				assert.equal(nft.sellerFeeBasisPoints, defaultSellerFeeBps);
+				assert.equal(nft.name, metadata.name);
 				assert.isTrue(nft.collection.verified);
-				assert.equal(nft.collection.address.toString(), collectionMint.toString());
 				assert.isTrue(nft.primarySaleHappened);
-				assert.deepEqual(nft.creators, [
-					{
-						address: marketplaceAuthority.publicKey,
-						verified: true,
-						share: 100,
-					},
-				]);
-
-				// The mint record should exist
-				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
-				const mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
-				assert.equal(mintRecord.collectionConfig.toString(), collectionConfigPDA.toString());
-				assert.deepEqual(mintRecord.itemId, itemId);
-				assert.equal(mintRecord.mint.toString(), mint.toString());
-
-				// Make sure buyer is the owner and can transfer the NFT
-				const sellerTokenAccount = await createAssociatedTokenAccount(
-					provider.connection,
-					buyer,
-					mint,
-					seller.publicKey
-				);
-				const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey);
-				await transferChecked(
+				const buyerTokenAccount = await getAccount(
 					provider.connection,
-					buyer,
-					buyerTokenAccount,
-					mint,
-					sellerTokenAccount,
-					buyer,
-					1,
-					0
+					getAssociatedTokenAddressSync(mint, buyer.publicKey)
 				);
+				assert.equal(Number(buyerTokenAccount.amount), 1);
 
-				// Make sure correct amounts were sent to seller and fee account
-				const mintingFee = 0.01 * LAMPORTS_PER_SOL;
-				assert.isAtLeast(buyerPreBalance - buyerPostBalance, mintingFee);
-
-				assert.equal(marketplaceAuthPreBalance - marketplaceAuthPostBalance, 15000);
-			});
-
-			it("should throw when trying to mint the same item id twice", async function () {
-				const marketplaceAuthPreBalance = await provider.connection.getBalance(
-					marketplaceAuthority.publicKey
+				const mintRecord = await program.account.mintRecord.fetch(
+					carbon.pdas.mintRecord(collectionConfigPDA, itemId)
 				);
+				assert.equal(mintRecord.mint.toString(), mint.toString());
+			});
 
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
-				const buyerPreBalance = await provider.connection.getBalance(buyer.publicKey);
-
-				itemId = createVirtualItemId();
-				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
-					buyer: buyer.publicKey,
-					itemId,
-					collectionConfig,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
-				});
-				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+			it("should throw when redeeming the voucher twice", async function () {
+				const { voucher, signature } = signVoucher();
+				await redeem(buyer, voucher, signature);
 
 				await assertThrows(async () => {
-					const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
-						buyer: buyer.publicKey,
-						itemId,
-						collectionConfig,
-						metadata: {
-							name: "Ghost #1",
-							uri: "https://example.com",
-						},
-					});
-					await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+					await redeem(seller, voucher, signature);
 				});
 			});
-		});
-
-		describe("close_mint_record", function () {
-			it("should close the mint record correctly", async function () {
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
 
-				itemId = createVirtualItemId();
-				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
-					buyer: buyer.publicKey,
-					itemId,
-					collectionConfig,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
-				});
-				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+			it("should throw when redeemed again after the mint record is closed", async function () {
+				const { voucher, signature } = signVoucher();
+				const mint = await redeem(buyer, voucher, signature);
 
 				const metaplex = new Metaplex(provider.connection).use(keypairIdentity(buyer));
 				await metaplex.nfts().delete({
-					mintAddress: mintKeypair.publicKey,
+					mintAddress: mint,
 					collection: collectionMint,
 				});
-
-				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
-				let mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
-
 				await carbon.methods.closeMintRecord({
-					mintRecord,
+					mintRecord: await program.account.mintRecord.fetch(
+						carbon.pdas.mintRecord(collectionConfigPDA, itemId)
+					),
 				});
 
-				// The mint record should no longer exist
-				let account = await provider.connection.getAccountInfo(mintRecordPDA);
-				assert.isNull(account);
-			});
-
-			it("should throw when closing a mint record with an existing mint", async function () {
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
-
-				itemId = createVirtualItemId();
-				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
-					buyer: buyer.publicKey,
-					itemId,
-					collectionConfig,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
+				await assertThrows(async () => {
+					await redeem(buyer, voucher, signature);
 				});
-				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+			});
 
-				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
-				let mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
+			it("should throw when redeemed by a wallet other than the recipient", async function () {
+				const { voucher, signature } = signVoucher({ recipient: buyer.publicKey });
 
 				await assertThrows(async () => {
-					await carbon.methods.closeMintRecord({
-						mintRecord,
-					});
+					await redeem(seller, voucher, signature);
 				});
 			});
 
-			it("should throw when closing a mint record as non-authority", async function () {
-				const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+			it("should throw when the price differs from the signed one", async function () {
+				const { voucher, signature } = signVoucher();
 
-				itemId = createVirtualItemId();
-				const { mint: mintKeypair, transaction } = await carbon.transactions.mintVirtual({
-					buyer: buyer.publicKey,
-					itemId,
-					collectionConfig,
-					metadata: {
-						name: "Ghost #1",
-						uri: "https://example.com",
-					},
+				await assertThrows(async () => {
+					await redeem(buyer, { ...voucher, price: new anchor.BN(0) }, signature);
 				});
-				await provider.sendAndConfirm(transaction, [marketplaceAuthority, mintKeypair, buyer]);
+			});
 
-				const metaplex = new Metaplex(provider.connection).use(keypairIdentity(buyer));
-				await metaplex.nfts().delete({
-					mintAddress: mintKeypair.publicKey,
-					collection: collectionMint,
+			it("should throw when redeemed into a collection other than the signed one", async function () {
+				const { voucher, signature } = signVoucher({
+					collectionConfig: Keypair.generate().publicKey,
 				});
 
-				const mintRecordPDA = carbon.pdas.mintRecord(collectionConfigPDA, itemId);
-				let mintRecord = await program.account.mintRecord.fetch(mintRecordPDA);
-
 				await assertThrows(async () => {
-					const ix = await carbon.instructions.closeMintRecord({
-						marketplaceAuthority: buyer.publicKey,
-						mintRecord,
-					});
-
-					await provider.sendAndConfirm(new Transaction().add(ix), [buyer]);
+					await redeem(buyer, voucher, signature);
 				});
 			});
 		});
@@ -1426,6 +4025,7 @@ describe("carbon", () => {
 					carbon.methods.initMarketplaceConfig({
 						args: {
 							feeConfig: defaultFeeConfig,
+							keeperReward: defaultKeeperReward,
 						},
 					}),
 					carbon.methods.initCollectionConfig({
@@ -1433,6 +4033,9 @@ describe("carbon", () => {
 							collectionMint,
 							sellerFeeBasisPoints: defaultSellerFeeBps,
 							symbol: defaultSymbol,
+							swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+							mintStandard: defaultMintStandard,
+							verification: defaultVerification,
 						},
 					}),
 				]);
@@ -1456,6 +4059,7 @@ describe("carbon", () => {
 					carbon.methods.initMarketplaceConfig({
 						args: {
 							feeConfig: defaultFeeConfig,
+							keeperReward: defaultKeeperReward,
 						},
 					}),
 					carbon.methods.initCollectionConfig({
@@ -1463,6 +4067,9 @@ describe("carbon", () => {
 							collectionMint,
 							sellerFeeBasisPoints: defaultSellerFeeBps,
 							symbol: defaultSymbol,
+							swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+							mintStandard: defaultMintStandard,
+							verification: defaultVerification,
 						},
 					}),
 					createNFT(provider, marketplaceAuthority, collectionMint, {
@@ -1494,6 +4101,7 @@ describe("carbon", () => {
 					carbon.methods.initMarketplaceConfig({
 						args: {
 							feeConfig: defaultFeeConfig,
+							keeperReward: defaultKeeperReward,
 						},
 					}),
 					carbon.methods.initCollectionConfig({
@@ -1501,6 +4109,9 @@ describe("carbon", () => {
 							collectionMint,
 							sellerFeeBasisPoints: defaultSellerFeeBps,
 							symbol: defaultSymbol,
+							swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+							mintStandard: defaultMintStandard,
+							verification: defaultVerification,
 						},
 					}),
 					createNFT(provider, marketplaceAuthority, collectionMint, {
@@ -1542,6 +4153,7 @@ describe("carbon", () => {
 					carbon.methods.initMarketplaceConfig({
 						args: {
 							feeConfig: defaultFeeConfig,
+							keeperReward: defaultKeeperReward,
 						},
 					}),
 					carbon.methods.initCollectionConfig({
@@ -1549,6 +4161,9 @@ describe("carbon", () => {
 							collectionMint,
 							sellerFeeBasisPoints: defaultSellerFeeBps,
 							symbol: defaultSymbol,
+							swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+							mintStandard: defaultMintStandard,
+							verification: defaultVerification,
 						},
 					}),
 				]);
@@ -1711,6 +4326,7 @@ describe("carbon", () => {
 				carbon.methods.initMarketplaceConfig({
 					args: {
 						feeConfig: defaultFeeConfig,
+						keeperReward: defaultKeeperReward,
 					},
 				}),
 				carbon.methods.initCollectionConfig({
@@ -1718,6 +4334,9 @@ describe("carbon", () => {
 						collectionMint,
 						sellerFeeBasisPoints: defaultSellerFeeBps,
 						symbol: defaultSymbol,
+						swapRoyaltyPolicy: defaultSwapRoyaltyPolicy,
+						mintStandard: defaultMintStandard,
+						verification: defaultVerification,
 					},
 				}),
 			]);
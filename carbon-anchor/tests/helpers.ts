@@ -1,10 +1,26 @@
 import { Provider } from "@coral-xyz/anchor";
-import { keypairIdentity, Metaplex } from "@metaplex-foundation/js";
-import { Keypair, Transaction, SystemProgram, PublicKey } from "@solana/web3.js";
-import { createMint, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { keypairIdentity, Metaplex, token } from "@metaplex-foundation/js";
+import {
+	Keypair,
+	Transaction,
+	SystemProgram,
+	PublicKey,
+	sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+	createInitializeMintInstruction,
+	createInitializeTransferFeeConfigInstruction,
+	createMint,
+	ExtensionType,
+	getMintLen,
+	getOrCreateAssociatedTokenAccount,
+	mintTo,
+	TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 import { assert } from "chai";
 import { toItemId } from "@raresloth/carbon-sdk";
 import * as uuid from "uuid";
+import * as crypto from "crypto";
 
 export async function setBalance(provider: Provider, keypair: Keypair, amount: number) {
 	const balance = await provider.connection.getBalance(keypair.publicKey);
@@ -75,6 +91,32 @@ export async function createNFT(
 	return { mint, metadataAccount, edition };
 }
 
+// Single token with metadata in a verified collection but no master edition, so its edition
+// can't freeze it
+export async function createSFT(
+	provider: Provider,
+	payer: Keypair,
+	collectionMint: PublicKey,
+	mintArgs?: any
+) {
+	const metaplex = new Metaplex(provider.connection).use(keypairIdentity(payer));
+	const result = await metaplex.nfts().createSft({
+		name: "SFT Item",
+		uri: "https://arweave.net/Rb9SwSImzCInyGbaxbT1bpnjJGiTszkCTLWZiomnerw",
+		sellerFeeBasisPoints: 500,
+		collection: collectionMint,
+		collectionAuthority: payer,
+		tokenAmount: token(1),
+		...(mintArgs || {}),
+	});
+	const mint = result.mintAddress;
+	const metadataAccount = metaplex.nfts().pdas().metadata({
+		mint,
+	});
+
+	return { mint, metadataAccount };
+}
+
 export async function fetchNFT(provider: Provider, payer: Keypair, mint: PublicKey) {
 	const metaplex = new Metaplex(provider.connection).use(keypairIdentity(payer));
 	return await metaplex.nfts().findByMint({
@@ -109,6 +151,66 @@ export async function createSplToken(
 	return { mint };
 }
 
+export async function createTransferFeeToken(
+	provider: Provider,
+	payer: Keypair,
+	mintToWallet: PublicKey,
+	amount: number,
+	transferFeeBps: number
+) {
+	const mintKeypair = Keypair.generate();
+	const mint = mintKeypair.publicKey;
+	const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+
+	await sendAndConfirmTransaction(
+		provider.connection,
+		new Transaction().add(
+			SystemProgram.createAccount({
+				fromPubkey: payer.publicKey,
+				newAccountPubkey: mint,
+				space: mintLen,
+				lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLen),
+				programId: TOKEN_2022_PROGRAM_ID,
+			}),
+			createInitializeTransferFeeConfigInstruction(
+				mint,
+				payer.publicKey,
+				payer.publicKey,
+				transferFeeBps,
+				BigInt(amount),
+				TOKEN_2022_PROGRAM_ID
+			),
+			createInitializeMintInstruction(mint, 0, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+		),
+		[payer, mintKeypair]
+	);
+
+	const toWalletCurrencyAccount = await getOrCreateAssociatedTokenAccount(
+		provider.connection,
+		payer,
+		mint,
+		mintToWallet,
+		false,
+		undefined,
+		undefined,
+		TOKEN_2022_PROGRAM_ID
+	);
+
+	await mintTo(
+		provider.connection,
+		payer,
+		mint,
+		toWalletCurrencyAccount.address,
+		payer.publicKey,
+		amount,
+		[],
+		undefined,
+		TOKEN_2022_PROGRAM_ID
+	);
+
+	return { mint };
+}
+
 export async function assertThrows(fn: () => Promise<any | void>, code?: number, message?: string) {
 	let throws = false;
 	try {
@@ -126,6 +228,27 @@ export async function assertThrows(fn: () => Promise<any | void>, code?: number,
 	assert.isTrue(throws, "Expected error to be thrown");
 }
 
+// Ed25519 signature of the message by the keypair, as checked by the Ed25519 program
+export function signMessage(keypair: Keypair, message: Buffer): Uint8Array {
+	const key = crypto.createPrivateKey({
+		key: {
+			kty: "OKP",
+			crv: "Ed25519",
+			d: Buffer.from(keypair.secretKey.slice(0, 32)).toString("base64url"),
+			x: Buffer.from(keypair.publicKey.toBytes()).toString("base64url"),
+		},
+		format: "jwk",
+	});
+	return crypto.sign(null, message, key);
+}
+
+// Polls until the cluster has passed the slot
+export async function waitForSlot(provider: Provider, slot: number) {
+	while ((await provider.connection.getSlot()) <= slot) {
+		await new Promise((resolve) => setTimeout(resolve, 1000));
+	}
+}
+
 export function createVirtualItemId(): number[] {
 	return toItemId(uuid.v4().slice(0, 32));
 }
@@ -1,5 +1,24 @@
 import { PublicKey } from "@solana/web3.js";
-import { CustodyAccount, Listing, MintRecord } from "./types";
+import {
+	AuthorizationNonce,
+	BondingCurve,
+	CustodyAccount,
+	FungibleListing,
+	Listing,
+	Loan,
+	Market,
+	MintRecord,
+	MysteryBox,
+	OpenOrders,
+	PriceFeed,
+	Raffle,
+	RaffleTicket,
+	Recipe,
+	Rental,
+	SealedBox,
+	Session,
+	Swap,
+} from "./types";
 import Carbon from "./carbon";
 
 const DOES_NOT_EXIST_ERROR = "Account does not exist";
@@ -30,8 +49,191 @@ export class Accounts {
 	}
 
 	async listing(itemId: number[]): Promise<Listing | undefined> {
+		// Fall back to the legacy address for listings created before namespacing
+		for (const address of [
+			this.carbon.pdas.listing(itemId),
+			this.carbon.pdas.legacyListing(itemId),
+		]) {
+			try {
+				return await this.carbon.program.account.listing.fetch(address);
+			} catch (e) {
+				if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+					throw e;
+				}
+			}
+		}
+	}
+
+	async swap(maker: PublicKey, swapId: number[]): Promise<Swap | undefined> {
+		try {
+			return await this.carbon.program.account.swap.fetch(this.carbon.pdas.swap(maker, swapId));
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async rental(mint: PublicKey): Promise<Rental | undefined> {
+		try {
+			return await this.carbon.program.account.rental.fetch(this.carbon.pdas.rental(mint));
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async loan(lender: PublicKey, loanId: number[]): Promise<Loan | undefined> {
+		try {
+			return await this.carbon.program.account.loan.fetch(this.carbon.pdas.loan(lender, loanId));
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async fungibleListing(
+		seller: PublicKey,
+		mint: PublicKey,
+		marketplaceAuthority?: PublicKey
+	): Promise<FungibleListing | undefined> {
+		try {
+			return await this.carbon.program.account.fungibleListing.fetch(
+				this.carbon.pdas.fungibleListing(seller, mint, marketplaceAuthority)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async market(baseMint: PublicKey, quoteMint: PublicKey): Promise<Market | undefined> {
+		try {
+			return await this.carbon.program.account.market.fetch(
+				this.carbon.pdas.market(baseMint, quoteMint)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async openOrders(market: PublicKey, owner: PublicKey): Promise<OpenOrders | undefined> {
+		try {
+			return await this.carbon.program.account.openOrders.fetch(
+				this.carbon.pdas.openOrders(market, owner)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async bondingCurve(
+		collectionMint: PublicKey,
+		itemType: number[]
+	): Promise<BondingCurve | undefined> {
+		try {
+			return await this.carbon.program.account.bondingCurve.fetch(
+				this.carbon.pdas.bondingCurve(this.carbon.pdas.collectionConfig(collectionMint), itemType)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async recipe(collectionMint: PublicKey, recipeId: number[]): Promise<Recipe | undefined> {
+		try {
+			return await this.carbon.program.account.recipe.fetch(
+				this.carbon.pdas.recipe(this.carbon.pdas.collectionConfig(collectionMint), recipeId)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async mysteryBox(collectionMint: PublicKey, boxId: number[]): Promise<MysteryBox | undefined> {
+		try {
+			return await this.carbon.program.account.mysteryBox.fetch(
+				this.carbon.pdas.mysteryBox(this.carbon.pdas.collectionConfig(collectionMint), boxId)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async sealedBox(mint: PublicKey): Promise<SealedBox | undefined> {
+		try {
+			return await this.carbon.program.account.sealedBox.fetch(this.carbon.pdas.sealedBox(mint));
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async raffle(raffleId: number[]): Promise<Raffle | undefined> {
 		try {
-			return await this.carbon.program.account.listing.fetch(this.carbon.pdas.listing(itemId));
+			return await this.carbon.program.account.raffle.fetch(this.carbon.pdas.raffle(raffleId));
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async raffleTicket(raffle: PublicKey, firstTicket: number): Promise<RaffleTicket | undefined> {
+		try {
+			return await this.carbon.program.account.raffleTicket.fetch(
+				this.carbon.pdas.raffleTicket(raffle, firstTicket)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async session(owner: PublicKey, sessionKey: PublicKey): Promise<Session | undefined> {
+		try {
+			return await this.carbon.program.account.session.fetch(
+				this.carbon.pdas.session(owner, sessionKey)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async authorizationNonce(nonce: number): Promise<AuthorizationNonce | undefined> {
+		try {
+			return await this.carbon.program.account.authorizationNonce.fetch(
+				this.carbon.pdas.authorizationNonce(nonce)
+			);
+		} catch (e) {
+			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
+				throw e;
+			}
+		}
+	}
+
+	async priceFeed(currencyMint: PublicKey): Promise<PriceFeed | undefined> {
+		try {
+			return await this.carbon.program.account.priceFeed.fetch(
+				this.carbon.pdas.priceFeed(this.carbon.marketplaceAuthority, currencyMint)
+			);
 		} catch (e) {
 			if (!e?.message.includes(DOES_NOT_EXIST_ERROR)) {
 				throw e;
@@ -80,9 +80,34 @@ export type Carbon = {
         {
           "name": "seller",
           "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet, which only has to sign when the NFT is not custodied."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "Seller wallet."
+            "The seller, or the key of a session of the seller."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the seller signs."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the authority pays it when omitted."
           ]
         },
         {
@@ -106,7 +131,8 @@ export type Carbon = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The verified collection mint of the NFT to sell."
+            "The collection mint of the collection config, which only has to be a verified collection of",
+            "the NFT when the config verifies by collection."
           ]
         },
         {
@@ -122,7 +148,8 @@ export type Carbon = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "Edition of the NFT to sell."
+            "Edition of the NFT to sell. The NFT is escrowed instead of frozen if it is not the freeze",
+            "authority of the mint."
           ]
         },
         {
@@ -133,6 +160,14 @@ export type Carbon = {
             "The currency to use or native mint if using SOL"
           ]
         },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -182,6 +217,21 @@ export type Carbon = {
         {
           "name": "expiry",
           "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         }
       ]
     },
@@ -196,6 +246,15 @@ export type Carbon = {
             "Seller wallet."
           ]
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the seller pays it when omitted."
+          ]
+        },
         {
           "name": "marketplaceAuthority",
           "isMut": false,
@@ -212,6 +271,14 @@ export type Carbon = {
             "The currency to use or native mint if using SOL"
           ]
         },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -255,6 +322,10 @@ export type Carbon = {
         {
           "name": "expiry",
           "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
         }
       ]
     },
@@ -263,12 +334,28 @@ export type Carbon = {
       "accounts": [
         {
           "name": "seller",
-          "isMut": true,
-          "isSigner": true,
+          "isMut": false,
+          "isSigner": false,
           "docs": [
             "Seller wallet."
           ]
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The seller, or the key of a session of the seller."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the seller signs."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -283,6 +370,10 @@ export type Carbon = {
         {
           "name": "expiry",
           "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
         }
       ]
     },
@@ -292,9 +383,25 @@ export type Carbon = {
         {
           "name": "seller",
           "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet, which only has to sign when the NFT is frozen or delegated to the listing."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "Seller wallet."
+            "The seller, or the key of a session of the seller."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the seller signs."
           ]
         },
         {
@@ -321,6 +428,14 @@ export type Carbon = {
             "Edition of the NFT to sell."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -360,6 +475,14 @@ export type Carbon = {
             "Seller wallet."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -394,6 +517,15 @@ export type Carbon = {
             "Buyer wallet."
           ]
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
         {
           "name": "seller",
           "isMut": true,
@@ -442,6 +574,14 @@ export type Carbon = {
             "Edition account for the NFT."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -452,6 +592,22 @@ export type Carbon = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "custodyRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the custody account, or any account if the NFT is not custodied."
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed of the listing, or the default pubkey if priced in the currency."
+          ]
+        },
         {
           "name": "feeAccount",
           "isMut": true,
@@ -504,6 +660,15 @@ export type Carbon = {
             "Buyer wallet."
           ]
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
         {
           "name": "seller",
           "isMut": true,
@@ -568,6 +733,14 @@ export type Carbon = {
             "Edition of the collection NFT."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
         {
           "name": "listing",
           "isMut": true,
@@ -591,6 +764,14 @@ export type Carbon = {
             "Account to send fees to."
           ]
         },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed of the listing, or the default pubkey if priced in the currency."
+          ]
+        },
         {
           "name": "tokenMetadataProgram",
           "isMut": false,
@@ -650,6 +831,15 @@ export type Carbon = {
             "Buyer wallet."
           ]
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
         {
           "name": "marketplaceAuthority",
           "isMut": true,
@@ -722,6 +912,11 @@ export type Carbon = {
             "Collection config for the new NFT."
           ]
         },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "mintRecord",
           "isMut": true,
@@ -768,6 +963,14 @@ export type Carbon = {
           "type": {
             "defined": "Metadata"
           }
+        },
+        {
+          "name": "primarySale",
+          "type": {
+            "option": {
+              "defined": "PrimarySale"
+            }
+          }
         }
       ]
     },
@@ -777,9 +980,35 @@ export type Carbon = {
         {
           "name": "owner",
           "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User wallet, which only has to sign when it has not approved the custody account as",
+            "delegate of the token account."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the key of a session of the owner."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the owner signs."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": true,
+          "isOptional": true,
           "docs": [
-            "User wallet."
+            "Wallet sponsoring the rent of new accounts, the authority pays it when omitted."
           ]
         },
         {
@@ -900,6 +1129,14 @@ export type Carbon = {
             "Edition of the NFT to uncustody."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the custody account."
+          ]
+        },
         {
           "name": "custodyAccount",
           "isMut": true,
@@ -979,6 +1216,14 @@ export type Carbon = {
             "Edition of the custodied mint."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the custody account."
+          ]
+        },
         {
           "name": "custodyAccount",
           "isMut": true,
@@ -1028,6 +1273,14 @@ export type Carbon = {
             "Marketplace authority wallet."
           ]
         },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the mint record."
+          ]
+        },
         {
           "name": "mint",
           "isMut": false,
@@ -1058,72 +1311,15247 @@ export type Carbon = {
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "collectionConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "version",
-            "type": "u8"
-          },
-          {
-            "name": "marketplaceAuthority",
-            "docs": [
-              "Pubkey of the marketplace authority's wallet."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "collectionMint",
-            "docs": [
-              "The verified collection to add newly minted items to."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "sellerFeeBasisPoints",
-            "docs": [
-              "Royalty bps. Inserted into newly minted metadata."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "symbol",
-            "docs": [
-              "Max 16 chars for symbol. Inserted into newly minted metadata."
-            ],
-            "type": "string"
-          }
-        ]
-      }
     },
     {
-      "name": "custodyAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
+      "name": "migrateMintRecord",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initPriceFeed",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency the price is quoted for or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updatePriceFeed",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "i64"
+        },
+        {
+          "name": "conf",
+          "type": "u64"
+        },
+        {
+          "name": "expo",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "closeExpiredListing",
+      "accounts": [
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet closing the listing. Receives the keeper reward."
+          ]
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet proposing the swap."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the maker pays it when omitted."
+          ]
+        },
+        {
+          "name": "swap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the swap amounts or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "takeSwap",
+      "accounts": [
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet settling the swap."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the taker pays it when omitted."
+          ]
+        },
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that proposed the swap."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the swap."
+          ]
+        },
+        {
+          "name": "swap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the swap amounts or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that proposed the swap."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the swap."
+          ]
+        },
+        {
+          "name": "swap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the swap amounts or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Owner wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the mint to rent out."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the NFT to rent out."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to rent out."
+          ]
+        },
+        {
+          "name": "rental",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rentNft",
+      "accounts": [
+        {
+          "name": "renter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Renter wallet."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner wallet."
+          ]
+        },
+        {
+          "name": "rental",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the rented NFT."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Metadata of the rented NFT."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "days",
+          "type": "u16"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "endRental",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Any wallet once the rental term has ended, otherwise the owner."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the rental."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the rented mint."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the rented NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the rented NFT."
+          ]
+        },
+        {
+          "name": "rental",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "offerLoan",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet lending the principal."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the lender pays it when omitted."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "takeLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet borrowing against the NFT."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the borrower pays it when omitted."
+          ]
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet lending the principal."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Borrower's token account of the collateral."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collateral."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Metadata of the collateral."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collateral."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "repayLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that borrowed against the NFT."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the borrower pays it when omitted."
+          ]
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that lent the principal."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Borrower's token account of the collateral."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collateral."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collateral."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forecloseLoan",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that lent the principal."
+          ]
+        },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Wallet that defaulted on the loan."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "borrowerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Borrower's token account of the collateral."
+          ]
+        },
+        {
+          "name": "lenderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Lender's token account to receive the collateral."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collateral."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collateral."
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelLoanOffer",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that offered the loan."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listFungible",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the seller pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller's token account of the mint to sell."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token to sell."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Listing's token account holding the tokens for sale."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "FungibleListingArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyFungible",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token for sale."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Listing's token account holding the tokens for sale."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer's token account of the mint, created if it does not exist."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "delistFungible",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller's token account to return the unsold tokens to."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token for sale."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Listing's token account holding the tokens for sale."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initMarket",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token being traded."
+          ]
+        },
+        {
+          "name": "quoteMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token prices are quoted in."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account holding the base tokens of open asks and unsettled buys."
+          ]
+        },
+        {
+          "name": "quoteVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account holding the quote tokens of open bids, unsettled sales and fees."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "baseLotSize",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initOpenOrders",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet placing orders on the market."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "placeOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet placing the order."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Quote mint for bids, base mint for asks."
+          ]
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the mint to lock."
+          ]
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the mint to lock."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "quantity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Wallet that placed the order."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "orderId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "matchOrders",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anyone cranking the market. Pays for the fee token account if it does not exist."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "quoteMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the quote mint."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Fee account's token account of the quote mint."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "limit",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "settleFunds",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet owning the open orders account."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the base mint."
+          ]
+        },
+        {
+          "name": "quoteVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the quote mint."
+          ]
+        },
+        {
+          "name": "ownerBaseAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the base mint, created if it does not exist."
+          ]
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the quote mint, created if it does not exist."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initBondingCurve",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BondingCurveArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyBondingCurve",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnBondingCurve",
+      "accounts": [
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Holder of the NFT."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the holder pays it when omitted."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the mint record."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holder NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serial",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawBondingCurveReserve",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initRecipe",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipe",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RecipeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "craft",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Player wallet holding the inputs."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the player pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the crafted NFT."
+          ]
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Player NFT token account for the crafted NFT."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the crafted NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the crafted NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipe",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initMysteryBox",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MysteryBoxArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintMysteryBox",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the box NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the box NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the box NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "seedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "openMysteryBox",
+      "accounts": [
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Holder of the box NFT."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the mint record."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holder box NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the box NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the box NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "randomnessAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Account the mystery box's randomness source reads entropy from, the SlotHashes sysvar for",
+            "slot hashes."
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revealMysteryBox",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that opened the box."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the sealed box."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the prize NFT."
+          ]
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holder NFT token account for the prize."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the prize NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the prize NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createRaffle",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the creator pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Creator's token account of the NFT to raffle."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the NFT to raffle."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency tickets are paid in or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Raffle's token account escrowing the NFT."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RaffleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createVirtualRaffle",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency tickets are paid in or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RaffleArgs"
+          }
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyRaffleTickets",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "count",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "closeRaffle",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "drawRaffle",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that created the raffle."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "randomnessAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Account the raffle's randomness source reads entropy from, the SlotHashes sysvar for slot",
+            "hashes."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRaffleNft",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet holding the winning ticket."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the winner pays it when omitted."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that created the raffle."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle."
+          ]
+        },
+        {
+          "name": "ticketRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the raffled NFT."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Raffle's token account escrowing the NFT."
+          ]
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Winner's token account of the NFT, created if it does not exist."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimRaffleVirtual",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet holding the winning ticket."
+          ]
+        },
+        {
+          "name": "ticketRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the prize NFT."
+          ]
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Winner NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the prize NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the prize NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeRaffleTicket",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Raffle the tickets were bought for, closed once its prize was claimed."
+          ]
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRaffle",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expireRaffle",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundRaffleTickets",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "raffleRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle, refunded once the last ticket is."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSession",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Wallet the session key signs for."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ephemeral key allowed to sign for the owner."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SessionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeSession",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the session key itself."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the session."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listVirtualWithAuthorization",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the seller pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet, which signed the authorization."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorization",
+          "type": {
+            "defined": "Authorization"
+          }
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "mintVirtualWithAuthorization",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet, which signed the authorization."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionAuthorityRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Record of the marketplace authority approving the collection config as collection authority."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Collection config for the new NFT, which mints it in place of the marketplace authority."
+          ]
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorization",
+          "type": {
+            "defined": "Authorization"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeAuthorizationNonce",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the nonce account."
+          ]
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemMintVoucher",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet, which signed the voucher and receives the price."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionAuthorityRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Record of the marketplace authority approving the collection config as collection authority."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Collection config for the new NFT, which mints it in place of the marketplace authority."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "voucher",
+          "type": {
+            "defined": "MintVoucher"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPrimarySale",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "primarySale",
+          "type": {
+            "defined": "PrimarySale"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authorizationNonce",
+      "docs": [
+        "Marks the nonce of an authorization or mint voucher as used. The account can be closed once the",
+        "authorization or voucher expired, as it can no longer be used anyway."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the authorization or voucher can no longer be used"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the items are minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemType",
+            "docs": [
+              "ID of the virtual item type sold by the curve"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of every item minted by the curve"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency to accept for payment"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "basePrice",
+            "docs": [
+              "Price of the first unit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "slope",
+            "docs": [
+              "Price increase per unit in circulation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxSupply",
+            "docs": [
+              "Most units that can be in circulation at once, 0 if unlimited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundBps",
+            "docs": [
+              "Share of a unit's price refunded from the reserve when it is burned back.",
+              "Burning back is disabled when 0."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "supply",
+            "docs": [
+              "Units minted and not burned back"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minted",
+            "docs": [
+              "Units ever minted, used as the serial of the next unit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Part of the reserve owed as refunds to the units in circulation"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "The verified collection to add newly minted items to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "docs": [
+              "Royalty bps. Inserted into newly minted metadata."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "Max 16 chars for symbol. Inserted into newly minted metadata."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "swapRoyaltyPolicy",
+            "docs": [
+              "Royalties charged on items of the collection traded through swaps."
+            ],
+            "type": {
+              "defined": "SwapRoyaltyPolicy"
+            }
+          },
+          {
+            "name": "mintStandard",
+            "docs": [
+              "Token standard newly minted items are created with."
+            ],
+            "type": {
+              "defined": "MintStandard"
+            }
+          },
+          {
+            "name": "verification",
+            "docs": [
+              "How NFTs listed in the collection are proven to belong to it."
+            ],
+            "type": {
+              "defined": "CollectionVerification"
+            }
+          },
+          {
+            "name": "primarySale",
+            "docs": [
+              "Price items minted through mint_virtual are sold at."
+            ],
+            "type": {
+              "defined": "PrimarySale"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodyAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Pubkey of the user's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Pubkey of the mint being custodied"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "A unique ID for the virtual item matching the mint"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "isListed",
+            "docs": [
+              "True if the mint is listed for sale"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed.",
+              "Set to the default pubkey for accounts created before payers were tracked."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fungibleListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config the listing address is derived from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Pubkey of the seller's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the token being sold"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the token being sold"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency to accept for payment"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of one whole token, in base units of the currency"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "docs": [
+              "Amount still for sale, in base units of the token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the listing expires"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the listing"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Pubkey of the seller's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Set to bytes of NFT mint if listing is for NFT, otherwise a unique ID for the virtual item"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "isVirtual",
+            "docs": [
+              "True if the listing is for a virtual item, false if it is for an NFT"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency to accept for payment"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "Collection config for the item"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of the item, in reference units if the listing has a price feed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the listing expires"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the listing"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "priceFeed",
+            "docs": [
+              "Price feed used to convert the price into the currency at buy time.",
+              "Set to the default pubkey if the price is in the currency."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyDecimals",
+            "docs": [
+              "Decimals of the currency mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Unix timestamp of when the listing can be bought, 0 if immediately"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config the listing address is derived from.",
+              "Set to the default pubkey for legacy listings derived from the item ID only."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isEscrowed",
+            "docs": [
+              "True if the NFT could not be frozen and is escrowed in the listing's token account instead"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed.",
+              "Set to the default pubkey for accounts created before payers were tracked."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "loan",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lender",
+            "docs": [
+              "Pubkey of the wallet escrowing the principal"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "loanId",
+            "docs": [
+              "A unique ID for the loan chosen by the lender"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "Collection the collateral must be verified in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency of the principal and interest, native mint if SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "principal",
+            "docs": [
+              "Amount lent to the borrower"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "interest",
+            "docs": [
+              "Amount owed to the lender on top of the principal"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "Seconds the borrower has to repay once the loan is taken"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "borrower",
+            "docs": [
+              "Pubkey of the borrower's wallet, or the default pubkey until the offer is taken"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "NFT frozen in the borrower's wallet as collateral, or the default pubkey until the offer is taken"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "dueTime",
+            "docs": [
+              "Unix timestamp of when the loan must be repaid by"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "market",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config that approved the token pair"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseMint",
+            "docs": [
+              "Mint of the token being traded"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "quoteMint",
+            "docs": [
+              "Mint of the token prices are quoted in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseLotSize",
+            "docs": [
+              "Base units of the base token in one lot. Order quantities are in lots."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bids",
+            "docs": [
+              "Order book side holding the bids"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "asks",
+            "docs": [
+              "Order book side holding the asks"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config applied on fills"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "nextOrderId",
+            "docs": [
+              "ID given to the next order placed, increasing so older orders have lower IDs"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderBookSide",
+      "docs": [
+        "One side of a market's order book, kept sorted best price first and oldest first within a price"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "market",
+            "type": "publicKey"
+          },
+          {
+            "name": "isBid",
+            "docs": [
+              "True if the side holds bids, false if asks"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "Aligns count as repr(C) would, keeping the layout clients decode the same as on chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "docs": [
+              "Number of orders in use at the start of the array"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "orders",
+            "type": {
+              "array": [
+                {
+                  "defined": "Order"
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "marketplaceConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Royalty bps. Inserted into newly minted metadata."
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "keeperReward",
+            "docs": [
+              "Lamports paid out of the listing rent to whoever closes an expired listing."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config for the item"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "A unique ID for the virtual item within the collection"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Track NFT mint for the item"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mysteryBox",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the boxes and prizes are minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "boxId",
+            "docs": [
+              "A unique ID for the mystery box within the collection"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of the box NFTs"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "randomnessSource",
+            "docs": [
+              "Source of the entropy prizes are drawn with"
+            ],
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          },
+          {
+            "name": "prizes",
+            "docs": [
+              "Items a box can be opened into"
+            ],
+            "type": {
+              "vec": {
+                "defined": "Prize"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "openOrders",
+      "docs": [
+        "Balances of a user on a market that can be settled out of its vaults"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "market",
+            "docs": [
+              "Market the orders are placed on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Pubkey of the user's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseFree",
+            "docs": [
+              "Base units bought or released from cancelled asks"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quoteFree",
+            "docs": [
+              "Quote units received from sales, refunded from bids filled at a better price or released",
+              "from cancelled bids"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeed",
+      "docs": [
+        "Authority-published price for a currency, used where no oracle feed is available"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet allowed to publish prices"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency the price is quoted for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of one whole currency unit in reference units, scaled by 10^expo"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "conf",
+            "docs": [
+              "Confidence interval of the price, scaled by 10^expo"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expo",
+            "docs": [
+              "Exponent applied to price and conf"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "publishTime",
+            "docs": [
+              "Unix timestamp of when the price was last published"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "raffle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config the raffle address is derived from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "raffleId",
+            "docs": [
+              "A unique ID for the raffle within the marketplace"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Wallet that created the raffle and receives the proceeds"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isVirtual",
+            "docs": [
+              "Whether the prize is a virtual item minted to the winner rather than an escrowed NFT"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the escrowed NFT, or the default pubkey if the prize is virtual"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the virtual prize is minted into, or the default pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Item ID of the virtual prize"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of the virtual prize"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency tickets are paid in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "ticketPrice",
+            "docs": [
+              "Price of one ticket"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxTickets",
+            "docs": [
+              "Most tickets that can be sold"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "ticketsSold",
+            "docs": [
+              "Tickets sold, numbered from 0"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp of when ticket sales end"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the proceeds"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "randomnessSource",
+            "docs": [
+              "Source of the entropy the winning ticket is drawn with"
+            ],
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "RaffleState"
+            }
+          },
+          {
+            "name": "seedHash",
+            "docs": [
+              "Hash of the seed committed by the marketplace authority when the raffle was closed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "closeSlot",
+            "docs": [
+              "Slot the raffle was closed in, whose entropy the winning ticket is drawn with"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "winningTicket",
+            "docs": [
+              "Number of the winning ticket once drawn"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "ticketsRefunded",
+            "docs": [
+              "Tickets refunded since the raffle expired"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "proceeds",
+            "docs": [
+              "Currency received for the sold tickets, after any transfer fee"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "raffleTicket",
+      "docs": [
+        "Tickets bought together by one wallet"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "raffle",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "firstTicket",
+            "docs": [
+              "Number of the first ticket"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "count",
+            "type": "u32"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Currency the raffle received for the tickets, which is refunded if the raffle expires"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recipe",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the inputs are burned from and the output is minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipeId",
+            "docs": [
+              "A unique ID for the recipe within the collection"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "inputs",
+            "docs": [
+              "Item types burned by crafting, matched by NFT name"
+            ],
+            "type": {
+              "vec": {
+                "defined": "RecipeInput"
+              }
+            }
+          },
+          {
+            "name": "output",
+            "docs": [
+              "Metadata of the crafted item"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rental",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Pubkey of the owner's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Pubkey of the mint being rented out"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pricePerDay",
+            "docs": [
+              "Lamports charged per day of rental"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxDays",
+            "docs": [
+              "Longest rental term the owner accepts, in days"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "applyRoyalties",
+            "docs": [
+              "True if the creators of the NFT receive royalties on the rental fee"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the rental"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "renter",
+            "docs": [
+              "Pubkey of the wallet currently using the NFT, or the default pubkey if not rented"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rentedUntil",
+            "docs": [
+              "Unix timestamp of when the current rental term ends"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sealedBox",
+      "docs": [
+        "Randomness commitment of a minted mystery box, tracking the box from mint to reveal"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "mysteryBox",
+            "docs": [
+              "Mystery box the box was minted from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the box NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Item ID of the box NFT"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "seedHash",
+            "docs": [
+              "Hash of the seed committed by the marketplace authority when the box was minted"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "holder",
+            "docs": [
+              "Wallet that opened the box and receives the prize, or the default pubkey if not opened"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "openSlot",
+            "docs": [
+              "Slot the box was opened in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "entropy",
+            "docs": [
+              "Entropy read when the box was opened, which the prize is drawn with together with the seed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "session",
+      "docs": [
+        "An ephemeral key a player authorized to sign listing and custody actions for them"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Wallet the session key acts for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionKey",
+            "docs": [
+              "Ephemeral key allowed to sign for the owner"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Marketplace whose listings and custody accounts the session key can act on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the session key can no longer sign"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": "SessionScope"
+            }
+          },
+          {
+            "name": "collectionMints",
+            "docs": [
+              "Collections the session key can act on, or empty for any collection"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "maxPrice",
+            "docs": [
+              "Highest price the session key can list for, or 0 for no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minPrice",
+            "docs": [
+              "Lowest price the session key can list for, or 0 for no floor"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maker",
+            "docs": [
+              "Pubkey of the wallet proposing the swap"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "docs": [
+              "Pubkey of the only wallet allowed to take the swap, or the default pubkey if anyone can"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "swapId",
+            "docs": [
+              "A unique ID for the swap chosen by the maker"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "Collection all NFTs in the swap are verified in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency of the maker and taker amounts, native mint if SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "makerMints",
+            "docs": [
+              "NFTs escrowed by the maker, frozen by delegation or flagged in their custody account"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "makerAmount",
+            "docs": [
+              "Currency escrowed by the maker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "takerMints",
+            "docs": [
+              "NFTs requested from the taker"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "takerAmount",
+            "docs": [
+              "Currency requested from the taker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "royaltyPerNft",
+            "docs": [
+              "Lamports paid by the taker to the creators of each NFT in the swap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the swap expires, 0 if never"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CollectionConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collectionMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "swapRoyaltyPolicy",
+            "type": {
+              "defined": "SwapRoyaltyPolicy"
+            }
+          },
+          {
+            "name": "mintStandard",
+            "type": {
+              "defined": "MintStandard"
+            }
+          },
+          {
+            "name": "verification",
+            "type": {
+              "defined": "CollectionVerification"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MarketplaceConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeConfig",
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "keeperReward",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Authorization",
+      "docs": [
+        "Message the marketplace authority signs off-chain so an action can go through without the",
+        "authority signing the transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": "AuthorizedAction"
+            }
+          },
+          {
+            "name": "itemId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the item is listed or minted in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Wallet the action is for, the seller of a listing or the buyer of a mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of the listing, or 0 for mints"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "metadataHash",
+            "docs": [
+              "Hash of the metadata of the minted item, or zeroed for listings"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the authorization can no longer be used"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Number the marketplace authority picks so that each authorization can only be used once"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintVoucher",
+      "docs": [
+        "Voucher the marketplace authority signs off-chain so a player can mint an item by paying for it,",
+        "with no server in the transaction. Like an authorization, its nonce can only be used once, as mint",
+        "records can be closed and would not keep the voucher from being redeemed again."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "itemId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the item is minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Wallet the voucher is for, or the default pubkey if anyone can redeem it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "The currency the price is paid in or native mint if using SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the voucher can no longer be redeemed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "metadataHash",
+            "docs": [
+              "Hash of the metadata of the minted item"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Number the marketplace authority picks so that each voucher can only be redeemed once"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BondingCurveArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "itemType",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "basePrice",
+            "type": "u64"
+          },
+          {
+            "name": "slope",
+            "type": "u64"
+          },
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          },
+          {
+            "name": "refundBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PrimarySale",
+      "docs": [
+        "Primary sales are paid to the treasury with the marketplace fee taken out, and free until the",
+        "marketplace authority sets a price"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "docs": [
+              "Price of one item, or 0 if items are minted for free"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "The currency the price is paid in or native mint if using SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Wallet receiving the price net of the marketplace fee"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleListingArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "Amount to sell, in base units of the token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of one whole token, in base units of the currency"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the listing expires, 0 if never"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "loanId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "interest",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "Seconds the borrower has to repay once the loan is taken"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Order",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openOrders",
+            "docs": [
+              "Open orders account credited when the order fills or is cancelled"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "orderId",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Quote units per lot"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "docs": [
+              "Lots left to fill"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Metadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Prize",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of the prize item"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Odds of the prize relative to the weights of the other prizes"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MysteryBoxArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "boxId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "randomnessSource",
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          },
+          {
+            "name": "prizes",
+            "type": {
+              "vec": {
+                "defined": "Prize"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RaffleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffleId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "ticketPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxTickets",
+            "type": "u32"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp of when ticket sales end"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "randomnessSource",
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecipeInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "Name of the NFTs of the item type"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "quantity",
+            "docs": [
+              "Number of NFTs of the item type to burn"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecipeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipeId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "inputs",
+            "type": {
+              "vec": {
+                "defined": "RecipeInput"
+              }
+            }
+          },
+          {
+            "name": "output",
+            "type": {
+              "defined": "Metadata"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pricePerDay",
+            "type": "u64"
+          },
+          {
+            "name": "maxDays",
+            "type": "u16"
+          },
+          {
+            "name": "applyRoyalties",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SessionScope",
+      "docs": [
+        "Actions a session key can take on behalf of the owner"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "list",
+            "type": "bool"
+          },
+          {
+            "name": "updateListing",
+            "type": "bool"
+          },
+          {
+            "name": "delist",
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SessionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Marketplace whose listings and custody accounts the session key can act on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the session key can no longer sign"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": "SessionScope"
+            }
+          },
+          {
+            "name": "collectionMints",
+            "docs": [
+              "Collections the session key can act on, or empty for any collection"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "maxPrice",
+            "docs": [
+              "Highest price the session key can list for, or 0 for no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minPrice",
+            "docs": [
+              "Lowest price the session key can list for, or 0 for no floor"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swapId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "taker",
+            "docs": [
+              "Wallet allowed to take the swap, or the default pubkey for an open swap"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "makerMints",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "makerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "takerMints",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "takerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DelistReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Seller"
+          },
+          {
+            "name": "Expired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuthorizedAction",
+      "docs": [
+        "Instruction an authorization can be used for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ListVirtual"
+          },
+          {
+            "name": "MintVirtual"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRoyaltyPolicy",
+      "docs": [
+        "Swaps have no sale price to take royalty bps from, so the collection picks what is charged per item"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Fixed",
+            "fields": [
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintStandard",
+      "docs": [
+        "Collections opt in to minting programmable NFTs, which enforce royalties through a rule set"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "ProgrammableNonFungible",
+            "fields": [
+              {
+                "name": "rule_set",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionVerification",
+      "docs": [
+        "Older collections may have no verified sized collection, so the collection picks what proves an",
+        "NFT belongs to it"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "VerifiedCollection"
+          },
+          {
+            "name": "VerifiedCreator",
+            "fields": [
+              {
+                "name": "creator",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "MerkleRoot",
+            "fields": [
+              {
+                "name": "root",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "Side",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Bid"
+          },
+          {
+            "name": "Ask"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RaffleState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Closed"
+          },
+          {
+            "name": "Drawn"
+          },
+          {
+            "name": "Expired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SessionAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "List"
+          },
+          {
+            "name": "UpdateListing"
+          },
+          {
+            "name": "Delist"
+          },
+          {
+            "name": "Custody"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RandomnessSource",
+      "docs": [
+        "Source of the entropy combined with a committed seed to draw a random outcome. The seed is",
+        "committed before the entropy exists, so neither the committer nor the requester can pick the",
+        "outcome alone."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SlotHashes"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "BondingCurveBurn",
+      "fields": [
+        {
+          "name": "itemType",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "refund",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BondingCurveBuy",
+      "fields": [
+        {
+          "name": "itemType",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Buy",
+      "fields": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
+        },
+        {
+          "name": "priceFeed",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sellerAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "royaltyAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Craft",
+      "fields": [
+        {
+          "name": "recipeId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "burnedMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Custody",
+      "fields": [
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Delist",
+      "fields": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": "DelistReason"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ListingUpdate",
+      "fields": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "expiry",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
+        },
+        {
+          "name": "priceFeed",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "List",
+      "fields": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "expiry",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
+        },
+        {
+          "name": "priceFeed",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanCancel",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanForeclose",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanOffer",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "principal",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "interest",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanRepay",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanTake",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "dueTime",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Mint",
+      "fields": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasuryAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MysteryBoxOpen",
+      "fields": [
+        {
+          "name": "boxId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "boxMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "openSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MysteryBoxReveal",
+      "fields": [
+        {
+          "name": "boxId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "boxMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "prizeIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "randomness",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OrderCancel",
+      "fields": [
+        {
+          "name": "market",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "orderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          },
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OrderFill",
+      "fields": [
+        {
+          "name": "market",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bidOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "askOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OrderPlace",
+      "fields": [
+        {
+          "name": "market",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "orderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          },
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleCancel",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleClaim",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleCreate",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ticketPrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxTickets",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "endTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleDraw",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winningTicket",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "ticketsSold",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "randomness",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creatorAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleExpire",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ticketsSold",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleTicketBuy",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "firstTicket",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "count",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleTicketRefund",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "firstTicket",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "count",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RentalEnd",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "renter",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RentalList",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pricePerDay",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxDays",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "applyRoyalties",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Rent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "renter",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentedUntil",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SessionCreate",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sessionKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiry",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "scope",
+          "type": {
+            "defined": "SessionScope"
+          },
+          "index": false
+        },
+        {
+          "name": "collectionMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "minPrice",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SessionRevoke",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sessionKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapCancel",
+      "fields": [
+        {
+          "name": "swapId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapCreate",
+      "fields": [
+        {
+          "name": "swapId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "makerMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "makerAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "takerAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "royaltyPerNft",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "expiry",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapTake",
+      "fields": [
+        {
+          "name": "swapId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "royaltiesPaid",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Uncustody",
+      "fields": [
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "BumpSeedNotInHashMap",
+      "msg": "Bump seed not in hash map"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidExpiry",
+      "msg": "Invalid expiry"
+    },
+    {
+      "code": 6002,
+      "name": "NotVirtual",
+      "msg": "Not virtual"
+    },
+    {
+      "code": 6003,
+      "name": "ListingExpired",
+      "msg": "Listing expired"
+    },
+    {
+      "code": 6004,
+      "name": "MaxPriceExceeded",
+      "msg": "Max price exceeded"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidSeller",
+      "msg": "Invalid seller"
+    },
+    {
+      "code": 6006,
+      "name": "OverflowError",
+      "msg": "Overflow error"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidFeeAccount",
+      "msg": "Invalid fee account"
+    },
+    {
+      "code": 6008,
+      "name": "IsVirtual",
+      "msg": "Item is virtual"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidMint",
+      "msg": "Invalid mint"
+    },
+    {
+      "code": 6010,
+      "name": "NftIsListed",
+      "msg": "Nft is listed"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidCustodyAccount",
+      "msg": "Invalid custody account"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidListingAccount",
+      "msg": "Invalid listing account"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidPrice",
+      "msg": "Invalid price"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidCollectionConfig",
+      "msg": "Invalid collection config"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidEdition",
+      "msg": "Invalid edition"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidPriceFeed",
+      "msg": "Invalid price feed"
+    },
+    {
+      "code": 6017,
+      "name": "StalePrice",
+      "msg": "Price is stale"
+    },
+    {
+      "code": 6018,
+      "name": "PriceConfidenceExceeded",
+      "msg": "Price confidence interval too wide"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidStartTime",
+      "msg": "Invalid start time"
+    },
+    {
+      "code": 6020,
+      "name": "ListingNotStarted",
+      "msg": "Listing has not started"
+    },
+    {
+      "code": 6021,
+      "name": "ListingNotExpired",
+      "msg": "Listing not expired"
+    },
+    {
+      "code": 6022,
+      "name": "TooManySwapItems",
+      "msg": "Too many items in swap"
+    },
+    {
+      "code": 6023,
+      "name": "EmptySwap",
+      "msg": "Swap must exchange something on both sides"
+    },
+    {
+      "code": 6024,
+      "name": "SwapExpired",
+      "msg": "Swap expired"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidTaker",
+      "msg": "Invalid taker"
+    },
+    {
+      "code": 6026,
+      "name": "NftIsRented",
+      "msg": "Nft is rented"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRentalDuration",
+      "msg": "Invalid rental duration"
+    },
+    {
+      "code": 6028,
+      "name": "RentalTermNotEnded",
+      "msg": "Rental term has not ended"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidOwner",
+      "msg": "Invalid owner"
+    },
+    {
+      "code": 6030,
+      "name": "LoanIsActive",
+      "msg": "Loan is active"
+    },
+    {
+      "code": 6031,
+      "name": "LoanNotActive",
+      "msg": "Loan is not active"
+    },
+    {
+      "code": 6032,
+      "name": "LoanOverdue",
+      "msg": "Loan is overdue"
+    },
+    {
+      "code": 6033,
+      "name": "LoanNotOverdue",
+      "msg": "Loan is not overdue"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidLoanDuration",
+      "msg": "Invalid loan duration"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidBorrower",
+      "msg": "Invalid borrower"
+    },
+    {
+      "code": 6036,
+      "name": "ProgrammableNftInCustody",
+      "msg": "Custodial programmable NFTs can only be transferred to the marketplace authority"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidAmount",
+      "msg": "Invalid amount"
+    },
+    {
+      "code": 6038,
+      "name": "InsufficientListingAmount",
+      "msg": "Listing has less than the amount remaining"
+    },
+    {
+      "code": 6039,
+      "name": "OrderBookFull",
+      "msg": "Order book is full"
+    },
+    {
+      "code": 6040,
+      "name": "OrderNotFound",
+      "msg": "Order not found"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidOpenOrders",
+      "msg": "Invalid open orders account"
+    },
+    {
+      "code": 6042,
+      "name": "SupplyExhausted",
+      "msg": "Bonding curve supply exhausted"
+    },
+    {
+      "code": 6043,
+      "name": "BurnBackDisabled",
+      "msg": "Bonding curve does not allow burning back"
+    },
+    {
+      "code": 6044,
+      "name": "InsufficientReserve",
+      "msg": "Insufficient reserve"
+    },
+    {
+      "code": 6045,
+      "name": "UnsupportedMintStandard",
+      "msg": "Unsupported mint standard"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidMetadata",
+      "msg": "Invalid metadata"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidRecipe",
+      "msg": "Invalid recipe"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidRecipeInputs",
+      "msg": "NFTs do not match the recipe inputs"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidPrizes",
+      "msg": "Invalid prizes"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidSeed",
+      "msg": "Seed does not match its commitment"
+    },
+    {
+      "code": 6051,
+      "name": "RevealTooEarly",
+      "msg": "Randomness can't be revealed in the slot it was requested in"
+    },
+    {
+      "code": 6052,
+      "name": "RandomnessUnavailable",
+      "msg": "Randomness for the requested slot is not available"
+    },
+    {
+      "code": 6053,
+      "name": "BoxNotOpened",
+      "msg": "Mystery box has not been opened"
+    },
+    {
+      "code": 6054,
+      "name": "RaffleNotOpen",
+      "msg": "Raffle is not open"
+    },
+    {
+      "code": 6055,
+      "name": "RaffleNotEnded",
+      "msg": "Raffle has not ended"
+    },
+    {
+      "code": 6056,
+      "name": "RaffleSoldOut",
+      "msg": "Not enough raffle tickets left"
+    },
+    {
+      "code": 6057,
+      "name": "RaffleHasNoTickets",
+      "msg": "Raffle has no tickets sold"
+    },
+    {
+      "code": 6058,
+      "name": "RaffleNotClosed",
+      "msg": "Raffle is not closed"
+    },
+    {
+      "code": 6059,
+      "name": "RaffleNotDrawn",
+      "msg": "Raffle has not been drawn"
+    },
+    {
+      "code": 6060,
+      "name": "NotWinningTicket",
+      "msg": "Ticket is not the winning ticket"
+    },
+    {
+      "code": 6061,
+      "name": "RaffleHasTickets",
+      "msg": "Raffle has tickets sold"
+    },
+    {
+      "code": 6062,
+      "name": "WinningTicketUnclaimed",
+      "msg": "Winning ticket must be claimed"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidRentPayer",
+      "msg": "Invalid rent payer"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidSession",
+      "msg": "Invalid session"
+    },
+    {
+      "code": 6065,
+      "name": "SessionExpired",
+      "msg": "Session expired"
+    },
+    {
+      "code": 6066,
+      "name": "SessionScopeExceeded",
+      "msg": "Action is outside the scope of the session"
+    },
+    {
+      "code": 6067,
+      "name": "OwnerSignatureRequired",
+      "msg": "Action requires the owner's signature"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidAuthorization",
+      "msg": "Invalid authorization"
+    },
+    {
+      "code": 6069,
+      "name": "AuthorizationExpired",
+      "msg": "Authorization expired"
+    },
+    {
+      "code": 6070,
+      "name": "AuthorizationNotExpired",
+      "msg": "Authorization has not expired"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidTreasury",
+      "msg": "Invalid treasury"
+    },
+    {
+      "code": 6072,
+      "name": "DrawNotExpired",
+      "msg": "Draw can still be revealed"
+    },
+    {
+      "code": 6073,
+      "name": "RaffleNotExpired",
+      "msg": "Raffle has not expired"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidMintRecord",
+      "msg": "Invalid mint record"
+    },
+    {
+      "code": 6075,
+      "name": "RaffleCanBeClosed",
+      "msg": "Raffle can still be closed"
+    }
+  ]
+};
+
+export const IDL: Carbon = {
+  "version": "0.7.0",
+  "name": "carbon",
+  "instructions": [
+    {
+      "name": "initMarketplaceConfig",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MarketplaceConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initCollectionConfig",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CollectionConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "listNft",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet, which only has to sign when the NFT is not custodied."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The seller, or the key of a session of the seller."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the seller signs."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the authority pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller's token account of the mint to sell."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the NFT to sell."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collection mint of the collection config, which only has to be a verified collection of",
+            "the NFT when the config verifies by collection."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Metadata of the NFT to sell."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to sell. The NFT is escrowed instead of frozen if it is not the freeze",
+            "authority of the mint."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "listVirtual",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the seller pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateListing",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The seller, or the key of a session of the seller."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the seller signs."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "delistNft",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet, which only has to sign when the NFT is frozen or delegated to the listing."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The seller, or the key of a session of the seller."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the seller signs."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller's token account of the mint to delist."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the NFT to sell."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to sell."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "delistVirtual",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyNft",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "sellerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller NFT token account."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition account for the NFT."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the custody account, or any account if the NFT is not custodied."
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed of the listing, or the default pubkey if priced in the currency."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "buyVirtual",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed of the listing, or the default pubkey if priced in the currency."
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintVirtual",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Collection config for the new NFT."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        },
+        {
+          "name": "primarySale",
+          "type": {
+            "option": {
+              "defined": "PrimarySale"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "custody",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User wallet, which only has to sign when it has not approved the custody account as",
+            "delegate of the token account."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner, or the key of a session of the owner."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Session authorizing the authority, or any account if the owner signs."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the authority pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User's token account of the mint to custody."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint to be custodied"
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to custody."
+          ]
+        },
+        {
+          "name": "custodyAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "listing",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "uncustody",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "User wallet."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User's token account of the mint to uncustody."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint to be uncustodied"
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to uncustody."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the custody account."
+          ]
+        },
+        {
+          "name": "custodyAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "listing",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "takeOwnership",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User wallet with authority over the custodial mint."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User's token account of the custodial mint."
+          ]
+        },
+        {
+          "name": "marketplaceAuthorityTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority's token account of the custodied mint."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint custodied"
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the custodied mint."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the custody account."
+          ]
+        },
+        {
+          "name": "custodyAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "listing",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMintRecord",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the mint record."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition account of the NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMintRecord",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initPriceFeed",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency the price is quoted for or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updatePriceFeed",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "i64"
+        },
+        {
+          "name": "conf",
+          "type": "u64"
+        },
+        {
+          "name": "expo",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "closeExpiredListing",
+      "accounts": [
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet closing the listing. Receives the keeper reward."
+          ]
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet proposing the swap."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the maker pays it when omitted."
+          ]
+        },
+        {
+          "name": "swap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the swap amounts or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "takeSwap",
+      "accounts": [
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet settling the swap."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the taker pays it when omitted."
+          ]
+        },
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that proposed the swap."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the swap."
+          ]
+        },
+        {
+          "name": "swap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the swap amounts or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSwap",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that proposed the swap."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the swap."
+          ]
+        },
+        {
+          "name": "swap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the swap amounts or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listRental",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Owner wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the mint to rent out."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the NFT to rent out."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to rent out."
+          ]
+        },
+        {
+          "name": "rental",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RentalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rentNft",
+      "accounts": [
+        {
+          "name": "renter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Renter wallet."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner wallet."
+          ]
+        },
+        {
+          "name": "rental",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the rented NFT."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Metadata of the rented NFT."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "days",
+          "type": "u16"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "endRental",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Any wallet once the rental term has ended, otherwise the owner."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the rental."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the rented mint."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the rented NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the rented NFT."
+          ]
+        },
+        {
+          "name": "rental",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "offerLoan",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet lending the principal."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the lender pays it when omitted."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LoanArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "takeLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet borrowing against the NFT."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the borrower pays it when omitted."
+          ]
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet lending the principal."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Borrower's token account of the collateral."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collateral."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Metadata of the collateral."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collateral."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "repayLoan",
+      "accounts": [
+        {
+          "name": "borrower",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that borrowed against the NFT."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the borrower pays it when omitted."
+          ]
+        },
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that lent the principal."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Borrower's token account of the collateral."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collateral."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collateral."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forecloseLoan",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that lent the principal."
+          ]
+        },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Wallet that defaulted on the loan."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "borrowerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Borrower's token account of the collateral."
+          ]
+        },
+        {
+          "name": "lenderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Lender's token account to receive the collateral."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collateral."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collateral."
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelLoanOffer",
+      "accounts": [
+        {
+          "name": "lender",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet that offered the loan."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the loan."
+          ]
+        },
+        {
+          "name": "loan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency of the loan or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listFungible",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the seller pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller's token account of the mint to sell."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token to sell."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Listing's token account holding the tokens for sale."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "FungibleListingArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyFungible",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token for sale."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Listing's token account holding the tokens for sale."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer's token account of the mint, created if it does not exist."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "delistFungible",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the listing."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Seller's token account to return the unsold tokens to."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token for sale."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Listing's token account holding the tokens for sale."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initMarket",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token being traded."
+          ]
+        },
+        {
+          "name": "quoteMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the token prices are quoted in."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account holding the base tokens of open asks and unsettled buys."
+          ]
+        },
+        {
+          "name": "quoteVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account holding the quote tokens of open bids, unsettled sales and fees."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "baseLotSize",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initOpenOrders",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet placing orders on the market."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "placeOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet placing the order."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Quote mint for bids, base mint for asks."
+          ]
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the mint to lock."
+          ]
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the mint to lock."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "quantity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelOrder",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Wallet that placed the order."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "orderId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "matchOrders",
+      "accounts": [
+        {
+          "name": "cranker",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anyone cranking the market. Pays for the fee token account if it does not exist."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "quoteMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the quote mint."
+          ]
+        },
+        {
+          "name": "feeAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Fee account's token account of the quote mint."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "limit",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "settleFunds",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet owning the open orders account."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "market",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "quoteMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the base mint."
+          ]
+        },
+        {
+          "name": "quoteVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Market's token account of the quote mint."
+          ]
+        },
+        {
+          "name": "ownerBaseAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the base mint, created if it does not exist."
+          ]
+        },
+        {
+          "name": "ownerQuoteAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner's token account of the quote mint, created if it does not exist."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initBondingCurve",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BondingCurveArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyBondingCurve",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnBondingCurve",
+      "accounts": [
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Holder of the NFT."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the holder pays it when omitted."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the mint record."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holder NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serial",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawBondingCurveReserve",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initRecipe",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipe",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RecipeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "craft",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Player wallet holding the inputs."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the player pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the crafted NFT."
+          ]
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Player NFT token account for the crafted NFT."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the crafted NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the crafted NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipe",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initMysteryBox",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MysteryBoxArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintMysteryBox",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the box NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the box NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the box NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "seedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "openMysteryBox",
+      "accounts": [
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Holder of the box NFT."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the mint record."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holder box NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the box NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the box NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "randomnessAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Account the mystery box's randomness source reads entropy from, the SlotHashes sysvar for",
+            "slot hashes."
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revealMysteryBox",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that opened the box."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the sealed box."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the prize NFT."
+          ]
+        },
+        {
+          "name": "holderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holder NFT token account for the prize."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the prize NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the prize NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mysteryBox",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createRaffle",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the creator pays it when omitted."
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Creator's token account of the NFT to raffle."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the NFT to raffle."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency tickets are paid in or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Raffle's token account escrowing the NFT."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RaffleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createVirtualRaffle",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency tickets are paid in or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RaffleArgs"
+          }
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyRaffleTickets",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "count",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "closeRaffle",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seedHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "drawRaffle",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that created the raffle."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "randomnessAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Account the raffle's randomness source reads entropy from, the SlotHashes sysvar for slot",
+            "hashes."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "seed",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRaffleNft",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet holding the winning ticket."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the winner pays it when omitted."
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that created the raffle."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle."
+          ]
+        },
+        {
+          "name": "ticketRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Mint of the raffled NFT."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Raffle's token account escrowing the NFT."
+          ]
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Winner's token account of the NFT, created if it does not exist."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimRaffleVirtual",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet holding the winning ticket."
+          ]
+        },
+        {
+          "name": "ticketRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the prize NFT."
+          ]
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Winner NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the prize NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the prize NFT."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeRaffleTicket",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Raffle the tickets were bought for, closed once its prize was claimed."
+          ]
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRaffle",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expireRaffle",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundRaffleTickets",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the ticket."
+          ]
+        },
+        {
+          "name": "raffleRentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the raffle, refunded once the last ticket is."
+          ]
+        },
+        {
+          "name": "raffle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ticket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSession",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Wallet the session key signs for."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the owner pays it when omitted."
+          ]
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Ephemeral key allowed to sign for the owner."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SessionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeSession",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the session key itself."
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the session."
+          ]
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listVirtualWithAuthorization",
+      "accounts": [
+        {
+          "name": "seller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Seller wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the seller pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet, which signed the authorization."
+          ]
+        },
+        {
+          "name": "currencyMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The currency to use or native mint if using SOL"
+          ]
+        },
+        {
+          "name": "priceFeed",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Price feed to convert a reference price at buy time, or the default pubkey if priced in the currency."
+          ]
+        },
+        {
+          "name": "listing",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorization",
+          "type": {
+            "defined": "Authorization"
+          }
+        },
+        {
+          "name": "expiry",
+          "type": "i64"
+        },
+        {
+          "name": "startTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "mintVirtualWithAuthorization",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet, which signed the authorization."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionAuthorityRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Record of the marketplace authority approving the collection config as collection authority."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Collection config for the new NFT, which mints it in place of the marketplace authority."
+          ]
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorization",
+          "type": {
+            "defined": "Authorization"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeAuthorizationNonce",
+      "accounts": [
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Wallet that paid the rent of the nonce account."
+          ]
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemMintVoucher",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Buyer wallet."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Wallet sponsoring the rent of new accounts, the buyer pays it when omitted."
+          ]
+        },
+        {
+          "name": "marketplaceAuthority",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Marketplace authority wallet, which signed the voucher and receives the price."
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The new mint to be used for the NFT."
+          ]
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Buyer NFT token account."
+          ]
+        },
+        {
+          "name": "metadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata account for the NFT."
+          ]
+        },
+        {
+          "name": "edition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the NFT to mint."
+          ]
+        },
+        {
+          "name": "collectionMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Mint of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionMetadataAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Metadata for the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionEdition",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Edition of the collection NFT."
+          ]
+        },
+        {
+          "name": "collectionAuthorityRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Record of the marketplace authority approving the collection config as collection authority."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Collection config for the new NFT, which mints it in place of the marketplace authority."
+          ]
+        },
+        {
+          "name": "marketplaceConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationNonce",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Account to send fees to."
+          ]
+        },
+        {
+          "name": "instructionsSysvar",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "voucher",
+          "type": {
+            "defined": "MintVoucher"
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": "Metadata"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPrimarySale",
+      "accounts": [
+        {
+          "name": "marketplaceAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Marketplace authority wallet."
+          ]
+        },
+        {
+          "name": "collectionConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "primarySale",
+          "type": {
+            "defined": "PrimarySale"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authorizationNonce",
+      "docs": [
+        "Marks the nonce of an authorization or mint voucher as used. The account can be closed once the",
+        "authorization or voucher expired, as it can no longer be used anyway."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the authorization or voucher can no longer be used"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the items are minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemType",
+            "docs": [
+              "ID of the virtual item type sold by the curve"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of every item minted by the curve"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency to accept for payment"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "basePrice",
+            "docs": [
+              "Price of the first unit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "slope",
+            "docs": [
+              "Price increase per unit in circulation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxSupply",
+            "docs": [
+              "Most units that can be in circulation at once, 0 if unlimited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundBps",
+            "docs": [
+              "Share of a unit's price refunded from the reserve when it is burned back.",
+              "Burning back is disabled when 0."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "supply",
+            "docs": [
+              "Units minted and not burned back"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minted",
+            "docs": [
+              "Units ever minted, used as the serial of the next unit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Part of the reserve owed as refunds to the units in circulation"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "collectionConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "The verified collection to add newly minted items to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "docs": [
+              "Royalty bps. Inserted into newly minted metadata."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "Max 16 chars for symbol. Inserted into newly minted metadata."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "swapRoyaltyPolicy",
+            "docs": [
+              "Royalties charged on items of the collection traded through swaps."
+            ],
+            "type": {
+              "defined": "SwapRoyaltyPolicy"
+            }
+          },
+          {
+            "name": "mintStandard",
+            "docs": [
+              "Token standard newly minted items are created with."
+            ],
+            "type": {
+              "defined": "MintStandard"
+            }
+          },
+          {
+            "name": "verification",
+            "docs": [
+              "How NFTs listed in the collection are proven to belong to it."
+            ],
+            "type": {
+              "defined": "CollectionVerification"
+            }
+          },
+          {
+            "name": "primarySale",
+            "docs": [
+              "Price items minted through mint_virtual are sold at."
+            ],
+            "type": {
+              "defined": "PrimarySale"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodyAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Pubkey of the user's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Pubkey of the mint being custodied"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "A unique ID for the virtual item matching the mint"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "isListed",
+            "docs": [
+              "True if the mint is listed for sale"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed.",
+              "Set to the default pubkey for accounts created before payers were tracked."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fungibleListing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config the listing address is derived from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Pubkey of the seller's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the token being sold"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the token being sold"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency to accept for payment"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of one whole token, in base units of the currency"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "docs": [
+              "Amount still for sale, in base units of the token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the listing expires"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the listing"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Pubkey of the seller's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Set to bytes of NFT mint if listing is for NFT, otherwise a unique ID for the virtual item"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "isVirtual",
+            "docs": [
+              "True if the listing is for a virtual item, false if it is for an NFT"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency to accept for payment"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "Collection config for the item"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of the item, in reference units if the listing has a price feed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the listing expires"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the listing"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "priceFeed",
+            "docs": [
+              "Price feed used to convert the price into the currency at buy time.",
+              "Set to the default pubkey if the price is in the currency."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyDecimals",
+            "docs": [
+              "Decimals of the currency mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Unix timestamp of when the listing can be bought, 0 if immediately"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config the listing address is derived from.",
+              "Set to the default pubkey for legacy listings derived from the item ID only."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isEscrowed",
+            "docs": [
+              "True if the NFT could not be frozen and is escrowed in the listing's token account instead"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed.",
+              "Set to the default pubkey for accounts created before payers were tracked."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "loan",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lender",
+            "docs": [
+              "Pubkey of the wallet escrowing the principal"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "loanId",
+            "docs": [
+              "A unique ID for the loan chosen by the lender"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "collectionMint",
+            "docs": [
+              "Collection the collateral must be verified in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency of the principal and interest, native mint if SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "principal",
+            "docs": [
+              "Amount lent to the borrower"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "interest",
+            "docs": [
+              "Amount owed to the lender on top of the principal"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "Seconds the borrower has to repay once the loan is taken"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "borrower",
+            "docs": [
+              "Pubkey of the borrower's wallet, or the default pubkey until the offer is taken"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "NFT frozen in the borrower's wallet as collateral, or the default pubkey until the offer is taken"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "dueTime",
+            "docs": [
+              "Unix timestamp of when the loan must be repaid by"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "market",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config that approved the token pair"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseMint",
+            "docs": [
+              "Mint of the token being traded"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "quoteMint",
+            "docs": [
+              "Mint of the token prices are quoted in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseLotSize",
+            "docs": [
+              "Base units of the base token in one lot. Order quantities are in lots."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bids",
+            "docs": [
+              "Order book side holding the bids"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "asks",
+            "docs": [
+              "Order book side holding the asks"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config applied on fills"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "nextOrderId",
+            "docs": [
+              "ID given to the next order placed, increasing so older orders have lower IDs"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderBookSide",
+      "docs": [
+        "One side of a market's order book, kept sorted best price first and oldest first within a price"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "market",
+            "type": "publicKey"
+          },
+          {
+            "name": "isBid",
+            "docs": [
+              "True if the side holds bids, false if asks"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "Aligns count as repr(C) would, keeping the layout clients decode the same as on chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "docs": [
+              "Number of orders in use at the start of the array"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "orders",
+            "type": {
+              "array": [
+                {
+                  "defined": "Order"
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "marketplaceConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Royalty bps. Inserted into newly minted metadata."
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "keeperReward",
+            "docs": [
+              "Lamports paid out of the listing rent to whoever closes an expired listing."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config for the item"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "A unique ID for the virtual item within the collection"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Track NFT mint for the item"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mysteryBox",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the boxes and prizes are minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "boxId",
+            "docs": [
+              "A unique ID for the mystery box within the collection"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of the box NFTs"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "randomnessSource",
+            "docs": [
+              "Source of the entropy prizes are drawn with"
+            ],
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          },
+          {
+            "name": "prizes",
+            "docs": [
+              "Items a box can be opened into"
+            ],
+            "type": {
+              "vec": {
+                "defined": "Prize"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "openOrders",
+      "docs": [
+        "Balances of a user on a market that can be settled out of its vaults"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "market",
+            "docs": [
+              "Market the orders are placed on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Pubkey of the user's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseFree",
+            "docs": [
+              "Base units bought or released from cancelled asks"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quoteFree",
+            "docs": [
+              "Quote units received from sales, refunded from bids filled at a better price or released",
+              "from cancelled bids"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeed",
+      "docs": [
+        "Authority-published price for a currency, used where no oracle feed is available"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet allowed to publish prices"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency the price is quoted for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of one whole currency unit in reference units, scaled by 10^expo"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "conf",
+            "docs": [
+              "Confidence interval of the price, scaled by 10^expo"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expo",
+            "docs": [
+              "Exponent applied to price and conf"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "publishTime",
+            "docs": [
+              "Unix timestamp of when the price was last published"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "raffle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "namespace",
+            "docs": [
+              "Marketplace config the raffle address is derived from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "raffleId",
+            "docs": [
+              "A unique ID for the raffle within the marketplace"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Wallet that created the raffle and receives the proceeds"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isVirtual",
+            "docs": [
+              "Whether the prize is a virtual item minted to the winner rather than an escrowed NFT"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the escrowed NFT, or the default pubkey if the prize is virtual"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the virtual prize is minted into, or the default pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Item ID of the virtual prize"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of the virtual prize"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency tickets are paid in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "ticketPrice",
+            "docs": [
+              "Price of one ticket"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxTickets",
+            "docs": [
+              "Most tickets that can be sold"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "ticketsSold",
+            "docs": [
+              "Tickets sold, numbered from 0"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp of when ticket sales end"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the proceeds"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "randomnessSource",
+            "docs": [
+              "Source of the entropy the winning ticket is drawn with"
+            ],
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "RaffleState"
+            }
+          },
+          {
+            "name": "seedHash",
+            "docs": [
+              "Hash of the seed committed by the marketplace authority when the raffle was closed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "closeSlot",
+            "docs": [
+              "Slot the raffle was closed in, whose entropy the winning ticket is drawn with"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "winningTicket",
+            "docs": [
+              "Number of the winning ticket once drawn"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "ticketsRefunded",
+            "docs": [
+              "Tickets refunded since the raffle expired"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "proceeds",
+            "docs": [
+              "Currency received for the sold tickets, after any transfer fee"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "raffleTicket",
+      "docs": [
+        "Tickets bought together by one wallet"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "raffle",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyer",
+            "type": "publicKey"
+          },
+          {
+            "name": "firstTicket",
+            "docs": [
+              "Number of the first ticket"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "count",
+            "type": "u32"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Currency the raffle received for the tickets, which is refunded if the raffle expires"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recipe",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Pubkey of the marketplace authority's wallet"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the inputs are burned from and the output is minted into"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipeId",
+            "docs": [
+              "A unique ID for the recipe within the collection"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "inputs",
+            "docs": [
+              "Item types burned by crafting, matched by NFT name"
+            ],
+            "type": {
+              "vec": {
+                "defined": "RecipeInput"
+              }
+            }
+          },
+          {
+            "name": "output",
+            "docs": [
+              "Metadata of the crafted item"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rental",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
           {
             "name": "version",
             "type": "u8"
@@ -1138,21 +16566,148 @@ export type Carbon = {
           {
             "name": "owner",
             "docs": [
-              "Pubkey of the user's wallet"
+              "Pubkey of the owner's wallet"
             ],
             "type": "publicKey"
           },
           {
             "name": "mint",
             "docs": [
-              "Pubkey of the mint being custodied"
+              "Pubkey of the mint being rented out"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pricePerDay",
+            "docs": [
+              "Lamports charged per day of rental"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxDays",
+            "docs": [
+              "Longest rental term the owner accepts, in days"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "applyRoyalties",
+            "docs": [
+              "True if the creators of the NFT receive royalties on the rental fee"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "feeConfig",
+            "docs": [
+              "Fee config for the rental"
+            ],
+            "type": {
+              "defined": "FeeConfig"
+            }
+          },
+          {
+            "name": "renter",
+            "docs": [
+              "Pubkey of the wallet currently using the NFT, or the default pubkey if not rented"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rentedUntil",
+            "docs": [
+              "Unix timestamp of when the current rental term ends"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sealedBox",
+      "docs": [
+        "Randomness commitment of a minted mystery box, tracking the box from mint to reveal"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "mysteryBox",
+            "docs": [
+              "Mystery box the box was minted from"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the box NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Item ID of the box NFT"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "seedHash",
+            "docs": [
+              "Hash of the seed committed by the marketplace authority when the box was minted"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "holder",
+            "docs": [
+              "Wallet that opened the box and receives the prize, or the default pubkey if not opened"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "openSlot",
+            "docs": [
+              "Slot the box was opened in"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "itemId",
+            "name": "entropy",
             "docs": [
-              "A unique ID for the virtual item matching the mint"
+              "Entropy read when the box was opened, which the prize is drawn with together with the seed"
             ],
             "type": {
               "array": [
@@ -1162,17 +16717,20 @@ export type Carbon = {
             }
           },
           {
-            "name": "isListed",
+            "name": "payer",
             "docs": [
-              "True if the mint is listed for sale"
+              "Wallet that paid the rent and gets it back when the account is closed."
             ],
-            "type": "bool"
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "listing",
+      "name": "session",
+      "docs": [
+        "An ephemeral key a player authorized to sign listing and custody actions for them"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -1190,80 +16748,74 @@ export type Carbon = {
             "type": "u8"
           },
           {
-            "name": "marketplaceAuthority",
+            "name": "owner",
             "docs": [
-              "Pubkey of the marketplace authority's wallet"
+              "Wallet the session key acts for"
             ],
             "type": "publicKey"
           },
           {
-            "name": "seller",
+            "name": "sessionKey",
             "docs": [
-              "Pubkey of the seller's wallet"
+              "Ephemeral key allowed to sign for the owner"
             ],
             "type": "publicKey"
           },
           {
-            "name": "itemId",
+            "name": "marketplaceAuthority",
             "docs": [
-              "Set to bytes of NFT mint if listing is for NFT, otherwise a unique ID for the virtual item"
+              "Marketplace whose listings and custody accounts the session key can act on"
             ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "type": "publicKey"
           },
           {
-            "name": "isVirtual",
+            "name": "expiry",
             "docs": [
-              "True if the listing is for a virtual item, false if it is for an NFT"
+              "Unix timestamp after which the session key can no longer sign"
             ],
-            "type": "bool"
+            "type": "i64"
           },
           {
-            "name": "currencyMint",
-            "docs": [
-              "Currency to accept for payment"
-            ],
-            "type": "publicKey"
+            "name": "scope",
+            "type": {
+              "defined": "SessionScope"
+            }
           },
           {
-            "name": "collectionMint",
+            "name": "collectionMints",
             "docs": [
-              "Collection config for the item"
+              "Collections the session key can act on, or empty for any collection"
             ],
-            "type": "publicKey"
+            "type": {
+              "vec": "publicKey"
+            }
           },
           {
-            "name": "price",
+            "name": "maxPrice",
             "docs": [
-              "Price of the item"
+              "Highest price the session key can list for, or 0 for no limit"
             ],
             "type": "u64"
           },
           {
-            "name": "expiry",
+            "name": "minPrice",
             "docs": [
-              "Unix timestamp of when the listing expires"
+              "Lowest price the session key can list for, or 0 for no floor"
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "feeConfig",
+            "name": "payer",
             "docs": [
-              "Fee config for the listing"
+              "Wallet that paid the rent and gets it back when the account is closed"
             ],
-            "type": {
-              "defined": "FeeConfig"
-            }
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "marketplaceConfig",
+      "name": "swap",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1283,38 +16835,28 @@ export type Carbon = {
           {
             "name": "marketplaceAuthority",
             "docs": [
-              "Pubkey of the marketplace authority's wallet."
+              "Pubkey of the marketplace authority's wallet"
             ],
             "type": "publicKey"
           },
           {
-            "name": "feeConfig",
+            "name": "maker",
             "docs": [
-              "Royalty bps. Inserted into newly minted metadata."
+              "Pubkey of the wallet proposing the swap"
             ],
-            "type": {
-              "defined": "FeeConfig"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "mintRecord",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": "publicKey"
+          },
           {
-            "name": "collectionConfig",
+            "name": "taker",
             "docs": [
-              "Collection config for the item"
+              "Pubkey of the only wallet allowed to take the swap, or the default pubkey if anyone can"
             ],
             "type": "publicKey"
           },
           {
-            "name": "itemId",
+            "name": "swapId",
             "docs": [
-              "A unique ID for the virtual item within the collection"
+              "A unique ID for the swap chosen by the maker"
             ],
             "type": {
               "array": [
@@ -1324,9 +16866,69 @@ export type Carbon = {
             }
           },
           {
-            "name": "mint",
+            "name": "collectionMint",
             "docs": [
-              "Track NFT mint for the item"
+              "Collection all NFTs in the swap are verified in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "Currency of the maker and taker amounts, native mint if SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "makerMints",
+            "docs": [
+              "NFTs escrowed by the maker, frozen by delegation or flagged in their custody account"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "makerAmount",
+            "docs": [
+              "Currency escrowed by the maker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "takerMints",
+            "docs": [
+              "NFTs requested from the taker"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "takerAmount",
+            "docs": [
+              "Currency requested from the taker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "royaltyPerNft",
+            "docs": [
+              "Lamports paid by the taker to the creators of each NFT in the swap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the swap expires, 0 if never"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent and gets it back when the account is closed."
             ],
             "type": "publicKey"
           }
@@ -1351,685 +16953,889 @@ export type Carbon = {
           {
             "name": "symbol",
             "type": "string"
-          }
-        ]
-      }
-    },
-    {
-      "name": "MarketplaceConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "feeConfig",
+            "name": "swapRoyaltyPolicy",
             "type": {
-              "defined": "FeeConfig"
+              "defined": "SwapRoyaltyPolicy"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "FeeConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "feeAccount",
-            "type": "publicKey"
+            "name": "mintStandard",
+            "type": {
+              "defined": "MintStandard"
+            }
           },
           {
-            "name": "bps",
-            "type": "u16"
+            "name": "verification",
+            "type": {
+              "defined": "CollectionVerification"
+            }
           }
         ]
       }
     },
     {
-      "name": "Metadata",
+      "name": "MarketplaceConfigArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "Buy",
-      "fields": [
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          },
-          "index": false
-        },
-        {
-          "name": "mint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "price",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "seller",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "buyer",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "isVirtual",
-          "type": "bool",
-          "index": false
-        },
-        {
-          "name": "currencyMint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "collectionMint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "feeConfig",
-          "type": {
-            "defined": "FeeConfig"
-          },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "Custody",
-      "fields": [
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "owner",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
+            "name": "feeConfig",
+            "type": {
+              "defined": "FeeConfig"
+            }
           },
-          "index": false
-        }
-      ]
+          {
+            "name": "keeperReward",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "Delist",
-      "fields": [
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
+      "name": "Authorization",
+      "docs": [
+        "Message the marketplace authority signs off-chain so an action can go through without the",
+        "authority signing the transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": "AuthorizedAction"
+            }
           },
-          "index": false
-        },
-        {
-          "name": "seller",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "collectionMint",
-          "type": "publicKey",
-          "index": false
-        }
-      ]
+          {
+            "name": "itemId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the item is listed or minted in"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Wallet the action is for, the seller of a listing or the buyer of a mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of the listing, or 0 for mints"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "metadataHash",
+            "docs": [
+              "Hash of the metadata of the minted item, or zeroed for listings"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the authorization can no longer be used"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Number the marketplace authority picks so that each authorization can only be used once"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "ListingUpdate",
-      "fields": [
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
+      "name": "MintVoucher",
+      "docs": [
+        "Voucher the marketplace authority signs off-chain so a player can mint an item by paying for it,",
+        "with no server in the transaction. Like an authorization, its nonce can only be used once, as mint",
+        "records can be closed and would not keep the voucher from being redeemed again."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "itemId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
-          "index": false
-        },
-        {
-          "name": "price",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "expiry",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "seller",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "isVirtual",
-          "type": "bool",
-          "index": false
-        },
-        {
-          "name": "currencyMint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "collectionMint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "feeConfig",
-          "type": {
-            "defined": "FeeConfig"
+          {
+            "name": "collectionConfig",
+            "docs": [
+              "Collection config the item is minted into"
+            ],
+            "type": "publicKey"
           },
-          "index": false
-        }
-      ]
+          {
+            "name": "recipient",
+            "docs": [
+              "Wallet the voucher is for, or the default pubkey if anyone can redeem it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "currencyMint",
+            "docs": [
+              "The currency the price is paid in or native mint if using SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the voucher can no longer be redeemed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "metadataHash",
+            "docs": [
+              "Hash of the metadata of the minted item"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Number the marketplace authority picks so that each voucher can only be redeemed once"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "List",
-      "fields": [
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          },
-          "index": false
-        },
-        {
-          "name": "price",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "expiry",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "seller",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "isVirtual",
-          "type": "bool",
-          "index": false
-        },
-        {
-          "name": "currencyMint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "collectionMint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "feeConfig",
-          "type": {
-            "defined": "FeeConfig"
+      "name": "BondingCurveArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "itemType",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
-          "index": false
-        }
-      ]
+          {
+            "name": "metadata",
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "basePrice",
+            "type": "u64"
+          },
+          {
+            "name": "slope",
+            "type": "u64"
+          },
+          {
+            "name": "maxSupply",
+            "type": "u64"
+          },
+          {
+            "name": "refundBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "name": "Mint",
-      "fields": [
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
+      "name": "PrimarySale",
+      "docs": [
+        "Primary sales are paid to the treasury with the marketplace fee taken out, and free until the",
+        "marketplace authority sets a price"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "docs": [
+              "Price of one item, or 0 if items are minted for free"
+            ],
+            "type": "u64"
           },
-          "index": false
-        },
-        {
-          "name": "mint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "buyer",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "collectionMint",
-          "type": "publicKey",
-          "index": false
-        }
-      ]
+          {
+            "name": "currencyMint",
+            "docs": [
+              "The currency the price is paid in or native mint if using SOL"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Wallet receiving the price net of the marketplace fee"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
     },
     {
-      "name": "Uncustody",
-      "fields": [
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "owner",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mint",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
+      "name": "FeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeAccount",
+            "type": "publicKey"
           },
-          "index": false
-        }
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "BumpSeedNotInHashMap",
-      "msg": "Bump seed not in hash map"
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "InvalidExpiry",
-      "msg": "Invalid expiry"
+      "name": "FungibleListingArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "Amount to sell, in base units of the token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price of one whole token, in base units of the currency"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp of when the listing expires, 0 if never"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "NotVirtual",
-      "msg": "Not virtual"
+      "name": "LoanArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "loanId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "interest",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "Seconds the borrower has to repay once the loan is taken"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "ListingExpired",
-      "msg": "Listing expired"
+      "name": "Order",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openOrders",
+            "docs": [
+              "Open orders account credited when the order fills or is cancelled"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "orderId",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Quote units per lot"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quantity",
+            "docs": [
+              "Lots left to fill"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "MaxPriceExceeded",
-      "msg": "Max price exceeded"
+      "name": "Metadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "InvalidSeller",
-      "msg": "Invalid seller"
+      "name": "Prize",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metadata",
+            "docs": [
+              "Metadata of the prize item"
+            ],
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Odds of the prize relative to the weights of the other prizes"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "OverflowError",
-      "msg": "Overflow error"
+      "name": "MysteryBoxArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "boxId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadata",
+            "type": {
+              "defined": "Metadata"
+            }
+          },
+          {
+            "name": "randomnessSource",
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          },
+          {
+            "name": "prizes",
+            "type": {
+              "vec": {
+                "defined": "Prize"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "InvalidFeeAccount",
-      "msg": "Invalid fee account"
+      "name": "RaffleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "raffleId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "ticketPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxTickets",
+            "type": "u32"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp of when ticket sales end"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "randomnessSource",
+            "type": {
+              "defined": "RandomnessSource"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "IsVirtual",
-      "msg": "Item is virtual"
+      "name": "RecipeInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "Name of the NFTs of the item type"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "quantity",
+            "docs": [
+              "Number of NFTs of the item type to burn"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "InvalidMint",
-      "msg": "Invalid mint"
+      "name": "RecipeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipeId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "inputs",
+            "type": {
+              "vec": {
+                "defined": "RecipeInput"
+              }
+            }
+          },
+          {
+            "name": "output",
+            "type": {
+              "defined": "Metadata"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "NftIsListed",
-      "msg": "Nft is listed"
+      "name": "RentalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pricePerDay",
+            "type": "u64"
+          },
+          {
+            "name": "maxDays",
+            "type": "u16"
+          },
+          {
+            "name": "applyRoyalties",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "InvalidCustodyAccount",
-      "msg": "Invalid custody account"
+      "name": "SessionScope",
+      "docs": [
+        "Actions a session key can take on behalf of the owner"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "list",
+            "type": "bool"
+          },
+          {
+            "name": "updateListing",
+            "type": "bool"
+          },
+          {
+            "name": "delist",
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "InvalidListingAccount",
-      "msg": "Invalid listing account"
+      "name": "SessionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "marketplaceAuthority",
+            "docs": [
+              "Marketplace whose listings and custody accounts the session key can act on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the session key can no longer sign"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": "SessionScope"
+            }
+          },
+          {
+            "name": "collectionMints",
+            "docs": [
+              "Collections the session key can act on, or empty for any collection"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "maxPrice",
+            "docs": [
+              "Highest price the session key can list for, or 0 for no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minPrice",
+            "docs": [
+              "Lowest price the session key can list for, or 0 for no floor"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "InvalidPrice",
-      "msg": "Invalid price"
+      "name": "SwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swapId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "taker",
+            "docs": [
+              "Wallet allowed to take the swap, or the default pubkey for an open swap"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "makerMints",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "makerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "takerMints",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "takerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "InvalidCollectionConfig",
-      "msg": "Invalid collection config"
+      "name": "DelistReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Seller"
+          },
+          {
+            "name": "Expired"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "InvalidEdition",
-      "msg": "Invalid edition"
-    }
-  ]
-};
-
-export const IDL: Carbon = {
-  "version": "0.7.0",
-  "name": "carbon",
-  "instructions": [
-    {
-      "name": "initMarketplaceConfig",
-      "accounts": [
-        {
-          "name": "marketplaceAuthority",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
-        },
-        {
-          "name": "marketplaceConfig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "AuthorizedAction",
+      "docs": [
+        "Instruction an authorization can be used for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ListVirtual"
+          },
+          {
+            "name": "MintVirtual"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRoyaltyPolicy",
+      "docs": [
+        "Swaps have no sale price to take royalty bps from, so the collection picks what is charged per item"
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "MarketplaceConfigArgs"
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Fixed",
+            "fields": [
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "initCollectionConfig",
-      "accounts": [
-        {
-          "name": "marketplaceAuthority",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
-        },
-        {
-          "name": "collectionConfig",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "MintStandard",
+      "docs": [
+        "Collections opt in to minting programmable NFTs, which enforce royalties through a rule set"
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "CollectionConfigArgs"
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "ProgrammableNonFungible",
+            "fields": [
+              {
+                "name": "rule_set",
+                "type": "publicKey"
+              }
+            ]
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "listNft",
-      "accounts": [
-        {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Seller wallet."
-          ]
-        },
-        {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Seller's token account of the mint to sell."
-          ]
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Mint of the NFT to sell."
-          ]
-        },
-        {
-          "name": "collectionMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The verified collection mint of the NFT to sell."
-          ]
-        },
-        {
-          "name": "metadataAccount",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Metadata of the NFT to sell."
-          ]
-        },
-        {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Edition of the NFT to sell."
-          ]
-        },
-        {
-          "name": "currencyMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The currency to use or native mint if using SOL"
-          ]
-        },
-        {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "collectionConfig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marketplaceConfig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "custodyAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "CollectionVerification",
+      "docs": [
+        "Older collections may have no verified sized collection, so the collection picks what proves an",
+        "NFT belongs to it"
       ],
-      "args": [
-        {
-          "name": "price",
-          "type": "u64"
-        },
-        {
-          "name": "expiry",
-          "type": "i64"
-        }
-      ]
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "VerifiedCollection"
+          },
+          {
+            "name": "VerifiedCreator",
+            "fields": [
+              {
+                "name": "creator",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "MerkleRoot",
+            "fields": [
+              {
+                "name": "root",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "name": "listVirtual",
-      "accounts": [
-        {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Seller wallet."
-          ]
-        },
-        {
-          "name": "marketplaceAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
-        },
-        {
-          "name": "currencyMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The currency to use or native mint if using SOL"
-          ]
-        },
-        {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "collectionConfig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marketplaceConfig",
-          "isMut": false,
-          "isSigner": false
-        },
+      "name": "Side",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Bid"
+          },
+          {
+            "name": "Ask"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RaffleState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Closed"
+          },
+          {
+            "name": "Drawn"
+          },
+          {
+            "name": "Expired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SessionAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "List"
+          },
+          {
+            "name": "UpdateListing"
+          },
+          {
+            "name": "Delist"
+          },
+          {
+            "name": "Custody"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RandomnessSource",
+      "docs": [
+        "Source of the entropy combined with a committed seed to draw a random outcome. The seed is",
+        "committed before the entropy exists, so neither the committer nor the requester can pick the",
+        "outcome alone."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SlotHashes"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "BondingCurveBurn",
+      "fields": [
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "itemType",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
         {
           "name": "itemId",
           "type": {
@@ -2037,132 +17843,109 @@ export const IDL: Carbon = {
               "u8",
               32
             ]
-          }
+          },
+          "index": false
         },
         {
-          "name": "price",
-          "type": "u64"
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "expiry",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "updateListing",
-      "accounts": [
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Seller wallet."
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "price",
-          "type": "u64"
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "expiry",
-          "type": "i64"
+          "name": "refund",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "delistNft",
-      "accounts": [
+      "name": "BondingCurveBuy",
+      "fields": [
         {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Seller wallet."
-          ]
+          "name": "itemType",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Seller's token account of the mint to delist."
-          ]
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
           "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Mint of the NFT to sell."
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Edition of the NFT to sell."
-          ]
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "custodyAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "price",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "supply",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "delistVirtual",
-      "accounts": [
-        {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Seller wallet."
-          ]
-        },
-        {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+      "name": "Buy",
+      "fields": [
         {
           "name": "itemId",
           "type": {
@@ -2170,245 +17953,210 @@ export const IDL: Carbon = {
               "u8",
               32
             ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "buyNft",
-      "accounts": [
+          },
+          "index": false
+        },
         {
-          "name": "buyer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Buyer wallet."
-          ]
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Seller wallet."
-          ]
+          "name": "price",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The new mint to be used for the NFT."
-          ]
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "sellerTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Seller NFT token account."
-          ]
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "buyerTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Buyer NFT token account."
-          ]
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "metadataAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Metadata account for the NFT."
-          ]
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Edition account for the NFT."
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "custodyAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
         },
         {
-          "name": "feeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Account to send fees to."
-          ]
+          "name": "priceFeed",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "sellerAmount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "royaltyAmount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "maxPrice",
-          "type": "u64"
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "buyVirtual",
-      "accounts": [
+      "name": "Craft",
+      "fields": [
         {
-          "name": "buyer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Buyer wallet."
-          ]
+          "name": "recipeId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "seller",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Seller wallet."
-          ]
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
           "name": "mint",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "The new mint to be used for the NFT."
-          ]
-        },
-        {
-          "name": "buyerTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Buyer NFT token account."
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "metadataAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Metadata account for the NFT."
-          ]
+          "name": "player",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Edition of the NFT to mint."
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "collectionMint",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Mint of the collection NFT."
-          ]
-        },
-        {
-          "name": "collectionMetadataAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Metadata for the collection NFT."
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "collectionEdition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Edition of the collection NFT."
-          ]
-        },
+          "name": "burnedMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Custody",
+      "fields": [
         {
-          "name": "listing",
-          "isMut": true,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "collectionConfig",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "mintRecord",
-          "isMut": true,
-          "isSigner": false
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "feeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Account to send fees to."
-          ]
-        },
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Delist",
+      "fields": [
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "reason",
+          "type": {
+            "defined": "DelistReason"
+          },
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "ListingUpdate",
+      "fields": [
         {
           "name": "itemId",
           "type": {
@@ -2416,789 +18164,866 @@ export const IDL: Carbon = {
               "u8",
               32
             ]
-          }
+          },
+          "index": false
         },
         {
-          "name": "maxPrice",
-          "type": "u64"
+          "name": "price",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "metadata",
-          "type": {
-            "defined": "Metadata"
-          }
-        }
-      ]
-    },
-    {
-      "name": "mintVirtual",
-      "accounts": [
+          "name": "expiry",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
         {
-          "name": "buyer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Buyer wallet."
-          ]
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "marketplaceAuthority",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "The new mint to be used for the NFT."
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "buyerTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Buyer NFT token account."
-          ]
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
         },
         {
-          "name": "metadataAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Metadata account for the NFT."
-          ]
+          "name": "priceFeed",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "List",
+      "fields": [
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Edition of the NFT to mint."
-          ]
+          "name": "price",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "collectionMint",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Mint of the collection NFT."
-          ]
+          "name": "expiry",
+          "type": "i64",
+          "index": false
         },
         {
-          "name": "collectionMetadataAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Metadata for the collection NFT."
-          ]
+          "name": "startTime",
+          "type": "i64",
+          "index": false
         },
         {
-          "name": "collectionEdition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Edition of the collection NFT."
-          ]
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "collectionConfig",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Collection config for the new NFT."
-          ]
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "mintRecord",
-          "isMut": true,
-          "isSigner": false
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "priceFeed",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "LoanCancel",
+      "fields": [
         {
-          "name": "itemId",
+          "name": "loanId",
           "type": {
             "array": [
               "u8",
               32
             ]
-          }
+          },
+          "index": false
         },
         {
-          "name": "metadata",
-          "type": {
-            "defined": "Metadata"
-          }
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "custody",
-      "accounts": [
+      "name": "LoanForeclose",
+      "fields": [
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "User wallet."
-          ]
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "marketplaceAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User's token account of the mint to custody."
-          ]
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Mint to be custodied"
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Edition of the NFT to custody."
-          ]
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanOffer",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "custodyAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "listing",
-          "isMut": false,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "principal",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "interest",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "duration",
+          "type": "i64",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "LoanRepay",
+      "fields": [
         {
-          "name": "itemId",
+          "name": "loanId",
           "type": {
             "array": [
               "u8",
               32
             ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "uncustody",
-      "accounts": [
+          },
+          "index": false
+        },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "User wallet."
-          ]
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "marketplaceAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User's token account of the mint to uncustody."
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Mint to be uncustodied"
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Edition of the NFT to uncustody."
-          ]
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LoanTake",
+      "fields": [
+        {
+          "name": "loanId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "custodyAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "lender",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "listing",
-          "isMut": false,
-          "isSigner": false
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "dueTime",
+          "type": "i64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "takeOwnership",
-      "accounts": [
+      "name": "Mint",
+      "fields": [
         {
-          "name": "marketplaceAuthority",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User wallet with authority over the custodial mint."
-          ]
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User's token account of the custodial mint."
-          ]
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "marketplaceAuthorityTokenAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Marketplace authority's token account of the custodied mint."
-          ]
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Mint custodied"
-          ]
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Edition of the custodied mint."
-          ]
+          "name": "price",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "custodyAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "listing",
-          "isMut": false,
-          "isSigner": false
+          "name": "treasuryAmount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MysteryBoxOpen",
+      "fields": [
+        {
+          "name": "boxId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "boxMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "openSlot",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "closeMintRecord",
-      "accounts": [
+      "name": "MysteryBoxReveal",
+      "fields": [
+        {
+          "name": "boxId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "boxMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "holder",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "marketplaceAuthority",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Marketplace authority wallet."
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
+          "name": "collectionMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "edition",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Edition account of the NFT."
-          ]
+          "name": "prizeIndex",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "collectionConfig",
-          "isMut": false,
-          "isSigner": false
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "mintRecord",
-          "isMut": true,
-          "isSigner": false
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "collectionConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "version",
-            "type": "u8"
-          },
-          {
-            "name": "marketplaceAuthority",
-            "docs": [
-              "Pubkey of the marketplace authority's wallet."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "collectionMint",
-            "docs": [
-              "The verified collection to add newly minted items to."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "sellerFeeBasisPoints",
-            "docs": [
-              "Royalty bps. Inserted into newly minted metadata."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "symbol",
-            "docs": [
-              "Max 16 chars for symbol. Inserted into newly minted metadata."
-            ],
-            "type": "string"
-          }
-        ]
-      }
-    },
-    {
-      "name": "custodyAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "version",
-            "type": "u8"
-          },
-          {
-            "name": "marketplaceAuthority",
-            "docs": [
-              "Pubkey of the marketplace authority's wallet"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "owner",
-            "docs": [
-              "Pubkey of the user's wallet"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "mint",
-            "docs": [
-              "Pubkey of the mint being custodied"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "itemId",
-            "docs": [
-              "A unique ID for the virtual item matching the mint"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+          "name": "randomness",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "isListed",
-            "docs": [
-              "True if the mint is listed for sale"
-            ],
-            "type": "bool"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "listing",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "version",
-            "type": "u8"
-          },
-          {
-            "name": "marketplaceAuthority",
-            "docs": [
-              "Pubkey of the marketplace authority's wallet"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "seller",
-            "docs": [
-              "Pubkey of the seller's wallet"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "itemId",
-            "docs": [
-              "Set to bytes of NFT mint if listing is for NFT, otherwise a unique ID for the virtual item"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "isVirtual",
-            "docs": [
-              "True if the listing is for a virtual item, false if it is for an NFT"
-            ],
-            "type": "bool"
-          },
-          {
-            "name": "currencyMint",
-            "docs": [
-              "Currency to accept for payment"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "collectionMint",
-            "docs": [
-              "Collection config for the item"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "price",
-            "docs": [
-              "Price of the item"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "expiry",
-            "docs": [
-              "Unix timestamp of when the listing expires"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "feeConfig",
-            "docs": [
-              "Fee config for the listing"
-            ],
-            "type": {
-              "defined": "FeeConfig"
-            }
-          }
-        ]
-      }
+      "name": "OrderCancel",
+      "fields": [
+        {
+          "name": "market",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "orderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          },
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "marketplaceConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "version",
-            "type": "u8"
+      "name": "OrderFill",
+      "fields": [
+        {
+          "name": "market",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bidOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "askOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "OrderPlace",
+      "fields": [
+        {
+          "name": "market",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "orderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
           },
-          {
-            "name": "marketplaceAuthority",
-            "docs": [
-              "Pubkey of the marketplace authority's wallet."
-            ],
-            "type": "publicKey"
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RaffleCancel",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "feeConfig",
-            "docs": [
-              "Royalty bps. Inserted into newly minted metadata."
-            ],
-            "type": {
-              "defined": "FeeConfig"
-            }
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "mintRecord",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "collectionConfig",
-            "docs": [
-              "Collection config for the item"
-            ],
-            "type": "publicKey"
+      "name": "RaffleClaim",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "itemId",
-            "docs": [
-              "A unique ID for the virtual item within the collection"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "mint",
-            "docs": [
-              "Track NFT mint for the item"
-            ],
-            "type": "publicKey"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "CollectionConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "collectionMint",
-            "type": "publicKey"
+      "name": "RaffleCreate",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "sellerFeeBasisPoints",
-            "type": "u16"
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "isVirtual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "itemId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ticketPrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxTickets",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "endTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
           },
-          {
-            "name": "symbol",
-            "type": "string"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "MarketplaceConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "feeConfig",
-            "type": {
-              "defined": "FeeConfig"
-            }
-          }
-        ]
-      }
+      "name": "RaffleDraw",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winningTicket",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "ticketsSold",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "randomness",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "currencyMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creatorAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "FeeConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "feeAccount",
-            "type": "publicKey"
+      "name": "RaffleExpire",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "bps",
-            "type": "u16"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ticketsSold",
+          "type": "u32",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Metadata",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
+      "name": "RaffleTicketBuy",
+      "fields": [
+        {
+          "name": "raffleId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "uri",
-            "type": "string"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "firstTicket",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "count",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "Buy",
+      "name": "RaffleTicketRefund",
       "fields": [
         {
-          "name": "itemId",
+          "name": "raffleId",
           "type": {
             "array": [
               "u8",
@@ -3208,7 +19033,119 @@ export const IDL: Carbon = {
           "index": false
         },
         {
-          "name": "mint",
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "firstTicket",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "count",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RentalEnd",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "renter",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RentalList",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pricePerDay",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxDays",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "applyRoyalties",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "feeConfig",
+          "type": {
+            "defined": "FeeConfig"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Rent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "renter",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketplaceAuthority",
           "type": "publicKey",
           "index": false
         },
@@ -3218,79 +19155,81 @@ export const IDL: Carbon = {
           "index": false
         },
         {
-          "name": "seller",
-          "type": "publicKey",
+          "name": "rentedUntil",
+          "type": "i64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "SessionCreate",
+      "fields": [
         {
-          "name": "buyer",
+          "name": "owner",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "isVirtual",
-          "type": "bool",
+          "name": "sessionKey",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "currencyMint",
+          "name": "marketplaceAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
+          "name": "expiry",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "collectionMint",
-          "type": "publicKey",
+          "name": "scope",
+          "type": {
+            "defined": "SessionScope"
+          },
           "index": false
         },
         {
-          "name": "feeConfig",
+          "name": "collectionMints",
           "type": {
-            "defined": "FeeConfig"
+            "vec": "publicKey"
           },
           "index": false
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "minPrice",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "Custody",
+      "name": "SessionRevoke",
       "fields": [
-        {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
-          "index": false
-        },
         {
           "name": "owner",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "mint",
+          "name": "sessionKey",
           "type": "publicKey",
           "index": false
-        },
-        {
-          "name": "itemId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          },
-          "index": false
         }
       ]
     },
     {
-      "name": "Delist",
+      "name": "SwapCancel",
       "fields": [
         {
-          "name": "itemId",
+          "name": "swapId",
           "type": {
             "array": [
               "u8",
@@ -3300,7 +19239,7 @@ export const IDL: Carbon = {
           "index": false
         },
         {
-          "name": "seller",
+          "name": "maker",
           "type": "publicKey",
           "index": false
         },
@@ -3317,10 +19256,10 @@ export const IDL: Carbon = {
       ]
     },
     {
-      "name": "ListingUpdate",
+      "name": "SwapCreate",
       "fields": [
         {
-          "name": "itemId",
+          "name": "swapId",
           "type": {
             "array": [
               "u8",
@@ -3330,27 +19269,17 @@ export const IDL: Carbon = {
           "index": false
         },
         {
-          "name": "price",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "expiry",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "seller",
+          "name": "maker",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "isVirtual",
-          "type": "bool",
+          "name": "taker",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "currencyMint",
+          "name": "marketplaceAuthority",
           "type": "publicKey",
           "index": false
         },
@@ -3360,81 +19289,51 @@ export const IDL: Carbon = {
           "index": false
         },
         {
-          "name": "marketplaceAuthority",
+          "name": "currencyMint",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "feeConfig",
-          "type": {
-            "defined": "FeeConfig"
-          },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "List",
-      "fields": [
-        {
-          "name": "itemId",
+          "name": "makerMints",
           "type": {
-            "array": [
-              "u8",
-              32
-            ]
+            "vec": "publicKey"
           },
           "index": false
         },
         {
-          "name": "price",
+          "name": "makerAmount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "expiry",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "seller",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "isVirtual",
-          "type": "bool",
-          "index": false
-        },
-        {
-          "name": "currencyMint",
-          "type": "publicKey",
+          "name": "takerMints",
+          "type": {
+            "vec": "publicKey"
+          },
           "index": false
         },
         {
-          "name": "collectionMint",
-          "type": "publicKey",
+          "name": "takerAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "marketplaceAuthority",
-          "type": "publicKey",
+          "name": "royaltyPerNft",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "feeConfig",
-          "type": {
-            "defined": "FeeConfig"
-          },
+          "name": "expiry",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "Mint",
+      "name": "SwapTake",
       "fields": [
         {
-          "name": "itemId",
+          "name": "swapId",
           "type": {
             "array": [
               "u8",
@@ -3444,12 +19343,12 @@ export const IDL: Carbon = {
           "index": false
         },
         {
-          "name": "mint",
+          "name": "maker",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "buyer",
+          "name": "taker",
           "type": "publicKey",
           "index": false
         },
@@ -3462,6 +19361,11 @@ export const IDL: Carbon = {
           "name": "collectionMint",
           "type": "publicKey",
           "index": false
+        },
+        {
+          "name": "royaltiesPaid",
+          "type": "u64",
+          "index": false
         }
       ]
     },
@@ -3576,6 +19480,306 @@ export const IDL: Carbon = {
       "code": 6015,
       "name": "InvalidEdition",
       "msg": "Invalid edition"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidPriceFeed",
+      "msg": "Invalid price feed"
+    },
+    {
+      "code": 6017,
+      "name": "StalePrice",
+      "msg": "Price is stale"
+    },
+    {
+      "code": 6018,
+      "name": "PriceConfidenceExceeded",
+      "msg": "Price confidence interval too wide"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidStartTime",
+      "msg": "Invalid start time"
+    },
+    {
+      "code": 6020,
+      "name": "ListingNotStarted",
+      "msg": "Listing has not started"
+    },
+    {
+      "code": 6021,
+      "name": "ListingNotExpired",
+      "msg": "Listing not expired"
+    },
+    {
+      "code": 6022,
+      "name": "TooManySwapItems",
+      "msg": "Too many items in swap"
+    },
+    {
+      "code": 6023,
+      "name": "EmptySwap",
+      "msg": "Swap must exchange something on both sides"
+    },
+    {
+      "code": 6024,
+      "name": "SwapExpired",
+      "msg": "Swap expired"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidTaker",
+      "msg": "Invalid taker"
+    },
+    {
+      "code": 6026,
+      "name": "NftIsRented",
+      "msg": "Nft is rented"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRentalDuration",
+      "msg": "Invalid rental duration"
+    },
+    {
+      "code": 6028,
+      "name": "RentalTermNotEnded",
+      "msg": "Rental term has not ended"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidOwner",
+      "msg": "Invalid owner"
+    },
+    {
+      "code": 6030,
+      "name": "LoanIsActive",
+      "msg": "Loan is active"
+    },
+    {
+      "code": 6031,
+      "name": "LoanNotActive",
+      "msg": "Loan is not active"
+    },
+    {
+      "code": 6032,
+      "name": "LoanOverdue",
+      "msg": "Loan is overdue"
+    },
+    {
+      "code": 6033,
+      "name": "LoanNotOverdue",
+      "msg": "Loan is not overdue"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidLoanDuration",
+      "msg": "Invalid loan duration"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidBorrower",
+      "msg": "Invalid borrower"
+    },
+    {
+      "code": 6036,
+      "name": "ProgrammableNftInCustody",
+      "msg": "Custodial programmable NFTs can only be transferred to the marketplace authority"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidAmount",
+      "msg": "Invalid amount"
+    },
+    {
+      "code": 6038,
+      "name": "InsufficientListingAmount",
+      "msg": "Listing has less than the amount remaining"
+    },
+    {
+      "code": 6039,
+      "name": "OrderBookFull",
+      "msg": "Order book is full"
+    },
+    {
+      "code": 6040,
+      "name": "OrderNotFound",
+      "msg": "Order not found"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidOpenOrders",
+      "msg": "Invalid open orders account"
+    },
+    {
+      "code": 6042,
+      "name": "SupplyExhausted",
+      "msg": "Bonding curve supply exhausted"
+    },
+    {
+      "code": 6043,
+      "name": "BurnBackDisabled",
+      "msg": "Bonding curve does not allow burning back"
+    },
+    {
+      "code": 6044,
+      "name": "InsufficientReserve",
+      "msg": "Insufficient reserve"
+    },
+    {
+      "code": 6045,
+      "name": "UnsupportedMintStandard",
+      "msg": "Unsupported mint standard"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidMetadata",
+      "msg": "Invalid metadata"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidRecipe",
+      "msg": "Invalid recipe"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidRecipeInputs",
+      "msg": "NFTs do not match the recipe inputs"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidPrizes",
+      "msg": "Invalid prizes"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidSeed",
+      "msg": "Seed does not match its commitment"
+    },
+    {
+      "code": 6051,
+      "name": "RevealTooEarly",
+      "msg": "Randomness can't be revealed in the slot it was requested in"
+    },
+    {
+      "code": 6052,
+      "name": "RandomnessUnavailable",
+      "msg": "Randomness for the requested slot is not available"
+    },
+    {
+      "code": 6053,
+      "name": "BoxNotOpened",
+      "msg": "Mystery box has not been opened"
+    },
+    {
+      "code": 6054,
+      "name": "RaffleNotOpen",
+      "msg": "Raffle is not open"
+    },
+    {
+      "code": 6055,
+      "name": "RaffleNotEnded",
+      "msg": "Raffle has not ended"
+    },
+    {
+      "code": 6056,
+      "name": "RaffleSoldOut",
+      "msg": "Not enough raffle tickets left"
+    },
+    {
+      "code": 6057,
+      "name": "RaffleHasNoTickets",
+      "msg": "Raffle has no tickets sold"
+    },
+    {
+      "code": 6058,
+      "name": "RaffleNotClosed",
+      "msg": "Raffle is not closed"
+    },
+    {
+      "code": 6059,
+      "name": "RaffleNotDrawn",
+      "msg": "Raffle has not been drawn"
+    },
+    {
+      "code": 6060,
+      "name": "NotWinningTicket",
+      "msg": "Ticket is not the winning ticket"
+    },
+    {
+      "code": 6061,
+      "name": "RaffleHasTickets",
+      "msg": "Raffle has tickets sold"
+    },
+    {
+      "code": 6062,
+      "name": "WinningTicketUnclaimed",
+      "msg": "Winning ticket must be claimed"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidRentPayer",
+      "msg": "Invalid rent payer"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidSession",
+      "msg": "Invalid session"
+    },
+    {
+      "code": 6065,
+      "name": "SessionExpired",
+      "msg": "Session expired"
+    },
+    {
+      "code": 6066,
+      "name": "SessionScopeExceeded",
+      "msg": "Action is outside the scope of the session"
+    },
+    {
+      "code": 6067,
+      "name": "OwnerSignatureRequired",
+      "msg": "Action requires the owner's signature"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidAuthorization",
+      "msg": "Invalid authorization"
+    },
+    {
+      "code": 6069,
+      "name": "AuthorizationExpired",
+      "msg": "Authorization expired"
+    },
+    {
+      "code": 6070,
+      "name": "AuthorizationNotExpired",
+      "msg": "Authorization has not expired"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidTreasury",
+      "msg": "Invalid treasury"
+    },
+    {
+      "code": 6072,
+      "name": "DrawNotExpired",
+      "msg": "Draw can still be revealed"
+    },
+    {
+      "code": 6073,
+      "name": "RaffleNotExpired",
+      "msg": "Raffle has not expired"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidMintRecord",
+      "msg": "Invalid mint record"
+    },
+    {
+      "code": 6075,
+      "name": "RaffleCanBeClosed",
+      "msg": "Raffle can still be closed"
     }
   ]
 };
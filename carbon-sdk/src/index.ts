@@ -1,4 +1,8 @@
+import { BorshCoder } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
+import { sha256 } from "js-sha256";
+import { IDL } from "./idl/carbon";
+import { Authorization, Metadata, MintVoucher } from "./types";
 
 export * from "./carbon";
 export * from "./types";
@@ -27,3 +31,28 @@ export function parseItemId(itemId: number[]) {
 	const trimmed = itemId.slice(0, i);
 	return Buffer.from(trimmed).toString("utf8");
 }
+
+// Commitment to a secret seed, revealed later to draw randomness
+export function hashSeed(seed: number[]): number[] {
+	return sha256.array(Buffer.from(seed));
+}
+
+// Message the marketplace authority signs to authorize an action without co-signing it
+export function getAuthorizationMessage(authorization: Authorization): Buffer {
+	return Buffer.concat([
+		Buffer.from("carbon authorization"),
+		new BorshCoder(IDL).types.encode("Authorization", authorization),
+	]);
+}
+
+// Message the marketplace authority signs to let a player mint an item by paying for it
+export function getMintVoucherMessage(voucher: MintVoucher): Buffer {
+	return Buffer.concat([
+		Buffer.from("carbon mint voucher"),
+		new BorshCoder(IDL).types.encode("MintVoucher", voucher),
+	]);
+}
+
+export function hashMetadata(metadata: Metadata): number[] {
+	return sha256.array(new BorshCoder(IDL).types.encode("Metadata", metadata));
+}
@@ -16,7 +16,60 @@ import { custody } from "./instructions/custody";
 import { uncustody } from "./instructions/uncustody";
 import { takeOwnership } from "./instructions/takeOwnership";
 import { closeMintRecord } from "./instructions/closeMintRecord";
+import { migrateMintRecord } from "./instructions/migrateMintRecord";
 import { burnAndCloseMintRecord } from "./instructions/burnAndCloseMintRecord";
+import { initPriceFeed } from "./instructions/initPriceFeed";
+import { updatePriceFeed } from "./instructions/updatePriceFeed";
+import { closeExpiredListing } from "./instructions/closeExpiredListing";
+import { createSwap } from "./instructions/createSwap";
+import { takeSwap } from "./instructions/takeSwap";
+import { cancelSwap } from "./instructions/cancelSwap";
+import { listRental } from "./instructions/listRental";
+import { rentNft } from "./instructions/rentNft";
+import { endRental } from "./instructions/endRental";
+import { offerLoan } from "./instructions/offerLoan";
+import { takeLoan } from "./instructions/takeLoan";
+import { repayLoan } from "./instructions/repayLoan";
+import { forecloseLoan } from "./instructions/forecloseLoan";
+import { cancelLoanOffer } from "./instructions/cancelLoanOffer";
+import { listFungible } from "./instructions/listFungible";
+import { buyFungible } from "./instructions/buyFungible";
+import { delistFungible } from "./instructions/delistFungible";
+import { initMarket } from "./instructions/initMarket";
+import { initOpenOrders } from "./instructions/initOpenOrders";
+import { placeOrder } from "./instructions/placeOrder";
+import { cancelOrder } from "./instructions/cancelOrder";
+import { matchOrders } from "./instructions/matchOrders";
+import { settleFunds } from "./instructions/settleFunds";
+import { initBondingCurve } from "./instructions/initBondingCurve";
+import { buyBondingCurve } from "./instructions/buyBondingCurve";
+import { burnBondingCurve } from "./instructions/burnBondingCurve";
+import { withdrawBondingCurveReserve } from "./instructions/withdrawBondingCurveReserve";
+import { initRecipe } from "./instructions/initRecipe";
+import { craft } from "./instructions/craft";
+import { initMysteryBox } from "./instructions/initMysteryBox";
+import { mintMysteryBox } from "./instructions/mintMysteryBox";
+import { openMysteryBox } from "./instructions/openMysteryBox";
+import { revealMysteryBox } from "./instructions/revealMysteryBox";
+import { createRaffle } from "./instructions/createRaffle";
+import { createVirtualRaffle } from "./instructions/createVirtualRaffle";
+import { buyRaffleTickets } from "./instructions/buyRaffleTickets";
+import { closeRaffle } from "./instructions/closeRaffle";
+import { drawRaffle } from "./instructions/drawRaffle";
+import { claimRaffleNft } from "./instructions/claimRaffleNft";
+import { claimRaffleVirtual } from "./instructions/claimRaffleVirtual";
+import { closeRaffleTicket } from "./instructions/closeRaffleTicket";
+import { cancelRaffle } from "./instructions/cancelRaffle";
+import { expireRaffle } from "./instructions/expireRaffle";
+import { refundRaffleTickets } from "./instructions/refundRaffleTickets";
+import { createSession } from "./instructions/createSession";
+import { revokeSession } from "./instructions/revokeSession";
+import { listVirtualWithAuthorization } from "./instructions/listVirtualWithAuthorization";
+import { mintVirtualWithAuthorization } from "./instructions/mintVirtualWithAuthorization";
+import { closeAuthorizationNonce } from "./instructions/closeAuthorizationNonce";
+import { approveCollectionMinting } from "./instructions/approveCollectionMinting";
+import { redeemMintVoucher } from "./instructions/redeemMintVoucher";
+import { setPrimarySale } from "./instructions/setPrimarySale";
 
 export class Instructions {
 	constructor(public carbon: Carbon) {}
@@ -37,7 +90,60 @@ export class Instructions {
 	public uncustody = uncustody;
 	public takeOwnership = takeOwnership;
 	public closeMintRecord = closeMintRecord;
+	public migrateMintRecord = migrateMintRecord;
 	public burnAndCloseMintRecord = burnAndCloseMintRecord;
+	public initPriceFeed = initPriceFeed;
+	public updatePriceFeed = updatePriceFeed;
+	public closeExpiredListing = closeExpiredListing;
+	public createSwap = createSwap;
+	public takeSwap = takeSwap;
+	public cancelSwap = cancelSwap;
+	public listRental = listRental;
+	public rentNft = rentNft;
+	public endRental = endRental;
+	public offerLoan = offerLoan;
+	public takeLoan = takeLoan;
+	public repayLoan = repayLoan;
+	public forecloseLoan = forecloseLoan;
+	public cancelLoanOffer = cancelLoanOffer;
+	public listFungible = listFungible;
+	public buyFungible = buyFungible;
+	public delistFungible = delistFungible;
+	public initMarket = initMarket;
+	public initOpenOrders = initOpenOrders;
+	public placeOrder = placeOrder;
+	public cancelOrder = cancelOrder;
+	public matchOrders = matchOrders;
+	public settleFunds = settleFunds;
+	public initBondingCurve = initBondingCurve;
+	public buyBondingCurve = buyBondingCurve;
+	public burnBondingCurve = burnBondingCurve;
+	public withdrawBondingCurveReserve = withdrawBondingCurveReserve;
+	public initRecipe = initRecipe;
+	public craft = craft;
+	public initMysteryBox = initMysteryBox;
+	public mintMysteryBox = mintMysteryBox;
+	public openMysteryBox = openMysteryBox;
+	public revealMysteryBox = revealMysteryBox;
+	public createRaffle = createRaffle;
+	public createVirtualRaffle = createVirtualRaffle;
+	public buyRaffleTickets = buyRaffleTickets;
+	public closeRaffle = closeRaffle;
+	public drawRaffle = drawRaffle;
+	public claimRaffleNft = claimRaffleNft;
+	public claimRaffleVirtual = claimRaffleVirtual;
+	public closeRaffleTicket = closeRaffleTicket;
+	public cancelRaffle = cancelRaffle;
+	public expireRaffle = expireRaffle;
+	public refundRaffleTickets = refundRaffleTickets;
+	public createSession = createSession;
+	public revokeSession = revokeSession;
+	public listVirtualWithAuthorization = listVirtualWithAuthorization;
+	public mintVirtualWithAuthorization = mintVirtualWithAuthorization;
+	public closeAuthorizationNonce = closeAuthorizationNonce;
+	public approveCollectionMinting = approveCollectionMinting;
+	public redeemMintVoucher = redeemMintVoucher;
+	public setPrimarySale = setPrimarySale;
 }
 
 export default Instructions;
@@ -0,0 +1,28 @@
+import { createApproveCollectionAuthorityInstruction } from "@metaplex-foundation/mpl-token-metadata";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCollectionAuthorityRecordPDA, getMetadataPDA } from "../solana";
+
+export type ApproveCollectionMintingArgs = {
+	collectionMint: PublicKey;
+	// Wallet paying the rent of the collection authority record, the marketplace authority by default
+	payer?: PublicKey;
+};
+
+// Approves the collection config as collection authority, which mintVirtualWithAuthorization
+// verifies new NFTs into the collection with
+export async function approveCollectionMinting(
+	args: ApproveCollectionMintingArgs
+): Promise<TransactionInstruction> {
+	const { collectionMint } = args;
+	const marketplaceAuthority = this.carbon.marketplaceAuthority;
+	const collectionConfig = this.carbon.pdas.collectionConfig(collectionMint);
+
+	return createApproveCollectionAuthorityInstruction({
+		collectionAuthorityRecord: getCollectionAuthorityRecordPDA(collectionMint, collectionConfig),
+		newCollectionAuthority: collectionConfig,
+		updateAuthority: marketplaceAuthority,
+		payer: args.payer ?? marketplaceAuthority,
+		metadata: getMetadataPDA(collectionMint),
+		mint: collectionMint,
+	});
+}
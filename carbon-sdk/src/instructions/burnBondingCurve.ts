@@ -0,0 +1,65 @@
+import { BN } from "@coral-xyz/anchor";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { BondingCurve } from "../types";
+
+export type BurnBondingCurveArgs = {
+	holder: PublicKey;
+	collectionMint: PublicKey;
+	bondingCurve: BondingCurve;
+	mint: PublicKey;
+	// Serial of the unit the NFT was minted as
+	serial: number;
+	// Wallet paying the rent of new accounts, the holder by default
+	payer?: PublicKey;
+};
+
+export async function burnBondingCurve(args: BurnBondingCurveArgs): Promise<TransactionInstruction> {
+	const { holder, collectionMint, bondingCurve, mint, serial } = args;
+	const collectionConfig = this.carbon.pdas.collectionConfig(collectionMint);
+	const bondingCurvePDA = this.carbon.pdas.bondingCurve(collectionConfig, bondingCurve.itemType);
+	const itemId = this.carbon.pdas.bondingCurveItemId(bondingCurve.itemType, serial);
+	const mintRecordPDA = this.carbon.pdas.mintRecord(collectionConfig, itemId);
+	const mintRecord = await this.carbon.program.account.mintRecord.fetch(mintRecordPDA);
+
+	const builder = this.carbon.program.methods
+		.burnBondingCurve(itemId, new BN(serial))
+		.accounts({
+			holder,
+			payer: args.payer ?? null,
+			rentPayer: mintRecord.payer,
+			mint,
+			holderTokenAccount: getAssociatedTokenAddressSync(mint, holder),
+			metadataAccount: getMetadataPDA(mint),
+			edition: getEditionPDA(mint),
+			collectionMetadataAccount: getMetadataPDA(collectionMint),
+			collectionConfig,
+			bondingCurve: bondingCurvePDA,
+			mintRecord: mintRecordPDA,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		});
+
+	if (!bondingCurve.currencyMint.equals(NATIVE_MINT)) {
+		builder.remainingAccounts([
+			{ pubkey: bondingCurve.currencyMint, isWritable: false, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(bondingCurve.currencyMint, bondingCurvePDA, true),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(bondingCurve.currencyMint, holder),
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	}
+
+	return await builder.instruction();
+}
@@ -0,0 +1,78 @@
+import { BN } from "@coral-xyz/anchor";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, Keypair, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { BondingCurve, CollectionConfig } from "../types";
+
+export type BuyBondingCurveArgs = {
+	buyer: PublicKey;
+	collectionConfig: CollectionConfig;
+	bondingCurve: BondingCurve;
+	// Defaults to the curve's current price
+	maxPrice?: number;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
+};
+
+export async function buyBondingCurve(
+	args: BuyBondingCurveArgs
+): Promise<{ mint: Keypair; instruction: TransactionInstruction }> {
+	const { buyer, collectionConfig, bondingCurve } = args;
+	const collectionConfigPDA = this.carbon.pdas.collectionConfig(collectionConfig.collectionMint);
+	const bondingCurvePDA = this.carbon.pdas.bondingCurve(collectionConfigPDA, bondingCurve.itemType);
+	const itemId = this.carbon.pdas.bondingCurveItemId(
+		bondingCurve.itemType,
+		bondingCurve.minted.toNumber()
+	);
+	const maxPrice =
+		args.maxPrice != null
+			? new BN(args.maxPrice)
+			: bondingCurve.basePrice.add(bondingCurve.slope.mul(bondingCurve.supply));
+
+	const mint = Keypair.generate();
+
+	const builder = this.carbon.program.methods
+		.buyBondingCurve(itemId, maxPrice)
+		.accounts({
+			buyer,
+			payer: args.payer ?? null,
+			marketplaceAuthority: bondingCurve.marketplaceAuthority,
+			mint: mint.publicKey,
+			buyerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, buyer),
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			collectionConfig: collectionConfigPDA,
+			bondingCurve: bondingCurvePDA,
+			mintRecord: this.carbon.pdas.mintRecord(collectionConfigPDA, itemId),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		});
+
+	if (!bondingCurve.currencyMint.equals(NATIVE_MINT)) {
+		builder.remainingAccounts([
+			{ pubkey: bondingCurve.currencyMint, isWritable: false, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(bondingCurve.currencyMint, buyer),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(bondingCurve.currencyMint, bondingCurvePDA, true),
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	}
+
+	return {
+		mint,
+		instruction: await builder.instruction(),
+	};
+}
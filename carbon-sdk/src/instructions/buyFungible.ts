@@ -0,0 +1,64 @@
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, NATIVE_MINT } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCurrencyAccounts } from "../solana";
+import { FungibleListing } from "../types";
+
+export type BuyFungibleArgs = {
+	buyer: PublicKey;
+	listing: FungibleListing;
+	// Amount to buy, in base units of the token
+	amount: number;
+	maxPrice?: number;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
+};
+
+// Price of an amount of a fungible listing, rounded up like the program does
+export function getFungiblePrice(listing: FungibleListing, amount: number): BN {
+	const unit = new BN(10).pow(new BN(listing.decimals));
+	return new BN(amount).mul(listing.price).add(unit.subn(1)).div(unit);
+}
+
+export async function buyFungible(args: BuyFungibleArgs): Promise<TransactionInstruction> {
+	const { buyer, listing, amount } = args;
+	const listingAddress = this.carbon.pdas.fungibleListing(
+		listing.seller,
+		listing.mint,
+		listing.marketplaceAuthority
+	);
+
+	const builder = this.carbon.program.methods
+		.buyFungible(
+			new BN(amount),
+			args.maxPrice != null ? new BN(args.maxPrice) : getFungiblePrice(listing, amount)
+		)
+		.accounts({
+			buyer,
+			payer: args.payer ?? null,
+			seller: listing.seller,
+			rentPayer: listing.payer,
+			mint: listing.mint,
+			listing: listingAddress,
+			escrowTokenAccount: getAssociatedTokenAddressSync(listing.mint, listingAddress, true),
+			buyerTokenAccount: getAssociatedTokenAddressSync(listing.mint, buyer),
+			feeAccount: listing.feeConfig.feeAccount,
+		});
+
+	if (!listing.currencyMint.equals(NATIVE_MINT)) {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			listing.currencyMint
+		);
+		builder.remainingAccounts([
+			...currencyAccounts,
+			...[buyer, listing.seller, listing.feeConfig.feeAccount].map((owner) => ({
+				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, owner, true, tokenProgram),
+				isWritable: true,
+				isSigner: false,
+			})),
+		]);
+	}
+
+	return await builder.instruction();
+}
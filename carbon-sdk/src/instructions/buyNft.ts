@@ -5,7 +5,15 @@ import {
 	NATIVE_MINT,
 } from "@solana/spl-token";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	getCurrencyAccounts,
+	getMetadataPDA,
+	getEditionPDA,
+	getProgrammableAccounts,
+	getRentPayer,
+	getTokenRecordPDA,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 import { Listing } from "../types";
 
 export type BuyNftArgs = {
@@ -13,33 +21,69 @@ export type BuyNftArgs = {
 	tokenOwner?: PublicKey;
 	listing: Listing;
 	maxPrice?: number;
+	// Set for programmable NFTs, with the rule set of the NFT if it has one
+	programmable?: { ruleSet?: PublicKey };
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
 };
 
 export async function buyNft(args: BuyNftArgs): Promise<TransactionInstruction> {
-	const { buyer, listing, maxPrice } = args;
+	const { buyer, listing, maxPrice, programmable } = args;
 	const tokenOwner = args.tokenOwner ?? listing.seller;
 
+	if (maxPrice == null && !listing.priceFeed.equals(PublicKey.default)) {
+		throw new Error(`maxPrice is required for listings priced through a price feed`);
+	}
+
 	const mint: PublicKey = new PublicKey(listing.itemId);
+	const sellerTokenAccount = getAssociatedTokenAddressSync(mint, tokenOwner);
+	const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer);
+	const listingAddress = this.carbon.pdas.listingAddress(listing);
+	const custodyAccount = await this.carbon.accounts.custodyAccount(mint);
+
+	let nftAccounts = [];
+	if (listing.isEscrowed) {
+		nftAccounts = [
+			{
+				pubkey: getAssociatedTokenAddressSync(mint, listingAddress, true),
+				isWritable: true,
+				isSigner: false,
+			},
+		];
+	} else if (programmable) {
+		nftAccounts = [
+			...getProgrammableAccounts(mint, sellerTokenAccount, programmable.ruleSet),
+			{ pubkey: getTokenRecordPDA(mint, buyerTokenAccount), isWritable: true, isSigner: false },
+		];
+	}
 
 	const builder = this.carbon.program.methods
 		.buyNft(maxPrice ? new BN(maxPrice) : listing.price)
 		.accounts({
 			buyer,
+			payer: args.payer ?? null,
 			seller: listing.seller,
 			mint,
-			sellerTokenAccount: getAssociatedTokenAddressSync(mint, tokenOwner),
-			buyerTokenAccount: getAssociatedTokenAddressSync(mint, buyer),
+			sellerTokenAccount,
+			buyerTokenAccount,
 			metadataAccount: getMetadataPDA(mint),
 			edition: getEditionPDA(mint),
-			listing: this.carbon.pdas.listing(listing.itemId),
+			rentPayer: getRentPayer(listing, listing.seller),
+			listing: listingAddress,
 			custodyAccount: this.carbon.pdas.custodyAccount(mint),
+			// Any account will do when the NFT is not custodied
+			custodyRentPayer: custodyAccount
+				? getRentPayer(custodyAccount, custodyAccount.owner)
+				: listing.seller,
 			feeAccount: listing.feeConfig.feeAccount,
+			priceFeed: listing.priceFeed,
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 		});
 
 	if (listing.currencyMint.equals(NATIVE_MINT)) {
 		builder.remainingAccounts([
+			...nftAccounts,
 			{
 				pubkey: this.carbon.marketplaceAuthority,
 				isWritable: true,
@@ -47,14 +91,20 @@ export async function buyNft(args: BuyNftArgs): Promise<TransactionInstruction>
 			},
 		]);
 	} else {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			listing.currencyMint
+		);
 		builder.remainingAccounts([
+			...nftAccounts,
+			...currencyAccounts,
 			{
-				pubkey: listing.currencyMint,
-				isWritable: false,
-				isSigner: false,
-			},
-			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, buyer),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					buyer,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
@@ -66,18 +116,30 @@ export async function buyNft(args: BuyNftArgs): Promise<TransactionInstruction>
 			{
 				pubkey: getAssociatedTokenAddressSync(
 					listing.currencyMint,
-					this.carbon.marketplaceAuthority
+					this.carbon.marketplaceAuthority,
+					false,
+					tokenProgram
 				),
 				isWritable: true,
 				isSigner: false,
 			},
 			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, listing.feeConfig.feeAccount),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					listing.feeConfig.feeAccount,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
 			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, listing.seller),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					listing.seller,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
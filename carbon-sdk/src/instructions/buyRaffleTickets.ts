@@ -0,0 +1,46 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCurrencyAccounts } from "../solana";
+import { Raffle } from "../types";
+
+export type BuyRaffleTicketsArgs = {
+	buyer: PublicKey;
+	raffle: Raffle;
+	count: number;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
+};
+
+export async function buyRaffleTickets(args: BuyRaffleTicketsArgs): Promise<TransactionInstruction> {
+	const { buyer, raffle, count } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, raffle.marketplaceAuthority);
+
+	const builder = this.carbon.program.methods.buyRaffleTickets(count).accounts({
+		buyer,
+		payer: args.payer ?? null,
+		raffle: raffleAddress,
+		ticket: this.carbon.pdas.raffleTicket(raffleAddress, raffle.ticketsSold),
+		associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+	});
+
+	if (!raffle.currencyMint.equals(NATIVE_MINT)) {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			raffle.currencyMint
+		);
+		builder.remainingAccounts([
+			...currencyAccounts,
+			...[buyer, raffleAddress].map((owner) => ({
+				pubkey: getAssociatedTokenAddressSync(raffle.currencyMint, owner, true, tokenProgram),
+				isWritable: true,
+				isSigner: false,
+			})),
+		]);
+	}
+
+	return await builder.instruction();
+}
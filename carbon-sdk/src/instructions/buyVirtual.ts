@@ -5,7 +5,13 @@ import {
 	NATIVE_MINT,
 } from "@solana/spl-token";
 import { PublicKey, Keypair, TransactionInstruction } from "@solana/web3.js";
-import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	getCurrencyAccounts,
+	getMetadataPDA,
+	getEditionPDA,
+	getRentPayer,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 import { CollectionConfig, Listing, Metadata } from "../types";
 
 export type BuyVirtualArgs = {
@@ -15,6 +21,8 @@ export type BuyVirtualArgs = {
 	listing: Listing;
 	metadata: Metadata;
 	maxPrice?: number;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
 };
 
 export async function buyVirtual(
@@ -23,12 +31,17 @@ export async function buyVirtual(
 	const { buyer, listing, metadata, collectionConfig, maxPrice } = args;
 	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
 
+	if (maxPrice == null && !listing.priceFeed.equals(PublicKey.default)) {
+		throw new Error(`maxPrice is required for listings priced through a price feed`);
+	}
+
 	const mint = Keypair.generate();
 
 	const builder = this.carbon.program.methods
 		.buyVirtual(listing.itemId, maxPrice ? new BN(maxPrice) : listing.price, metadata)
 		.accounts({
 			buyer,
+			payer: args.payer ?? null,
 			seller: listing.seller,
 			mint: mint.publicKey,
 			collectionConfig: this.carbon.pdas.collectionConfig(collectionConfig.collectionMint),
@@ -42,8 +55,10 @@ export async function buyVirtual(
 			collectionMint: collectionConfig.collectionMint,
 			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
 			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
-			listing: this.carbon.pdas.listing(listing.itemId),
+			rentPayer: getRentPayer(listing, listing.seller),
+			listing: this.carbon.pdas.listingAddress(listing),
 			feeAccount: listing.feeConfig.feeAccount,
+			priceFeed: listing.priceFeed,
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 		});
@@ -57,14 +72,19 @@ export async function buyVirtual(
 			},
 		]);
 	} else {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			listing.currencyMint
+		);
 		builder.remainingAccounts([
+			...currencyAccounts,
 			{
-				pubkey: listing.currencyMint,
-				isWritable: false,
-				isSigner: false,
-			},
-			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, buyer),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					buyer,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
@@ -74,17 +94,32 @@ export async function buyVirtual(
 				isSigner: true,
 			},
 			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, marketplaceAuthority),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					marketplaceAuthority,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
 			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, listing.feeConfig.feeAccount),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					listing.feeConfig.feeAccount,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
 			{
-				pubkey: getAssociatedTokenAddressSync(listing.currencyMint, listing.seller),
+				pubkey: getAssociatedTokenAddressSync(
+					listing.currencyMint,
+					listing.seller,
+					false,
+					tokenProgram
+				),
 				isWritable: true,
 				isSigner: false,
 			},
@@ -0,0 +1,44 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, TransactionInstruction } from "@solana/web3.js";
+import { Loan } from "../types";
+
+export type CancelLoanOfferArgs = {
+	loan: Loan;
+};
+
+export async function cancelLoanOffer(args: CancelLoanOfferArgs): Promise<TransactionInstruction> {
+	const { loan } = args;
+	const loanAddress = this.carbon.pdas.loan(loan.lender, loan.loanId);
+
+	const remainingAccounts: AccountMeta[] = [];
+	if (!loan.currencyMint.equals(NATIVE_MINT)) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(loan.currencyMint, loan.lender),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(loan.currencyMint, loanAddress, true),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.cancelLoanOffer()
+		.accounts({
+			lender: loan.lender,
+			rentPayer: loan.payer,
+			loan: loanAddress,
+			currencyMint: loan.currencyMint,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
@@ -0,0 +1,30 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Market, Side } from "../types";
+
+export type CancelOrderArgs = {
+	owner: PublicKey;
+	market: Market;
+	side: Side;
+	orderId: BN;
+};
+
+export async function cancelOrder(args: CancelOrderArgs): Promise<TransactionInstruction> {
+	const { owner, market, side, orderId } = args;
+	const marketAddress = this.carbon.pdas.market(
+		market.baseMint,
+		market.quoteMint,
+		market.marketplaceAuthority
+	);
+
+	return await this.carbon.program.methods
+		.cancelOrder(side, orderId)
+		.accounts({
+			owner,
+			market: marketAddress,
+			bids: market.bids,
+			asks: market.asks,
+			openOrders: this.carbon.pdas.openOrders(marketAddress, owner),
+		})
+		.instruction();
+}
@@ -0,0 +1,37 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Raffle } from "../types";
+
+export type CancelRaffleArgs = {
+	creator: PublicKey;
+	raffle: Raffle;
+};
+
+export async function cancelRaffle(args: CancelRaffleArgs): Promise<TransactionInstruction> {
+	const { creator, raffle } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, raffle.marketplaceAuthority);
+
+	const builder = this.carbon.program.methods.cancelRaffle().accounts({
+		creator,
+		rentPayer: raffle.payer,
+		raffle: raffleAddress,
+	});
+
+	if (!raffle.isVirtual) {
+		builder.remainingAccounts([
+			{ pubkey: raffle.mint, isWritable: false, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(raffle.mint, raffleAddress, true),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(raffle.mint, creator),
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	}
+
+	return await builder.instruction();
+}
@@ -0,0 +1,52 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { Swap } from "../types";
+
+export type CancelSwapArgs = {
+	swap: Swap;
+};
+
+export async function cancelSwap(args: CancelSwapArgs): Promise<TransactionInstruction> {
+	const { swap } = args;
+	const swapAddress = this.carbon.pdas.swap(swap.maker, swap.swapId);
+
+	const remainingAccounts: AccountMeta[] = swap.makerMints.flatMap((mint) => [
+		{ pubkey: mint, isWritable: false, isSigner: false },
+		{ pubkey: getAssociatedTokenAddressSync(mint, swap.maker), isWritable: true, isSigner: false },
+		{ pubkey: getEditionPDA(mint), isWritable: false, isSigner: false },
+		{ pubkey: this.carbon.pdas.custodyAccount(mint), isWritable: true, isSigner: false },
+	]);
+
+	if (!swap.currencyMint.equals(NATIVE_MINT) && swap.makerAmount.gtn(0)) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(swap.currencyMint, swap.maker),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(swap.currencyMint, swapAddress, true),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.cancelSwap()
+		.accounts({
+			maker: swap.maker,
+			rentPayer: swap.payer,
+			swap: swapAddress,
+			currencyMint: swap.currencyMint,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
@@ -0,0 +1,34 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Raffle, RaffleTicket } from "../types";
+
+export type ClaimRaffleNftArgs = {
+	winner: PublicKey;
+	raffle: Raffle;
+	// Ticket account holding the winning ticket
+	ticket: RaffleTicket;
+	// Wallet paying the rent of new accounts, the winner by default
+	payer?: PublicKey;
+};
+
+export async function claimRaffleNft(args: ClaimRaffleNftArgs): Promise<TransactionInstruction> {
+	const { winner, raffle, ticket } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, raffle.marketplaceAuthority);
+
+	return await this.carbon.program.methods
+		.claimRaffleNft()
+		.accounts({
+			winner,
+			payer: args.payer ?? null,
+			creator: raffle.creator,
+			rentPayer: raffle.payer,
+			ticketRentPayer: ticket.payer,
+			mint: raffle.mint,
+			raffle: raffleAddress,
+			ticket: this.carbon.pdas.raffleTicket(raffleAddress, ticket.firstTicket),
+			escrowTokenAccount: getAssociatedTokenAddressSync(raffle.mint, raffleAddress, true),
+			winnerTokenAccount: getAssociatedTokenAddressSync(raffle.mint, winner),
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.instruction();
+}
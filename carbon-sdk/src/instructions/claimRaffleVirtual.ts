@@ -0,0 +1,45 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { CollectionConfig, Raffle, RaffleTicket } from "../types";
+
+export type ClaimRaffleVirtualArgs = {
+	winner: PublicKey;
+	collectionConfig: CollectionConfig;
+	raffle: Raffle;
+	// Ticket account holding the winning ticket
+	ticket: RaffleTicket;
+};
+
+export async function claimRaffleVirtual(
+	args: ClaimRaffleVirtualArgs
+): Promise<{ mint: Keypair; instruction: TransactionInstruction }> {
+	const { winner, collectionConfig, raffle, ticket } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, raffle.marketplaceAuthority);
+
+	const mint = Keypair.generate();
+
+	const instruction = await this.carbon.program.methods
+		.claimRaffleVirtual()
+		.accounts({
+			winner,
+			ticketRentPayer: ticket.payer,
+			marketplaceAuthority: raffle.marketplaceAuthority,
+			mint: mint.publicKey,
+			winnerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, winner),
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			collectionConfig: raffle.collectionConfig,
+			raffle: raffleAddress,
+			ticket: this.carbon.pdas.raffleTicket(raffleAddress, ticket.firstTicket),
+			mintRecord: this.carbon.pdas.mintRecord(raffle.collectionConfig, raffle.itemId),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.instruction();
+
+	return { mint, instruction };
+}
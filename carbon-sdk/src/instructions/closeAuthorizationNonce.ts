@@ -0,0 +1,23 @@
+import { TransactionInstruction } from "@solana/web3.js";
+import { AuthorizationNonce } from "../types";
+
+export type CloseAuthorizationNonceArgs = {
+	authorizationNonce: AuthorizationNonce;
+};
+
+export async function closeAuthorizationNonce(
+	args: CloseAuthorizationNonceArgs
+): Promise<TransactionInstruction> {
+	const { authorizationNonce } = args;
+
+	return await this.carbon.program.methods
+		.closeAuthorizationNonce()
+		.accounts({
+			rentPayer: authorizationNonce.payer,
+			authorizationNonce: this.carbon.pdas.authorizationNonce(
+				authorizationNonce.nonce.toNumber(),
+				authorizationNonce.marketplaceAuthority
+			),
+		})
+		.instruction();
+}
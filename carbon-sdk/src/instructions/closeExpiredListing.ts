@@ -0,0 +1,83 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+	getEditionPDA,
+	getMetadataPDA,
+	getProgrammableAccounts,
+	getRentPayer,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
+import { Listing } from "../types";
+
+export type CloseExpiredListingArgs = {
+	keeper: PublicKey;
+	listing: Listing;
+	tokenOwner?: PublicKey;
+	// Set for programmable NFTs, with the rule set of the NFT if it has one. The sale delegation
+	// is only revoked when the seller signs.
+	programmable?: { ruleSet?: PublicKey };
+};
+
+export async function closeExpiredListing(
+	args: CloseExpiredListingArgs
+): Promise<TransactionInstruction> {
+	const { keeper, listing, programmable } = args;
+	const tokenOwner = args.tokenOwner ?? listing.seller;
+
+	const builder = this.carbon.program.methods.closeExpiredListing().accounts({
+		keeper,
+		seller: listing.seller,
+		rentPayer: getRentPayer(listing, listing.seller),
+		listing: this.carbon.pdas.listingAddress(listing),
+		marketplaceConfig: this.carbon.pdas.marketplaceConfig(listing.marketplaceAuthority),
+		tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+	});
+
+	if (!listing.isVirtual) {
+		const mint = new PublicKey(listing.itemId);
+		const tokenAccount = getAssociatedTokenAddressSync(mint, tokenOwner);
+		const escrowAccounts = listing.isEscrowed
+			? [
+					{
+						pubkey: getAssociatedTokenAddressSync(
+							mint,
+							this.carbon.pdas.listingAddress(listing),
+							true
+						),
+						isWritable: true,
+						isSigner: false,
+					},
+			  ]
+			: programmable
+			? [
+					{ pubkey: getMetadataPDA(mint), isWritable: true, isSigner: false },
+					...getProgrammableAccounts(mint, tokenAccount, programmable.ruleSet),
+			  ]
+			: [];
+		builder.remainingAccounts([
+			{
+				pubkey: mint,
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: tokenAccount,
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getEditionPDA(mint),
+				isWritable: false,
+				isSigner: false,
+			},
+			{
+				pubkey: this.carbon.pdas.custodyAccount(mint),
+				isWritable: true,
+				isSigner: false,
+			},
+			...escrowAccounts,
+		]);
+	}
+
+	return await builder.instruction();
+}
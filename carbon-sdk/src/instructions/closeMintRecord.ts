@@ -15,6 +15,7 @@ export async function closeMintRecord(args: CloseMintRecordArgs): Promise<Transa
 		.closeMintRecord()
 		.accounts({
 			marketplaceAuthority,
+			rentPayer: mintRecord.payer,
 			mint: mintRecord.mint,
 			edition: getEditionPDA(mintRecord.mint),
 			collectionConfig: mintRecord.collectionConfig,
@@ -0,0 +1,21 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Raffle } from "../types";
+
+export type CloseRaffleArgs = {
+	marketplaceAuthority: PublicKey;
+	raffle: Raffle;
+	// Hash of the secret seed revealed when drawing
+	seedHash: number[];
+};
+
+export async function closeRaffle(args: CloseRaffleArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, raffle, seedHash } = args;
+
+	return await this.carbon.program.methods
+		.closeRaffle(seedHash)
+		.accounts({
+			marketplaceAuthority,
+			raffle: this.carbon.pdas.raffle(raffle.raffleId, marketplaceAuthority),
+		})
+		.instruction();
+}
@@ -0,0 +1,21 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { RaffleTicket } from "../types";
+
+export type CloseRaffleTicketArgs = {
+	buyer: PublicKey;
+	ticket: RaffleTicket;
+};
+
+export async function closeRaffleTicket(args: CloseRaffleTicketArgs): Promise<TransactionInstruction> {
+	const { buyer, ticket } = args;
+
+	return await this.carbon.program.methods
+		.closeRaffleTicket()
+		.accounts({
+			buyer,
+			rentPayer: ticket.payer,
+			raffle: ticket.raffle,
+			ticket: this.carbon.pdas.raffleTicket(ticket.raffle, ticket.firstTicket),
+		})
+		.instruction();
+}
@@ -0,0 +1,66 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { PublicKey, Keypair, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { CollectionConfig, MintRecord, Recipe } from "../types";
+
+export type CraftArgs = {
+	player: PublicKey;
+	collectionConfig: CollectionConfig;
+	recipe: Recipe;
+	// Mint records of the NFTs to burn
+	inputs: MintRecord[];
+	// Item ID of the crafted item
+	itemId: number[];
+	// Wallet paying the rent of new accounts, the player by default
+	payer?: PublicKey;
+};
+
+export async function craft(
+	args: CraftArgs
+): Promise<{ mint: Keypair; instruction: TransactionInstruction }> {
+	const { player, collectionConfig, recipe, inputs, itemId } = args;
+	const collectionConfigPDA = this.carbon.pdas.collectionConfig(collectionConfig.collectionMint);
+
+	const mint = Keypair.generate();
+
+	const instruction = await this.carbon.program.methods
+		.craft(itemId)
+		.accounts({
+			player,
+			payer: args.payer ?? null,
+			marketplaceAuthority: collectionConfig.marketplaceAuthority,
+			mint: mint.publicKey,
+			playerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, player),
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			collectionConfig: collectionConfigPDA,
+			recipe: this.carbon.pdas.recipe(collectionConfigPDA, recipe.recipeId),
+			mintRecord: this.carbon.pdas.mintRecord(collectionConfigPDA, itemId),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(
+			inputs.flatMap((input) => [
+				{ pubkey: input.mint, isWritable: true, isSigner: false },
+				{
+					pubkey: getAssociatedTokenAddressSync(input.mint, player),
+					isWritable: true,
+					isSigner: false,
+				},
+				{ pubkey: getMetadataPDA(input.mint), isWritable: true, isSigner: false },
+				{ pubkey: getEditionPDA(input.mint), isWritable: true, isSigner: false },
+				{
+					pubkey: this.carbon.pdas.mintRecord(collectionConfigPDA, input.itemId),
+					isWritable: true,
+					isSigner: false,
+				},
+				{ pubkey: input.payer, isWritable: true, isSigner: false },
+			])
+		)
+		.instruction();
+
+	return { mint, instruction };
+}
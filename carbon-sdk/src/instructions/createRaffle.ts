@@ -0,0 +1,45 @@
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, NATIVE_MINT } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { RandomnessSource } from "../types";
+
+export type CreateRaffleArgs = {
+	creator: PublicKey;
+	// Mint of the NFT to raffle
+	mint: PublicKey;
+	raffleId: number[];
+	ticketPrice: number;
+	maxTickets: number;
+	// Unix timestamp of when ticket sales end
+	endTime: number;
+	currencyMint?: PublicKey;
+	randomnessSource?: RandomnessSource;
+	// Wallet paying the rent of new accounts, the creator by default
+	payer?: PublicKey;
+};
+
+export async function createRaffle(args: CreateRaffleArgs): Promise<TransactionInstruction> {
+	const { creator, mint, raffleId } = args;
+	const marketplaceConfig = this.carbon.pdas.marketplaceConfig(this.carbon.marketplaceAuthority);
+	const raffle = this.carbon.pdas.raffle(raffleId);
+
+	return await this.carbon.program.methods
+		.createRaffle({
+			raffleId,
+			ticketPrice: new BN(args.ticketPrice),
+			maxTickets: args.maxTickets,
+			endTime: new BN(args.endTime),
+			randomnessSource: args.randomnessSource ?? { slotHashes: {} },
+		})
+		.accounts({
+			creator,
+			payer: args.payer ?? null,
+			tokenAccount: getAssociatedTokenAddressSync(mint, creator),
+			mint,
+			currencyMint: args.currencyMint ?? NATIVE_MINT,
+			raffle,
+			escrowTokenAccount: getAssociatedTokenAddressSync(mint, raffle, true),
+			marketplaceConfig,
+		})
+		.instruction();
+}
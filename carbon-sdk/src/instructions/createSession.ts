@@ -0,0 +1,43 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { SessionScope } from "../types";
+
+export type CreateSessionArgs = {
+	owner: PublicKey;
+	// Ephemeral key allowed to sign listing and custody actions for the owner
+	sessionKey: PublicKey;
+	// Unix timestamp after which the session key can no longer sign
+	expiry: number;
+	scope: SessionScope;
+	// Marketplace the session key can act on, the default marketplace authority by default
+	marketplaceAuthority?: PublicKey;
+	// Collections the session key can act on, any collection by default
+	collectionMints?: PublicKey[];
+	// Highest price the session key can list for, no limit by default
+	maxPrice?: number;
+	// Lowest price the session key can list for, no floor by default
+	minPrice?: number;
+	// Wallet paying the rent of new accounts, the owner by default
+	payer?: PublicKey;
+};
+
+export async function createSession(args: CreateSessionArgs): Promise<TransactionInstruction> {
+	const { owner, sessionKey, expiry, scope } = args;
+
+	return await this.carbon.program.methods
+		.createSession({
+			marketplaceAuthority: args.marketplaceAuthority ?? this.carbon.marketplaceAuthority,
+			expiry: new BN(expiry),
+			scope,
+			collectionMints: args.collectionMints ?? [],
+			maxPrice: new BN(args.maxPrice ?? 0),
+			minPrice: new BN(args.minPrice ?? 0),
+		})
+		.accounts({
+			owner,
+			payer: args.payer ?? null,
+			sessionKey,
+			session: this.carbon.pdas.session(owner, sessionKey),
+		})
+		.instruction();
+}
@@ -0,0 +1,76 @@
+import { BN } from "@coral-xyz/anchor";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, getMetadataPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+
+export type CreateSwapArgs = {
+	maker: PublicKey;
+	swapId: number[];
+	collectionMint: PublicKey;
+	taker?: PublicKey;
+	makerMints?: PublicKey[];
+	makerAmount?: number;
+	takerMints?: PublicKey[];
+	takerAmount?: number;
+	currencyMint?: PublicKey;
+	expiry?: number;
+	// Wallet paying the rent of new accounts, the maker by default
+	payer?: PublicKey;
+};
+
+export async function createSwap(args: CreateSwapArgs): Promise<TransactionInstruction> {
+	const { maker, swapId, collectionMint } = args;
+	const makerMints = args.makerMints ?? [];
+	const makerAmount = args.makerAmount ?? 0;
+	const currencyMint = args.currencyMint ?? NATIVE_MINT;
+	const swap = this.carbon.pdas.swap(maker, swapId);
+
+	const remainingAccounts = makerMints.flatMap((mint) => [
+		{ pubkey: mint, isWritable: false, isSigner: false },
+		{ pubkey: getAssociatedTokenAddressSync(mint, maker), isWritable: true, isSigner: false },
+		{ pubkey: getEditionPDA(mint), isWritable: false, isSigner: false },
+		{ pubkey: this.carbon.pdas.custodyAccount(mint), isWritable: true, isSigner: false },
+		{ pubkey: getMetadataPDA(mint), isWritable: false, isSigner: false },
+	]);
+
+	if (makerAmount > 0 && !currencyMint.equals(NATIVE_MINT)) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(currencyMint, maker),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(currencyMint, swap, true),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.createSwap({
+			swapId,
+			taker: args.taker ?? PublicKey.default,
+			makerMints,
+			makerAmount: new BN(makerAmount),
+			takerMints: args.takerMints ?? [],
+			takerAmount: new BN(args.takerAmount ?? 0),
+			expiry: new BN(args.expiry ?? 0),
+		})
+		.accounts({
+			maker,
+			payer: args.payer ?? null,
+			swap,
+			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
+			currencyMint,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
@@ -0,0 +1,46 @@
+import { BN } from "@coral-xyz/anchor";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Metadata, RandomnessSource } from "../types";
+
+export type CreateVirtualRaffleArgs = {
+	marketplaceAuthority: PublicKey;
+	collectionMint: PublicKey;
+	raffleId: number[];
+	// Item ID and metadata of the virtual item minted to the winner
+	itemId: number[];
+	metadata: Metadata;
+	ticketPrice: number;
+	maxTickets: number;
+	// Unix timestamp of when ticket sales end
+	endTime: number;
+	currencyMint?: PublicKey;
+	randomnessSource?: RandomnessSource;
+};
+
+export async function createVirtualRaffle(
+	args: CreateVirtualRaffleArgs
+): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, collectionMint, raffleId, itemId, metadata } = args;
+
+	return await this.carbon.program.methods
+		.createVirtualRaffle(
+			{
+				raffleId,
+				ticketPrice: new BN(args.ticketPrice),
+				maxTickets: args.maxTickets,
+				endTime: new BN(args.endTime),
+				randomnessSource: args.randomnessSource ?? { slotHashes: {} },
+			},
+			itemId,
+			metadata
+		)
+		.accounts({
+			marketplaceAuthority,
+			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
+			currencyMint: args.currencyMint ?? NATIVE_MINT,
+			raffle: this.carbon.pdas.raffle(raffleId, marketplaceAuthority),
+			marketplaceConfig: this.carbon.pdas.marketplaceConfig(marketplaceAuthority),
+		})
+		.instruction();
+}
@@ -1,31 +1,58 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	getEditionPDA,
+	getMetadataPDA,
+	getProgrammableAccounts,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 
 export type CustodyArgs = {
 	marketplaceAuthority?: PublicKey;
 	owner: PublicKey;
 	mint: PublicKey;
 	itemId: number[];
+	// Set for programmable NFTs, with the rule set of the NFT if it has one
+	programmable?: { ruleSet?: PublicKey };
+	// Session key signing for the owner, the owner by default. The owner must have approved the
+	// custody account as delegate of the token account beforehand.
+	authority?: PublicKey;
+	// Wallet paying the rent of new accounts, the authority by default
+	payer?: PublicKey;
 	accounts?: any;
 };
 
 export async function custody(args: CustodyArgs): Promise<TransactionInstruction> {
-	const { owner, mint, itemId, accounts } = args;
+	const { owner, mint, itemId, programmable, accounts } = args;
+	const tokenAccount = getAssociatedTokenAddressSync(mint, owner);
 	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
+	const authority = args.authority ?? owner;
 
 	return await this.carbon.program.methods
 		.custody(itemId)
 		.accounts({
 			owner,
+			authority,
+			session: authority.equals(owner)
+				? PublicKey.default
+				: this.carbon.pdas.session(owner, authority),
+			payer: args.payer ?? null,
 			marketplaceAuthority,
-			tokenAccount: getAssociatedTokenAddressSync(mint, owner),
+			tokenAccount,
 			mint,
 			edition: getEditionPDA(mint),
 			custodyAccount: this.carbon.pdas.custodyAccount(mint),
-			listing: this.carbon.pdas.listing(Array.from(mint.toBuffer())),
+			listing: this.carbon.pdas.listing(Array.from(mint.toBuffer()), marketplaceAuthority),
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 			...(accounts || {}),
 		})
+		.remainingAccounts(
+			programmable
+				? [
+						{ pubkey: getMetadataPDA(mint), isWritable: true, isSigner: false },
+						...getProgrammableAccounts(mint, tokenAccount, programmable.ruleSet),
+				  ]
+				: []
+		)
 		.instruction();
 }
@@ -0,0 +1,26 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type DelistFungibleArgs = {
+	seller: PublicKey;
+	mint: PublicKey;
+	// Wallet that paid the rent of the listing, the seller by default
+	rentPayer?: PublicKey;
+};
+
+export async function delistFungible(args: DelistFungibleArgs): Promise<TransactionInstruction> {
+	const { seller, mint } = args;
+	const listing = this.carbon.pdas.fungibleListing(seller, mint);
+
+	return await this.carbon.program.methods
+		.delistFungible()
+		.accounts({
+			seller,
+			rentPayer: args.rentPayer ?? seller,
+			tokenAccount: getAssociatedTokenAddressSync(mint, seller),
+			mint,
+			listing,
+			escrowTokenAccount: getAssociatedTokenAddressSync(mint, listing, true),
+		})
+		.instruction();
+}
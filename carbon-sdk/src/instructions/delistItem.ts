@@ -1,4 +1,5 @@
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getRentPayer } from "../solana";
 
 export type DelistItemArgs = {
 	seller?: PublicKey;
@@ -14,6 +15,8 @@ export async function delistItem(
 		return await this.delistVirtual({
 			seller,
 			itemId,
+			listing: listing && this.carbon.pdas.listingAddress(listing),
+			rentPayer: listing && getRentPayer(listing, listing.seller),
 		});
 	} else {
 		const custodyAccount = await this.carbon.accounts.custodyAccount(new PublicKey(itemId));
@@ -21,6 +24,9 @@ export async function delistItem(
 			seller: seller ?? this.carbon.marketplaceAuthority,
 			mint: new PublicKey(itemId),
 			tokenOwner: custodyAccount?.owner,
+			listing: listing && this.carbon.pdas.listingAddress(listing),
+			isEscrowed: listing?.isEscrowed,
+			rentPayer: listing && getRentPayer(listing, listing.seller),
 		});
 	}
 }
@@ -1,27 +1,66 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	getEditionPDA,
+	getMetadataPDA,
+	getProgrammableAccounts,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 
 export type DelistNftArgs = {
 	seller: PublicKey;
 	mint: PublicKey;
 	tokenOwner?: PublicKey;
+	listing?: PublicKey;
+	// Set when the listing escrows the NFT instead of freezing it
+	isEscrowed?: boolean;
+	// Set for programmable NFTs, with the rule set of the NFT if it has one
+	programmable?: { ruleSet?: PublicKey };
+	// Session key signing for the seller, the seller by default
+	authority?: PublicKey;
+	// Wallet that paid the rent of the listing, the seller by default
+	rentPayer?: PublicKey;
 };
 
 export async function delistNft(args: DelistNftArgs): Promise<TransactionInstruction> {
-	const { seller, mint } = args;
+	const { seller, mint, isEscrowed, programmable } = args;
 	const tokenOwner = args.tokenOwner ?? seller;
+	const authority = args.authority ?? seller;
+	const tokenAccount = getAssociatedTokenAddressSync(mint, tokenOwner);
+	const listing = args.listing ?? this.carbon.pdas.listing(Array.from(mint.toBuffer()));
+
+	let remainingAccounts = [];
+	if (isEscrowed) {
+		remainingAccounts = [
+			{
+				pubkey: getAssociatedTokenAddressSync(mint, listing, true),
+				isWritable: true,
+				isSigner: false,
+			},
+		];
+	} else if (programmable) {
+		remainingAccounts = [
+			{ pubkey: getMetadataPDA(mint), isWritable: true, isSigner: false },
+			...getProgrammableAccounts(mint, tokenAccount, programmable.ruleSet),
+		];
+	}
 
 	return await this.carbon.program.methods
 		.delistNft()
 		.accounts({
 			seller,
-			tokenAccount: getAssociatedTokenAddressSync(mint, tokenOwner),
+			authority,
+			session: authority.equals(seller)
+				? PublicKey.default
+				: this.carbon.pdas.session(seller, authority),
+			rentPayer: args.rentPayer ?? seller,
+			tokenAccount,
 			mint,
 			edition: getEditionPDA(mint),
-			listing: this.carbon.pdas.listing(Array.from(mint.toBuffer())),
+			listing,
 			custodyAccount: this.carbon.pdas.custodyAccount(mint),
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 		})
+		.remainingAccounts(remainingAccounts)
 		.instruction();
 }
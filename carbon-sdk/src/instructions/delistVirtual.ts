@@ -3,6 +3,9 @@ import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 export type DelistVirtualArgs = {
 	seller?: PublicKey;
 	itemId: number[];
+	listing?: PublicKey;
+	// Wallet that paid the rent of the listing, the seller by default
+	rentPayer?: PublicKey;
 };
 
 export async function delistVirtual(args: DelistVirtualArgs): Promise<TransactionInstruction> {
@@ -13,7 +16,8 @@ export async function delistVirtual(args: DelistVirtualArgs): Promise<Transactio
 		.delistVirtual(itemId)
 		.accounts({
 			seller,
-			listing: this.carbon.pdas.listing(itemId),
+			rentPayer: args.rentPayer ?? seller,
+			listing: args.listing ?? this.carbon.pdas.listing(itemId),
 		})
 		.instruction();
 }
@@ -0,0 +1,46 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, SYSVAR_SLOT_HASHES_PUBKEY, TransactionInstruction } from "@solana/web3.js";
+import { getCurrencyAccounts } from "../solana";
+import { Raffle } from "../types";
+
+export type DrawRaffleArgs = {
+	marketplaceAuthority: PublicKey;
+	raffle: Raffle;
+	// Secret seed committed when the raffle was closed
+	seed: number[];
+};
+
+export async function drawRaffle(args: DrawRaffleArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, raffle, seed } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, marketplaceAuthority);
+
+	const builder = this.carbon.program.methods.drawRaffle(seed).accounts({
+		marketplaceAuthority,
+		creator: raffle.creator,
+		raffle: raffleAddress,
+		feeAccount: raffle.feeConfig.feeAccount,
+		randomnessAccount: SYSVAR_SLOT_HASHES_PUBKEY,
+		associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+	});
+
+	if (!raffle.currencyMint.equals(NATIVE_MINT)) {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			raffle.currencyMint
+		);
+		builder.remainingAccounts([
+			...currencyAccounts,
+			...[raffleAddress, raffle.creator, raffle.feeConfig.feeAccount].map((owner) => ({
+				pubkey: getAssociatedTokenAddressSync(raffle.currencyMint, owner, true, tokenProgram),
+				isWritable: true,
+				isSigner: false,
+			})),
+		]);
+	}
+
+	return await builder.instruction();
+}
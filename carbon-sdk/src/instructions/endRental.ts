@@ -0,0 +1,27 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { Rental } from "../types";
+
+export type EndRentalArgs = {
+	authority: PublicKey;
+	rental: Rental;
+};
+
+export async function endRental(args: EndRentalArgs): Promise<TransactionInstruction> {
+	const { authority, rental } = args;
+
+	return await this.carbon.program.methods
+		.endRental()
+		.accounts({
+			authority,
+			owner: rental.owner,
+			rentPayer: rental.payer,
+			tokenAccount: getAssociatedTokenAddressSync(rental.mint, rental.owner),
+			mint: rental.mint,
+			edition: getEditionPDA(rental.mint),
+			rental: this.carbon.pdas.rental(rental.mint),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+		})
+		.instruction();
+}
@@ -0,0 +1,35 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TransactionInstruction } from "@solana/web3.js";
+import { Raffle } from "../types";
+
+export type ExpireRaffleArgs = {
+	raffle: Raffle;
+};
+
+export async function expireRaffle(args: ExpireRaffleArgs): Promise<TransactionInstruction> {
+	const { raffle } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, raffle.marketplaceAuthority);
+
+	const builder = this.carbon.program.methods.expireRaffle().accounts({
+		rentPayer: raffle.payer,
+		raffle: raffleAddress,
+	});
+
+	if (!raffle.isVirtual) {
+		builder.remainingAccounts([
+			{ pubkey: raffle.mint, isWritable: false, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(raffle.mint, raffleAddress, true),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(raffle.mint, raffle.creator),
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	}
+
+	return await builder.instruction();
+}
@@ -0,0 +1,28 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { Loan } from "../types";
+
+export type ForecloseLoanArgs = {
+	loan: Loan;
+};
+
+export async function forecloseLoan(args: ForecloseLoanArgs): Promise<TransactionInstruction> {
+	const { loan } = args;
+
+	return await this.carbon.program.methods
+		.forecloseLoan()
+		.accounts({
+			lender: loan.lender,
+			borrower: loan.borrower,
+			rentPayer: loan.payer,
+			loan: this.carbon.pdas.loan(loan.lender, loan.loanId),
+			borrowerTokenAccount: getAssociatedTokenAddressSync(loan.mint, loan.borrower),
+			lenderTokenAccount: getAssociatedTokenAddressSync(loan.mint, loan.lender),
+			mint: loan.mint,
+			edition: getEditionPDA(loan.mint),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.instruction();
+}
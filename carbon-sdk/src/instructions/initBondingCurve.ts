@@ -0,0 +1,43 @@
+import { BN } from "@coral-xyz/anchor";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Metadata } from "../types";
+
+export type InitBondingCurveArgs = {
+	marketplaceAuthority: PublicKey;
+	collectionMint: PublicKey;
+	itemType: number[];
+	// Metadata of every item minted by the curve
+	metadata: Metadata;
+	// Price of the first unit, in base units of the currency
+	basePrice: number;
+	// Price increase per unit in circulation
+	slope: number;
+	// Most units in circulation at once, unlimited if not given
+	maxSupply?: number;
+	// Share of a unit's price refunded when burned back, burning back is disabled if not given
+	refundBps?: number;
+	currencyMint?: PublicKey;
+};
+
+export async function initBondingCurve(args: InitBondingCurveArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, collectionMint, itemType, metadata, basePrice, slope } = args;
+	const collectionConfig = this.carbon.pdas.collectionConfig(collectionMint);
+
+	return await this.carbon.program.methods
+		.initBondingCurve({
+			itemType,
+			metadata,
+			basePrice: new BN(basePrice),
+			slope: new BN(slope),
+			maxSupply: new BN(args.maxSupply ?? 0),
+			refundBps: args.refundBps ?? 0,
+		})
+		.accounts({
+			marketplaceAuthority,
+			collectionConfig,
+			currencyMint: args.currencyMint ?? NATIVE_MINT,
+			bondingCurve: this.carbon.pdas.bondingCurve(collectionConfig, itemType),
+		})
+		.instruction();
+}
@@ -0,0 +1,31 @@
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type InitMarketArgs = {
+	marketplaceAuthority: PublicKey;
+	baseMint: PublicKey;
+	quoteMint: PublicKey;
+	// Base units of the base token in one lot
+	baseLotSize: number;
+};
+
+export async function initMarket(args: InitMarketArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, baseMint, quoteMint, baseLotSize } = args;
+	const market = this.carbon.pdas.market(baseMint, quoteMint, marketplaceAuthority);
+
+	return await this.carbon.program.methods
+		.initMarket(new BN(baseLotSize))
+		.accounts({
+			marketplaceAuthority,
+			marketplaceConfig: this.carbon.pdas.marketplaceConfig(marketplaceAuthority),
+			baseMint,
+			quoteMint,
+			market,
+			bids: this.carbon.pdas.bids(market),
+			asks: this.carbon.pdas.asks(market),
+			baseVault: getAssociatedTokenAddressSync(baseMint, market, true),
+			quoteVault: getAssociatedTokenAddressSync(quoteMint, market, true),
+		})
+		.instruction();
+}
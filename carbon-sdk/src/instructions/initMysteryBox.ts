@@ -0,0 +1,31 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Metadata, Prize, RandomnessSource } from "../types";
+
+export type InitMysteryBoxArgs = {
+	marketplaceAuthority: PublicKey;
+	collectionMint: PublicKey;
+	boxId: number[];
+	// Metadata of the box NFTs
+	metadata: Metadata;
+	prizes: Prize[];
+	randomnessSource?: RandomnessSource;
+};
+
+export async function initMysteryBox(args: InitMysteryBoxArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, collectionMint, boxId, metadata, prizes } = args;
+	const collectionConfig = this.carbon.pdas.collectionConfig(collectionMint);
+
+	return await this.carbon.program.methods
+		.initMysteryBox({
+			boxId,
+			metadata,
+			prizes,
+			randomnessSource: args.randomnessSource ?? { slotHashes: {} },
+		})
+		.accounts({
+			marketplaceAuthority,
+			collectionConfig,
+			mysteryBox: this.carbon.pdas.mysteryBox(collectionConfig, boxId),
+		})
+		.instruction();
+}
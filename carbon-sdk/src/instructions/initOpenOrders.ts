@@ -0,0 +1,22 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type InitOpenOrdersArgs = {
+	owner: PublicKey;
+	market: PublicKey;
+	// Wallet paying the rent of new accounts, the owner by default
+	payer?: PublicKey;
+};
+
+export async function initOpenOrders(args: InitOpenOrdersArgs): Promise<TransactionInstruction> {
+	const { owner, market } = args;
+
+	return await this.carbon.program.methods
+		.initOpenOrders()
+		.accounts({
+			owner,
+			payer: args.payer ?? null,
+			market,
+			openOrders: this.carbon.pdas.openOrders(market, owner),
+		})
+		.instruction();
+}
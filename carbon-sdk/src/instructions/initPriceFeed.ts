@@ -0,0 +1,18 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type InitPriceFeedArgs = {
+	marketplaceAuthority: PublicKey;
+	currencyMint: PublicKey;
+};
+
+export async function initPriceFeed(args: InitPriceFeedArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, currencyMint } = args;
+	return await this.carbon.program.methods
+		.initPriceFeed()
+		.accounts({
+			marketplaceAuthority,
+			currencyMint,
+			priceFeed: this.carbon.pdas.priceFeed(marketplaceAuthority, currencyMint),
+		})
+		.instruction();
+}
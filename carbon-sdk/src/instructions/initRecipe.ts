@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Metadata, RecipeInput } from "../types";
+
+export type InitRecipeArgs = {
+	marketplaceAuthority: PublicKey;
+	collectionMint: PublicKey;
+	recipeId: number[];
+	// Item types burned by crafting, matched by NFT name
+	inputs: RecipeInput[];
+	// Metadata of the crafted item
+	output: Metadata;
+};
+
+export async function initRecipe(args: InitRecipeArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, collectionMint, recipeId, inputs, output } = args;
+	const collectionConfig = this.carbon.pdas.collectionConfig(collectionMint);
+
+	return await this.carbon.program.methods
+		.initRecipe({ recipeId, inputs, output })
+		.accounts({
+			marketplaceAuthority,
+			collectionConfig,
+			recipe: this.carbon.pdas.recipe(collectionConfig, recipeId),
+		})
+		.instruction();
+}
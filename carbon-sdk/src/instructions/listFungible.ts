@@ -0,0 +1,40 @@
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, NATIVE_MINT } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type ListFungibleArgs = {
+	seller: PublicKey;
+	mint: PublicKey;
+	// Amount to sell, in base units of the token
+	amount: number;
+	// Price of one whole token, in base units of the currency
+	price: number;
+	expiry?: number;
+	currencyMint?: PublicKey;
+	// Wallet paying the rent of new accounts, the seller by default
+	payer?: PublicKey;
+};
+
+export async function listFungible(args: ListFungibleArgs): Promise<TransactionInstruction> {
+	const { seller, mint, amount, price } = args;
+	const marketplaceConfig = this.carbon.pdas.marketplaceConfig(this.carbon.marketplaceAuthority);
+	const listing = this.carbon.pdas.fungibleListing(seller, mint);
+
+	return await this.carbon.program.methods
+		.listFungible({
+			amount: new BN(amount),
+			price: new BN(price),
+			expiry: new BN(args.expiry ?? 0),
+		})
+		.accounts({
+			seller,
+			payer: args.payer ?? null,
+			tokenAccount: getAssociatedTokenAddressSync(mint, seller),
+			mint,
+			currencyMint: args.currencyMint ?? NATIVE_MINT,
+			listing,
+			escrowTokenAccount: getAssociatedTokenAddressSync(mint, listing, true),
+			marketplaceConfig,
+		})
+		.instruction();
+}
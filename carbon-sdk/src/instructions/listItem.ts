@@ -7,11 +7,26 @@ export type ListItemArgs = {
 	collectionMint: PublicKey;
 	price: number;
 	expiry: number;
+	startTime?: number;
 	currencyMint?: PublicKey;
+	priceFeed?: PublicKey;
+	// Wallet paying the rent of new accounts, the seller by default
+	payer?: PublicKey;
 };
 
 export async function listItem(args: ListItemArgs): Promise<TransactionInstruction> {
-	const { seller, tokenOwner, itemId, collectionMint, price, expiry, currencyMint } = args;
+	const {
+		seller,
+		tokenOwner,
+		itemId,
+		collectionMint,
+		price,
+		expiry,
+		startTime,
+		currencyMint,
+		priceFeed,
+		payer,
+	} = args;
 
 	const mintAccountInfo = await this.carbon.accounts.getAccountInfo(new PublicKey(itemId));
 
@@ -23,7 +38,10 @@ export async function listItem(args: ListItemArgs): Promise<TransactionInstructi
 			collectionMint,
 			price,
 			expiry,
+			startTime,
 			currencyMint,
+			priceFeed,
+			payer,
 		});
 	} else {
 		return await this.listNft({
@@ -33,7 +51,10 @@ export async function listItem(args: ListItemArgs): Promise<TransactionInstructi
 			collectionMint,
 			price,
 			expiry,
+			startTime,
 			currencyMint,
+			priceFeed,
+			payer,
 		});
 	}
 }
@@ -1,7 +1,17 @@
 import { BN } from "@coral-xyz/anchor";
-import { getAssociatedTokenAddressSync, NATIVE_MINT } from "@solana/spl-token";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	canFreezeWithEdition,
+	getMetadataPDA,
+	getEditionPDA,
+	getProgrammableAccounts,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 
 export type ListNftArgs = {
 	seller: PublicKey;
@@ -10,30 +20,76 @@ export type ListNftArgs = {
 	collectionMint: PublicKey;
 	price: number;
 	expiry: number;
+	startTime?: number;
 	currencyMint?: PublicKey;
+	priceFeed?: PublicKey;
+	// Proof of the mint for collections verified by Merkle root
+	proof?: number[][];
+	// Set for programmable NFTs, with the rule set of the NFT if it has one
+	programmable?: { ruleSet?: PublicKey };
+	// Session key signing for the seller, the seller by default
+	authority?: PublicKey;
+	// Wallet paying the rent of new accounts, the authority by default
+	payer?: PublicKey;
 	accounts?: any;
 };
 
 export async function listNft(args: ListNftArgs): Promise<TransactionInstruction> {
-	const { seller, mint, collectionMint, price, expiry, currencyMint, accounts } = args;
+	const {
+		seller,
+		mint,
+		collectionMint,
+		price,
+		expiry,
+		startTime,
+		currencyMint,
+		priceFeed,
+		proof,
+		programmable,
+		accounts,
+	} = args;
 	const tokenOwner = args.tokenOwner ?? seller;
+	const authority = args.authority ?? seller;
+	const tokenAccount = getAssociatedTokenAddressSync(mint, tokenOwner);
+	const listing = this.carbon.pdas.listing(Array.from(mint.toBytes()));
+
+	let remainingAccounts = [];
+	if (programmable) {
+		remainingAccounts = getProgrammableAccounts(mint, tokenAccount, programmable.ruleSet);
+	} else if (!(await canFreezeWithEdition(this.carbon.provider.connection, mint))) {
+		remainingAccounts = [
+			{
+				pubkey: getAssociatedTokenAddressSync(mint, listing, true),
+				isWritable: true,
+				isSigner: false,
+			},
+			{ pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isWritable: false, isSigner: false },
+		];
+	}
 
 	return await this.carbon.program.methods
-		.listNft(new BN(price), new BN(expiry))
+		.listNft(new BN(price), new BN(expiry), new BN(startTime ?? 0), proof ?? [])
 		.accounts({
 			seller,
-			tokenAccount: getAssociatedTokenAddressSync(mint, tokenOwner),
+			authority,
+			session: authority.equals(seller)
+				? PublicKey.default
+				: this.carbon.pdas.session(seller, authority),
+			payer: args.payer ?? null,
+			tokenAccount,
 			mint,
 			collectionMint,
 			metadataAccount: getMetadataPDA(mint),
 			edition: getEditionPDA(mint),
 			currencyMint: currencyMint ?? NATIVE_MINT,
-			listing: this.carbon.pdas.listing(Array.from(mint.toBytes())),
+			priceFeed: priceFeed ?? PublicKey.default,
+			listing,
 			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
 			marketplaceConfig: this.carbon.pdas.marketplaceConfig(this.carbon.marketplaceAuthority),
 			custodyAccount: this.carbon.pdas.custodyAccount(mint),
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 			...(accounts || {}),
 		})
+		.remainingAccounts(remainingAccounts)
 		.instruction();
 }
@@ -0,0 +1,36 @@
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+
+export type ListRentalArgs = {
+	owner: PublicKey;
+	mint: PublicKey;
+	pricePerDay: number;
+	maxDays: number;
+	applyRoyalties?: boolean;
+	// Wallet paying the rent of new accounts, the owner by default
+	payer?: PublicKey;
+};
+
+export async function listRental(args: ListRentalArgs): Promise<TransactionInstruction> {
+	const { owner, mint, pricePerDay, maxDays } = args;
+
+	return await this.carbon.program.methods
+		.listRental({
+			pricePerDay: new BN(pricePerDay),
+			maxDays,
+			applyRoyalties: args.applyRoyalties ?? false,
+		})
+		.accounts({
+			owner,
+			payer: args.payer ?? null,
+			tokenAccount: getAssociatedTokenAddressSync(mint, owner),
+			mint,
+			edition: getEditionPDA(mint),
+			rental: this.carbon.pdas.rental(mint),
+			marketplaceConfig: this.carbon.pdas.marketplaceConfig(this.carbon.marketplaceAuthority),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+		})
+		.instruction();
+}
@@ -9,21 +9,27 @@ export type ListVirtualArgs = {
 	collectionMint: PublicKey;
 	price: number;
 	expiry: number;
+	startTime?: number;
 	currencyMint?: PublicKey;
+	priceFeed?: PublicKey;
+	// Wallet paying the rent of new accounts, the seller by default
+	payer?: PublicKey;
 };
 
 export async function listVirtual(args: ListVirtualArgs): Promise<TransactionInstruction> {
-	const { itemId, price, expiry, collectionMint, currencyMint } = args;
+	const { itemId, price, expiry, startTime, collectionMint, currencyMint, priceFeed } = args;
 	const seller = args.seller ?? this.carbon.marketplaceAuthority;
 	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
 
 	return await this.carbon.program.methods
-		.listVirtual(itemId, new BN(price), new BN(expiry))
+		.listVirtual(itemId, new BN(price), new BN(expiry), new BN(startTime ?? 0))
 		.accounts({
 			seller,
+			payer: args.payer ?? null,
 			marketplaceAuthority,
 			currencyMint: currencyMint ?? NATIVE_MINT,
-			listing: this.carbon.pdas.listing(itemId),
+			priceFeed: priceFeed ?? PublicKey.default,
+			listing: this.carbon.pdas.listing(itemId, marketplaceAuthority),
 			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
 			marketplaceConfig: this.carbon.pdas.marketplaceConfig(marketplaceAuthority),
 		})
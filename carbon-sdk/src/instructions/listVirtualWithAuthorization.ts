@@ -0,0 +1,61 @@
+import { BN } from "@coral-xyz/anchor";
+import { NATIVE_MINT } from "@solana/spl-token";
+import {
+	Ed25519Program,
+	PublicKey,
+	SYSVAR_INSTRUCTIONS_PUBKEY,
+	TransactionInstruction,
+} from "@solana/web3.js";
+import { getAuthorizationMessage } from "..";
+import { Authorization } from "../types";
+
+export type ListVirtualWithAuthorizationArgs = {
+	marketplaceAuthority?: PublicKey;
+	authorization: Authorization;
+	// Signature of the authorization message by the marketplace authority
+	signature: Uint8Array;
+	collectionMint: PublicKey;
+	expiry: number;
+	startTime?: number;
+	currencyMint?: PublicKey;
+	priceFeed?: PublicKey;
+	// Wallet paying the rent of new accounts, the seller by default
+	payer?: PublicKey;
+};
+
+export async function listVirtualWithAuthorization(
+	args: ListVirtualWithAuthorizationArgs
+): Promise<TransactionInstruction[]> {
+	const { authorization, signature, expiry, startTime, collectionMint, currencyMint, priceFeed } =
+		args;
+	const seller = authorization.owner;
+	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
+
+	// The program checks the signature through the Ed25519 instruction right before its own
+	const signatureIx = Ed25519Program.createInstructionWithPublicKey({
+		publicKey: marketplaceAuthority.toBytes(),
+		message: getAuthorizationMessage(authorization),
+		signature,
+	});
+
+	const listIx = await this.carbon.program.methods
+		.listVirtualWithAuthorization(authorization, new BN(expiry), new BN(startTime ?? 0))
+		.accounts({
+			seller,
+			payer: args.payer ?? null,
+			marketplaceAuthority,
+			currencyMint: currencyMint ?? NATIVE_MINT,
+			priceFeed: priceFeed ?? PublicKey.default,
+			listing: this.carbon.pdas.listing(authorization.itemId, marketplaceAuthority),
+			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
+			marketplaceConfig: this.carbon.pdas.marketplaceConfig(marketplaceAuthority),
+			authorizationNonce: this.carbon.pdas.authorizationNonce(
+				authorization.nonce.toNumber(),
+				marketplaceAuthority
+			),
+			instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+		})
+		.instruction();
+
+	return [signatureIx, listIx];
+}
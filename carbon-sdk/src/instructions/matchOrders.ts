@@ -0,0 +1,61 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Market, OrderBookSide } from "../types";
+
+export type MatchOrdersArgs = {
+	cranker: PublicKey;
+	market: Market;
+	limit?: number;
+};
+
+export async function matchOrders(args: MatchOrdersArgs): Promise<TransactionInstruction> {
+	const { cranker, market } = args;
+	const marketAddress = this.carbon.pdas.market(
+		market.baseMint,
+		market.quoteMint,
+		market.marketplaceAuthority
+	);
+
+	const [bids, asks]: OrderBookSide[] = await Promise.all([
+		this.carbon.program.account.orderBookSide.fetch(market.bids),
+		this.carbon.program.account.orderBookSide.fetch(market.asks),
+	]);
+	const bidOrders = bids.orders.slice(0, bids.count.toNumber());
+	const askOrders = asks.orders.slice(0, asks.count.toNumber());
+
+	// Open orders accounts of every order priced to cross the other side of the book
+	const openOrders = new Set<string>();
+	if (bidOrders.length > 0 && askOrders.length > 0) {
+		bidOrders
+			.filter((order) => order.price.gte(askOrders[0].price))
+			.forEach((order) => openOrders.add(order.openOrders.toBase58()));
+		askOrders
+			.filter((order) => order.price.lte(bidOrders[0].price))
+			.forEach((order) => openOrders.add(order.openOrders.toBase58()));
+	}
+
+	return await this.carbon.program.methods
+		.matchOrders(args.limit ?? 8)
+		.accounts({
+			cranker,
+			market: marketAddress,
+			bids: market.bids,
+			asks: market.asks,
+			quoteMint: market.quoteMint,
+			quoteVault: getAssociatedTokenAddressSync(market.quoteMint, marketAddress, true),
+			feeAccount: market.feeConfig.feeAccount,
+			feeTokenAccount: getAssociatedTokenAddressSync(
+				market.quoteMint,
+				market.feeConfig.feeAccount,
+				true
+			),
+		})
+		.remainingAccounts(
+			Array.from(openOrders).map((key) => ({
+				pubkey: new PublicKey(key),
+				isWritable: true,
+				isSigner: false,
+			}))
+		)
+		.instruction();
+}
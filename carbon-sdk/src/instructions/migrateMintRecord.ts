@@ -0,0 +1,23 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type MigrateMintRecordArgs = {
+	marketplaceAuthority?: PublicKey;
+	collectionConfig: PublicKey;
+	itemId: number[];
+};
+
+export async function migrateMintRecord(
+	args: MigrateMintRecordArgs
+): Promise<TransactionInstruction> {
+	const { collectionConfig, itemId } = args;
+	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
+
+	return await this.carbon.program.methods
+		.migrateMintRecord()
+		.accounts({
+			marketplaceAuthority,
+			collectionConfig,
+			mintRecord: this.carbon.pdas.mintRecord(collectionConfig, itemId),
+		})
+		.instruction();
+}
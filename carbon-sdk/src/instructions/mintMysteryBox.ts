@@ -0,0 +1,48 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { PublicKey, Keypair, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { CollectionConfig, MysteryBox } from "../types";
+
+export type MintMysteryBoxArgs = {
+	buyer: PublicKey;
+	collectionConfig: CollectionConfig;
+	mysteryBox: MysteryBox;
+	itemId: number[];
+	// Hash of the secret seed revealed once the box is opened, see hashSeed
+	seedHash: number[];
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
+};
+
+export async function mintMysteryBox(
+	args: MintMysteryBoxArgs
+): Promise<{ mint: Keypair; instruction: TransactionInstruction }> {
+	const { buyer, collectionConfig, mysteryBox, itemId, seedHash } = args;
+	const collectionConfigPDA = this.carbon.pdas.collectionConfig(collectionConfig.collectionMint);
+
+	const mint = Keypair.generate();
+
+	const instruction = await this.carbon.program.methods
+		.mintMysteryBox(itemId, seedHash)
+		.accounts({
+			buyer,
+			payer: args.payer ?? null,
+			marketplaceAuthority: collectionConfig.marketplaceAuthority,
+			mint: mint.publicKey,
+			buyerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, buyer),
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			collectionConfig: collectionConfigPDA,
+			mysteryBox: this.carbon.pdas.mysteryBox(collectionConfigPDA, mysteryBox.boxId),
+			sealedBox: this.carbon.pdas.sealedBox(mint.publicKey),
+			mintRecord: this.carbon.pdas.mintRecord(collectionConfigPDA, itemId),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.instruction();
+
+	return { mint, instruction };
+}
@@ -1,9 +1,19 @@
 import { IdlTypes } from "@coral-xyz/anchor";
-import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { PublicKey, Keypair, TransactionInstruction } from "@solana/web3.js";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, PublicKey, Keypair, TransactionInstruction } from "@solana/web3.js";
 import { CarbonIDL } from "..";
-import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
-import { CollectionConfig } from "../types";
+import {
+	getCurrencyAccounts,
+	getMetadataPDA,
+	getEditionPDA,
+	getProgrammableAccounts,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
+import { CollectionConfig, PrimarySale } from "../types";
 
 export type MintVirtualArgs = {
 	marketplaceAuthority?: PublicKey;
@@ -11,6 +21,10 @@ export type MintVirtualArgs = {
 	itemId: number[];
 	collectionConfig: CollectionConfig;
 	metadata: IdlTypes<CarbonIDL.Carbon>["Metadata"];
+	// Price of this mint in place of the collection's primary sale, set by the marketplace authority
+	primarySale?: PrimarySale;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
 };
 
 export async function mintVirtual(
@@ -20,25 +34,69 @@ export async function mintVirtual(
 	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
 
 	const mint = Keypair.generate();
+	const buyerTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, buyer);
+	const { programmableNonFungible } = collectionConfig.mintStandard;
+	const primarySale = args.primarySale ?? collectionConfig.primarySale;
 
-	const builder = this.carbon.program.methods.mintVirtual(itemId, metadata).accounts({
-		buyer,
-		marketplaceAuthority,
-		mint: mint.publicKey,
-		collectionConfig: this.carbon.pdas.collectionConfig(collectionConfig.collectionMint),
-		mintRecord: this.carbon.pdas.mintRecord(
-			this.carbon.pdas.collectionConfig(collectionConfig.collectionMint),
-			itemId
-		),
-		buyerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, buyer),
-		metadataAccount: getMetadataPDA(mint.publicKey),
-		edition: getEditionPDA(mint.publicKey),
-		collectionMint: collectionConfig.collectionMint,
-		collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
-		collectionEdition: getEditionPDA(collectionConfig.collectionMint),
-		tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
-		associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-	});
+	const builder = this.carbon.program.methods
+		.mintVirtual(itemId, metadata, args.primarySale ?? null)
+		.accounts({
+			buyer,
+			payer: args.payer ?? null,
+			marketplaceAuthority,
+			mint: mint.publicKey,
+			collectionConfig: this.carbon.pdas.collectionConfig(collectionConfig.collectionMint),
+			marketplaceConfig: this.carbon.pdas.marketplaceConfig(marketplaceAuthority),
+			mintRecord: this.carbon.pdas.mintRecord(
+				this.carbon.pdas.collectionConfig(collectionConfig.collectionMint),
+				itemId
+			),
+			buyerTokenAccount,
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		});
+
+	const remainingAccounts: AccountMeta[] = [];
+	if (programmableNonFungible) {
+		remainingAccounts.push(
+			...getProgrammableAccounts(mint.publicKey, buyerTokenAccount, programmableNonFungible.ruleSet)
+		);
+	}
+
+	if (!primarySale.price.isZero()) {
+		const marketplaceConfig = await this.carbon.program.account.marketplaceConfig.fetch(
+			this.carbon.pdas.marketplaceConfig(marketplaceAuthority)
+		);
+		const { treasury, currencyMint } = primarySale;
+		const feeAccount = marketplaceConfig.feeConfig.feeAccount;
+
+		remainingAccounts.push(
+			{ pubkey: treasury, isWritable: true, isSigner: false },
+			{ pubkey: feeAccount, isWritable: true, isSigner: false }
+		);
+
+		if (!currencyMint.equals(NATIVE_MINT)) {
+			const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+				this.carbon.provider.connection,
+				currencyMint
+			);
+			remainingAccounts.push(
+				...currencyAccounts,
+				...[buyer, treasury, feeAccount].map((owner) => ({
+					pubkey: getAssociatedTokenAddressSync(currencyMint, owner, false, tokenProgram),
+					isWritable: true,
+					isSigner: false,
+				}))
+			);
+		}
+	}
+
+	builder.remainingAccounts(remainingAccounts);
 
 	return {
 		mint,
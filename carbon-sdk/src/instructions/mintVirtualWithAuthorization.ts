@@ -0,0 +1,78 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+	Ed25519Program,
+	Keypair,
+	PublicKey,
+	SYSVAR_INSTRUCTIONS_PUBKEY,
+	TransactionInstruction,
+} from "@solana/web3.js";
+import { getAuthorizationMessage } from "..";
+import {
+	getMetadataPDA,
+	getEditionPDA,
+	getCollectionAuthorityRecordPDA,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
+import { Authorization, CollectionConfig, Metadata } from "../types";
+
+export type MintVirtualWithAuthorizationArgs = {
+	marketplaceAuthority?: PublicKey;
+	authorization: Authorization;
+	// Signature of the authorization message by the marketplace authority
+	signature: Uint8Array;
+	collectionConfig: CollectionConfig;
+	metadata: Metadata;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
+};
+
+export async function mintVirtualWithAuthorization(
+	args: MintVirtualWithAuthorizationArgs
+): Promise<{ mint: Keypair; instructions: TransactionInstruction[] }> {
+	const { authorization, signature, metadata, collectionConfig } = args;
+	const buyer = authorization.owner;
+	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
+
+	const mint = Keypair.generate();
+	const collectionConfigPDA = this.carbon.pdas.collectionConfig(collectionConfig.collectionMint);
+
+	const signatureIx = Ed25519Program.createInstructionWithPublicKey({
+		publicKey: marketplaceAuthority.toBytes(),
+		message: getAuthorizationMessage(authorization),
+		signature,
+	});
+
+	const mintIx = await this.carbon.program.methods
+		.mintVirtualWithAuthorization(authorization, metadata)
+		.accounts({
+			buyer,
+			payer: args.payer ?? null,
+			marketplaceAuthority,
+			mint: mint.publicKey,
+			buyerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, buyer),
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			collectionAuthorityRecord: getCollectionAuthorityRecordPDA(
+				collectionConfig.collectionMint,
+				collectionConfigPDA
+			),
+			collectionConfig: collectionConfigPDA,
+			mintRecord: this.carbon.pdas.mintRecord(collectionConfigPDA, authorization.itemId),
+			authorizationNonce: this.carbon.pdas.authorizationNonce(
+				authorization.nonce.toNumber(),
+				marketplaceAuthority
+			),
+			instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.instruction();
+
+	return {
+		mint,
+		instructions: [signatureIx, mintIx],
+	};
+}
@@ -0,0 +1,59 @@
+import { BN } from "@coral-xyz/anchor";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type OfferLoanArgs = {
+	lender: PublicKey;
+	loanId: number[];
+	collectionMint: PublicKey;
+	principal: number;
+	interest: number;
+	duration: number;
+	currencyMint?: PublicKey;
+	// Wallet paying the rent of new accounts, the lender by default
+	payer?: PublicKey;
+};
+
+export async function offerLoan(args: OfferLoanArgs): Promise<TransactionInstruction> {
+	const { lender, loanId, collectionMint, principal, interest, duration } = args;
+	const currencyMint = args.currencyMint ?? NATIVE_MINT;
+	const loan = this.carbon.pdas.loan(lender, loanId);
+
+	const remainingAccounts: AccountMeta[] = [];
+	if (!currencyMint.equals(NATIVE_MINT)) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(currencyMint, lender),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(currencyMint, loan, true),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.offerLoan({
+			loanId,
+			principal: new BN(principal),
+			interest: new BN(interest),
+			duration: new BN(duration),
+		})
+		.accounts({
+			lender,
+			payer: args.payer ?? null,
+			loan,
+			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
+			currencyMint,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
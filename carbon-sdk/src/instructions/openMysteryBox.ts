@@ -0,0 +1,38 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, SYSVAR_SLOT_HASHES_PUBKEY, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { MysteryBox, SealedBox } from "../types";
+
+export type OpenMysteryBoxArgs = {
+	holder: PublicKey;
+	collectionMint: PublicKey;
+	mysteryBox: MysteryBox;
+	sealedBox: SealedBox;
+};
+
+export async function openMysteryBox(args: OpenMysteryBoxArgs): Promise<TransactionInstruction> {
+	const { holder, collectionMint, mysteryBox, sealedBox } = args;
+	const collectionConfig = this.carbon.pdas.collectionConfig(collectionMint);
+	const mint = sealedBox.mint;
+	const mintRecordPDA = this.carbon.pdas.mintRecord(collectionConfig, sealedBox.itemId);
+	const mintRecord = await this.carbon.program.account.mintRecord.fetch(mintRecordPDA);
+
+	return await this.carbon.program.methods
+		.openMysteryBox()
+		.accounts({
+			holder,
+			rentPayer: mintRecord.payer,
+			mint,
+			holderTokenAccount: getAssociatedTokenAddressSync(mint, holder),
+			metadataAccount: getMetadataPDA(mint),
+			edition: getEditionPDA(mint),
+			collectionMetadataAccount: getMetadataPDA(collectionMint),
+			collectionConfig,
+			mysteryBox: this.carbon.pdas.mysteryBox(collectionConfig, mysteryBox.boxId),
+			sealedBox: this.carbon.pdas.sealedBox(mint),
+			mintRecord: mintRecordPDA,
+			randomnessAccount: SYSVAR_SLOT_HASHES_PUBKEY,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+		})
+		.instruction();
+}
@@ -0,0 +1,55 @@
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Market, OrderBookSide, Side } from "../types";
+
+// Orders a side of the book holds, after which placing an order evicts the worst-priced one
+const MAX_ORDERS = 64;
+
+export type PlaceOrderArgs = {
+	owner: PublicKey;
+	market: Market;
+	side: Side;
+	// Quote units per lot
+	price: number;
+	// Lots to buy or sell
+	quantity: number;
+	// Wallet paying the rent of new accounts, the owner by default
+	payer?: PublicKey;
+};
+
+export async function placeOrder(args: PlaceOrderArgs): Promise<TransactionInstruction> {
+	const { owner, market, side, price, quantity } = args;
+	const marketAddress = this.carbon.pdas.market(
+		market.baseMint,
+		market.quoteMint,
+		market.marketplaceAuthority
+	);
+	const mint = side.bid ? market.quoteMint : market.baseMint;
+
+	// A full side needs the open orders account of the order the new one would evict
+	const book: OrderBookSide = await this.carbon.program.account.orderBookSide.fetch(
+		side.bid ? market.bids : market.asks
+	);
+	const count = book.count.toNumber();
+	const evictedOpenOrders =
+		count === MAX_ORDERS
+			? [{ pubkey: book.orders[count - 1].openOrders, isWritable: true, isSigner: false }]
+			: [];
+
+	return await this.carbon.program.methods
+		.placeOrder(side, new BN(price), new BN(quantity))
+		.accounts({
+			owner,
+			payer: args.payer ?? null,
+			market: marketAddress,
+			bids: market.bids,
+			asks: market.asks,
+			openOrders: this.carbon.pdas.openOrders(marketAddress, owner),
+			mint,
+			ownerTokenAccount: getAssociatedTokenAddressSync(mint, owner),
+			vault: getAssociatedTokenAddressSync(mint, marketAddress, true),
+		})
+		.remainingAccounts(evictedOpenOrders)
+		.instruction();
+}
@@ -0,0 +1,134 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import {
+	Ed25519Program,
+	Keypair,
+	PublicKey,
+	SYSVAR_INSTRUCTIONS_PUBKEY,
+	TransactionInstruction,
+} from "@solana/web3.js";
+import { getMintVoucherMessage } from "..";
+import {
+	getCurrencyAccounts,
+	getMetadataPDA,
+	getEditionPDA,
+	getCollectionAuthorityRecordPDA,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
+import { CollectionConfig, Metadata, MintVoucher } from "../types";
+
+export type RedeemMintVoucherArgs = {
+	marketplaceAuthority?: PublicKey;
+	buyer: PublicKey;
+	voucher: MintVoucher;
+	// Signature of the voucher message by the marketplace authority
+	signature: Uint8Array;
+	collectionConfig: CollectionConfig;
+	metadata: Metadata;
+	// Wallet paying the rent of new accounts, the buyer by default
+	payer?: PublicKey;
+};
+
+export async function redeemMintVoucher(
+	args: RedeemMintVoucherArgs
+): Promise<{ mint: Keypair; instructions: TransactionInstruction[] }> {
+	const { buyer, voucher, signature, metadata, collectionConfig } = args;
+	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
+
+	const mint = Keypair.generate();
+	const collectionConfigPDA = this.carbon.pdas.collectionConfig(collectionConfig.collectionMint);
+	const marketplaceConfig = await this.carbon.program.account.marketplaceConfig.fetch(
+		this.carbon.pdas.marketplaceConfig(marketplaceAuthority)
+	);
+	const feeAccount = marketplaceConfig.feeConfig.feeAccount;
+
+	const signatureIx = Ed25519Program.createInstructionWithPublicKey({
+		publicKey: marketplaceAuthority.toBytes(),
+		message: getMintVoucherMessage(voucher),
+		signature,
+	});
+
+	const builder = this.carbon.program.methods.redeemMintVoucher(voucher, metadata).accounts({
+		buyer,
+		payer: args.payer ?? null,
+		marketplaceAuthority,
+		mint: mint.publicKey,
+		buyerTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, buyer),
+		metadataAccount: getMetadataPDA(mint.publicKey),
+		edition: getEditionPDA(mint.publicKey),
+		collectionMint: collectionConfig.collectionMint,
+		collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+		collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+		collectionAuthorityRecord: getCollectionAuthorityRecordPDA(
+			collectionConfig.collectionMint,
+			collectionConfigPDA
+		),
+		collectionConfig: collectionConfigPDA,
+		marketplaceConfig: this.carbon.pdas.marketplaceConfig(marketplaceAuthority),
+		mintRecord: this.carbon.pdas.mintRecord(collectionConfigPDA, voucher.itemId),
+		authorizationNonce: this.carbon.pdas.authorizationNonce(
+			voucher.nonce.toNumber(),
+			marketplaceAuthority
+		),
+		feeAccount,
+		instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+		tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+		associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+	});
+
+	if (voucher.currencyMint.equals(NATIVE_MINT)) {
+		builder.remainingAccounts([
+			{
+				pubkey: marketplaceAuthority,
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	} else {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			voucher.currencyMint
+		);
+		builder.remainingAccounts([
+			...currencyAccounts,
+			{
+				pubkey: getAssociatedTokenAddressSync(voucher.currencyMint, buyer, false, tokenProgram),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: marketplaceAuthority,
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(
+					voucher.currencyMint,
+					marketplaceAuthority,
+					false,
+					tokenProgram
+				),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(
+					voucher.currencyMint,
+					feeAccount,
+					false,
+					tokenProgram
+				),
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	}
+
+	return {
+		mint,
+		instructions: [signatureIx, await builder.instruction()],
+	};
+}
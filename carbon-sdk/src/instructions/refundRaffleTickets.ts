@@ -0,0 +1,47 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCurrencyAccounts } from "../solana";
+import { Raffle, RaffleTicket } from "../types";
+
+export type RefundRaffleTicketsArgs = {
+	buyer: PublicKey;
+	raffle: Raffle;
+	ticket: RaffleTicket;
+};
+
+export async function refundRaffleTickets(
+	args: RefundRaffleTicketsArgs
+): Promise<TransactionInstruction> {
+	const { buyer, raffle, ticket } = args;
+	const raffleAddress = this.carbon.pdas.raffle(raffle.raffleId, raffle.marketplaceAuthority);
+
+	const builder = this.carbon.program.methods.refundRaffleTickets().accounts({
+		buyer,
+		rentPayer: ticket.payer,
+		raffleRentPayer: raffle.payer,
+		raffle: raffleAddress,
+		ticket: this.carbon.pdas.raffleTicket(raffleAddress, ticket.firstTicket),
+		associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+	});
+
+	if (!raffle.currencyMint.equals(NATIVE_MINT)) {
+		const { tokenProgram, accounts: currencyAccounts } = await getCurrencyAccounts(
+			this.carbon.provider.connection,
+			raffle.currencyMint
+		);
+		builder.remainingAccounts([
+			...currencyAccounts,
+			...[raffleAddress, buyer].map((owner) => ({
+				pubkey: getAssociatedTokenAddressSync(raffle.currencyMint, owner, true, tokenProgram),
+				isWritable: true,
+				isSigner: false,
+			})),
+		]);
+	}
+
+	return await builder.instruction();
+}
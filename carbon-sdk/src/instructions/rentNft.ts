@@ -0,0 +1,40 @@
+import { BN } from "@coral-xyz/anchor";
+import { Metadata as MplMetadata } from "@metaplex-foundation/mpl-token-metadata";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCreatorAccounts, getMetadataPDA } from "../solana";
+import { Rental } from "../types";
+
+export type RentNftArgs = {
+	renter: PublicKey;
+	rental: Rental;
+	days: number;
+	maxPrice?: number;
+};
+
+export async function rentNft(args: RentNftArgs): Promise<TransactionInstruction> {
+	const { renter, rental, days } = args;
+	const price = rental.pricePerDay.muln(days);
+	const metadataAccount = getMetadataPDA(rental.mint);
+
+	const builder = this.carbon.program.methods
+		.rentNft(days, args.maxPrice != null ? new BN(args.maxPrice) : price)
+		.accounts({
+			renter,
+			owner: rental.owner,
+			rental: this.carbon.pdas.rental(rental.mint),
+			mint: rental.mint,
+			metadataAccount,
+			feeAccount: rental.feeConfig.feeAccount,
+		});
+
+	if (rental.applyRoyalties) {
+		const metadata = await MplMetadata.fromAccountAddress(
+			this.carbon.provider.connection,
+			metadataAccount
+		);
+		const royalty = price.muln(metadata.data.sellerFeeBasisPoints).divn(10_000);
+		builder.remainingAccounts(getCreatorAccounts(metadata, royalty));
+	}
+
+	return await builder.instruction();
+}
@@ -0,0 +1,52 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { Loan } from "../types";
+
+export type RepayLoanArgs = {
+	loan: Loan;
+	// Wallet paying the rent of new accounts, the borrower by default
+	payer?: PublicKey;
+};
+
+export async function repayLoan(args: RepayLoanArgs): Promise<TransactionInstruction> {
+	const { loan } = args;
+
+	const remainingAccounts: AccountMeta[] = [];
+	if (!loan.currencyMint.equals(NATIVE_MINT)) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(loan.currencyMint, loan.borrower),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(loan.currencyMint, loan.lender),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.repayLoan()
+		.accounts({
+			borrower: loan.borrower,
+			payer: args.payer ?? null,
+			lender: loan.lender,
+			rentPayer: loan.payer,
+			loan: this.carbon.pdas.loan(loan.lender, loan.loanId),
+			tokenAccount: getAssociatedTokenAddressSync(loan.mint, loan.borrower),
+			mint: loan.mint,
+			edition: getEditionPDA(loan.mint),
+			currencyMint: loan.currencyMint,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
@@ -0,0 +1,48 @@
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, TransactionInstruction } from "@solana/web3.js";
+import { getMetadataPDA, getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { CollectionConfig, MysteryBox, SealedBox } from "../types";
+
+export type RevealMysteryBoxArgs = {
+	collectionConfig: CollectionConfig;
+	mysteryBox: MysteryBox;
+	sealedBox: SealedBox;
+	// Item ID of the prize
+	itemId: number[];
+	// Secret seed committed when the box was minted
+	seed: number[];
+};
+
+export async function revealMysteryBox(
+	args: RevealMysteryBoxArgs
+): Promise<{ mint: Keypair; instruction: TransactionInstruction }> {
+	const { collectionConfig, mysteryBox, sealedBox, itemId, seed } = args;
+	const collectionConfigPDA = this.carbon.pdas.collectionConfig(collectionConfig.collectionMint);
+	const holder = sealedBox.holder;
+
+	const mint = Keypair.generate();
+
+	const instruction = await this.carbon.program.methods
+		.revealMysteryBox(itemId, seed)
+		.accounts({
+			marketplaceAuthority: collectionConfig.marketplaceAuthority,
+			holder,
+			rentPayer: sealedBox.payer,
+			mint: mint.publicKey,
+			holderTokenAccount: getAssociatedTokenAddressSync(mint.publicKey, holder),
+			metadataAccount: getMetadataPDA(mint.publicKey),
+			edition: getEditionPDA(mint.publicKey),
+			collectionMint: collectionConfig.collectionMint,
+			collectionMetadataAccount: getMetadataPDA(collectionConfig.collectionMint),
+			collectionEdition: getEditionPDA(collectionConfig.collectionMint),
+			collectionConfig: collectionConfigPDA,
+			mysteryBox: this.carbon.pdas.mysteryBox(collectionConfigPDA, mysteryBox.boxId),
+			sealedBox: this.carbon.pdas.sealedBox(sealedBox.mint),
+			mintRecord: this.carbon.pdas.mintRecord(collectionConfigPDA, itemId),
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.instruction();
+
+	return { mint, instruction };
+}
@@ -0,0 +1,21 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Session } from "../types";
+
+export type RevokeSessionArgs = {
+	// The owner or the session key itself
+	authority: PublicKey;
+	session: Session;
+};
+
+export async function revokeSession(args: RevokeSessionArgs): Promise<TransactionInstruction> {
+	const { authority, session } = args;
+
+	return await this.carbon.program.methods
+		.revokeSession()
+		.accounts({
+			authority,
+			rentPayer: session.payer,
+			session: this.carbon.pdas.session(session.owner, session.sessionKey),
+		})
+		.instruction();
+}
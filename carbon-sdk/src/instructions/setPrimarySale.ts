@@ -0,0 +1,28 @@
+import { BN } from "@coral-xyz/anchor";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type SetPrimarySaleArgs = {
+	marketplaceAuthority: PublicKey;
+	collectionMint: PublicKey;
+	// Price of one item minted through mintVirtual, 0 to mint for free
+	price: number;
+	currencyMint?: PublicKey;
+	// Wallet receiving the price net of the marketplace fee, the marketplace authority by default
+	treasury?: PublicKey;
+};
+
+export async function setPrimarySale(args: SetPrimarySaleArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, collectionMint, price, currencyMint, treasury } = args;
+	return await this.carbon.program.methods
+		.setPrimarySale({
+			price: new BN(price),
+			currencyMint: currencyMint ?? NATIVE_MINT,
+			treasury: treasury ?? marketplaceAuthority,
+		})
+		.accounts({
+			marketplaceAuthority,
+			collectionConfig: this.carbon.pdas.collectionConfig(collectionMint),
+		})
+		.instruction();
+}
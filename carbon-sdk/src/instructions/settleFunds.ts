@@ -0,0 +1,35 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Market } from "../types";
+
+export type SettleFundsArgs = {
+	owner: PublicKey;
+	market: Market;
+	// Wallet paying the rent of new accounts, the owner by default
+	payer?: PublicKey;
+};
+
+export async function settleFunds(args: SettleFundsArgs): Promise<TransactionInstruction> {
+	const { owner, market } = args;
+	const marketAddress = this.carbon.pdas.market(
+		market.baseMint,
+		market.quoteMint,
+		market.marketplaceAuthority
+	);
+
+	return await this.carbon.program.methods
+		.settleFunds()
+		.accounts({
+			owner,
+			payer: args.payer ?? null,
+			market: marketAddress,
+			openOrders: this.carbon.pdas.openOrders(marketAddress, owner),
+			baseMint: market.baseMint,
+			quoteMint: market.quoteMint,
+			baseVault: getAssociatedTokenAddressSync(market.baseMint, marketAddress, true),
+			quoteVault: getAssociatedTokenAddressSync(market.quoteMint, marketAddress, true),
+			ownerBaseAccount: getAssociatedTokenAddressSync(market.baseMint, owner),
+			ownerQuoteAccount: getAssociatedTokenAddressSync(market.quoteMint, owner),
+		})
+		.instruction();
+}
@@ -0,0 +1,56 @@
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getEditionPDA, getMetadataPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import { Loan } from "../types";
+
+export type TakeLoanArgs = {
+	borrower: PublicKey;
+	loan: Loan;
+	mint: PublicKey;
+	// Wallet paying the rent of new accounts, the borrower by default
+	payer?: PublicKey;
+};
+
+export async function takeLoan(args: TakeLoanArgs): Promise<TransactionInstruction> {
+	const { borrower, loan, mint } = args;
+	const loanAddress = this.carbon.pdas.loan(loan.lender, loan.loanId);
+
+	const remainingAccounts: AccountMeta[] = [];
+	if (!loan.currencyMint.equals(NATIVE_MINT)) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(loan.currencyMint, loanAddress, true),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(loan.currencyMint, borrower),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.takeLoan()
+		.accounts({
+			borrower,
+			payer: args.payer ?? null,
+			lender: loan.lender,
+			rentPayer: loan.payer,
+			loan: loanAddress,
+			tokenAccount: getAssociatedTokenAddressSync(mint, borrower),
+			mint,
+			metadataAccount: getMetadataPDA(mint),
+			edition: getEditionPDA(mint),
+			currencyMint: loan.currencyMint,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
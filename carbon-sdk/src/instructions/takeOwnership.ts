@@ -1,33 +1,59 @@
 import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	getEditionPDA,
+	getMetadataPDA,
+	getProgrammableAccounts,
+	getRentPayer,
+	getTokenRecordPDA,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 import { CustodyAccount } from "../types";
 
 export type TakeOwnershipArgs = {
 	marketplaceAuthority?: PublicKey;
 	custodyAccount: CustodyAccount;
+	// Set for programmable NFTs, with the rule set of the NFT if it has one
+	programmable?: { ruleSet?: PublicKey };
 };
 
 export async function takeOwnership(args: TakeOwnershipArgs): Promise<TransactionInstruction> {
-	const { custodyAccount } = args;
+	const { custodyAccount, programmable } = args;
 	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
+	const { mint, owner } = custodyAccount;
+	const tokenAccount = getAssociatedTokenAddressSync(mint, owner);
+	const marketplaceAuthorityTokenAccount = getAssociatedTokenAddressSync(mint, marketplaceAuthority!);
 
 	return await this.carbon.program.methods
 		.takeOwnership()
 		.accounts({
 			marketplaceAuthority,
 			owner: custodyAccount.owner,
-			tokenAccount: getAssociatedTokenAddressSync(custodyAccount.mint, custodyAccount.owner),
-			marketplaceAuthorityTokenAccount: getAssociatedTokenAddressSync(
-				custodyAccount.mint,
-				marketplaceAuthority!
-			),
+			tokenAccount,
+			marketplaceAuthorityTokenAccount,
 			mint: custodyAccount.mint,
 			edition: getEditionPDA(custodyAccount.mint),
+			rentPayer: getRentPayer(custodyAccount, custodyAccount.owner),
 			custodyAccount: this.carbon.pdas.custodyAccount(custodyAccount.mint),
-			listing: this.carbon.pdas.listing(Array.from(custodyAccount.mint.toBuffer())),
+			listing: this.carbon.pdas.listing(
+				Array.from(custodyAccount.mint.toBuffer()),
+				custodyAccount.marketplaceAuthority
+			),
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
 		})
+		.remainingAccounts(
+			programmable
+				? [
+						{ pubkey: getMetadataPDA(mint), isWritable: true, isSigner: false },
+						...getProgrammableAccounts(mint, tokenAccount, programmable.ruleSet),
+						{
+							pubkey: getTokenRecordPDA(mint, marketplaceAuthorityTokenAccount),
+							isWritable: true,
+							isSigner: false,
+						},
+				  ]
+				: []
+		)
 		.instruction();
 }
@@ -0,0 +1,118 @@
+import { Metadata as MplMetadata } from "@metaplex-foundation/mpl-token-metadata";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { AccountMeta, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+	getCreatorAccounts as getMetadataCreatorAccounts,
+	getEditionPDA,
+	getMetadataPDA,
+	getRentPayer,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
+import { Swap } from "../types";
+
+export type TakeSwapArgs = {
+	taker: PublicKey;
+	swap: Swap;
+	// Wallet paying the rent of new accounts, the taker by default
+	payer?: PublicKey;
+};
+
+export async function takeSwap(args: TakeSwapArgs): Promise<TransactionInstruction> {
+	const { taker, swap } = args;
+	const swapAddress = this.carbon.pdas.swap(swap.maker, swap.swapId);
+
+	const getCreatorAccounts = async (mint: PublicKey): Promise<AccountMeta[]> => {
+		if (swap.royaltyPerNft.isZero()) {
+			return [];
+		}
+
+		const metadata = await MplMetadata.fromAccountAddress(
+			this.carbon.provider.connection,
+			getMetadataPDA(mint)
+		);
+		return getMetadataCreatorAccounts(metadata, swap.royaltyPerNft);
+	};
+
+	const remainingAccounts: AccountMeta[] = [];
+
+	for (const mint of swap.makerMints) {
+		// Any account will do as the custody rent payer when the NFT is not custodied
+		const custodyAccount = await this.carbon.accounts.custodyAccount(mint);
+		remainingAccounts.push(
+			{ pubkey: mint, isWritable: false, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(mint, swap.maker),
+				isWritable: true,
+				isSigner: false,
+			},
+			{ pubkey: getAssociatedTokenAddressSync(mint, taker), isWritable: true, isSigner: false },
+			{ pubkey: getEditionPDA(mint), isWritable: false, isSigner: false },
+			{ pubkey: this.carbon.pdas.custodyAccount(mint), isWritable: true, isSigner: false },
+			{ pubkey: getMetadataPDA(mint), isWritable: false, isSigner: false },
+			{
+				pubkey: custodyAccount
+					? getRentPayer(custodyAccount, custodyAccount.owner)
+					: swap.maker,
+				isWritable: true,
+				isSigner: false,
+			},
+			...(await getCreatorAccounts(mint))
+		);
+	}
+
+	for (const mint of swap.takerMints) {
+		remainingAccounts.push(
+			{ pubkey: mint, isWritable: false, isSigner: false },
+			{ pubkey: getAssociatedTokenAddressSync(mint, taker), isWritable: true, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(mint, swap.maker),
+				isWritable: true,
+				isSigner: false,
+			},
+			{ pubkey: getMetadataPDA(mint), isWritable: false, isSigner: false },
+			...(await getCreatorAccounts(mint))
+		);
+	}
+
+	if (
+		!swap.currencyMint.equals(NATIVE_MINT) &&
+		(swap.takerAmount.gtn(0) || swap.makerAmount.gtn(0))
+	) {
+		remainingAccounts.push(
+			{
+				pubkey: getAssociatedTokenAddressSync(swap.currencyMint, taker),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(swap.currencyMint, swap.maker),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(swap.currencyMint, swapAddress, true),
+				isWritable: true,
+				isSigner: false,
+			}
+		);
+	}
+
+	return await this.carbon.program.methods
+		.takeSwap()
+		.accounts({
+			taker,
+			payer: args.payer ?? null,
+			maker: swap.maker,
+			rentPayer: swap.payer,
+			swap: swapAddress,
+			currencyMint: swap.currencyMint,
+			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		})
+		.remainingAccounts(remainingAccounts)
+		.instruction();
+}
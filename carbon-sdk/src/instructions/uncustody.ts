@@ -1,16 +1,25 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getEditionPDA, TOKEN_METADATA_PROGRAM_ID } from "../solana";
+import {
+	getEditionPDA,
+	getMetadataPDA,
+	getProgrammableAccounts,
+	getRentPayer,
+	TOKEN_METADATA_PROGRAM_ID,
+} from "../solana";
 import { CustodyAccount } from "../types";
 
 export type UncustodyArgs = {
 	marketplaceAuthority?: PublicKey;
 	owner: PublicKey;
 	custodyAccount: CustodyAccount;
+	// Set for programmable NFTs, with the rule set of the NFT if it has one
+	programmable?: { ruleSet?: PublicKey };
 };
 
 export async function uncustody(args: UncustodyArgs): Promise<TransactionInstruction> {
-	const { owner, custodyAccount } = args;
+	const { owner, custodyAccount, programmable } = args;
+	const tokenAccount = getAssociatedTokenAddressSync(custodyAccount.mint, owner);
 	const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.marketplaceAuthority;
 
 	return await this.carbon.program.methods
@@ -18,12 +27,24 @@ export async function uncustody(args: UncustodyArgs): Promise<TransactionInstruc
 		.accounts({
 			owner,
 			marketplaceAuthority,
-			tokenAccount: getAssociatedTokenAddressSync(custodyAccount.mint, owner),
+			tokenAccount,
 			mint: custodyAccount.mint,
 			edition: getEditionPDA(custodyAccount.mint),
+			rentPayer: getRentPayer(custodyAccount, custodyAccount.owner),
 			custodyAccount: this.carbon.pdas.custodyAccount(custodyAccount.mint),
-			listing: this.carbon.pdas.listing(Array.from(custodyAccount.mint.toBuffer())),
+			listing: this.carbon.pdas.listing(
+				Array.from(custodyAccount.mint.toBuffer()),
+				custodyAccount.marketplaceAuthority
+			),
 			tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
 		})
+		.remainingAccounts(
+			programmable
+				? [
+						{ pubkey: getMetadataPDA(custodyAccount.mint), isWritable: true, isSigner: false },
+						...getProgrammableAccounts(custodyAccount.mint, tokenAccount, programmable.ruleSet),
+				  ]
+				: []
+		)
 		.instruction();
 }
@@ -6,16 +6,24 @@ export type UpdateListingArgs = {
 	listing: PublicKey;
 	price: number;
 	expiry: number;
+	startTime?: number;
+	// Session key signing for the seller, the seller by default
+	authority?: PublicKey;
 };
 
 export async function updateListing(args: UpdateListingArgs): Promise<TransactionInstruction> {
-	const { listing, price, expiry } = args;
+	const { listing, price, expiry, startTime } = args;
 	const seller = args.seller ?? this.carbon.marketplaceAuthority;
+	const authority = args.authority ?? seller;
 
 	return await this.carbon.program.methods
-		.updateListing(new BN(price), new BN(expiry))
+		.updateListing(new BN(price), new BN(expiry), new BN(startTime ?? 0))
 		.accounts({
 			seller,
+			authority,
+			session: authority.equals(seller)
+				? PublicKey.default
+				: this.carbon.pdas.session(seller, authority),
 			listing,
 		})
 		.instruction();
@@ -0,0 +1,21 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+export type UpdatePriceFeedArgs = {
+	marketplaceAuthority: PublicKey;
+	currencyMint: PublicKey;
+	price: number;
+	conf: number;
+	expo: number;
+};
+
+export async function updatePriceFeed(args: UpdatePriceFeedArgs): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, currencyMint, price, conf, expo } = args;
+	return await this.carbon.program.methods
+		.updatePriceFeed(new BN(price), new BN(conf), expo)
+		.accounts({
+			marketplaceAuthority,
+			priceFeed: this.carbon.pdas.priceFeed(marketplaceAuthority, currencyMint),
+		})
+		.instruction();
+}
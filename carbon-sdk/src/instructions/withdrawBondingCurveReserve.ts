@@ -0,0 +1,50 @@
+import { BN } from "@coral-xyz/anchor";
+import {
+	getAssociatedTokenAddressSync,
+	ASSOCIATED_TOKEN_PROGRAM_ID,
+	NATIVE_MINT,
+} from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { BondingCurve } from "../types";
+
+export type WithdrawBondingCurveReserveArgs = {
+	marketplaceAuthority: PublicKey;
+	bondingCurve: BondingCurve;
+	amount: number;
+};
+
+export async function withdrawBondingCurveReserve(
+	args: WithdrawBondingCurveReserveArgs
+): Promise<TransactionInstruction> {
+	const { marketplaceAuthority, bondingCurve, amount } = args;
+	const bondingCurvePDA = this.carbon.pdas.bondingCurve(
+		bondingCurve.collectionConfig,
+		bondingCurve.itemType
+	);
+
+	const builder = this.carbon.program.methods
+		.withdrawBondingCurveReserve(new BN(amount))
+		.accounts({
+			marketplaceAuthority,
+			bondingCurve: bondingCurvePDA,
+			associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+		});
+
+	if (!bondingCurve.currencyMint.equals(NATIVE_MINT)) {
+		builder.remainingAccounts([
+			{ pubkey: bondingCurve.currencyMint, isWritable: false, isSigner: false },
+			{
+				pubkey: getAssociatedTokenAddressSync(bondingCurve.currencyMint, bondingCurvePDA, true),
+				isWritable: true,
+				isSigner: false,
+			},
+			{
+				pubkey: getAssociatedTokenAddressSync(bondingCurve.currencyMint, marketplaceAuthority),
+				isWritable: true,
+				isSigner: false,
+			},
+		]);
+	}
+
+	return await builder.instruction();
+}
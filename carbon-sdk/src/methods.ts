@@ -20,7 +20,53 @@ import { ListVirtualArgs } from "./instructions/listVirtual";
 import { TakeOwnershipArgs } from "./instructions/takeOwnership";
 import { UncustodyArgs } from "./instructions/uncustody";
 import { CloseMintRecordArgs } from "./instructions/closeMintRecord";
+import { MigrateMintRecordArgs } from "./instructions/migrateMintRecord";
 import { UpdateListingArgs } from "./instructions/updateListing";
+import { InitPriceFeedArgs } from "./instructions/initPriceFeed";
+import { UpdatePriceFeedArgs } from "./instructions/updatePriceFeed";
+import { CloseExpiredListingArgs } from "./instructions/closeExpiredListing";
+import { CreateSwapArgs } from "./instructions/createSwap";
+import { TakeSwapArgs } from "./instructions/takeSwap";
+import { CancelSwapArgs } from "./instructions/cancelSwap";
+import { ListRentalArgs } from "./instructions/listRental";
+import { RentNftArgs } from "./instructions/rentNft";
+import { EndRentalArgs } from "./instructions/endRental";
+import { OfferLoanArgs } from "./instructions/offerLoan";
+import { TakeLoanArgs } from "./instructions/takeLoan";
+import { RepayLoanArgs } from "./instructions/repayLoan";
+import { ForecloseLoanArgs } from "./instructions/forecloseLoan";
+import { CancelLoanOfferArgs } from "./instructions/cancelLoanOffer";
+import { ListFungibleArgs } from "./instructions/listFungible";
+import { BuyFungibleArgs } from "./instructions/buyFungible";
+import { DelistFungibleArgs } from "./instructions/delistFungible";
+import { InitMarketArgs } from "./instructions/initMarket";
+import { InitOpenOrdersArgs } from "./instructions/initOpenOrders";
+import { PlaceOrderArgs } from "./instructions/placeOrder";
+import { CancelOrderArgs } from "./instructions/cancelOrder";
+import { MatchOrdersArgs } from "./instructions/matchOrders";
+import { SettleFundsArgs } from "./instructions/settleFunds";
+import { InitBondingCurveArgs } from "./instructions/initBondingCurve";
+import { BurnBondingCurveArgs } from "./instructions/burnBondingCurve";
+import { WithdrawBondingCurveReserveArgs } from "./instructions/withdrawBondingCurveReserve";
+import { InitRecipeArgs } from "./instructions/initRecipe";
+import { InitMysteryBoxArgs } from "./instructions/initMysteryBox";
+import { OpenMysteryBoxArgs } from "./instructions/openMysteryBox";
+import { CreateRaffleArgs } from "./instructions/createRaffle";
+import { CreateVirtualRaffleArgs } from "./instructions/createVirtualRaffle";
+import { BuyRaffleTicketsArgs } from "./instructions/buyRaffleTickets";
+import { CloseRaffleArgs } from "./instructions/closeRaffle";
+import { DrawRaffleArgs } from "./instructions/drawRaffle";
+import { ClaimRaffleNftArgs } from "./instructions/claimRaffleNft";
+import { CloseRaffleTicketArgs } from "./instructions/closeRaffleTicket";
+import { CancelRaffleArgs } from "./instructions/cancelRaffle";
+import { ExpireRaffleArgs } from "./instructions/expireRaffle";
+import { RefundRaffleTicketsArgs } from "./instructions/refundRaffleTickets";
+import { CreateSessionArgs } from "./instructions/createSession";
+import { RevokeSessionArgs } from "./instructions/revokeSession";
+import { ListVirtualWithAuthorizationArgs } from "./instructions/listVirtualWithAuthorization";
+import { CloseAuthorizationNonceArgs } from "./instructions/closeAuthorizationNonce";
+import { ApproveCollectionMintingArgs } from "./instructions/approveCollectionMinting";
+import { SetPrimarySaleArgs } from "./instructions/setPrimarySale";
 import { getComputeIxs } from "./solana";
 
 export class Methods {
@@ -81,33 +127,43 @@ export class Methods {
 	// delistOrBuyItem may require a signature from the mint if buying a virtual item so
 	// transaction helpers should be used instead
 
-	async listNft(args: Omit<ListNftArgs, "seller"> & { seller?: Wallet }): Promise<string> {
+	async listNft(
+		args: Omit<ListNftArgs, "seller" | "authority"> & { seller?: Wallet; authority?: Wallet }
+	): Promise<string> {
 		const seller = args.seller ?? this.carbon.provider.wallet;
+		const authority = args.authority ?? seller;
 		const ix = await this.carbon.instructions.listNft({
 			...args,
 			seller: seller.publicKey,
+			authority: authority.publicKey,
 		});
-		return await this.sendIxWithWallet(ix, seller);
+		return await this.sendIxWithWallet(ix, authority);
 	}
 
 	async updateListing(
-		args: Omit<UpdateListingArgs, "seller"> & { seller?: Wallet }
+		args: Omit<UpdateListingArgs, "seller" | "authority"> & { seller?: Wallet; authority?: Wallet }
 	): Promise<string> {
 		const seller = args.seller ?? this.carbon.provider.wallet;
+		const authority = args.authority ?? seller;
 		const ix = await this.carbon.instructions.updateListing({
 			...args,
 			seller: seller.publicKey,
+			authority: authority.publicKey,
 		});
-		return await this.sendIxWithWallet(ix, seller);
+		return await this.sendIxWithWallet(ix, authority);
 	}
 
-	async delistNft(args: Omit<DelistNftArgs, "seller"> & { seller?: Wallet }): Promise<string> {
+	async delistNft(
+		args: Omit<DelistNftArgs, "seller" | "authority"> & { seller?: Wallet; authority?: Wallet }
+	): Promise<string> {
 		const seller = args.seller ?? this.carbon.provider.wallet;
+		const authority = args.authority ?? seller;
 		const ix = await this.carbon.instructions.delistNft({
 			...args,
 			seller: seller.publicKey,
+			authority: authority.publicKey,
 		});
-		return await this.sendIxWithWallet(ix, seller);
+		return await this.sendIxWithWallet(ix, authority);
 	}
 
 	async buyNft(args: Omit<BuyNftArgs, "buyer"> & { buyer?: Wallet }): Promise<string> {
@@ -161,13 +217,17 @@ export class Methods {
 	// buyVirtual requires a signature from the buyer and marketplace authority so the
 	// instruction or transaction helpers should be used instead
 
-	async custody(args: Omit<CustodyArgs, "owner"> & { owner?: Wallet }): Promise<string> {
+	async custody(
+		args: Omit<CustodyArgs, "owner" | "authority"> & { owner?: Wallet; authority?: Wallet }
+	): Promise<string> {
 		const owner = args.owner ?? this.carbon.provider.wallet;
+		const authority = args.authority ?? owner;
 		const ix = await this.carbon.instructions.custody({
 			...args,
 			owner: owner.publicKey,
+			authority: authority.publicKey,
 		});
-		return await this.sendIxWithWallet(ix, owner);
+		return await this.sendIxWithWallet(ix, authority);
 	}
 
 	async uncustody(args: Omit<UncustodyArgs, "owner"> & { owner?: Wallet }): Promise<string> {
@@ -201,6 +261,470 @@ export class Methods {
 		return await this.sendIxWithWallet(ix, marketplaceAuthority);
 	}
 
+	async migrateMintRecord(
+		args: Omit<MigrateMintRecordArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.migrateMintRecord({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async initPriceFeed(
+		args: Omit<InitPriceFeedArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.initPriceFeed({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async updatePriceFeed(
+		args: Omit<UpdatePriceFeedArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.updatePriceFeed({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async closeExpiredListing(
+		args: Omit<CloseExpiredListingArgs, "keeper"> & { keeper?: Wallet }
+	): Promise<string> {
+		const keeper = args.keeper ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.closeExpiredListing({
+			...args,
+			keeper: keeper.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, keeper, 300_000);
+	}
+
+	async createSwap(args: Omit<CreateSwapArgs, "maker"> & { maker?: Wallet }): Promise<string> {
+		const maker = args.maker ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.createSwap({
+			...args,
+			maker: maker.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, maker, 400_000);
+	}
+
+	async takeSwap(args: Omit<TakeSwapArgs, "taker"> & { taker?: Wallet }): Promise<string> {
+		const taker = args.taker ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.takeSwap({
+			...args,
+			taker: taker.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, taker, 600_000);
+	}
+
+	async cancelSwap(args: CancelSwapArgs & { maker?: Wallet }): Promise<string> {
+		const maker = args.maker ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.cancelSwap(args);
+		return await this.sendIxWithWallet(ix, maker, 400_000);
+	}
+
+	async listRental(args: Omit<ListRentalArgs, "owner"> & { owner?: Wallet }): Promise<string> {
+		const owner = args.owner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.listRental({
+			...args,
+			owner: owner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, owner);
+	}
+
+	async rentNft(args: Omit<RentNftArgs, "renter"> & { renter?: Wallet }): Promise<string> {
+		const renter = args.renter ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.rentNft({
+			...args,
+			renter: renter.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, renter);
+	}
+
+	async endRental(args: Omit<EndRentalArgs, "authority"> & { authority?: Wallet }): Promise<string> {
+		const authority = args.authority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.endRental({
+			...args,
+			authority: authority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, authority);
+	}
+
+	async offerLoan(args: Omit<OfferLoanArgs, "lender"> & { lender?: Wallet }): Promise<string> {
+		const lender = args.lender ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.offerLoan({
+			...args,
+			lender: lender.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, lender);
+	}
+
+	async takeLoan(args: Omit<TakeLoanArgs, "borrower"> & { borrower?: Wallet }): Promise<string> {
+		const borrower = args.borrower ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.takeLoan({
+			...args,
+			borrower: borrower.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, borrower);
+	}
+
+	async repayLoan(args: RepayLoanArgs & { borrower?: Wallet }): Promise<string> {
+		const borrower = args.borrower ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.repayLoan(args);
+		return await this.sendIxWithWallet(ix, borrower);
+	}
+
+	async forecloseLoan(args: ForecloseLoanArgs & { lender?: Wallet }): Promise<string> {
+		const lender = args.lender ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.forecloseLoan(args);
+		return await this.sendIxWithWallet(ix, lender);
+	}
+
+	async cancelLoanOffer(args: CancelLoanOfferArgs & { lender?: Wallet }): Promise<string> {
+		const lender = args.lender ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.cancelLoanOffer(args);
+		return await this.sendIxWithWallet(ix, lender);
+	}
+
+	async listFungible(args: Omit<ListFungibleArgs, "seller"> & { seller?: Wallet }): Promise<string> {
+		const seller = args.seller ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.listFungible({
+			...args,
+			seller: seller.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, seller);
+	}
+
+	async buyFungible(args: Omit<BuyFungibleArgs, "buyer"> & { buyer?: Wallet }): Promise<string> {
+		const buyer = args.buyer ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.buyFungible({
+			...args,
+			buyer: buyer.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, buyer);
+	}
+
+	async delistFungible(
+		args: Omit<DelistFungibleArgs, "seller"> & { seller?: Wallet }
+	): Promise<string> {
+		const seller = args.seller ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.delistFungible({
+			...args,
+			seller: seller.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, seller);
+	}
+
+	async initMarket(
+		args: Omit<InitMarketArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.initMarket({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async initOpenOrders(
+		args: Omit<InitOpenOrdersArgs, "owner"> & { owner?: Wallet }
+	): Promise<string> {
+		const owner = args.owner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.initOpenOrders({
+			...args,
+			owner: owner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, owner);
+	}
+
+	async placeOrder(args: Omit<PlaceOrderArgs, "owner"> & { owner?: Wallet }): Promise<string> {
+		const owner = args.owner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.placeOrder({
+			...args,
+			owner: owner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, owner);
+	}
+
+	async cancelOrder(args: Omit<CancelOrderArgs, "owner"> & { owner?: Wallet }): Promise<string> {
+		const owner = args.owner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.cancelOrder({
+			...args,
+			owner: owner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, owner);
+	}
+
+	async matchOrders(
+		args: Omit<MatchOrdersArgs, "cranker"> & { cranker?: Wallet }
+	): Promise<string> {
+		const cranker = args.cranker ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.matchOrders({
+			...args,
+			cranker: cranker.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, cranker, 400_000);
+	}
+
+	async settleFunds(args: Omit<SettleFundsArgs, "owner"> & { owner?: Wallet }): Promise<string> {
+		const owner = args.owner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.settleFunds({
+			...args,
+			owner: owner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, owner);
+	}
+
+	async initBondingCurve(
+		args: Omit<InitBondingCurveArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.initBondingCurve({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	// buyBondingCurve requires a signature from the buyer and marketplace authority so the
+	// instruction should be used instead
+
+	async burnBondingCurve(
+		args: Omit<BurnBondingCurveArgs, "holder"> & { holder?: Wallet }
+	): Promise<string> {
+		const holder = args.holder ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.burnBondingCurve({
+			...args,
+			holder: holder.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, holder, 300_000);
+	}
+
+	async withdrawBondingCurveReserve(
+		args: Omit<WithdrawBondingCurveReserveArgs, "marketplaceAuthority"> & {
+			marketplaceAuthority?: Wallet;
+		}
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.withdrawBondingCurveReserve({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async initRecipe(
+		args: Omit<InitRecipeArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.initRecipe({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	// craft requires a signature from the player and marketplace authority so the instruction
+	// should be used instead
+
+	async initMysteryBox(
+		args: Omit<InitMysteryBoxArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.initMysteryBox({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	// mintMysteryBox requires a signature from the buyer and marketplace authority, and
+	// revealMysteryBox from the new prize mint, so the instructions should be used instead
+
+	async openMysteryBox(
+		args: Omit<OpenMysteryBoxArgs, "holder"> & { holder?: Wallet }
+	): Promise<string> {
+		const holder = args.holder ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.openMysteryBox({
+			...args,
+			holder: holder.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, holder);
+	}
+
+	async createRaffle(
+		args: Omit<CreateRaffleArgs, "creator"> & { creator?: Wallet }
+	): Promise<string> {
+		const creator = args.creator ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.createRaffle({
+			...args,
+			creator: creator.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, creator);
+	}
+
+	async createVirtualRaffle(
+		args: Omit<CreateVirtualRaffleArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.createVirtualRaffle({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async buyRaffleTickets(
+		args: Omit<BuyRaffleTicketsArgs, "buyer"> & { buyer?: Wallet }
+	): Promise<string> {
+		const buyer = args.buyer ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.buyRaffleTickets({
+			...args,
+			buyer: buyer.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, buyer);
+	}
+
+	async closeRaffle(
+		args: Omit<CloseRaffleArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.closeRaffle({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async drawRaffle(
+		args: Omit<DrawRaffleArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.drawRaffle({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
+	async claimRaffleNft(
+		args: Omit<ClaimRaffleNftArgs, "winner"> & { winner?: Wallet }
+	): Promise<string> {
+		const winner = args.winner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.claimRaffleNft({
+			...args,
+			winner: winner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, winner);
+	}
+
+	// claimRaffleVirtual requires a signature from the winner, marketplace authority and new mint,
+	// so the instruction should be used instead
+
+	async closeRaffleTicket(
+		args: Omit<CloseRaffleTicketArgs, "buyer"> & { buyer?: Wallet }
+	): Promise<string> {
+		const buyer = args.buyer ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.closeRaffleTicket({
+			...args,
+			buyer: buyer.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, buyer);
+	}
+
+	async cancelRaffle(
+		args: Omit<CancelRaffleArgs, "creator"> & { creator?: Wallet }
+	): Promise<string> {
+		const creator = args.creator ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.cancelRaffle({
+			...args,
+			creator: creator.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, creator);
+	}
+
+	// Anyone can expire a raffle, the wallet only pays the transaction fee
+	async expireRaffle(args: ExpireRaffleArgs & { wallet?: Wallet }): Promise<string> {
+		const wallet = args.wallet ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.expireRaffle(args);
+		return await this.sendIxWithWallet(ix, wallet);
+	}
+
+	async refundRaffleTickets(
+		args: Omit<RefundRaffleTicketsArgs, "buyer"> & { buyer?: Wallet }
+	): Promise<string> {
+		const buyer = args.buyer ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.refundRaffleTickets({
+			...args,
+			buyer: buyer.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, buyer);
+	}
+
+	async createSession(
+		args: Omit<CreateSessionArgs, "owner"> & { owner?: Wallet }
+	): Promise<string> {
+		const owner = args.owner ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.createSession({
+			...args,
+			owner: owner.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, owner);
+	}
+
+	async revokeSession(
+		args: Omit<RevokeSessionArgs, "authority"> & { authority?: Wallet }
+	): Promise<string> {
+		const authority = args.authority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.revokeSession({
+			...args,
+			authority: authority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, authority);
+	}
+
+	// Sent by the seller, with the marketplace authority only signing the authorization
+	async listVirtualWithAuthorization(
+		args: ListVirtualWithAuthorizationArgs & { seller: Wallet }
+	): Promise<string> {
+		const { seller, ...ixArgs } = args;
+		const ixs = await this.carbon.instructions.listVirtualWithAuthorization(ixArgs);
+		return await this.sendIxsWithWallet(ixs, seller);
+	}
+
+	// mintVirtualWithAuthorization and redeemMintVoucher require a signature from the new mint, so
+	// the instructions should be used instead
+
+	async closeAuthorizationNonce(
+		args: CloseAuthorizationNonceArgs & { keeper?: Wallet }
+	): Promise<string> {
+		const { keeper = this.carbon.provider.wallet, ...ixArgs } = args;
+		const ix = await this.carbon.instructions.closeAuthorizationNonce(ixArgs);
+		return await this.sendIxWithWallet(ix, keeper);
+	}
+
+	async approveCollectionMinting(args: ApproveCollectionMintingArgs): Promise<string> {
+		const ix = await this.carbon.instructions.approveCollectionMinting(args);
+		return await this.sendIxWithWallet(ix, this.carbon.provider.wallet);
+	}
+
+	async setPrimarySale(
+		args: Omit<SetPrimarySaleArgs, "marketplaceAuthority"> & { marketplaceAuthority?: Wallet }
+	): Promise<string> {
+		const marketplaceAuthority = args.marketplaceAuthority ?? this.carbon.provider.wallet;
+		const ix = await this.carbon.instructions.setPrimarySale({
+			...args,
+			marketplaceAuthority: marketplaceAuthority.publicKey,
+		});
+		return await this.sendIxWithWallet(ix, marketplaceAuthority);
+	}
+
 	async getListingsForMarketplaceAuthority(
 		marketplaceAuthority: PublicKey
 	): Promise<ListingWithKey[]> {
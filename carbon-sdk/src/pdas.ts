@@ -1,5 +1,8 @@
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
+import { sha256 } from "js-sha256";
 import Carbon from "./carbon";
+import { Listing } from "./types";
 
 export class Pdas {
 	constructor(public carbon: Carbon) {}
@@ -18,13 +21,34 @@ export class Pdas {
 		)[0];
 	}
 
-	listing(itemId: number[]): PublicKey {
+	listing(
+		itemId: number[],
+		marketplaceAuthority: PublicKey = this.carbon.marketplaceAuthority
+	): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("listing"),
+				this.marketplaceConfig(marketplaceAuthority).toBuffer(),
+				Buffer.from(itemId),
+			],
+			this.carbon.programId
+		)[0];
+	}
+
+	// Address of listings created before listings were namespaced by marketplace
+	legacyListing(itemId: number[]): PublicKey {
 		return PublicKey.findProgramAddressSync(
 			[Buffer.from("listing"), Buffer.from(itemId)],
 			this.carbon.programId
 		)[0];
 	}
 
+	listingAddress(listing: Listing): PublicKey {
+		return listing.namespace.equals(PublicKey.default)
+			? this.legacyListing(listing.itemId)
+			: this.listing(listing.itemId, listing.marketplaceAuthority);
+	}
+
 	custodyAccount(mint: PublicKey): PublicKey {
 		return PublicKey.findProgramAddressSync(
 			[Buffer.from("custody_account"), mint.toBuffer()],
@@ -32,6 +56,172 @@ export class Pdas {
 		)[0];
 	}
 
+	priceFeed(marketplaceAuthority: PublicKey, currencyMint: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("price_feed"), marketplaceAuthority.toBuffer(), currencyMint.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	swap(maker: PublicKey, swapId: number[]): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("swap"), maker.toBuffer(), Buffer.from(swapId)],
+			this.carbon.programId
+		)[0];
+	}
+
+	rental(mint: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("rental"), mint.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	loan(lender: PublicKey, loanId: number[]): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("loan"), lender.toBuffer(), Buffer.from(loanId)],
+			this.carbon.programId
+		)[0];
+	}
+
+	fungibleListing(
+		seller: PublicKey,
+		mint: PublicKey,
+		marketplaceAuthority: PublicKey = this.carbon.marketplaceAuthority
+	): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("fungible_listing"),
+				this.marketplaceConfig(marketplaceAuthority).toBuffer(),
+				seller.toBuffer(),
+				mint.toBuffer(),
+			],
+			this.carbon.programId
+		)[0];
+	}
+
+	market(
+		baseMint: PublicKey,
+		quoteMint: PublicKey,
+		marketplaceAuthority: PublicKey = this.carbon.marketplaceAuthority
+	): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("market"),
+				this.marketplaceConfig(marketplaceAuthority).toBuffer(),
+				baseMint.toBuffer(),
+				quoteMint.toBuffer(),
+			],
+			this.carbon.programId
+		)[0];
+	}
+
+	bids(market: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("bids"), market.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	asks(market: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("asks"), market.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	openOrders(market: PublicKey, owner: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("open_orders"), market.toBuffer(), owner.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	bondingCurve(collectionConfig: PublicKey, itemType: number[]): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("bonding_curve"), collectionConfig.toBuffer(), Buffer.from(itemType)],
+			this.carbon.programId
+		)[0];
+	}
+
+	// Item ID of the unit of a bonding curve with the serial
+	bondingCurveItemId(itemType: number[], serial: number): number[] {
+		return sha256.array(
+			Buffer.concat([
+				Buffer.from("bonding_curve"),
+				Buffer.from(itemType),
+				new BN(serial).toArrayLike(Buffer, "le", 8),
+			])
+		);
+	}
+
+	recipe(collectionConfig: PublicKey, recipeId: number[]): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("recipe"), collectionConfig.toBuffer(), Buffer.from(recipeId)],
+			this.carbon.programId
+		)[0];
+	}
+
+	mysteryBox(collectionConfig: PublicKey, boxId: number[]): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("mystery_box"), collectionConfig.toBuffer(), Buffer.from(boxId)],
+			this.carbon.programId
+		)[0];
+	}
+
+	sealedBox(mint: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("sealed_box"), mint.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	raffle(
+		raffleId: number[],
+		marketplaceAuthority: PublicKey = this.carbon.marketplaceAuthority
+	): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("raffle"),
+				this.marketplaceConfig(marketplaceAuthority).toBuffer(),
+				Buffer.from(raffleId),
+			],
+			this.carbon.programId
+		)[0];
+	}
+
+	raffleTicket(raffle: PublicKey, firstTicket: number): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("raffle_ticket"),
+				raffle.toBuffer(),
+				new BN(firstTicket).toArrayLike(Buffer, "le", 4),
+			],
+			this.carbon.programId
+		)[0];
+	}
+
+	session(owner: PublicKey, sessionKey: PublicKey): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[Buffer.from("session"), owner.toBuffer(), sessionKey.toBuffer()],
+			this.carbon.programId
+		)[0];
+	}
+
+	authorizationNonce(
+		nonce: number,
+		marketplaceAuthority: PublicKey = this.carbon.marketplaceAuthority
+	): PublicKey {
+		return PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("authorization_nonce"),
+				marketplaceAuthority.toBuffer(),
+				new BN(nonce).toArrayLike(Buffer, "le", 8),
+			],
+			this.carbon.programId
+		)[0];
+	}
+
 	mintRecord(collectionConfig: PublicKey, itemId: number[]): PublicKey {
 		return PublicKey.findProgramAddressSync(
 			[Buffer.from("mint_record"), collectionConfig.toBuffer(), Buffer.from(itemId)],
@@ -1,10 +1,23 @@
-import { ComputeBudgetProgram, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { Metadata as MplMetadata } from "@metaplex-foundation/mpl-token-metadata";
+import { getMint, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+	AccountMeta,
+	ComputeBudgetProgram,
+	Connection,
+	PublicKey,
+	SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import { Buffer } from "buffer";
 
 export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
 	"metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
 );
 
+export const AUTHORIZATION_RULES_PROGRAM_ID = new PublicKey(
+	"auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg"
+);
+
 export function getEditionPDA(mint: PublicKey) {
 	return PublicKey.findProgramAddressSync(
 		[
@@ -24,6 +37,89 @@ export function getMetadataPDA(mint: PublicKey) {
 	)[0];
 }
 
+export function getTokenRecordPDA(mint: PublicKey, tokenAccount: PublicKey) {
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("metadata", "utf8"),
+			TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+			mint.toBuffer(),
+			Buffer.from("token_record", "utf8"),
+			tokenAccount.toBuffer(),
+		],
+		TOKEN_METADATA_PROGRAM_ID
+	)[0];
+}
+
+export function getCollectionAuthorityRecordPDA(mint: PublicKey, authority: PublicKey) {
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("metadata", "utf8"),
+			TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+			mint.toBuffer(),
+			Buffer.from("collection_authority", "utf8"),
+			authority.toBuffer(),
+		],
+		TOKEN_METADATA_PROGRAM_ID
+	)[0];
+}
+
+// Wallet that paid the rent of an account, falling back to its owner for accounts created before
+// payers were tracked
+export function getRentPayer(account: { payer: PublicKey }, owner: PublicKey) {
+	return account.payer.equals(PublicKey.default) ? owner : account.payer;
+}
+
+// NFTs whose edition is not the freeze authority of the mint are escrowed when listed
+export async function canFreezeWithEdition(connection: Connection, mint: PublicKey) {
+	const mintInfo = await getMint(connection, mint);
+	return mintInfo.freezeAuthority?.equals(getEditionPDA(mint)) ?? false;
+}
+
+// Token record and rule set accounts the program needs to act on a programmable NFT
+export function getProgrammableAccounts(
+	mint: PublicKey,
+	tokenAccount: PublicKey,
+	ruleSet?: PublicKey | null
+): AccountMeta[] {
+	const rules = ruleSet && !ruleSet.equals(PublicKey.default) ? ruleSet : null;
+	return [
+		{ pubkey: getTokenRecordPDA(mint, tokenAccount), isWritable: true, isSigner: false },
+		{
+			pubkey: rules ? AUTHORIZATION_RULES_PROGRAM_ID : TOKEN_METADATA_PROGRAM_ID,
+			isWritable: false,
+			isSigner: false,
+		},
+		{ pubkey: rules ?? TOKEN_METADATA_PROGRAM_ID, isWritable: false, isSigner: false },
+		{ pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isWritable: false, isSigner: false },
+	];
+}
+
+// Currency mint followed by the Token-2022 program for Token-2022 currencies, in the order the
+// program reads them, along with the token program owning the currency
+export async function getCurrencyAccounts(
+	connection: Connection,
+	currencyMint: PublicKey
+): Promise<{ tokenProgram: PublicKey; accounts: AccountMeta[] }> {
+	const mintInfo = await connection.getAccountInfo(currencyMint);
+	const tokenProgram = mintInfo?.owner.equals(TOKEN_2022_PROGRAM_ID)
+		? TOKEN_2022_PROGRAM_ID
+		: TOKEN_PROGRAM_ID;
+
+	const accounts: AccountMeta[] = [{ pubkey: currencyMint, isWritable: false, isSigner: false }];
+	if (tokenProgram.equals(TOKEN_2022_PROGRAM_ID)) {
+		accounts.push({ pubkey: TOKEN_2022_PROGRAM_ID, isWritable: false, isSigner: false });
+	}
+
+	return { tokenProgram, accounts };
+}
+
+// Creators receiving a share of a royalty, in the order the program pays them
+export function getCreatorAccounts(metadata: MplMetadata, royalty: BN): AccountMeta[] {
+	return (metadata.data.creators ?? [])
+		.filter((creator) => royalty.muln(creator.share).divn(100).gtn(0))
+		.map((creator) => ({ pubkey: creator.address, isWritable: true, isSigner: false }));
+}
+
 export const GLOBAL_SETTINGS = {
 	targetPriorityFeeLamports: 10_000,
 };
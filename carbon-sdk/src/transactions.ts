@@ -12,6 +12,7 @@ import { ListVirtualArgs } from "./instructions/listVirtual";
 import { MintVirtualArgs } from "./instructions/mintVirtual";
 import { Listing, Metadata } from "./types";
 import { BurnArgs } from "./instructions/burnAndCloseMintRecord";
+import { getRentPayer } from "./solana";
 
 export type DelistOrBuyItemArgs = {
 	listing: Listing;
@@ -39,6 +40,7 @@ export class Transactions {
 					await carbon.instructions.delistVirtual({
 						seller: carbon.marketplaceAuthority,
 						itemId: listing.itemId,
+						rentPayer: getRentPayer(listing, listing.seller),
 					})
 				);
 			} else {
@@ -64,6 +66,8 @@ export class Transactions {
 									mint: buyVirtualTxInfo.mint.publicKey,
 									collectionConfig,
 									itemId: listing.itemId,
+									// The marketplace authority bought the item, so it paid the rent
+									payer: carbon.marketplaceAuthority,
 								},
 							},
 							listing
@@ -82,6 +86,8 @@ export class Transactions {
 						seller: carbon.marketplaceAuthority,
 						mint: new PublicKey(listing.itemId),
 						tokenOwner: custodyAccount?.owner ?? listing.seller,
+						isEscrowed: listing.isEscrowed,
+						rentPayer: getRentPayer(listing, listing.seller),
 					})
 				);
 
@@ -134,7 +140,17 @@ export class Transactions {
 	}
 
 	async listVirtual(args: ListVirtualArgs, recentBlockhash?: string): Promise<Transaction> {
-		const { seller, itemId, collectionMint, price, expiry, currencyMint } = args;
+		const {
+			seller,
+			itemId,
+			collectionMint,
+			price,
+			expiry,
+			startTime,
+			currencyMint,
+			priceFeed,
+			payer,
+		} = args;
 
 		const tx = new Transaction();
 
@@ -144,7 +160,10 @@ export class Transactions {
 			collectionMint,
 			price,
 			expiry,
+			startTime,
 			currencyMint,
+			priceFeed,
+			payer,
 		});
 
 		tx.add(listIx);
@@ -158,7 +177,7 @@ export class Transactions {
 		args: BuyVirtualArgs,
 		recentBlockhash?: string
 	): Promise<{ mint: Keypair; transaction: Transaction }> {
-		const { buyer, collectionConfig, listing, metadata, maxPrice } = args;
+		const { buyer, collectionConfig, listing, metadata, maxPrice, payer } = args;
 
 		const tx = new Transaction().add(
 			ComputeBudgetProgram.setComputeUnitLimit({
@@ -172,6 +191,7 @@ export class Transactions {
 			listing,
 			metadata,
 			maxPrice,
+			payer,
 		});
 
 		tx.add(buyVirtualIxInfo.instruction);
@@ -191,7 +211,7 @@ export class Transactions {
 		args: BuyVirtualArgs,
 		recentBlockhash?: string
 	): Promise<{ mint: Keypair; transaction: Transaction }> {
-		const { buyer, collectionConfig, listing, metadata, maxPrice } = args;
+		const { buyer, collectionConfig, listing, metadata, maxPrice, payer } = args;
 
 		const tx = new Transaction().add(
 			ComputeBudgetProgram.setComputeUnitLimit({
@@ -205,6 +225,7 @@ export class Transactions {
 			listing,
 			metadata,
 			maxPrice,
+			payer,
 		});
 
 		tx.add(buyVirtualIxInfo.instruction);
@@ -213,6 +234,7 @@ export class Transactions {
 			owner: buyer,
 			mint: buyVirtualIxInfo.mint.publicKey,
 			itemId: listing.itemId,
+			payer,
 		});
 
 		tx.add(custodyIx);
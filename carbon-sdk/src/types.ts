@@ -7,8 +7,34 @@ export type CollectionConfig = IdlAccounts<Carbon>["collectionConfig"];
 export type Listing = IdlAccounts<Carbon>["listing"];
 export type CustodyAccount = IdlAccounts<Carbon>["custodyAccount"];
 export type MintRecord = IdlAccounts<Carbon>["mintRecord"];
+export type PriceFeed = IdlAccounts<Carbon>["priceFeed"];
+export type Swap = IdlAccounts<Carbon>["swap"];
+export type Rental = IdlAccounts<Carbon>["rental"];
+export type Loan = IdlAccounts<Carbon>["loan"];
+export type FungibleListing = IdlAccounts<Carbon>["fungibleListing"];
+export type Market = IdlAccounts<Carbon>["market"];
+export type OrderBookSide = IdlAccounts<Carbon>["orderBookSide"];
+export type OpenOrders = IdlAccounts<Carbon>["openOrders"];
+export type BondingCurve = IdlAccounts<Carbon>["bondingCurve"];
+export type Recipe = IdlAccounts<Carbon>["recipe"];
+export type MysteryBox = IdlAccounts<Carbon>["mysteryBox"];
+export type SealedBox = IdlAccounts<Carbon>["sealedBox"];
+export type Raffle = IdlAccounts<Carbon>["raffle"];
+export type RaffleTicket = IdlAccounts<Carbon>["raffleTicket"];
+export type Session = IdlAccounts<Carbon>["session"];
+export type AuthorizationNonce = IdlAccounts<Carbon>["authorizationNonce"];
 
 export type Metadata = IdlTypes<Carbon>["Metadata"];
+export type Side = IdlTypes<Carbon>["Side"];
+export type RecipeInput = IdlTypes<Carbon>["RecipeInput"];
+export type Prize = IdlTypes<Carbon>["Prize"];
+export type RandomnessSource = IdlTypes<Carbon>["RandomnessSource"];
+export type RaffleState = IdlTypes<Carbon>["RaffleState"];
+export type SessionScope = IdlTypes<Carbon>["SessionScope"];
+export type Authorization = IdlTypes<Carbon>["Authorization"];
+export type AuthorizedAction = IdlTypes<Carbon>["AuthorizedAction"];
+export type MintVoucher = IdlTypes<Carbon>["MintVoucher"];
+export type PrimarySale = IdlTypes<Carbon>["PrimarySale"];
 
 export type ListingWithKey = {
 	publicKey: PublicKey;
@@ -26,3 +52,31 @@ export type MintEvent = IdlEvents<Carbon>["Mint"];
 export type DelistEvent = IdlEvents<Carbon>["Delist"];
 export type CustodyEvent = IdlEvents<Carbon>["Custody"];
 export type UncustodyEvent = IdlEvents<Carbon>["Uncustody"];
+export type SwapCreateEvent = IdlEvents<Carbon>["SwapCreate"];
+export type SwapTakeEvent = IdlEvents<Carbon>["SwapTake"];
+export type SwapCancelEvent = IdlEvents<Carbon>["SwapCancel"];
+export type RentalListEvent = IdlEvents<Carbon>["RentalList"];
+export type RentEvent = IdlEvents<Carbon>["Rent"];
+export type RentalEndEvent = IdlEvents<Carbon>["RentalEnd"];
+export type LoanOfferEvent = IdlEvents<Carbon>["LoanOffer"];
+export type LoanTakeEvent = IdlEvents<Carbon>["LoanTake"];
+export type LoanRepayEvent = IdlEvents<Carbon>["LoanRepay"];
+export type LoanForecloseEvent = IdlEvents<Carbon>["LoanForeclose"];
+export type LoanCancelEvent = IdlEvents<Carbon>["LoanCancel"];
+export type OrderPlaceEvent = IdlEvents<Carbon>["OrderPlace"];
+export type OrderCancelEvent = IdlEvents<Carbon>["OrderCancel"];
+export type OrderFillEvent = IdlEvents<Carbon>["OrderFill"];
+export type BondingCurveBuyEvent = IdlEvents<Carbon>["BondingCurveBuy"];
+export type BondingCurveBurnEvent = IdlEvents<Carbon>["BondingCurveBurn"];
+export type CraftEvent = IdlEvents<Carbon>["Craft"];
+export type MysteryBoxOpenEvent = IdlEvents<Carbon>["MysteryBoxOpen"];
+export type MysteryBoxRevealEvent = IdlEvents<Carbon>["MysteryBoxReveal"];
+export type RaffleCreateEvent = IdlEvents<Carbon>["RaffleCreate"];
+export type RaffleTicketBuyEvent = IdlEvents<Carbon>["RaffleTicketBuy"];
+export type RaffleDrawEvent = IdlEvents<Carbon>["RaffleDraw"];
+export type RaffleClaimEvent = IdlEvents<Carbon>["RaffleClaim"];
+export type RaffleCancelEvent = IdlEvents<Carbon>["RaffleCancel"];
+export type RaffleExpireEvent = IdlEvents<Carbon>["RaffleExpire"];
+export type RaffleTicketRefundEvent = IdlEvents<Carbon>["RaffleTicketRefund"];
+export type SessionCreateEvent = IdlEvents<Carbon>["SessionCreate"];
+export type SessionRevokeEvent = IdlEvents<Carbon>["SessionRevoke"];